@@ -0,0 +1,251 @@
+use std::any::Any;
+use std::collections::HashMap;
+
+use crate::graph::{with_vertices_of, DirectedEdge, Edge, SimpleGraph, UndirectedEdge};
+use crate::provide::{Edges, Graph, Vertices};
+use crate::storage::GraphStorage;
+
+/// Orients each edge of an undirected graph from the vertex with lower id to the vertex with higher id.
+///
+/// Every directed path visits vertices in increasing order of their ids, so the result is always acyclic.
+///
+/// # Arguments
+/// * `graph`: Undirected graph to orient its edges.
+/// * `storage`: Empty directed storage that the new graph uses.
+///
+/// # Returns
+/// The directed graph. Vertices keep their ids and edges keep their weights, but edges may get new ids.
+///
+/// # Complexity
+/// O(|V| + |E|) calls to `add_vertex`, `remove_vertex` and `add_edge` of the new graph.
+///
+/// # Panics
+/// If `storage` is not empty.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::{Mat, DiMat};
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::acyclic_orientation;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b
+/// //       \   /
+/// //         c
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 2.into());
+/// graph.add_edge(c, a, 3.into());
+///
+/// // When: Orienting the edges.
+/// let oriented = acyclic_orientation(&graph, DiMat::<usize>::init());
+///
+/// // Then:
+/// assert!(oriented.has_any_edge(a, c).unwrap());
+/// assert!(!oriented.has_any_edge(c, a).unwrap());
+/// ```
+pub fn acyclic_orientation<W, E, E2, G, S2>(
+    graph: &G,
+    storage: S2,
+) -> SimpleGraph<W, E2, DirectedEdge, S2>
+where
+    W: Clone + Any,
+    E: Edge<W>,
+    E2: Edge<W>,
+    G: Edges<W, E> + Vertices + Graph<W, E, UndirectedEdge>,
+    S2: GraphStorage<W, E2, DirectedEdge>,
+{
+    let mut order = graph.vertices();
+    order.sort_unstable();
+
+    acyclic_orientation_by(graph, storage, &order)
+}
+
+/// Orients each edge of an undirected graph from the vertex that comes first in `order` to the one that comes later.
+///
+/// Every directed path visits vertices in the same order as they appear in `order`, so the result is always acyclic.
+/// Every acyclic orientation of the graph can be produced by some order(for example a topological sort of it).
+///
+/// # Arguments
+/// * `graph`: Undirected graph to orient its edges.
+/// * `storage`: Empty directed storage that the new graph uses.
+/// * `order`: Id of the vertices of the graph, each appearing once.
+///
+/// # Returns
+/// The directed graph. Vertices keep their ids and edges keep their weights, but edges may get new ids.
+///
+/// # Complexity
+/// O(|V| + |E|) calls to `add_vertex`, `remove_vertex` and `add_edge` of the new graph.
+///
+/// # Panics
+/// * If `storage` is not empty.
+/// * If a vertex of the graph does not appear in `order`.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::{Mat, DiMat};
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::acyclic_orientation_by;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b --- c
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+///
+/// // When: Orienting the edges away from b.
+/// let oriented = acyclic_orientation_by(&graph, DiMat::<usize>::init(), &[b, a, c]);
+///
+/// // Then:
+/// assert!(oriented.has_any_edge(b, a).unwrap());
+/// assert!(oriented.has_any_edge(b, c).unwrap());
+/// ```
+pub fn acyclic_orientation_by<W, E, E2, G, S2>(
+    graph: &G,
+    storage: S2,
+    order: &[usize],
+) -> SimpleGraph<W, E2, DirectedEdge, S2>
+where
+    W: Clone + Any,
+    E: Edge<W>,
+    E2: Edge<W>,
+    G: Edges<W, E> + Vertices + Graph<W, E, UndirectedEdge>,
+    S2: GraphStorage<W, E2, DirectedEdge>,
+{
+    let position_of: HashMap<usize, usize> = order
+        .iter()
+        .enumerate()
+        .map(|(position, vertex_id)| (*vertex_id, position))
+        .collect();
+
+    let mut oriented = with_vertices_of(graph, storage);
+
+    for (src_id, dst_id, edge) in graph.edges() {
+        let (src_id, dst_id) = if position_of[&src_id] < position_of[&dst_id] {
+            (src_id, dst_id)
+        } else {
+            (dst_id, src_id)
+        };
+
+        oriented
+            .add_edge(src_id, dst_id, E2::init(edge.get_weight().clone()))
+            .unwrap();
+    }
+
+    oriented
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::HasCycle;
+    use crate::graph::MatGraph;
+    use crate::storage::{DiList, DiMat, Mat};
+
+    // Given: Graph
+    //
+    //      a --1-- b --2-- c
+    //      |     / |     /
+    //      3   4   5   6
+    //      | /     | /
+    //      d --7-- e
+    //
+    fn graph() -> MatGraph<usize, UndirectedEdge> {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 2.into()).unwrap();
+        graph.add_edge(a, d, 3.into()).unwrap();
+        graph.add_edge(b, d, 4.into()).unwrap();
+        graph.add_edge(b, e, 5.into()).unwrap();
+        graph.add_edge(c, e, 6.into()).unwrap();
+        graph.add_edge(d, e, 7.into()).unwrap();
+
+        graph
+    }
+
+    // Edges as (smaller id, larger id, weight), so orientation is ignored.
+    fn edge_set<G: Edges<usize, E>, E: Edge<usize>>(graph: &G) -> Vec<(usize, usize, usize)> {
+        let mut edges: Vec<(usize, usize, usize)> = graph
+            .edges()
+            .into_iter()
+            .map(|(src_id, dst_id, edge)| {
+                (
+                    src_id.min(dst_id),
+                    src_id.max(dst_id),
+                    *edge.get_weight().as_ref().unwrap(),
+                )
+            })
+            .collect();
+        edges.sort_unstable();
+        edges
+    }
+
+    #[test]
+    fn orientation_by_id() {
+        let mut graph = graph();
+        graph.remove_vertex(0).unwrap();
+
+        // When: Orienting the graph that has lost vertex a.
+        let oriented = acyclic_orientation(&graph, DiMat::<usize>::init());
+
+        // Then:
+        assert_eq!(oriented.vertices(), graph.vertices());
+        assert_eq!(edge_set(&oriented), edge_set(&graph));
+        assert!(oriented
+            .edges()
+            .into_iter()
+            .all(|(src_id, dst_id, _)| src_id < dst_id));
+        assert!(HasCycle::init(&oriented).execute(&oriented).is_none());
+    }
+
+    #[test]
+    fn orientation_by_order() {
+        let graph = graph();
+        let orders = vec![
+            vec![0, 1, 2, 3, 4],
+            vec![4, 3, 2, 1, 0],
+            vec![2, 0, 4, 1, 3],
+            vec![1, 4, 0, 3, 2],
+        ];
+
+        for order in orders {
+            // When: Orienting the graph.
+            let oriented = acyclic_orientation_by(&graph, DiList::<usize>::init(), &order);
+
+            // Then: Edges are the same as the original graph, and each one goes forward in the order.
+            assert_eq!(edge_set(&oriented), edge_set(&graph));
+            for (src_id, dst_id, _) in oriented.edges() {
+                let src_position = order.iter().position(|v_id| *v_id == src_id).unwrap();
+                let dst_position = order.iter().position(|v_id| *v_id == dst_id).unwrap();
+
+                assert!(src_position < dst_position);
+            }
+            assert!(HasCycle::init(&oriented).execute(&oriented).is_none());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn incomplete_order() {
+        let graph = graph();
+
+        acyclic_orientation_by(&graph, DiMat::<usize>::init(), &[0, 1, 2]);
+    }
+}
@@ -0,0 +1,152 @@
+use magnitude::Magnitude;
+use std::any::Any;
+
+use crate::graph::{Edge, EdgeDir};
+use crate::provide::{Edges, Graph, IdMap, Vertices};
+
+/// Adjacency of each vertex by its virtual id, in the format of (neighbor virtual id, weight).
+pub type AdjacencyList<W> = Vec<Vec<(usize, Magnitude<W>)>>;
+
+/// Exports the graph as a plain nested adjacency list.
+///
+/// Vertices are indexed by their virtual id, which is continuous from 0 to |V| - 1.
+/// Use the returned id map to convert them back to ids of the graph.
+///
+/// # Arguments
+/// `graph`: Graph to export.
+///
+/// # Returns
+/// * Adjacency list: Entry at index `i` lists edges going out of the vertex with virtual id `i`, in the format of (neighbor virtual id, weight).
+///   Undirected edges appear in adjacency of both of their end points.
+/// * Id map: Mapping between virtual ids used in the adjacency list and real ids of the graph.
+///
+/// # Complexity
+/// O(|V| + |E|)
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::DiMat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::adjacency_list;
+///
+/// // Given: Graph
+/// //
+/// //      a --2--> b --3--> c
+/// //
+/// let mut graph = MatGraph::init(DiMat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 2.into());
+/// graph.add_edge(b, c, 3.into());
+///
+/// // When: Exporting the graph.
+/// let (adjacency, id_map) = adjacency_list(&graph);
+///
+/// // Then:
+/// let (a, b, c) = (id_map.virt_id_of(a), id_map.virt_id_of(b), id_map.virt_id_of(c));
+/// assert_eq!(adjacency[a], vec![(b, 2.into())]);
+/// assert_eq!(adjacency[b], vec![(c, 3.into())]);
+/// assert!(adjacency[c].is_empty());
+/// ```
+pub fn adjacency_list<W, E, Ty, G>(graph: &G) -> (AdjacencyList<W>, IdMap)
+where
+    W: Copy + Any,
+    E: Edge<W>,
+    Ty: EdgeDir,
+    G: Edges<W, E> + Vertices + Graph<W, E, Ty>,
+{
+    let id_map = graph.continuos_id_map();
+
+    let adjacency = (0..graph.vertex_count())
+        .map(|virt_id| {
+            graph
+                .edges_from(id_map.real_id_of(virt_id))
+                .unwrap()
+                .into_iter()
+                .map(|(dst_id, edge)| (id_map.virt_id_of(dst_id), *edge.get_weight()))
+                .collect()
+        })
+        .collect();
+
+    (adjacency, id_map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::storage::{DiMat, Mat};
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        let (adjacency, _) = adjacency_list(&graph);
+
+        assert!(adjacency.is_empty());
+    }
+
+    #[test]
+    fn matches_edges_from() {
+        // Given: Graph
+        //
+        //      a --1--> b --2--> c
+        //      ^        |
+        //      4        3
+        //      |        v
+        //      '------- d
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 2.into()).unwrap();
+        graph.add_edge(b, d, 3.into()).unwrap();
+        graph.add_edge(d, a, 4.into()).unwrap();
+        graph.remove_vertex(c).unwrap();
+
+        // When: Exporting the graph.
+        let (adjacency, id_map) = adjacency_list(&graph);
+
+        // Then:
+        assert_eq!(adjacency.len(), graph.vertex_count());
+        for vertex_id in graph.vertices() {
+            let expected: Vec<(usize, Magnitude<usize>)> = graph
+                .edges_from(vertex_id)
+                .unwrap()
+                .into_iter()
+                .map(|(dst_id, edge)| (dst_id, *edge.get_weight()))
+                .collect();
+            let exported: Vec<(usize, Magnitude<usize>)> = adjacency[id_map.virt_id_of(vertex_id)]
+                .iter()
+                .map(|(virt_id, weight)| (id_map.real_id_of(*virt_id), *weight))
+                .collect();
+
+            assert_eq!(exported, expected);
+        }
+    }
+
+    #[test]
+    fn undirected_edges_appear_on_both_ends() {
+        // Given: Graph
+        //
+        //      a --5-- b
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        graph.add_edge(a, b, 5.into()).unwrap();
+
+        // When: Exporting the graph.
+        let (adjacency, id_map) = adjacency_list(&graph);
+
+        // Then:
+        let (a, b) = (id_map.virt_id_of(a), id_map.virt_id_of(b));
+        assert_eq!(adjacency[a], vec![(b, 5.into())]);
+        assert_eq!(adjacency[b], vec![(a, 5.into())]);
+    }
+}
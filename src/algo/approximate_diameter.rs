@@ -0,0 +1,264 @@
+use std::collections::VecDeque;
+
+use crate::graph::{Edge, UndirectedEdge};
+use crate::provide::{Graph, Neighbors, Vertices};
+
+/// Estimates diameter of an undirected graph, in number of edges, using repeated double sweeps of breadth-first search.
+///
+/// In each component, a bfs is run from its first vertex, and then each next bfs is run from the farthest vertex found by the previous one.
+/// Eccentricity of every vertex is a lower bound of the diameter, so the largest eccentricity found is returned.
+/// On trees two sweeps always find the exact diameter, and on many other graphs a few sweeps are enough.
+/// For the exact diameter checkout [`diametral_path`](crate::algo::diametral_path).
+///
+/// # Arguments
+/// * `graph`: Undirected graph to estimate diameter of. Weights of the edges are ignored.
+/// * `sweeps`: Maximum number of bfs runs in each component. Sweeping stops early once the farthest vertex is the one the bfs started from.
+///
+/// # Returns
+/// A lower bound of the number of edges of the longest shortest path inside any of the components.
+/// It is 0 if `sweeps` is 0 or the graph has no edges.
+///
+/// # Complexity
+/// O(sweeps · (|V| + |E|))
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::approximate_diameter;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b --- c --- d
+/// //            |
+/// //            e
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// let e = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(c, d, 1.into());
+/// graph.add_edge(b, e, 1.into());
+///
+/// // When: Estimating the diameter with two sweeps.
+/// let diameter = approximate_diameter(&graph, 2);
+///
+/// // Then: First sweep from a finds d, and second sweep from d finds a path of 3 edges.
+/// assert_eq!(diameter, 3);
+/// ```
+pub fn approximate_diameter<W, E, G>(graph: &G, sweeps: usize) -> usize
+where
+    E: Edge<W>,
+    G: Vertices + Neighbors + Graph<W, E, UndirectedEdge>,
+{
+    let id_map = graph.continuos_id_map();
+    let vertex_count = graph.vertex_count();
+
+    let adjacency: Vec<Vec<usize>> = (0..vertex_count)
+        .map(|virt_id| {
+            graph
+                .neighbors(id_map.real_id_of(virt_id))
+                .unwrap()
+                .into_iter()
+                .map(|n_id| id_map.virt_id_of(n_id))
+                .collect()
+        })
+        .collect();
+
+    let mut is_swept = vec![false; vertex_count];
+    // Depth of each vertex in the current sweep, reset after each sweep using the reached vertices.
+    let mut depth_of: Vec<Option<usize>> = vec![None; vertex_count];
+    let mut diameter = 0;
+
+    for root_virt_id in 0..vertex_count {
+        if is_swept[root_virt_id] || sweeps == 0 {
+            continue;
+        }
+
+        let mut src_virt_id = root_virt_id;
+        for _ in 0..sweeps {
+            let (farthest_virt_id, eccentricity, reached) =
+                sweep(&adjacency, src_virt_id, &mut depth_of);
+            diameter = diameter.max(eccentricity);
+
+            // Each sweep reaches the whole component, so any of them can mark it as swept.
+            for virt_id in reached {
+                depth_of[virt_id] = None;
+                is_swept[virt_id] = true;
+            }
+
+            if farthest_virt_id == src_virt_id {
+                break;
+            }
+            src_virt_id = farthest_virt_id;
+        }
+    }
+
+    diameter
+}
+
+// Runs bfs from `src_virt_id` and leaves depth of the reached vertices in `depth_of`.
+// Vertices of other components must have no depth in `depth_of`.
+//
+// # Returns
+// (virtual id of the farthest vertex, its depth, virtual ids of the reached vertices).
+// Among the farthest vertices, the last one discovered is picked.
+fn sweep(
+    adjacency: &[Vec<usize>],
+    src_virt_id: usize,
+    depth_of: &mut [Option<usize>],
+) -> (usize, usize, Vec<usize>) {
+    let mut reached = vec![src_virt_id];
+    let mut queue = VecDeque::new();
+    let mut farthest = (src_virt_id, 0);
+
+    depth_of[src_virt_id] = Some(0);
+    queue.push_back(src_virt_id);
+    while let Some(virt_id) = queue.pop_front() {
+        let depth = depth_of[virt_id].unwrap();
+        farthest = (virt_id, depth);
+
+        for n_virt_id in adjacency[virt_id].iter().copied() {
+            if depth_of[n_virt_id].is_none() {
+                depth_of[n_virt_id] = Some(depth + 1);
+                reached.push(n_virt_id);
+                queue.push_back(n_virt_id);
+            }
+        }
+    }
+
+    (farthest.0, farthest.1, reached)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::diametral_path;
+    use crate::gen::{GridGraphGenerator, RandomGnp};
+    use crate::graph::MatGraph;
+    use crate::storage::Mat;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn path_of(vertex_count: usize) -> MatGraph<usize, UndirectedEdge> {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let vertices: Vec<usize> = (0..vertex_count).map(|_| graph.add_vertex()).collect();
+        for window in vertices.windows(2) {
+            graph.add_edge(window[0], window[1], 1.into()).unwrap();
+        }
+
+        graph
+    }
+
+    // Exact diameter in number of edges, as all edges have unit weight.
+    fn exact_diameter(graph: &MatGraph<usize, UndirectedEdge>) -> usize {
+        diametral_path(graph).0.unwrap()
+    }
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        assert_eq!(approximate_diameter(&graph, 4), 0);
+    }
+
+    #[test]
+    fn path_graph() {
+        for vertex_count in 1..10 {
+            // Given: Path graph.
+            let graph = path_of(vertex_count);
+
+            // Then: Second sweep starts from an end of the path.
+            assert_eq!(approximate_diameter(&graph, 2), vertex_count - 1);
+            assert_eq!(approximate_diameter(&graph, 0), 0);
+        }
+    }
+
+    #[test]
+    fn middle_of_path_needs_two_sweeps() {
+        // Given: Graph
+        //
+        //      b --- c --- a --- d --- e
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, a, 1.into()).unwrap();
+        graph.add_edge(a, d, 1.into()).unwrap();
+        graph.add_edge(d, e, 1.into()).unwrap();
+
+        // Then: Eccentricity of a is only half of the diameter.
+        assert_eq!(approximate_diameter(&graph, 1), 2);
+        assert_eq!(approximate_diameter(&graph, 2), 4);
+    }
+
+    #[test]
+    fn disconnected_graph() {
+        // Given: Graph
+        //
+        //      a --- b     c --- d --- e --- f
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+        graph.add_edge(d, e, 1.into()).unwrap();
+        graph.add_edge(e, f, 1.into()).unwrap();
+
+        // Then: Longest path is in the second component.
+        assert_eq!(approximate_diameter(&graph, 2), 3);
+    }
+
+    #[test]
+    fn many_components() {
+        // Given: Graph made of 500 disjoint edges and a path of 3 edges.
+        let mut graph = path_of(4);
+        for _ in 0..500 {
+            let src_id = graph.add_vertex();
+            let dst_id = graph.add_vertex();
+            graph.add_edge(src_id, dst_id, 1.into()).unwrap();
+        }
+
+        // Then: Depths left by sweeps of one component do not leak into the others.
+        assert_eq!(approximate_diameter(&graph, 3), 3);
+        assert_eq!(approximate_diameter(&graph, 1), 3);
+    }
+
+    #[test]
+    fn lower_bound_of_exact_diameter() {
+        let mut rng = StdRng::seed_from_u64(23);
+        let mut graphs = vec![GridGraphGenerator::init(4, 7).generate()];
+        for _ in 0..5 {
+            graphs.push(RandomGnp::init(25, 0.1).generate(&mut rng));
+        }
+
+        for graph in graphs {
+            // When: Estimating the diameter with more and more sweeps.
+            let exact = exact_diameter(&graph);
+            let estimates: Vec<usize> = (1..6)
+                .map(|sweeps| approximate_diameter(&graph, sweeps))
+                .collect();
+
+            // Then: More sweeps never make the estimate worse, and it never exceeds the exact diameter.
+            // Eccentricity of any vertex is at least half of the diameter of its component.
+            for window in estimates.windows(2) {
+                assert!(window[0] <= window[1]);
+            }
+            assert!(estimates.iter().all(|estimate| *estimate <= exact));
+            assert!(estimates[0] * 2 >= exact);
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use crate::algo::low_link::{LowLink, LowLinkListener};
+use crate::graph::{Edge, UndirectedEdge};
+use crate::provide::{Edges, Graph, Vertices};
+
+/// Finds articulation points(cut vertices): vertices whose removal increases the number of connected components of an undirected graph.
+///
+/// Uses the low-link dfs of Tarjan. A vertex other than the root of a dfs tree is an articulation point
+/// if it has a child whose subtree has no edge back to a proper ancestor of the vertex.
+/// The root is an articulation point only if it has more than one child in the dfs tree.
+/// Each connected component is searched separately, so the graph does not need to be connected.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::ArticulationPoints;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b --- c
+/// //             \   /
+/// //               d
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(c, d, 1.into());
+/// graph.add_edge(d, b, 1.into());
+///
+/// // When: Finding articulation points.
+/// let articulation_points = ArticulationPoints::init(&graph).execute();
+///
+/// // Then:
+/// assert_eq!(articulation_points, vec![b]);
+/// ```
+pub struct ArticulationPoints {
+    low_link: LowLink,
+}
+
+impl ArticulationPoints {
+    /// Initializes the structure.
+    ///
+    /// # Arguments
+    /// `graph`: Undirected graph to find its articulation points.
+    pub fn init<W, E: Edge<W>, G>(graph: &G) -> Self
+    where
+        G: Vertices + Edges<W, E> + Graph<W, E, UndirectedEdge>,
+    {
+        ArticulationPoints {
+            low_link: LowLink::init(graph),
+        }
+    }
+
+    /// Finds articulation points of the graph.
+    ///
+    /// # Returns
+    /// Id of the articulation points, in ascending order.
+    ///
+    /// # Complexity
+    /// O(|V| + |E|)
+    pub fn execute(&self) -> Vec<usize> {
+        let vertex_count = self.low_link.adjacency.len();
+
+        let mut listener = ArticulationPointsListener {
+            is_articulation_point: vec![false; vertex_count],
+            root_child_count_of: vec![0; vertex_count],
+        };
+        self.low_link.execute(&mut listener);
+
+        let mut articulation_points: Vec<usize> = (0..vertex_count)
+            .filter(|virt_id| {
+                // Root has no proper ancestor, so it only separates its subtrees if it has more than one.
+                listener.is_articulation_point[*virt_id]
+                    || listener.root_child_count_of[*virt_id] > 1
+            })
+            .map(|virt_id| self.low_link.id_map.real_id_of(virt_id))
+            .collect();
+        articulation_points.sort_unstable();
+
+        articulation_points
+    }
+}
+
+struct ArticulationPointsListener {
+    // Only filled for vertices other than the roots of the dfs trees.
+    is_articulation_point: Vec<bool>,
+
+    // Number of children of each root in the dfs tree, and 0 for the other vertices.
+    root_child_count_of: Vec<usize>,
+}
+
+impl LowLinkListener for ArticulationPointsListener {
+    fn on_child_finished(
+        &mut self,
+        virt_id: usize,
+        _: usize,
+        _: usize,
+        depth: usize,
+        child_low: usize,
+    ) {
+        if depth == 0 {
+            self.root_child_count_of[virt_id] += 1;
+        } else if child_low >= depth {
+            self.is_articulation_point[virt_id] = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::storage::Mat;
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        assert!(ArticulationPoints::init(&graph).execute().is_empty());
+    }
+
+    #[test]
+    fn bowtie() {
+        // Given: Graph
+        //
+        //      a           d
+        //      | \       / |
+        //      |   c ---   |
+        //      | /       \ |
+        //      b           e
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, a, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+        graph.add_edge(d, e, 1.into()).unwrap();
+        graph.add_edge(e, c, 1.into()).unwrap();
+
+        // When: Finding articulation points, in which dfs starts from a vertex other than the center.
+        let articulation_points = ArticulationPoints::init(&graph).execute();
+
+        // Then:
+        assert_eq!(articulation_points, vec![c]);
+    }
+
+    #[test]
+    fn bowtie_center_as_root() {
+        // Given: Graph
+        //
+        //      b           d
+        //      | \       / |
+        //      |   a ---   |
+        //      | /       \ |
+        //      c           e
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, a, 1.into()).unwrap();
+        graph.add_edge(a, d, 1.into()).unwrap();
+        graph.add_edge(d, e, 1.into()).unwrap();
+        graph.add_edge(e, a, 1.into()).unwrap();
+
+        // When: Finding articulation points, in which dfs starts from the center.
+        let articulation_points = ArticulationPoints::init(&graph).execute();
+
+        // Then: Center has two children in the dfs tree.
+        assert_eq!(articulation_points, vec![a]);
+    }
+
+    #[test]
+    fn cycle() {
+        // Given: Graph
+        //
+        //      a --- b
+        //      |     |
+        //      d --- c
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+        graph.add_edge(d, a, 1.into()).unwrap();
+
+        // When: Finding articulation points.
+        let articulation_points = ArticulationPoints::init(&graph).execute();
+
+        // Then:
+        assert!(articulation_points.is_empty());
+    }
+
+    #[test]
+    fn disconnected_graph() {
+        // Given: Graph
+        //
+        //      a --- b --- c     d --- e     f
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(d, e, 1.into()).unwrap();
+
+        // When: Finding articulation points.
+        let articulation_points = ArticulationPoints::init(&graph).execute();
+
+        // Then: Root of a path with one child is not an articulation point.
+        assert_eq!(articulation_points, vec![b]);
+    }
+}
@@ -0,0 +1,278 @@
+use std::collections::{HashMap, VecDeque};
+use std::marker::PhantomData;
+
+use crate::graph::{Edge, EdgeDir};
+use crate::provide::{Graph, IdMap, Neighbors, Vertices};
+
+/// Computes betweenness centrality of the vertices using Brandes' algorithm.
+///
+/// Betweenness of a vertex `v` is the sum of σ<sub>st</sub>(v) / σ<sub>st</sub> over all pairs of vertices `s` and `t` other than `v`,
+/// where σ<sub>st</sub> is the number of shortest paths from `s` to `t` and σ<sub>st</sub>(v) is the number of them that pass through `v`.
+/// So when there are multiple shortest paths between a pair, each vertex on them gets its share of the pair.
+/// In undirected graphs each unordered pair is counted once, and in directed graphs both (`s`, `t`) and (`t`, `s`) are counted.
+///
+/// Length of a path is the number of its edges, so weights of the edges and parallel edges are ignored.
+///
+/// # Generic Parameters
+/// * `Dir`: Specifies the graph is directed or undirected.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::BetweennessCentrality;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b --- c
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+///
+/// // When: Computing the centrality.
+/// let centrality = BetweennessCentrality::init(&graph).execute(false);
+///
+/// // Then: Only the shortest path between a and c passes through another vertex.
+/// assert_eq!(centrality[&a], 0.0);
+/// assert_eq!(centrality[&b], 1.0);
+/// assert_eq!(centrality[&c], 0.0);
+/// ```
+pub struct BetweennessCentrality<Dir: EdgeDir> {
+    id_map: IdMap,
+
+    // Maps each vertex(by virtual id) to virtual id of the vertices it has an edge to.
+    adjacency: Vec<Vec<usize>>,
+
+    phantom_dir: PhantomData<Dir>,
+}
+
+impl<Dir: EdgeDir> BetweennessCentrality<Dir> {
+    /// Initializes the structure.
+    ///
+    /// # Arguments
+    /// `graph`: Graph to compute centrality of its vertices.
+    pub fn init<W, E, G>(graph: &G) -> Self
+    where
+        E: Edge<W>,
+        G: Vertices + Neighbors + Graph<W, E, Dir>,
+    {
+        let id_map = graph.continuos_id_map();
+
+        let adjacency = (0..graph.vertex_count())
+            .map(|virt_id| {
+                let mut neighbors: Vec<usize> = graph
+                    .neighbors(id_map.real_id_of(virt_id))
+                    .unwrap()
+                    .into_iter()
+                    .map(|n_id| id_map.virt_id_of(n_id))
+                    .collect();
+                neighbors.sort_unstable();
+                neighbors.dedup();
+
+                neighbors
+            })
+            .collect();
+
+        BetweennessCentrality {
+            id_map,
+            adjacency,
+            phantom_dir: PhantomData,
+        }
+    }
+
+    /// Computes the centrality.
+    ///
+    /// # Arguments
+    /// `normalized`: If `true`, scores are divided by the number of pairs that do not contain the vertex,
+    /// which is (|V| - 1)(|V| - 2) in directed graphs and half of it in undirected ones. So all scores fall in [0, 1].
+    ///
+    /// # Returns
+    /// Maps id of each vertex to its betweenness centrality.
+    ///
+    /// # Complexity
+    /// O(|V||E|)
+    pub fn execute(&self, normalized: bool) -> HashMap<usize, f64> {
+        let vertex_count = self.adjacency.len();
+        let mut centrality = vec![0.0; vertex_count];
+
+        for src_virt_id in 0..vertex_count {
+            // Bfs to find the number of shortest paths from source to each vertex, and predecessors of each vertex on them.
+            let mut stack = vec![];
+            let mut predecessors_of: Vec<Vec<usize>> = vec![vec![]; vertex_count];
+            let mut paths_count_of = vec![0.0; vertex_count];
+            let mut distance_of: Vec<Option<usize>> = vec![None; vertex_count];
+            paths_count_of[src_virt_id] = 1.0;
+            distance_of[src_virt_id] = Some(0);
+
+            let mut queue = VecDeque::from(vec![src_virt_id]);
+            while let Some(virt_id) = queue.pop_front() {
+                stack.push(virt_id);
+                let distance = distance_of[virt_id].unwrap();
+
+                for n_virt_id in self.adjacency[virt_id].iter().copied() {
+                    if distance_of[n_virt_id].is_none() {
+                        distance_of[n_virt_id] = Some(distance + 1);
+                        queue.push_back(n_virt_id);
+                    }
+
+                    if distance_of[n_virt_id] == Some(distance + 1) {
+                        paths_count_of[n_virt_id] += paths_count_of[virt_id];
+                        predecessors_of[n_virt_id].push(virt_id);
+                    }
+                }
+            }
+
+            // Vertices are popped in non-increasing order of their distance from source,
+            // so dependency of each vertex is complete before it is passed to its predecessors.
+            let mut dependency_of = vec![0.0; vertex_count];
+            while let Some(virt_id) = stack.pop() {
+                for p_virt_id in predecessors_of[virt_id].iter().copied() {
+                    dependency_of[p_virt_id] += paths_count_of[p_virt_id] / paths_count_of[virt_id]
+                        * (1.0 + dependency_of[virt_id]);
+                }
+
+                if virt_id != src_virt_id {
+                    centrality[virt_id] += dependency_of[virt_id];
+                }
+            }
+        }
+
+        // In undirected graphs each pair is counted from both of its ends.
+        let (mut scale, mut pairs_count) = if Dir::is_directed() {
+            (
+                1.0,
+                vertex_count.saturating_sub(1) * vertex_count.saturating_sub(2),
+            )
+        } else {
+            (
+                0.5,
+                vertex_count.saturating_sub(1) * vertex_count.saturating_sub(2) / 2,
+            )
+        };
+        if !normalized || pairs_count == 0 {
+            pairs_count = 1;
+        }
+        scale /= pairs_count as f64;
+
+        centrality
+            .into_iter()
+            .enumerate()
+            .map(|(virt_id, score)| (self.id_map.real_id_of(virt_id), score * scale))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::storage::{DiMat, Mat};
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        assert!(BetweennessCentrality::init(&graph).execute(true).is_empty());
+    }
+
+    #[test]
+    fn path_graph() {
+        // Given: Graph
+        //
+        //      a --- b --- c --- d --- e
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+        graph.add_edge(d, e, 1.into()).unwrap();
+
+        // When: Computing the centrality.
+        let centrality = BetweennessCentrality::init(&graph).execute(false);
+        let normalized = BetweennessCentrality::init(&graph).execute(true);
+
+        // Then: c is on the paths of pairs (a, d), (a, e), (b, d) and (b, e).
+        assert_eq!(centrality[&a], 0.0);
+        assert_eq!(centrality[&b], 3.0);
+        assert_eq!(centrality[&c], 4.0);
+        assert_eq!(centrality[&d], 3.0);
+        assert_eq!(centrality[&e], 0.0);
+        assert!(centrality.values().all(|score| *score <= centrality[&c]));
+
+        // Then: Each score is divided by number of pairs among the other 4 vertices.
+        for (vertex_id, score) in centrality {
+            assert!((normalized[&vertex_id] - score / 6.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn multiple_shortest_paths() {
+        // Given: Graph
+        //
+        //      a --- b --- d --- e
+        //       \         /
+        //        '-- c --'
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(a, c, 1.into()).unwrap();
+        graph.add_edge(b, d, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+        graph.add_edge(d, e, 1.into()).unwrap();
+
+        // When: Computing the centrality.
+        let centrality = BetweennessCentrality::init(&graph).execute(false);
+
+        // Then: b and c each get half of pairs (a, d) and (a, e), and a and d each get half of pair (b, c).
+        assert_eq!(centrality[&a], 0.5);
+        assert_eq!(centrality[&b], 1.0);
+        assert_eq!(centrality[&c], 1.0);
+        assert_eq!(centrality[&d], 3.5);
+        assert_eq!(centrality[&e], 0.0);
+    }
+
+    #[test]
+    fn directed_graph() {
+        // Given: Graph
+        //
+        //      a --> b --> c --> d
+        //      ^           |
+        //      '-----------'
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+        graph.add_edge(c, a, 1.into()).unwrap();
+
+        // When: Computing the centrality.
+        let centrality = BetweennessCentrality::init(&graph).execute(false);
+        let normalized = BetweennessCentrality::init(&graph).execute(true);
+
+        // Then: a is on (c, b), b is on (a, c) and (a, d), and c is on (a, d), (b, d) and (b, a).
+        assert_eq!(centrality[&a], 1.0);
+        assert_eq!(centrality[&b], 2.0);
+        assert_eq!(centrality[&c], 3.0);
+        assert_eq!(centrality[&d], 0.0);
+        assert!((normalized[&c] - 3.0 / 6.0).abs() < 1e-12);
+    }
+}
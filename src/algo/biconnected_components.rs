@@ -0,0 +1,256 @@
+use crate::algo::low_link::{LowLink, LowLinkListener};
+use crate::graph::{Edge, UndirectedEdge};
+use crate::provide::{Edges, Graph, IdMap, Vertices};
+
+/// Partitions edges of an undirected graph into biconnected components(blocks).
+///
+/// A block is a maximal subgraph that stays connected after removing any one of its vertices.
+/// Every edge belongs to exactly one block, and two blocks share at most one vertex, which is an [articulation point](crate::algo::ArticulationPoints).
+/// A bridge forms a block of its own, and isolated vertices belong to no block.
+///
+/// Uses the edge stack variant of Tarjan's low-link dfs: Edges are pushed to a stack as they are traversed,
+/// and when a child turns out to have no edge back above its parent, edges down to the tree edge of the child are popped as one block.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::BiconnectedComponents;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b --- c
+/// //             \   /
+/// //               d
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// let ab = graph.add_edge(a, b, 1.into()).unwrap();
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(c, d, 1.into());
+/// graph.add_edge(d, b, 1.into());
+///
+/// // When: Finding the blocks.
+/// let blocks = BiconnectedComponents::init(&graph).execute();
+///
+/// // Then: Bridge a --- b is a block, and the triangle is another block.
+/// assert_eq!(blocks.len(), 2);
+/// assert!(blocks.iter().any(|block| block.len() == 1 && block[0].2 == ab));
+/// assert!(blocks.iter().any(|block| block.len() == 3));
+/// ```
+pub struct BiconnectedComponents {
+    low_link: LowLink,
+}
+
+impl BiconnectedComponents {
+    /// Initializes the structure.
+    ///
+    /// # Arguments
+    /// `graph`: Undirected graph to find its blocks.
+    pub fn init<W, E: Edge<W>, G>(graph: &G) -> Self
+    where
+        G: Vertices + Edges<W, E> + Graph<W, E, UndirectedEdge>,
+    {
+        BiconnectedComponents {
+            low_link: LowLink::init(graph),
+        }
+    }
+
+    /// Finds blocks of the graph.
+    ///
+    /// # Returns
+    /// Edges of each block in the format of (`src_id`, `dst_id`, `edge_id`).
+    ///
+    /// # Complexity
+    /// O(|V| + |E|)
+    pub fn execute(&self) -> Vec<Vec<(usize, usize, usize)>> {
+        let mut listener = BlocksListener {
+            id_map: &self.low_link.id_map,
+            edge_stack: vec![],
+            blocks: vec![],
+        };
+        self.low_link.execute(&mut listener);
+
+        listener.blocks
+    }
+}
+
+struct BlocksListener<'a> {
+    id_map: &'a IdMap,
+
+    // Edges that are traversed but not assigned to a block yet, in the format of (`src_id`, `dst_id`, `edge_id`).
+    edge_stack: Vec<(usize, usize, usize)>,
+    blocks: Vec<Vec<(usize, usize, usize)>>,
+}
+
+impl<'a> BlocksListener<'a> {
+    fn push_edge(&mut self, virt_id: usize, n_virt_id: usize, edge_id: usize) {
+        self.edge_stack.push((
+            self.id_map.real_id_of(virt_id),
+            self.id_map.real_id_of(n_virt_id),
+            edge_id,
+        ));
+    }
+}
+
+impl<'a> LowLinkListener for BlocksListener<'a> {
+    fn on_tree_edge(&mut self, virt_id: usize, child_virt_id: usize, edge_id: usize) {
+        self.push_edge(virt_id, child_virt_id, edge_id);
+    }
+
+    fn on_back_edge(&mut self, virt_id: usize, ancestor_virt_id: usize, edge_id: usize) {
+        self.push_edge(virt_id, ancestor_virt_id, edge_id);
+    }
+
+    fn on_child_finished(
+        &mut self,
+        _: usize,
+        _: usize,
+        edge_id: usize,
+        depth: usize,
+        child_low: usize,
+    ) {
+        // Subtree of the child can not reach above this vertex, so this vertex separates it.
+        if child_low >= depth {
+            let mut block = vec![];
+            while let Some(edge) = self.edge_stack.pop() {
+                block.push(edge);
+                if edge.2 == edge_id {
+                    break;
+                }
+            }
+            self.blocks.push(block);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::storage::Mat;
+
+    // Sorts id of the edges in each block, and then the blocks.
+    fn sorted_blocks(blocks: Vec<Vec<(usize, usize, usize)>>) -> Vec<Vec<usize>> {
+        let mut blocks: Vec<Vec<usize>> = blocks
+            .into_iter()
+            .map(|block| {
+                let mut edge_ids: Vec<usize> = block.into_iter().map(|(_, _, id)| id).collect();
+                edge_ids.sort_unstable();
+                edge_ids
+            })
+            .collect();
+        blocks.sort_unstable();
+        blocks
+    }
+
+    #[test]
+    fn empty_and_isolated_vertices() {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        assert!(BiconnectedComponents::init(&graph).execute().is_empty());
+
+        graph.add_vertex();
+        graph.add_vertex();
+        assert!(BiconnectedComponents::init(&graph).execute().is_empty());
+    }
+
+    #[test]
+    fn two_triangles_sharing_a_vertex() {
+        // Given: Graph
+        //
+        //      a           d
+        //      | \       / |
+        //      |   c ---   |
+        //      | /       \ |
+        //      b           e
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let ab = graph.add_edge(a, b, 1.into()).unwrap();
+        let bc = graph.add_edge(b, c, 1.into()).unwrap();
+        let ca = graph.add_edge(c, a, 1.into()).unwrap();
+        let cd = graph.add_edge(c, d, 1.into()).unwrap();
+        let de = graph.add_edge(d, e, 1.into()).unwrap();
+        let ec = graph.add_edge(e, c, 1.into()).unwrap();
+
+        // When: Finding the blocks.
+        let blocks = BiconnectedComponents::init(&graph).execute();
+
+        // Then: Each triangle is a block.
+        assert_eq!(blocks.len(), 2);
+        assert!(blocks.iter().all(|block| block.len() == 3));
+        let mut expected = vec![vec![ab, bc, ca], vec![cd, de, ec]];
+        expected.iter_mut().for_each(|block| block.sort_unstable());
+        expected.sort_unstable();
+        assert_eq!(sorted_blocks(blocks), expected);
+    }
+
+    #[test]
+    fn bridges_and_disconnected_components() {
+        // Given: Graph
+        //
+        //      a --- b --- c --- d     e --- f     g
+        //             \   /
+        //               h
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        graph.add_vertex();
+        let h = graph.add_vertex();
+        let ab = graph.add_edge(a, b, 1.into()).unwrap();
+        let bc = graph.add_edge(b, c, 1.into()).unwrap();
+        let cd = graph.add_edge(c, d, 1.into()).unwrap();
+        let ef = graph.add_edge(e, f, 1.into()).unwrap();
+        let bh = graph.add_edge(b, h, 1.into()).unwrap();
+        let ch = graph.add_edge(c, h, 1.into()).unwrap();
+
+        // When: Finding the blocks.
+        let blocks = BiconnectedComponents::init(&graph).execute();
+
+        // Then: Each bridge is a block of its own, and isolated g belongs to no block.
+        let mut expected = vec![vec![ab], vec![cd], vec![ef], vec![bc, bh, ch]];
+        expected.iter_mut().for_each(|block| block.sort_unstable());
+        expected.sort_unstable();
+        assert_eq!(sorted_blocks(blocks), expected);
+    }
+
+    #[test]
+    fn cycle_is_one_block() {
+        // Given: Graph
+        //
+        //      a --- b
+        //      |  \  |
+        //      d --- c
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+        graph.add_edge(d, a, 1.into()).unwrap();
+        graph.add_edge(a, c, 1.into()).unwrap();
+
+        // When: Finding the blocks.
+        let blocks = BiconnectedComponents::init(&graph).execute();
+
+        // Then:
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].len(), 5);
+    }
+}
@@ -0,0 +1,273 @@
+use crate::{
+    algo::low_link::{LowLink, LowLinkListener},
+    graph::{Edge, UndirectedEdge},
+    provide::{Edges, Graph, IdMap, Vertices},
+};
+
+/// Finds bridges(cut edges): edges whose removal increases the number of connected components of an undirected graph.
+///
+/// Uses the low-link dfs in which only the edge that is used to enter a vertex is excluded when computing low-links,
+/// so parallel edges between two vertices are never reported as bridges.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::Bridges;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b --- c
+/// //             \   /
+/// //               d
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// let ab = graph.add_edge(a, b, 1.into()).unwrap();
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(c, d, 1.into());
+/// graph.add_edge(d, b, 1.into());
+///
+/// // When: Finding bridges.
+/// let bridges = Bridges::init(&graph).execute();
+///
+/// // Then:
+/// assert_eq!(bridges.len(), 1);
+/// assert_eq!(bridges[0].2, ab);
+/// ```
+pub struct Bridges {
+    low_link: LowLink,
+}
+
+impl Bridges {
+    /// Initializes the structure.
+    ///
+    /// # Arguments
+    /// `graph`: Undirected graph to find its bridges.
+    pub fn init<W, E: Edge<W>, G>(graph: &G) -> Self
+    where
+        G: Vertices + Edges<W, E> + Graph<W, E, UndirectedEdge>,
+    {
+        Bridges {
+            low_link: LowLink::init(graph),
+        }
+    }
+
+    /// Finds bridges of the graph.
+    ///
+    /// # Returns
+    /// Bridges in the format of (`src_id`, `dst_id`, `edge_id`).
+    ///
+    /// # Complexity
+    /// O(|V| + |E|)
+    pub fn execute(&self) -> Vec<(usize, usize, usize)> {
+        let mut listener = BridgesListener {
+            id_map: &self.low_link.id_map,
+            bridges: vec![],
+        };
+        self.low_link.execute(&mut listener);
+
+        listener.bridges
+    }
+
+    /// # Arguments
+    /// `vertex_id`: Id of the vertex to get its incident edges.
+    ///
+    /// # Returns
+    /// Edges incident to `vertex_id` that are not removed yet, in the format of (`neighbor_id`, `edge_id`).
+    pub(crate) fn edges_from(&self, vertex_id: usize) -> Vec<(usize, usize)> {
+        let id_map = &self.low_link.id_map;
+
+        self.low_link.adjacency[id_map.virt_id_of(vertex_id)]
+            .iter()
+            .map(|(n_virt_id, edge_id)| (id_map.real_id_of(*n_virt_id), *edge_id))
+            .collect()
+    }
+
+    /// Removes the edge from the structure, so the next `execute` finds the bridges of the graph without it.
+    ///
+    /// # Arguments
+    /// `edge_id`: Id of the edge to be removed.
+    pub(crate) fn remove_edge(&mut self, edge_id: usize) {
+        for edges in self.low_link.adjacency.iter_mut() {
+            edges.retain(|(_, e_id)| *e_id != edge_id);
+        }
+    }
+}
+
+struct BridgesListener<'a> {
+    id_map: &'a IdMap,
+    bridges: Vec<(usize, usize, usize)>,
+}
+
+impl<'a> LowLinkListener for BridgesListener<'a> {
+    fn on_child_finished(
+        &mut self,
+        virt_id: usize,
+        child_virt_id: usize,
+        edge_id: usize,
+        depth: usize,
+        child_low: usize,
+    ) {
+        // Subtree of the child has no other way to this vertex or above it.
+        if child_low > depth {
+            self.bridges.push((
+                self.id_map.real_id_of(virt_id),
+                self.id_map.real_id_of(child_virt_id),
+                edge_id,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::storage::Mat;
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        assert!(Bridges::init(&graph).execute().is_empty());
+    }
+
+    #[test]
+    fn disconnected_graph() {
+        // Given: Graph
+        //
+        //      a --- b     c --- d
+        //                   \   /
+        //                     e
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let ab = graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+        graph.add_edge(d, e, 1.into()).unwrap();
+        graph.add_edge(e, c, 1.into()).unwrap();
+
+        // When: Finding bridges.
+        let bridges = Bridges::init(&graph).execute();
+
+        // Then:
+        assert_eq!(bridges.len(), 1);
+        assert_eq!(bridges[0].2, ab);
+    }
+
+    #[test]
+    fn removed_edge_creates_bridges() {
+        // Given: Graph
+        //
+        //      a --- b
+        //      |     |
+        //      d --- c
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let ab = graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+        graph.add_edge(d, a, 1.into()).unwrap();
+        let mut bridges = Bridges::init(&graph);
+        assert!(bridges.execute().is_empty());
+
+        // When: Removing one edge of the cycle.
+        bridges.remove_edge(ab);
+
+        // Then: Every remaining edge is a bridge.
+        assert_eq!(bridges.execute().len(), 3);
+        assert!(bridges.edges_from(a).iter().all(|(n_id, _)| *n_id == d));
+    }
+
+    #[test]
+    fn path_graph() {
+        // Given: Graph
+        //
+        //      a --- b --- c --- d
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let ab = graph.add_edge(a, b, 1.into()).unwrap();
+        let bc = graph.add_edge(b, c, 1.into()).unwrap();
+        let cd = graph.add_edge(c, d, 1.into()).unwrap();
+
+        // When: Finding bridges.
+        let bridges = Bridges::init(&graph).execute();
+
+        // Then: Every edge is a bridge.
+        let mut bridge_ids: Vec<usize> = bridges.iter().map(|(_, _, edge_id)| *edge_id).collect();
+        bridge_ids.sort_unstable();
+        let mut edge_ids = vec![ab, bc, cd];
+        edge_ids.sort_unstable();
+        assert_eq!(bridge_ids, edge_ids);
+    }
+
+    #[test]
+    fn cycle_with_pendant() {
+        // Given: Graph
+        //
+        //      a --- b
+        //      |     |
+        //      d --- c --- e
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+        graph.add_edge(d, a, 1.into()).unwrap();
+        let ce = graph.add_edge(c, e, 1.into()).unwrap();
+
+        // When: Finding bridges.
+        let bridges = Bridges::init(&graph).execute();
+
+        // Then: Only the pendant edge is a bridge.
+        assert_eq!(bridges, vec![(c, e, ce)]);
+    }
+
+    #[test]
+    fn parallel_edges() {
+        // Given: Graph
+        //
+        //      a --- b --- c
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let ab = graph.add_edge(a, b, 1.into()).unwrap();
+        let bc = graph.add_edge(b, c, 1.into()).unwrap();
+        let mut bridges = Bridges::init(&graph);
+
+        // When: Doubling the edge between a and b. Simple graphs do not allow it, so it is added to the structure directly.
+        let (a_virt_id, b_virt_id) = (
+            bridges.low_link.id_map.virt_id_of(a),
+            bridges.low_link.id_map.virt_id_of(b),
+        );
+        bridges.low_link.adjacency[a_virt_id].push((b_virt_id, ab + bc + 1));
+        bridges.low_link.adjacency[b_virt_id].push((a_virt_id, ab + bc + 1));
+
+        // Then: Doubled edge is not a bridge.
+        assert_eq!(bridges.execute(), vec![(b, c, bc)]);
+    }
+}
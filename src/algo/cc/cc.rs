@@ -1,5 +1,5 @@
 use crate::algo::{Dfs, DfsListener};
-use crate::graph::{Edge, UndirectedEdge};
+use crate::graph::{subgraph::Subgraph, Edge, UndirectedEdge};
 use crate::provide;
 
 /// Finds connected components of an undirected graph.
@@ -81,9 +81,34 @@ impl ConnectedComponents {
     /// # Returns
     /// Connected components of the graph. \
     /// Returned value will be vector of vectors. Each vector contains ids of vertices that are in a component.
-    pub fn execute<G, W, E: Edge<W>>(mut self, graph: &G) -> Vec<Vec<usize>>
+    pub fn execute<G, W, E: Edge<W>>(self, graph: &G) -> Vec<Vec<usize>>
     where
         G: provide::Graph<W, E, UndirectedEdge> + provide::Vertices + provide::Neighbors,
+    {
+        self.components_of(graph)
+    }
+
+    /// Finds connected components of an undirected subgraph.
+    /// Only vertices and edges of the subgraph are considered, so a connected graph may have a disconnected subgraph.
+    ///
+    /// # Arguments
+    /// `subgraph`: Subgraph to search for its connected components.
+    ///
+    /// # Returns
+    /// Connected components of the subgraph, in the same format as [`execute`](crate::algo::ConnectedComponents::execute).
+    pub fn execute_on_subgraph<'a, G, W, E: Edge<W>>(
+        self,
+        subgraph: &Subgraph<'a, W, E, UndirectedEdge, G>,
+    ) -> Vec<Vec<usize>>
+    where
+        G: provide::Graph<W, E, UndirectedEdge> + provide::Edges<W, E> + provide::Neighbors,
+    {
+        self.components_of(subgraph)
+    }
+
+    fn components_of<G>(mut self, graph: &G) -> Vec<Vec<usize>>
+    where
+        G: provide::Vertices + provide::Neighbors,
     {
         let mut dfs = Dfs::init(graph, &mut self);
 
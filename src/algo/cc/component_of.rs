@@ -0,0 +1,137 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::graph::{Edge, UndirectedEdge};
+use crate::provide::{Graph, Neighbors, Vertices};
+
+/// Finds the connected component that contains `vertex_id`, without computing the other components.
+///
+/// # Arguments
+/// * `graph`: Undirected graph to search in.
+/// * `vertex_id`: Id of the vertex to find its component.
+///
+/// # Returns
+/// Id of the vertices in the component in breadth-first order, starting with `vertex_id`.
+///
+/// # Complexity
+/// O(|V<sub>c</sub>| + |E<sub>c</sub>|) where V<sub>c</sub> and E<sub>c</sub> are vertices and edges of the component.
+///
+/// # Panics
+/// If vertex with id: `vertex_id` does not exist in the graph.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::component_of;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b     c --- d
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(c, d, 1.into());
+///
+/// // Then:
+/// assert_eq!(component_of(&graph, c), vec![c, d]);
+/// ```
+pub fn component_of<W, E, G>(graph: &G, vertex_id: usize) -> Vec<usize>
+where
+    E: Edge<W>,
+    G: Graph<W, E, UndirectedEdge> + Vertices + Neighbors,
+{
+    assert!(
+        graph.contains_vertex(vertex_id),
+        "Vertex with id: {} does not exist",
+        vertex_id
+    );
+
+    let mut component = vec![vertex_id];
+    let mut is_discovered: HashSet<usize> = component.iter().copied().collect();
+    let mut queue = VecDeque::from(vec![vertex_id]);
+
+    while let Some(src_id) = queue.pop_front() {
+        for dst_id in graph.neighbors(src_id).unwrap() {
+            if is_discovered.insert(dst_id) {
+                component.push(dst_id);
+                queue.push_back(dst_id);
+            }
+        }
+    }
+
+    component
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::ConnectedComponents;
+    use crate::graph::MatGraph;
+    use crate::storage::Mat;
+
+    #[test]
+    fn isolated_vertex() {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        let c = graph.add_vertex();
+
+        assert_eq!(component_of(&graph, c), vec![c]);
+    }
+
+    #[test]
+    fn equals_connected_components() {
+        // Given: Graph
+        //
+        //      a --- b --- d       g
+        //      |    /
+        //      c --'       e --- f
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(a, c, 1.into()).unwrap();
+        graph.add_edge(c, b, 1.into()).unwrap();
+        graph.add_edge(b, d, 1.into()).unwrap();
+        graph.add_edge(e, f, 1.into()).unwrap();
+
+        // When: Finding all components.
+        let ccs = ConnectedComponents::init(&graph).execute(&graph);
+
+        // Then: Component of each vertex is the component that contains it.
+        for vertex_id in graph.vertices() {
+            let mut component = component_of(&graph, vertex_id);
+            component.sort_unstable();
+
+            let mut expected = ccs
+                .iter()
+                .find(|cc| cc.contains(&vertex_id))
+                .unwrap()
+                .clone();
+            expected.sort_unstable();
+
+            assert_eq!(component, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn missing_vertex() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        component_of(&graph, 0);
+    }
+}
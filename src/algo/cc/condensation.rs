@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+
+use crate::graph::{DirectedEdge, Edge, ListGraph};
+use crate::provide::{Edges, Graph, Vertices};
+use crate::storage::DiList;
+
+/// Builds the condensation of a directed graph: a graph in which each vertex represents a strongly connected component.
+///
+/// There is an edge from one component to another if there is at least one edge from a member of the first one to a member of the second one.
+/// Condensation of any directed graph is acyclic.
+///
+/// # Arguments
+/// * `graph`: Directed graph to condense.
+/// * `sccs`: Strongly connected components of `graph`, as returned by [`TarjanSCC`](crate::algo::TarjanSCC).
+///
+/// # Returns
+/// (condensed graph, maps id of each vertex of `graph` to id of the vertex representing its component in the condensed graph). \
+/// Component at index i of `sccs` is represented by vertex with id: i.
+/// Weight of each edge of the condensed graph is the number of edges of `graph` that go between the two components.
+///
+/// # Complexity
+/// O(|V| + |E|)
+///
+/// # Panics
+/// If a vertex of `graph` is not in any of the components.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::DiMat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::{condensation, TarjanSCC};
+///
+/// // Given: Graph
+/// //
+/// //      a --> b --> c
+/// //      ^     |
+/// //      '-----'
+/// //
+/// let mut graph = MatGraph::init(DiMat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, a, 1.into());
+/// graph.add_edge(b, c, 1.into());
+///
+/// // When: Condensing the graph.
+/// let sccs = TarjanSCC::init(&graph).execute(&graph);
+/// let (condensed, component_of) = condensation(&graph, &sccs);
+///
+/// // Then:
+/// assert_eq!(condensed.vertex_count(), 2);
+/// assert_eq!(component_of[&a], component_of[&b]);
+/// assert!(condensed.is_adjacent(component_of[&b], component_of[&c]));
+/// ```
+pub fn condensation<W, E, G>(
+    graph: &G,
+    sccs: &[Vec<usize>],
+) -> (ListGraph<usize, DirectedEdge>, HashMap<usize, usize>)
+where
+    E: Edge<W>,
+    G: Edges<W, E> + Vertices + Graph<W, E, DirectedEdge>,
+{
+    let component_of: HashMap<usize, usize> = sccs
+        .iter()
+        .enumerate()
+        .flat_map(|(index, scc)| scc.iter().map(move |vertex_id| (*vertex_id, index)))
+        .collect();
+
+    for vertex_id in graph.vertices() {
+        assert!(
+            component_of.contains_key(&vertex_id),
+            "Vertex with id: {} is not in any of the components",
+            vertex_id
+        );
+    }
+
+    // Number of edges from each component to the other ones.
+    let mut edge_count_of: HashMap<(usize, usize), usize> = HashMap::new();
+    for (src_id, dst_id, _) in graph.edges() {
+        let (src_index, dst_index) = (component_of[&src_id], component_of[&dst_id]);
+        if src_index != dst_index {
+            *edge_count_of.entry((src_index, dst_index)).or_insert(0) += 1;
+        }
+    }
+
+    let mut condensed = ListGraph::init(DiList::<usize>::init());
+    condensed.add_vertices(sccs.len());
+
+    let mut edges: Vec<(usize, usize, usize)> = edge_count_of
+        .into_iter()
+        .map(|((src_index, dst_index), count)| (src_index, dst_index, count))
+        .collect();
+    edges.sort_unstable();
+    condensed.add_edges(&edges).unwrap();
+
+    (condensed, component_of)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::{HasCycle, TarjanSCC};
+    use crate::graph::MatGraph;
+    use crate::provide::Neighbors;
+    use crate::storage::DiMat;
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(DiMat::<usize>::init());
+
+        let (condensed, component_of) = condensation(&graph, &[]);
+
+        assert_eq!(condensed.vertex_count(), 0);
+        assert!(component_of.is_empty());
+    }
+
+    #[test]
+    fn two_cycles_connected_by_one_edge() {
+        // Given: Graph
+        //
+        //      a --> b --> c --> d --> e
+        //      ^           |     ^     |
+        //      '-----------'     '-----'
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, a, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+        graph.add_edge(d, e, 1.into()).unwrap();
+        graph.add_edge(e, d, 1.into()).unwrap();
+
+        // When: Condensing the graph.
+        let sccs = TarjanSCC::init(&graph).execute(&graph);
+        let (condensed, component_of) = condensation(&graph, &sccs);
+
+        // Then: Each cycle becomes a vertex, connected by the single edge between them.
+        assert_eq!(condensed.vertex_count(), 2);
+        assert_eq!(condensed.edges_count(), 1);
+        assert_eq!(component_of.len(), 5);
+        assert!([a, b, c]
+            .iter()
+            .all(|vertex_id| component_of[vertex_id] == component_of[&a]));
+        assert!([d, e]
+            .iter()
+            .all(|vertex_id| component_of[vertex_id] == component_of[&d]));
+        assert!(condensed.is_adjacent(component_of[&a], component_of[&d]));
+        assert!(!condensed.is_adjacent(component_of[&d], component_of[&a]));
+    }
+
+    #[test]
+    fn parallel_edges_between_components_are_counted() {
+        // Given: Graph
+        //
+        //      a <--> b      c
+        //      |      |      ^
+        //      '------'------'
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, a, 1.into()).unwrap();
+        graph.add_edge(a, c, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+
+        // When: Condensing the graph.
+        let sccs = TarjanSCC::init(&graph).execute(&graph);
+        let (condensed, component_of) = condensation(&graph, &sccs);
+
+        // Then: Condensed graph is acyclic, and its edge weight counts the original edges.
+        let (ab, c) = (component_of[&a], component_of[&c]);
+        assert_eq!(condensed.neighbors(ab).unwrap(), vec![c]);
+        assert_eq!(
+            condensed.edges_between(ab, c).unwrap()[0].get_weight(),
+            &2.into()
+        );
+        assert!(HasCycle::init(&condensed).execute(&condensed).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn vertex_missing_from_components() {
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        graph.add_vertex();
+
+        condensation(&graph, &[vec![a]]);
+    }
+}
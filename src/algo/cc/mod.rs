@@ -1,5 +1,10 @@
+#[allow(clippy::module_inception)]
 mod cc;
+mod component_of;
+mod condensation;
 mod tarjan;
 
 pub use cc::ConnectedComponents;
+pub use component_of::component_of;
+pub use condensation::condensation;
 pub use tarjan::TarjanSCC;
@@ -0,0 +1,213 @@
+use num_traits::{ToPrimitive, Unsigned, Zero};
+use std::any::Any;
+use std::collections::HashMap;
+
+use crate::algo::Dijkstra;
+use crate::graph::{Edge, EdgeDir};
+use crate::provide::{Edges, Graph, Neighbors, Vertices};
+
+/// Computes closeness centrality of the vertices using [`Dijkstra`](crate::algo::Dijkstra).
+///
+/// Closeness of a vertex `v` is the reciprocal of the sum of shortest distances from `v` to all vertices reachable from it.
+/// In directed graphs, distances are measured along the outgoing edges of `v`.
+/// For unweighted graphs, set weight of every edge to 1 so distances are the number of edges.
+///
+/// In disconnected graphs, a vertex that reaches only a few close vertices gets a high closeness.
+/// To compensate, Wasserman-Faust correction scales closeness of each vertex by the fraction of other vertices it can reach.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::ClosenessCentrality;
+///
+/// // Given: Graph
+/// //
+/// //      a --1-- b --2-- c
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 2.into());
+///
+/// // When: Computing the centrality.
+/// let centrality = ClosenessCentrality::init(&graph).execute(&graph, false);
+///
+/// // Then:
+/// assert_eq!(centrality[&a], 1.0 / 4.0);
+/// assert_eq!(centrality[&b], 1.0 / 3.0);
+/// assert_eq!(centrality[&c], 1.0 / 5.0);
+/// ```
+pub struct ClosenessCentrality {
+    vertices: Vec<usize>,
+}
+
+impl ClosenessCentrality {
+    /// Initializes the structure.
+    ///
+    /// # Arguments
+    /// `graph`: Graph to compute centrality of its vertices.
+    pub fn init<G: Vertices>(graph: &G) -> Self {
+        ClosenessCentrality {
+            vertices: graph.vertices(),
+        }
+    }
+
+    /// Computes the centrality.
+    ///
+    /// # Arguments
+    /// * `graph`: Graph to compute centrality of its vertices.
+    /// * `wasserman_faust`: If `true`, closeness of each vertex is multiplied by r / (|V| - 1), where r is the number of other vertices it can reach.
+    ///
+    /// # Returns
+    /// Maps id of each vertex to its closeness centrality.
+    /// It is 0 for vertices that can not reach any other vertex(or only reach them with distance of zero).
+    ///
+    /// # Complexity
+    /// Runs `Dijkstra` once from every vertex.
+    pub fn execute<W, E, Dir, G>(&self, graph: &G, wasserman_faust: bool) -> HashMap<usize, f64>
+    where
+        W: Copy + Ord + Zero + Any + Unsigned + ToPrimitive,
+        E: Edge<W>,
+        Dir: EdgeDir,
+        G: Edges<W, E> + Neighbors + Vertices + Graph<W, E, Dir>,
+    {
+        self.vertices
+            .iter()
+            .copied()
+            .map(|src_id| {
+                let sp_subgraph = Dijkstra::init(graph).execute(graph, src_id);
+
+                let mut reachable_count = 0;
+                let mut distance_sum = 0.0;
+                for dst_id in self.vertices.iter().copied() {
+                    if let Some(distance) = sp_subgraph.distance_to(dst_id).unwrap().as_ref() {
+                        if dst_id != src_id {
+                            reachable_count += 1;
+                            distance_sum += distance.to_f64().unwrap();
+                        }
+                    }
+                }
+
+                let mut closeness = if distance_sum > 0.0 {
+                    1.0 / distance_sum
+                } else {
+                    0.0
+                };
+                if wasserman_faust && closeness > 0.0 {
+                    closeness *= reachable_count as f64 / (self.vertices.len() - 1) as f64;
+                }
+
+                (src_id, closeness)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::storage::{DiMat, Mat};
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        assert!(ClosenessCentrality::init(&graph)
+            .execute(&graph, true)
+            .is_empty());
+    }
+
+    #[test]
+    fn star_graph() {
+        // Given: Graph
+        //
+        //          b
+        //          |
+        //      c - a - d
+        //          |
+        //          e
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let leaves: Vec<usize> = (0..4).map(|_| graph.add_vertex()).collect();
+        for leaf_id in leaves.iter().copied() {
+            graph.add_edge(a, leaf_id, 1.into()).unwrap();
+        }
+
+        // When: Computing the centrality.
+        let centrality = ClosenessCentrality::init(&graph).execute(&graph, false);
+
+        // Then: Center is at distance 1 from all leaves, and each leaf is at distance 2 from other leaves.
+        assert_eq!(centrality[&a], 1.0 / 4.0);
+        for leaf_id in leaves {
+            assert_eq!(centrality[&leaf_id], 1.0 / 7.0);
+            assert!(centrality[&leaf_id] < centrality[&a]);
+        }
+
+        // Then: Correction does not change closeness in connected graphs.
+        assert_eq!(
+            ClosenessCentrality::init(&graph).execute(&graph, true),
+            centrality
+        );
+    }
+
+    #[test]
+    fn disconnected_graph() {
+        // Given: Graph
+        //
+        //      a --1-- b --1-- c       d --2-- e       f
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(d, e, 2.into()).unwrap();
+
+        // When: Computing the centrality.
+        let centrality = ClosenessCentrality::init(&graph).execute(&graph, false);
+        let corrected = ClosenessCentrality::init(&graph).execute(&graph, true);
+
+        // Then: Without correction, b and d look equally central.
+        assert_eq!(centrality[&b], 1.0 / 2.0);
+        assert_eq!(centrality[&d], 1.0 / 2.0);
+        assert_eq!(centrality[&f], 0.0);
+
+        // Then: With correction, b reaches 2 of the other 5 vertices and d reaches only 1.
+        assert!((corrected[&b] - 1.0 / 5.0).abs() < 1e-12);
+        assert!((corrected[&d] - 1.0 / 10.0).abs() < 1e-12);
+        assert_eq!(corrected[&f], 0.0);
+        assert!(corrected[&b] > corrected[&d]);
+    }
+
+    #[test]
+    fn directed_graph() {
+        // Given: Graph
+        //
+        //      a --1--> b --3--> c
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 3.into()).unwrap();
+
+        // When: Computing the centrality.
+        let centrality = ClosenessCentrality::init(&graph).execute(&graph, true);
+
+        // Then: Only outgoing distances are counted, and c reaches no vertex.
+        assert_eq!(centrality[&a], 1.0 / 5.0);
+        assert_eq!(centrality[&b], 1.0 / 3.0 * 1.0 / 2.0);
+        assert_eq!(centrality[&c], 0.0);
+    }
+}
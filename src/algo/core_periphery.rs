@@ -0,0 +1,237 @@
+use std::collections::HashSet;
+
+use crate::{
+    graph::{Edge, UndirectedEdge},
+    provide::{Edges, Graph, IdMap, Vertices},
+};
+
+/// Partitions vertices of an undirected graph into a densely connected core and a sparsely connected periphery.
+///
+/// Ideal core-periphery structure is a core in which every pair of vertices is connected and a periphery in which no pair is connected.
+/// Pairs with one vertex in the core and the other in the periphery are ignored, as they can go both ways.
+/// Vertices are sorted by their degree and every prefix of them is tried as the core.
+/// The partition whose adjacency has the highest correlation with the ideal structure is returned, preferring larger cores on ties.
+///
+/// Weights of the edges and parallel edges are ignored.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::CorePeriphery;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b --- d
+/// //       \   /
+/// //         c --- e
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// let e = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(c, a, 1.into());
+/// graph.add_edge(b, d, 1.into());
+/// graph.add_edge(c, e, 1.into());
+///
+/// // When: Splitting the graph.
+/// let (core, periphery) = CorePeriphery::init(&graph).execute();
+///
+/// // Then:
+/// assert_eq!(core, [a, b, c].iter().copied().collect());
+/// assert_eq!(periphery, [d, e].iter().copied().collect());
+/// ```
+pub struct CorePeriphery {
+    id_map: IdMap,
+
+    // Maps each vertex(by virtual id) to virtual id of its neighbors.
+    adjacency: Vec<HashSet<usize>>,
+}
+
+impl CorePeriphery {
+    /// Initializes the structure.
+    ///
+    /// # Arguments
+    /// `graph`: Undirected graph to split.
+    pub fn init<W, E: Edge<W>, G>(graph: &G) -> Self
+    where
+        G: Vertices + Edges<W, E> + Graph<W, E, UndirectedEdge>,
+    {
+        let id_map = graph.continuos_id_map();
+
+        let mut adjacency = vec![HashSet::new(); graph.vertex_count()];
+        for (src_id, dst_id, _) in graph.edges() {
+            let src_virt_id = id_map.virt_id_of(src_id);
+            let dst_virt_id = id_map.virt_id_of(dst_id);
+
+            adjacency[src_virt_id].insert(dst_virt_id);
+            adjacency[dst_virt_id].insert(src_virt_id);
+        }
+
+        CorePeriphery { id_map, adjacency }
+    }
+
+    /// Splits the graph.
+    ///
+    /// # Returns
+    /// Id of the vertices in the format of (`core`, `periphery`).
+    /// If no partition correlates with the ideal structure(for example when graph has no edges), all vertices are put in the periphery.
+    ///
+    /// # Complexity
+    /// O(|V|log(|V|) + |E|)
+    pub fn execute(&self) -> (HashSet<usize>, HashSet<usize>) {
+        let vertex_count = self.adjacency.len();
+        let total_edges_count: usize = self.adjacency.iter().map(|n| n.len()).sum::<usize>() / 2;
+
+        let mut sorted: Vec<usize> = (0..vertex_count).collect();
+        sorted.sort_by_key(|virt_id| std::cmp::Reverse(self.adjacency[*virt_id].len()));
+
+        let mut in_core = vec![false; vertex_count];
+        let mut core_edges_count = 0;
+        let mut cross_edges_count = 0;
+        let mut best: Option<(f64, usize)> = None;
+        for (index, virt_id) in sorted.iter().copied().enumerate() {
+            let core_neighbors_count = self.adjacency[virt_id]
+                .iter()
+                .filter(|n_virt_id| in_core[**n_virt_id])
+                .count();
+            let degree = self.adjacency[virt_id].len();
+
+            in_core[virt_id] = true;
+            core_edges_count += core_neighbors_count;
+            cross_edges_count = cross_edges_count + degree - 2 * core_neighbors_count;
+
+            let core_size = index + 1;
+            let periphery_size = vertex_count - core_size;
+            let periphery_edges_count = total_edges_count - core_edges_count - cross_edges_count;
+
+            if let Some(correlation) = CorePeriphery::correlation(
+                core_size * (core_size.max(1) - 1) / 2,
+                core_edges_count,
+                periphery_size * (periphery_size.max(1) - 1) / 2,
+                periphery_edges_count,
+            ) {
+                // On ties, larger core is preferred. Because pairs between core and periphery are ignored,
+                // a vertex connected to every core vertex fits both in the core and in the periphery.
+                if best.map_or(true, |(best_correlation, _)| {
+                    correlation >= best_correlation
+                }) {
+                    best = Some((correlation, core_size));
+                }
+            }
+        }
+
+        let core_size = best.map_or(0, |(_, core_size)| core_size);
+
+        let core = sorted[..core_size]
+            .iter()
+            .map(|virt_id| self.id_map.real_id_of(*virt_id))
+            .collect();
+        let periphery = sorted[core_size..]
+            .iter()
+            .map(|virt_id| self.id_map.real_id_of(*virt_id))
+            .collect();
+
+        (core, periphery)
+    }
+
+    // Pearson correlation between adjacency of the pairs and the ideal structure,
+    // in which core pairs are connected and periphery pairs are not.
+    // Returns `None` if either of them is constant, so correlation is not defined.
+    fn correlation(
+        core_pairs_count: usize,
+        core_edges_count: usize,
+        periphery_pairs_count: usize,
+        periphery_edges_count: usize,
+    ) -> Option<f64> {
+        let connected_core = core_edges_count as f64;
+        let disconnected_core = (core_pairs_count - core_edges_count) as f64;
+        let connected_periphery = periphery_edges_count as f64;
+        let disconnected_periphery = (periphery_pairs_count - periphery_edges_count) as f64;
+
+        let denominator = (core_pairs_count as f64
+            * periphery_pairs_count as f64
+            * (connected_core + connected_periphery)
+            * (disconnected_core + disconnected_periphery))
+            .sqrt();
+
+        if denominator == 0.0 {
+            None
+        } else {
+            Some(
+                (connected_core * disconnected_periphery - disconnected_core * connected_periphery)
+                    / denominator,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::storage::Mat;
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        let (core, periphery) = CorePeriphery::init(&graph).execute();
+
+        assert!(core.is_empty());
+        assert!(periphery.is_empty());
+    }
+
+    #[test]
+    fn graph_without_edges() {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let vertices: HashSet<usize> = (0..4).map(|_| graph.add_vertex()).collect();
+
+        let (core, periphery) = CorePeriphery::init(&graph).execute();
+
+        assert!(core.is_empty());
+        assert_eq!(periphery, vertices);
+    }
+
+    #[test]
+    fn dense_core() {
+        // Given: Clique of 5 vertices as core, and 10 periphery vertices each connected to one or two core vertices.
+        // Also two pairs of periphery vertices are connected, so periphery is not completely empty.
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let core_vertices: Vec<usize> = (0..5).map(|_| graph.add_vertex()).collect();
+        for (index, src_id) in core_vertices.iter().enumerate() {
+            for dst_id in core_vertices.iter().skip(index + 1) {
+                graph.add_edge(*src_id, *dst_id, 1.into()).unwrap();
+            }
+        }
+        let periphery_vertices: Vec<usize> = (0..10).map(|_| graph.add_vertex()).collect();
+        for (index, vertex_id) in periphery_vertices.iter().enumerate() {
+            graph
+                .add_edge(*vertex_id, core_vertices[index % 5], 1.into())
+                .unwrap();
+            if index % 2 == 0 {
+                graph
+                    .add_edge(*vertex_id, core_vertices[(index + 1) % 5], 1.into())
+                    .unwrap();
+            }
+        }
+        graph
+            .add_edge(periphery_vertices[0], periphery_vertices[1], 1.into())
+            .unwrap();
+        graph
+            .add_edge(periphery_vertices[5], periphery_vertices[6], 1.into())
+            .unwrap();
+
+        // When: Splitting the graph.
+        let (core, periphery) = CorePeriphery::init(&graph).execute();
+
+        // Then:
+        assert_eq!(core, core_vertices.into_iter().collect());
+        assert_eq!(periphery, periphery_vertices.into_iter().collect());
+    }
+}
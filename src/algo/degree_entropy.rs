@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use crate::algo::utils::degrees_of;
+use crate::graph::Edge;
+use crate::provide::{Edges, Vertices};
+
+/// Computes Shannon entropy of the degree distribution of the graph: -Σ p(d) ln p(d),
+/// where p(d) is the fraction of vertices with degree d. In directed graphs d is the sum of in-degree and out-degree.
+///
+/// # Arguments
+/// `graph`: Graph to compute entropy of its degree distribution.
+///
+/// # Returns
+/// Entropy in nats. It is 0 for regular graphs and for the empty graph.
+///
+/// # Complexity
+/// O(|V| + |E|)
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::degree_entropy;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b --- c --- d
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(c, d, 1.into());
+///
+/// // Then: Half of the vertices have degree 1 and the other half have degree 2.
+/// assert!((degree_entropy(&graph) - 2f64.ln()).abs() < 1e-12);
+/// ```
+pub fn degree_entropy<W, E, G>(graph: &G) -> f64
+where
+    E: Edge<W>,
+    G: Edges<W, E> + Vertices,
+{
+    let vertex_count = graph.vertex_count();
+    if vertex_count == 0 {
+        return 0.0;
+    }
+
+    // Number of vertices with each degree.
+    let mut count_of: HashMap<usize, usize> = HashMap::new();
+    for degree in degrees_of(graph).values() {
+        *count_of.entry(*degree).or_insert(0) += 1;
+    }
+
+    count_of
+        .values()
+        .map(|count| {
+            let probability = *count as f64 / vertex_count as f64;
+
+            -probability * probability.ln()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::provide::Graph;
+    use crate::storage::{DiMat, Mat};
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        assert_eq!(degree_entropy(&graph), 0.0);
+    }
+
+    #[test]
+    fn regular_graph() {
+        // Given: Cycle of 6 vertices, in which every vertex has degree 2.
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let vertices: Vec<usize> = (0..6).map(|_| graph.add_vertex()).collect();
+        for (index, src_id) in vertices.iter().enumerate() {
+            let dst_id = vertices[(index + 1) % vertices.len()];
+            graph.add_edge(*src_id, dst_id, 1.into()).unwrap();
+        }
+
+        assert_eq!(degree_entropy(&graph), 0.0);
+    }
+
+    #[test]
+    fn varied_degrees() {
+        // Given: Star with 3 leaves and an isolated vertex.
+        //
+        //      b   c   d
+        //       \  |  /
+        //          a           e
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        for _ in 0..3 {
+            let leaf = graph.add_vertex();
+            graph.add_edge(a, leaf, 1.into()).unwrap();
+        }
+        graph.add_vertex();
+
+        // Then: Degrees are 3, 1, 1, 1, 0.
+        let expected = -(0.2f64 * 0.2f64.ln() + 0.6 * 0.6f64.ln() + 0.2 * 0.2f64.ln());
+        let entropy = degree_entropy(&graph);
+        assert!(entropy > 0.0);
+        assert!((entropy - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn directed_graph_counts_both_directions() {
+        // Given: Directed cycle, in which every vertex has one incoming and one outgoing edge.
+        //
+        //      a --> b --> c --> a
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, a, 1.into()).unwrap();
+
+        assert_eq!(degree_entropy(&graph), 0.0);
+    }
+}
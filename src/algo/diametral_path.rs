@@ -0,0 +1,190 @@
+use magnitude::Magnitude;
+use num_traits::{Unsigned, Zero};
+use std::any::Any;
+
+use crate::algo::{AStar, Dijkstra};
+use crate::graph::{Edge, EdgeDir};
+use crate::provide::{Edges, Graph, Neighbors, Vertices};
+
+/// Finds the diametral path of the graph: the longest one among shortest paths between every pair of vertices.
+///
+/// Only pairs of vertices that can reach each other are considered, so for disconnected graphs the longest shortest path inside any of the components is returned.
+///
+/// # Arguments
+/// `graph`: Graph to search for the diametral path in.
+///
+/// # Returns
+/// Weight of the diametral path(diameter of the graph) alongside id of vertices on the path, in order of traversal.
+/// If there are multiple candidates, the one that starts and ends at vertices that come first in `graph.vertices()` is picked.
+/// For an empty graph, weight is zero and the path is empty.
+///
+/// # Complexity
+/// Runs `Dijkstra` once from every vertex.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::diametral_path;
+///
+/// // Given: Graph
+/// //
+/// //      a --1-- b --2-- c
+/// //              |
+/// //              4
+/// //              |
+/// //              d
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 2.into());
+/// graph.add_edge(b, d, 4.into());
+///
+/// // When: Searching for the diametral path.
+/// let (diameter, path) = diametral_path(&graph);
+///
+/// // Then:
+/// assert_eq!(diameter, 6.into());
+/// assert_eq!(path, vec![c, b, d]);
+/// ```
+pub fn diametral_path<W, E, Ty, G>(graph: &G) -> (Magnitude<W>, Vec<usize>)
+where
+    W: Copy + Ord + Zero + Any + Unsigned,
+    E: Edge<W>,
+    Ty: EdgeDir,
+    G: Edges<W, E> + Neighbors + Vertices + Graph<W, E, Ty>,
+{
+    let vertices = graph.vertices();
+
+    // Farthest pair found so far, in the format of (src_id, dst_id, distance).
+    let mut farthest: Option<(usize, usize, Magnitude<W>)> = None;
+    for src_id in vertices.iter().copied() {
+        let sp_subgraph = Dijkstra::init(graph).execute(graph, src_id);
+
+        for dst_id in vertices.iter().copied() {
+            let distance = sp_subgraph.distance_to(dst_id).unwrap();
+
+            // Unreachable vertices are in another component.
+            if distance.is_finite()
+                && farthest.map_or(true, |(_, _, max_distance)| distance > max_distance)
+            {
+                farthest = Some((src_id, dst_id, distance));
+            }
+        }
+    }
+
+    match farthest {
+        None => (W::zero().into(), vec![]),
+        Some((src_id, dst_id, distance)) => {
+            let (_, path) = AStar::init(graph)
+                .execute(graph, src_id, dst_id, |_| W::zero().into())
+                .unwrap();
+
+            (distance, path)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::path_weight;
+    use crate::graph::MatGraph;
+    use crate::storage::{DiMat, Mat};
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        let (diameter, path) = diametral_path(&graph);
+
+        assert_eq!(diameter, 0.into());
+        assert!(path.is_empty());
+    }
+
+    #[test]
+    fn single_vertex() {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+
+        let (diameter, path) = diametral_path(&graph);
+
+        assert_eq!(diameter, 0.into());
+        assert_eq!(path, vec![a]);
+    }
+
+    #[test]
+    fn path_graph() {
+        // Given: Graph
+        //
+        //      a --2-- b --1-- c --3-- d --1-- e
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let vertices: Vec<usize> = (0..5).map(|_| graph.add_vertex()).collect();
+        let weights = [2, 1, 3, 1];
+        for (pair, weight) in vertices.windows(2).zip(weights.iter()) {
+            graph.add_edge(pair[0], pair[1], (*weight).into()).unwrap();
+        }
+
+        // When: Searching for the diametral path.
+        let (diameter, path) = diametral_path(&graph);
+
+        // Then: Path goes from one end to the other.
+        assert_eq!(diameter, 7.into());
+        assert_eq!(path, vertices);
+        assert_eq!(path_weight(&graph, &path), Some(diameter));
+    }
+
+    #[test]
+    fn disconnected_graph() {
+        // Given: Graph
+        //
+        //      a --1-- b --1-- c       d --5-- e
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(d, e, 5.into()).unwrap();
+
+        // When: Searching for the diametral path.
+        let (diameter, path) = diametral_path(&graph);
+
+        // Then: Longest shortest path among components is picked.
+        assert_eq!(diameter, 5.into());
+        assert_eq!(path, vec![d, e]);
+    }
+
+    #[test]
+    fn directed_graph() {
+        // Given: Graph
+        //
+        //      a --1--> b --1--> c
+        //      ^                 |
+        //      '--------1--------'
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, a, 1.into()).unwrap();
+
+        // When: Searching for the diametral path.
+        let (diameter, path) = diametral_path(&graph);
+
+        // Then:
+        assert_eq!(diameter, 2.into());
+        assert_eq!(path, vec![a, b, c]);
+    }
+}
@@ -0,0 +1,361 @@
+use std::collections::VecDeque;
+
+use crate::algo::max_flow::residual_of;
+use crate::graph::{DirectedEdge, Edge, FlowEdge};
+use crate::provide::{Edges, Graph, IdMap, Vertices};
+
+/// Finds maximum flow from a source to a sink using Dinic's algorithm.
+///
+/// In each phase a bfs builds the level graph of the residual network(distance of each vertex from source),
+/// and dfs pushes a blocking flow along the edges that go from one level to the next, until sink is unreachable from source.
+/// Residual network is the same as [`MaxFlow`](crate::algo::MaxFlow), but there are at most |V| phases, which makes it faster on dense networks.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::DiFlowMat;
+/// use prepona::graph::{FlowEdge, SimpleGraph};
+/// use prepona::algo::Dinic;
+///
+/// // Given: Network with capacities on the edges.
+/// //
+/// //      .--3--> a --2--.
+/// //      |       |      v
+/// //      s       1      t
+/// //      |       v      ^
+/// //      '--2--> b --3--'
+/// //
+/// let mut graph = SimpleGraph::init(DiFlowMat::<usize>::init());
+/// let s = graph.add_vertex();
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let t = graph.add_vertex();
+/// graph.add_edge(s, a, FlowEdge::init_with(1.into(), 3, 0));
+/// graph.add_edge(s, b, FlowEdge::init_with(1.into(), 2, 0));
+/// let ab = graph.add_edge(a, b, FlowEdge::init_with(1.into(), 1, 0)).unwrap();
+/// graph.add_edge(a, t, FlowEdge::init_with(1.into(), 2, 0));
+/// graph.add_edge(b, t, FlowEdge::init_with(1.into(), 3, 0));
+///
+/// // When: Finding the max flow.
+/// let flow = Dinic::init(&graph).execute(&mut graph, s, t);
+///
+/// // Then:
+/// assert_eq!(flow, 5);
+/// assert_eq!(graph.edge(ab).unwrap().get_flow(), 1);
+/// ```
+pub struct Dinic {
+    id_map: IdMap,
+    level_of: Vec<Option<usize>>,
+
+    // Index of the next residual edge of each vertex that dfs should try in the current phase.
+    next_of: Vec<usize>,
+}
+
+impl Dinic {
+    /// Initializes the structure.
+    pub fn init<W, G>(graph: &G) -> Self
+    where
+        G: Vertices + Edges<W, FlowEdge<W>> + Graph<W, FlowEdge<W>, DirectedEdge>,
+    {
+        Dinic {
+            id_map: graph.continuos_id_map(),
+            level_of: vec![None; graph.vertex_count()],
+            next_of: vec![0; graph.vertex_count()],
+        }
+    }
+
+    /// Finds the maximum flow and stores flow of each edge in it.
+    ///
+    /// # Arguments
+    /// * `graph`: Directed flow network. Capacities of edges are used as capacities of the network and weights are ignored.
+    ///   Flow of every edge is overwritten, so the residual network can be inspected afterward using flow and capacity of the edges.
+    /// * `src_id`: Id of the source vertex.
+    /// * `dst_id`: Id of the sink vertex.
+    ///
+    /// # Returns
+    /// Value of the maximum flow, which is 0 if `src_id` and `dst_id` are the same.
+    ///
+    /// # Complexity
+    /// O(|V|<sup>2</sup>|E|)
+    ///
+    /// # Panics
+    /// If `src_id` or `dst_id` is not in the graph.
+    pub fn execute<W, G>(mut self, graph: &mut G, src_id: usize, dst_id: usize) -> usize
+    where
+        W: Clone,
+        G: Vertices + Edges<W, FlowEdge<W>> + Graph<W, FlowEdge<W>, DirectedEdge>,
+    {
+        let src_virt_id = self.id_map.virt_id_of(src_id);
+        let dst_virt_id = self.id_map.virt_id_of(dst_id);
+
+        // Each edge is stored as (src_virt_id, dst_virt_id, edge_id, capacity), and its flow at the same index in `flow_of`.
+        let arcs: Vec<(usize, usize, usize, isize)> = graph
+            .edges()
+            .into_iter()
+            .map(|(src_id, dst_id, edge)| {
+                (
+                    self.id_map.virt_id_of(src_id),
+                    self.id_map.virt_id_of(dst_id),
+                    edge.get_id(),
+                    edge.get_capacity() as isize,
+                )
+            })
+            .collect();
+        let mut flow_of = vec![0isize; arcs.len()];
+
+        // Residual edges going out of each vertex as (index of the arc, is forward).
+        let mut residuals_of: Vec<Vec<(usize, bool)>> = vec![vec![]; self.level_of.len()];
+        for (index, (src_virt_id, dst_virt_id, _, _)) in arcs.iter().enumerate() {
+            residuals_of[*src_virt_id].push((index, true));
+            residuals_of[*dst_virt_id].push((index, false));
+        }
+
+        let mut total_flow = 0;
+        while src_virt_id != dst_virt_id
+            && self.build_levels(&arcs, &residuals_of, &flow_of, src_virt_id, dst_virt_id)
+        {
+            self.next_of.iter_mut().for_each(|next| *next = 0);
+
+            loop {
+                let pushed = self.push(
+                    &arcs,
+                    &residuals_of,
+                    &mut flow_of,
+                    src_virt_id,
+                    dst_virt_id,
+                    isize::MAX,
+                );
+                if pushed == 0 {
+                    break;
+                }
+
+                total_flow += pushed as usize;
+            }
+        }
+
+        for ((src_virt_id, dst_virt_id, edge_id, _), flow) in arcs.iter().zip(flow_of) {
+            let src_id = self.id_map.real_id_of(*src_virt_id);
+            let dst_id = self.id_map.real_id_of(*dst_virt_id);
+
+            let mut edge = graph.edge(*edge_id).unwrap().clone();
+            edge.set_flow(flow);
+            graph.update_edge(src_id, dst_id, *edge_id, edge).unwrap();
+        }
+
+        total_flow
+    }
+
+    // Bfs in residual network to find level of each vertex, which is its distance from source.
+    //
+    // # Returns
+    // `true` if sink is reachable from source in residual network, `false` otherwise.
+    fn build_levels(
+        &mut self,
+        arcs: &[(usize, usize, usize, isize)],
+        residuals_of: &[Vec<(usize, bool)>],
+        flow_of: &[isize],
+        src_virt_id: usize,
+        dst_virt_id: usize,
+    ) -> bool {
+        self.level_of.iter_mut().for_each(|level| *level = None);
+        self.level_of[src_virt_id] = Some(0);
+
+        let mut queue = VecDeque::from(vec![src_virt_id]);
+        while let Some(virt_id) = queue.pop_front() {
+            let level = self.level_of[virt_id].unwrap();
+
+            for (index, is_forward) in residuals_of[virt_id].iter().copied() {
+                let n_virt_id = other_end_of(arcs, index, is_forward);
+
+                if self.level_of[n_virt_id].is_none()
+                    && residual_of(arcs, flow_of, index, is_forward) > 0
+                {
+                    self.level_of[n_virt_id] = Some(level + 1);
+                    queue.push_back(n_virt_id);
+                }
+            }
+        }
+
+        self.level_of[dst_virt_id].is_some()
+    }
+
+    // Dfs along the level graph to push at most `limit` units of flow from `virt_id` to sink.
+    // Residual edges that can not push any more flow in this phase are skipped for good using `next_of`.
+    //
+    // # Returns
+    // Amount of the pushed flow, which is 0 if there is no path to sink.
+    fn push(
+        &mut self,
+        arcs: &[(usize, usize, usize, isize)],
+        residuals_of: &[Vec<(usize, bool)>],
+        flow_of: &mut [isize],
+        virt_id: usize,
+        dst_virt_id: usize,
+        limit: isize,
+    ) -> isize {
+        if virt_id == dst_virt_id {
+            return limit;
+        }
+
+        while let Some((index, is_forward)) =
+            residuals_of[virt_id].get(self.next_of[virt_id]).copied()
+        {
+            let n_virt_id = other_end_of(arcs, index, is_forward);
+            let residual = residual_of(arcs, flow_of, index, is_forward);
+
+            if residual > 0
+                && self.level_of[n_virt_id] == self.level_of[virt_id].map(|level| level + 1)
+            {
+                let pushed = self.push(
+                    arcs,
+                    residuals_of,
+                    flow_of,
+                    n_virt_id,
+                    dst_virt_id,
+                    limit.min(residual),
+                );
+
+                if pushed > 0 {
+                    flow_of[index] += if is_forward { pushed } else { -pushed };
+                    return pushed;
+                }
+            }
+
+            self.next_of[virt_id] += 1;
+        }
+
+        0
+    }
+}
+
+// Returns virtual id of the vertex that the forward or backward residual edge of the arc at `index` goes to.
+fn other_end_of(arcs: &[(usize, usize, usize, isize)], index: usize, is_forward: bool) -> usize {
+    if is_forward {
+        arcs[index].1
+    } else {
+        arcs[index].0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::max_flow::assert_is_valid_flow;
+    use crate::algo::MaxFlow;
+    use crate::graph::SimpleGraph;
+    use crate::storage::{DiFlowList, DiFlowMat};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    type Network = SimpleGraph<usize, FlowEdge<usize>, DirectedEdge, DiFlowList<usize>>;
+
+    // Builds a network with a source, `layers` layers of `width` vertices and a sink.
+    // Source is connected to every vertex of the first layer, every vertex of the last layer is connected to sink,
+    // and every vertex of a layer is connected to every vertex of the next one. Capacities are chosen at random.
+    fn layered_network(layers: usize, width: usize, seed: u64) -> (Network, usize, usize) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut graph = SimpleGraph::init(DiFlowList::<usize>::init());
+
+        let s = graph.add_vertex();
+        let t = graph.add_vertex();
+        let layer_ids: Vec<Vec<usize>> = (0..layers)
+            .map(|_| (0..width).map(|_| graph.add_vertex()).collect())
+            .collect();
+
+        let mut pairs: Vec<(usize, usize)> = layer_ids[0].iter().map(|v_id| (s, *v_id)).collect();
+        for window in layer_ids.windows(2) {
+            for src_id in &window[0] {
+                pairs.extend(window[1].iter().map(|dst_id| (*src_id, *dst_id)));
+            }
+        }
+        pairs.extend(layer_ids[layers - 1].iter().map(|v_id| (*v_id, t)));
+
+        for (src_id, dst_id) in pairs {
+            let capacity = rng.gen_range(1..20);
+            graph
+                .add_edge(src_id, dst_id, FlowEdge::init_with(1.into(), capacity, 0))
+                .unwrap();
+        }
+
+        (graph, s, t)
+    }
+
+    #[test]
+    fn same_as_edmonds_karp_on_layered_networks() {
+        for seed in 0..5 {
+            // Given: Layered network with 6 layers of 8 vertices.
+            let (mut graph, s, t) = layered_network(6, 8, seed);
+            let (mut expected_graph, _, _) = layered_network(6, 8, seed);
+
+            // When: Finding the max flow with both algorithms.
+            let flow = Dinic::init(&graph).execute(&mut graph, s, t);
+            let expected = MaxFlow::init(&expected_graph).execute(&mut expected_graph, s, t);
+
+            // Then:
+            assert_eq!(flow, expected);
+            assert!(flow > 0);
+            assert_is_valid_flow(&graph, s, t, flow);
+        }
+    }
+
+    #[test]
+    fn flow_is_cancelled_through_backward_edges() {
+        // Given: Network
+        //
+        //      s --1--> a --1--> t
+        //      |        |        ^
+        //      1        1        1
+        //      v        v        |
+        //      b --1--> c -------'
+        //
+        // Where dfs may first push through s -> a -> c -> t, which has to be undone to reach the max flow of 2.
+        let mut graph = SimpleGraph::init(DiFlowMat::<usize>::init());
+        let s = graph.add_vertex();
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let t = graph.add_vertex();
+        for (src_id, dst_id) in [(s, a), (a, c), (c, t), (s, b), (b, c), (a, t)] {
+            graph
+                .add_edge(src_id, dst_id, FlowEdge::init_with(1.into(), 1, 0))
+                .unwrap();
+        }
+
+        // When: Finding the max flow.
+        let flow = Dinic::init(&graph).execute(&mut graph, s, t);
+
+        // Then:
+        assert_eq!(flow, 2);
+        assert_is_valid_flow(&graph, s, t, flow);
+    }
+
+    #[test]
+    fn previous_flow_is_overwritten() {
+        let mut graph = SimpleGraph::init(DiFlowMat::<usize>::init());
+        let s = graph.add_vertex();
+        let a = graph.add_vertex();
+        let t = graph.add_vertex();
+        graph
+            .add_edge(s, a, FlowEdge::init_with(1.into(), 3, 3))
+            .unwrap();
+        graph
+            .add_edge(a, t, FlowEdge::init_with(1.into(), 2, 1))
+            .unwrap();
+
+        assert_eq!(Dinic::init(&graph).execute(&mut graph, s, t), 2);
+        assert_is_valid_flow(&graph, s, t, 2);
+    }
+
+    #[test]
+    fn unreachable_sink_and_source_is_sink() {
+        let mut graph = SimpleGraph::init(DiFlowMat::<usize>::init());
+        let s = graph.add_vertex();
+        let t = graph.add_vertex();
+        graph
+            .add_edge(t, s, FlowEdge::init_with(1.into(), 5, 0))
+            .unwrap();
+
+        assert_eq!(Dinic::init(&graph).execute(&mut graph, s, t), 0);
+        assert_eq!(Dinic::init(&graph).execute(&mut graph, s, s), 0);
+        assert_eq!(graph.edges_between(t, s).unwrap()[0].get_flow(), 0);
+    }
+}
@@ -0,0 +1,489 @@
+use std::collections::HashMap;
+
+use crate::algo::{split_vertices, MaxFlow};
+use crate::graph::{with_vertices_of, DirectedEdge, Edge, EdgeDir, FlowEdge, SimpleGraph};
+use crate::provide::{Edges, Graph, Vertices};
+use crate::storage::DiFlowList;
+
+/// Finds maximum number of edge-disjoint paths between two vertices.
+///
+/// Each edge gets a capacity of one, and paths are extracted from the maximum flow between the vertices.
+/// By Menger's theorem, the number of paths equals the minimum number of edges whose removal disconnects `dst_id` from `src_id`.
+/// In undirected graphs each edge can be traversed in either direction, but is still used by at most one of the paths.
+///
+/// # Arguments
+/// * `graph`: Graph to search for the paths in. Weights of the edges are ignored.
+/// * `src_id`: Id of the vertex that paths start from.
+/// * `dst_id`: Id of the vertex that paths end at.
+///
+/// # Returns
+/// Paths as id of the vertices on them, each starting with `src_id` and ending with `dst_id`. It is empty if `src_id` and `dst_id` are the same.
+///
+/// # Complexity
+/// O(|V||E|<sup>2</sup>)
+///
+/// # Panics
+/// If `src_id` or `dst_id` is not in the graph.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::edge_disjoint_paths;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b --- c
+/// //      |   /   \   |
+/// //      | /       \ |
+/// //      d           e
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// let e = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(a, d, 1.into());
+/// graph.add_edge(d, b, 1.into());
+/// graph.add_edge(b, e, 1.into());
+/// graph.add_edge(e, c, 1.into());
+///
+/// // When: Finding edge-disjoint paths from a to c.
+/// let paths = edge_disjoint_paths(&graph, a, c);
+///
+/// // Then: Both paths pass through b, but they use different edges.
+/// assert_eq!(paths.len(), 2);
+/// assert!(paths.iter().all(|path| path.contains(&b)));
+/// ```
+pub fn edge_disjoint_paths<W, E, Dir, G>(graph: &G, src_id: usize, dst_id: usize) -> Vec<Vec<usize>>
+where
+    E: Edge<W>,
+    Dir: EdgeDir,
+    G: Vertices + Edges<W, E> + Graph<W, E, Dir>,
+{
+    let mut network = unit_network(graph);
+
+    MaxFlow::init(&network).execute(&mut network, src_id, dst_id);
+
+    decompose_flow(&network, src_id, dst_id)
+}
+
+/// Finds maximum number of internally vertex-disjoint paths between two vertices.
+///
+/// Each edge and each vertex other than `src_id` and `dst_id` gets a capacity of one,
+/// using [`split_vertices`](crate::algo::split_vertices), and paths are extracted from the maximum flow between the vertices.
+/// By Menger's theorem, if `src_id` and `dst_id` are not adjacent,
+/// the number of paths equals the minimum number of vertices whose removal disconnects `dst_id` from `src_id`.
+///
+/// # Arguments
+/// * `graph`: Graph to search for the paths in. Weights of the edges are ignored.
+/// * `src_id`: Id of the vertex that paths start from.
+/// * `dst_id`: Id of the vertex that paths end at.
+///
+/// # Returns
+/// Paths as id of the vertices on them, each starting with `src_id` and ending with `dst_id`.
+/// No two paths share a vertex other than `src_id` and `dst_id`. It is empty if `src_id` and `dst_id` are the same.
+///
+/// # Complexity
+/// O(|V||E|<sup>2</sup>)
+///
+/// # Panics
+/// If `src_id` or `dst_id` is not in the graph.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::vertex_disjoint_paths;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b --- c
+/// //      |   /   \   |
+/// //      | /       \ |
+/// //      d           e
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// let e = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(a, d, 1.into());
+/// graph.add_edge(d, b, 1.into());
+/// graph.add_edge(b, e, 1.into());
+/// graph.add_edge(e, c, 1.into());
+///
+/// // When: Finding vertex-disjoint paths from a to c.
+/// let paths = vertex_disjoint_paths(&graph, a, c);
+///
+/// // Then: b is on every path from a to c, so only one path can pass through it.
+/// assert_eq!(paths.len(), 1);
+/// ```
+pub fn vertex_disjoint_paths<W, E, Dir, G>(
+    graph: &G,
+    src_id: usize,
+    dst_id: usize,
+) -> Vec<Vec<usize>>
+where
+    E: Edge<W>,
+    Dir: EdgeDir,
+    G: Vertices + Edges<W, E> + Graph<W, E, Dir>,
+{
+    let network = unit_network(graph);
+
+    let capacities: HashMap<usize, usize> = network
+        .vertices()
+        .into_iter()
+        .filter(|vertex_id| *vertex_id != src_id && *vertex_id != dst_id)
+        .map(|vertex_id| (vertex_id, 1))
+        .collect();
+    let (mut split, ids_of) = split_vertices(&network, DiFlowList::init(), &capacities);
+
+    let (split_src_id, split_dst_id) = (ids_of[&src_id].1, ids_of[&dst_id].0);
+    MaxFlow::init(&split).execute(&mut split, split_src_id, split_dst_id);
+
+    // Maps both in and out vertex of each split vertex back to the original vertex.
+    let original_of: HashMap<usize, usize> = ids_of
+        .into_iter()
+        .flat_map(|(vertex_id, (in_id, out_id))| vec![(in_id, vertex_id), (out_id, vertex_id)])
+        .collect();
+
+    decompose_flow(&split, split_src_id, split_dst_id)
+        .into_iter()
+        .map(|path| {
+            let mut path: Vec<usize> = path
+                .into_iter()
+                .map(|vertex_id| original_of[&vertex_id])
+                .collect();
+            path.dedup();
+            path
+        })
+        .collect()
+}
+
+// Creates a directed network with the same vertices as `graph`, in which each edge has capacity of one.
+// Edges of undirected graphs are added in both directions.
+fn unit_network<W, E, Dir, G>(
+    graph: &G,
+) -> SimpleGraph<usize, FlowEdge<usize>, DirectedEdge, DiFlowList<usize>>
+where
+    E: Edge<W>,
+    Dir: EdgeDir,
+    G: Vertices + Edges<W, E> + Graph<W, E, Dir>,
+{
+    let mut network = with_vertices_of(graph, DiFlowList::init());
+
+    for (src_id, dst_id, _) in graph.edges() {
+        if src_id == dst_id {
+            continue;
+        }
+
+        network
+            .add_edge(src_id, dst_id, FlowEdge::init_with(1.into(), 1, 0))
+            .unwrap();
+        if !Dir::is_directed() {
+            network
+                .add_edge(dst_id, src_id, FlowEdge::init_with(1.into(), 1, 0))
+                .unwrap();
+        }
+    }
+
+    network
+}
+
+// Splits the flow of `network` into paths from `src_id` to `dst_id`. Cycles of flow are dropped.
+fn decompose_flow(
+    network: &SimpleGraph<usize, FlowEdge<usize>, DirectedEdge, DiFlowList<usize>>,
+    src_id: usize,
+    dst_id: usize,
+) -> Vec<Vec<usize>> {
+    if src_id == dst_id {
+        return vec![];
+    }
+
+    let mut flow_dsts_of: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (edge_src_id, edge_dst_id, edge) in network.edges() {
+        if edge.get_flow() > 0 {
+            flow_dsts_of
+                .entry(edge_src_id)
+                .or_default()
+                .push(edge_dst_id);
+        }
+    }
+
+    let mut paths = vec![];
+    while flow_dsts_of
+        .get(&src_id)
+        .map_or(false, |dsts| !dsts.is_empty())
+    {
+        let mut path = vec![src_id];
+        let mut index_of = HashMap::new();
+        index_of.insert(src_id, 0);
+
+        let mut vertex_id = src_id;
+        while vertex_id != dst_id {
+            // Flow is conserved, so a vertex that flow enters has flow to leave it too.
+            vertex_id = flow_dsts_of.get_mut(&vertex_id).unwrap().pop().unwrap();
+
+            if let Some(index) = index_of.get(&vertex_id).copied() {
+                // Walked around a cycle, so drop it from the path.
+                for dropped_id in path.drain(index + 1..) {
+                    index_of.remove(&dropped_id);
+                }
+            } else {
+                index_of.insert(vertex_id, path.len());
+                path.push(vertex_id);
+            }
+        }
+
+        paths.push(path);
+    }
+
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{MatGraph, UndirectedEdge};
+    use crate::provide::Neighbors;
+    use crate::storage::{DiMat, Mat};
+    use std::collections::HashSet;
+
+    // Checks that each path is a path of `graph` from `src_id` to `dst_id`, and no edge is used twice.
+    fn assert_edge_disjoint<Dir: EdgeDir>(
+        graph: &MatGraph<usize, Dir>,
+        paths: &[Vec<usize>],
+        src_id: usize,
+        dst_id: usize,
+    ) {
+        let mut used_edges = HashSet::new();
+        for path in paths {
+            assert_eq!(path.first(), Some(&src_id));
+            assert_eq!(path.last(), Some(&dst_id));
+            for ids in path.windows(2) {
+                assert!(graph.has_any_edge(ids[0], ids[1]).unwrap());
+
+                let edge = if Dir::is_directed() {
+                    (ids[0], ids[1])
+                } else {
+                    (ids[0].min(ids[1]), ids[0].max(ids[1]))
+                };
+                assert!(used_edges.insert(edge));
+            }
+        }
+    }
+
+    // Whether `dst_id` is reachable from `src_id` without passing through the removed vertices and edges.
+    fn is_reachable(
+        graph: &MatGraph<usize, UndirectedEdge>,
+        src_id: usize,
+        dst_id: usize,
+        removed_vertices: &HashSet<usize>,
+        removed_edges: &HashSet<(usize, usize)>,
+    ) -> bool {
+        let mut visited = HashSet::new();
+        let mut stack = vec![src_id];
+        while let Some(vertex_id) = stack.pop() {
+            if vertex_id == dst_id {
+                return true;
+            }
+            if !visited.insert(vertex_id) {
+                continue;
+            }
+
+            for n_id in graph.neighbors(vertex_id).unwrap() {
+                let edge = (vertex_id.min(n_id), vertex_id.max(n_id));
+                if !removed_vertices.contains(&n_id) && !removed_edges.contains(&edge) {
+                    stack.push(n_id);
+                }
+            }
+        }
+
+        false
+    }
+
+    #[test]
+    fn same_source_and_destination() {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+
+        assert!(edge_disjoint_paths(&graph, a, a).is_empty());
+        assert!(vertex_disjoint_paths(&graph, a, a).is_empty());
+    }
+
+    #[test]
+    fn disconnected_vertices() {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+
+        assert!(edge_disjoint_paths(&graph, a, b).is_empty());
+        assert!(vertex_disjoint_paths(&graph, a, b).is_empty());
+    }
+
+    #[test]
+    fn directed_graph() {
+        // Given: Graph
+        //
+        //      a --> b --> d
+        //      |     ^     ^
+        //      v     |     |
+        //      c ----'-----'
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(a, c, 1.into()).unwrap();
+        graph.add_edge(c, b, 1.into()).unwrap();
+        graph.add_edge(b, d, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+
+        // When: Finding the paths from a to d, and from d to a.
+        let paths = edge_disjoint_paths(&graph, a, d);
+
+        // Then: Edges can not be traversed backward.
+        assert_eq!(paths.len(), 2);
+        assert_edge_disjoint(&graph, &paths, a, d);
+        assert_eq!(vertex_disjoint_paths(&graph, a, d).len(), 2);
+        assert!(edge_disjoint_paths(&graph, d, a).is_empty());
+    }
+
+    #[test]
+    fn counts_match_local_connectivity() {
+        // Given: Graph
+        //
+        //      .---- b ---- e ----.
+        //      |     |      |     |
+        //      a --- c ---- f --- h
+        //      |     |            |
+        //      '---- d ---- g ----'
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        let g = graph.add_vertex();
+        let h = graph.add_vertex();
+        for (src_id, dst_id) in [
+            (a, b),
+            (a, c),
+            (a, d),
+            (b, c),
+            (c, d),
+            (b, e),
+            (c, f),
+            (e, f),
+            (d, g),
+            (e, h),
+            (f, h),
+            (g, h),
+        ] {
+            graph.add_edge(src_id, dst_id, 1.into()).unwrap();
+        }
+        let edges: Vec<(usize, usize)> = graph
+            .edges()
+            .into_iter()
+            .map(|(src_id, dst_id, _)| (src_id.min(dst_id), src_id.max(dst_id)))
+            .collect();
+
+        // When: Finding the disjoint paths from a to h.
+        let edge_paths = edge_disjoint_paths(&graph, a, h);
+        let vertex_paths = vertex_disjoint_paths(&graph, a, h);
+
+        // Then: Counts are the size of the smallest edge and vertex cuts between a and h, found by brute force.
+        let min_edge_cut = (0..1usize << edges.len())
+            .filter(|mask| {
+                let removed_edges = (0..edges.len())
+                    .filter(|index| mask & (1 << index) != 0)
+                    .map(|index| edges[index])
+                    .collect();
+                !is_reachable(&graph, a, h, &HashSet::new(), &removed_edges)
+            })
+            .map(|mask| mask.count_ones())
+            .min()
+            .unwrap();
+        let inner_vertices = [b, c, d, e, f, g];
+        let min_vertex_cut = (0..1usize << inner_vertices.len())
+            .filter(|mask| {
+                let removed_vertices = (0..inner_vertices.len())
+                    .filter(|index| mask & (1 << index) != 0)
+                    .map(|index| inner_vertices[index])
+                    .collect();
+                !is_reachable(&graph, a, h, &removed_vertices, &HashSet::new())
+            })
+            .map(|mask| mask.count_ones())
+            .min()
+            .unwrap();
+
+        assert_eq!(edge_paths.len(), 3);
+        assert_eq!(edge_paths.len(), min_edge_cut as usize);
+        assert_edge_disjoint(&graph, &edge_paths, a, h);
+
+        assert_eq!(vertex_paths.len(), 3);
+        assert_eq!(vertex_paths.len(), min_vertex_cut as usize);
+        assert_edge_disjoint(&graph, &vertex_paths, a, h);
+        let mut used_vertices = HashSet::new();
+        for path in &vertex_paths {
+            for vertex_id in &path[1..path.len() - 1] {
+                assert!(used_vertices.insert(*vertex_id));
+            }
+        }
+    }
+
+    #[test]
+    fn vertex_bottleneck() {
+        // Given: Graph in which every path from a to e passes through c.
+        //
+        //      a --- b --- c --- d --- e
+        //      |         /   \         |
+        //      '--- f --'     '-- g ---'
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        let g = graph.add_vertex();
+        for (src_id, dst_id) in [
+            (a, b),
+            (b, c),
+            (c, d),
+            (d, e),
+            (a, f),
+            (f, c),
+            (c, g),
+            (g, e),
+        ] {
+            graph.add_edge(src_id, dst_id, 1.into()).unwrap();
+        }
+
+        // When: Finding the disjoint paths from a to e.
+        let edge_paths = edge_disjoint_paths(&graph, a, e);
+        let vertex_paths = vertex_disjoint_paths(&graph, a, e);
+
+        // Then:
+        assert_eq!(edge_paths.len(), 2);
+        assert_edge_disjoint(&graph, &edge_paths, a, e);
+        assert_eq!(vertex_paths.len(), 1);
+        assert_edge_disjoint(&graph, &vertex_paths, a, e);
+    }
+}
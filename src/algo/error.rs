@@ -3,6 +3,11 @@ pub enum ErrorKind {
     EulerianTrailNotFound,
     EulerianCircuitNotFound,
     NegativeCycleDetected,
+    UnequalPartitions,
+    PerfectMatchingNotFound,
+    InfeasibleConstraints,
+    InvalidMatching,
+    NotBipartite,
 }
 
 /// Error type returns in [`algo`](crate::algo) module.
@@ -29,7 +34,7 @@ impl Error {
     pub fn new_etnf() -> Self {
         Error {
             kind: ErrorKind::EulerianTrailNotFound,
-            msg: format!("Eulerian trail not found"),
+            msg: "Eulerian trail not found".to_string(),
         }
     }
 
@@ -40,14 +45,82 @@ impl Error {
     pub fn new_ecnf() -> Self {
         Error {
             kind: ErrorKind::EulerianCircuitNotFound,
-            msg: format!("Eulerian circuit not found"),
+            msg: "Eulerian circuit not found".to_string(),
         }
     }
 
     pub fn new_ncd() -> Self {
         Error {
             kind: ErrorKind::NegativeCycleDetected,
-            msg: format!("Graph contains cycle"),
+            msg: "Graph contains cycle".to_string(),
+        }
+    }
+
+    /// Creates a new [`UnequalPartitions`](crate::algo::ErrorKind::UnequalPartitions) kind of error.
+    ///
+    /// # Arguments
+    /// * `left_count`: Number of vertices in the left partition.
+    /// * `right_count`: Number of vertices in the right partition.
+    ///
+    /// # Returns
+    /// `Error` with `UnequalPartitions` kind and predefined message.
+    pub fn new_up(left_count: usize, right_count: usize) -> Self {
+        Error {
+            kind: ErrorKind::UnequalPartitions,
+            msg: format!(
+                "Partitions must have equal sizes, but left has {} vertices and right has {}",
+                left_count, right_count
+            ),
+        }
+    }
+
+    /// Creates a new [`PerfectMatchingNotFound`](crate::algo::ErrorKind::PerfectMatchingNotFound) kind of error.
+    ///
+    /// # Returns
+    /// `Error` with `PerfectMatchingNotFound` kind and predefined message.
+    pub fn new_pmnf() -> Self {
+        Error {
+            kind: ErrorKind::PerfectMatchingNotFound,
+            msg: "Perfect matching not found".to_string(),
+        }
+    }
+
+    /// Creates a new [`InfeasibleConstraints`](crate::algo::ErrorKind::InfeasibleConstraints) kind of error.
+    ///
+    /// # Arguments
+    /// `reason`: Why the constraints can not be satisfied.
+    ///
+    /// # Returns
+    /// `Error` with `InfeasibleConstraints` kind and predefined message.
+    pub fn new_ic(reason: &str) -> Self {
+        Error {
+            kind: ErrorKind::InfeasibleConstraints,
+            msg: format!("Constraints can not be satisfied: {}", reason),
+        }
+    }
+
+    /// Creates a new [`InvalidMatching`](crate::algo::ErrorKind::InvalidMatching) kind of error.
+    ///
+    /// # Arguments
+    /// `reason`: Why the pairs do not form a matching.
+    ///
+    /// # Returns
+    /// `Error` with `InvalidMatching` kind and predefined message.
+    pub fn new_im(reason: &str) -> Self {
+        Error {
+            kind: ErrorKind::InvalidMatching,
+            msg: format!("Pairs do not form a matching: {}", reason),
+        }
+    }
+
+    /// Creates a new [`NotBipartite`](crate::algo::ErrorKind::NotBipartite) kind of error.
+    ///
+    /// # Returns
+    /// `Error` with `NotBipartite` kind and predefined message.
+    pub fn new_nb() -> Self {
+        Error {
+            kind: ErrorKind::NotBipartite,
+            msg: "Graph is not bipartite".to_string(),
         }
     }
 
@@ -3,11 +3,30 @@ use std::{collections::HashSet, marker::PhantomData};
 use anyhow::Result;
 
 use crate::{
-    algo::Error,
-    graph::{Edge, EdgeDir},
+    algo::{Bridges, Error},
+    graph::{Edge, EdgeDir, UndirectedEdge},
     provide::{Edges, Graph, IdMap, Vertices},
 };
 
+/// Describes whether a graph is Eulerian, and if not, why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EulerianDiagnostic {
+    /// Graph has an Eulerian circuit.
+    Circuit,
+
+    /// Graph has an Eulerian trail(but not a circuit) that starts from the first vertex and ends at the second one.
+    Path(usize, usize),
+
+    /// Graph has neither an Eulerian circuit nor a trail.
+    None {
+        /// Vertices with odd degree in undirected graphs, or vertices with different in and out degree in directed graphs.
+        odd_vertices: Vec<usize>,
+
+        /// Whether edges of the graph are spread over more than one (weakly) connected component.
+        disconnected: bool,
+    },
+}
+
 /// Finds Eulerian trail and circuit.
 ///
 /// # Examples
@@ -172,6 +191,119 @@ where
         has_circuit.then(|| self.find_start_virt_id()).flatten()
     }
 
+    /// Explains whether graph has an Eulerian circuit or trail.
+    ///
+    /// Must be called before finding the trail or circuit.
+    ///
+    /// # Arguments
+    /// `graph`: The graph that structure is initialized with.
+    ///
+    /// # Returns
+    /// * `Circuit`: If graph has an Eulerian circuit. Graphs without edges are considered to have one.
+    /// * `Path`: If graph has an Eulerian trail but no circuit, containing the start and end of the trail.
+    /// * `None`: Otherwise, containing the vertices with unbalanced degree and whether edges are disconnected from each other.
+    ///
+    /// # Examples
+    /// ```
+    /// use prepona::prelude::*;
+    /// use prepona::algo::{Eulerian, EulerianDiagnostic};
+    /// use prepona::storage::Mat;
+    /// use prepona::graph::MatGraph;
+    ///
+    /// // Given: Graph
+    /// //
+    /// //      a --- b --- c
+    /// //
+    /// let mut graph = MatGraph::init(Mat::<usize>::init());
+    /// let a = graph.add_vertex();
+    /// let b = graph.add_vertex();
+    /// let c = graph.add_vertex();
+    /// graph.add_edge(a, b, 1.into());
+    /// graph.add_edge(b, c, 1.into());
+    ///
+    /// // Then:
+    /// assert_eq!(Eulerian::init(&graph).diagnose(&graph), EulerianDiagnostic::Path(a, c));
+    /// ```
+    pub fn diagnose(&self, graph: &G) -> EulerianDiagnostic {
+        let vertex_count = self.out_deg.len();
+
+        // Virtual ids of vertices with unbalanced degree.
+        let unbalanced = (0..vertex_count)
+            .filter(|virt_id| {
+                if Ty::is_undirected() {
+                    self.out_deg[*virt_id] % 2 != 0
+                } else {
+                    self.diff_deg[*virt_id] != 0
+                }
+            })
+            .collect::<Vec<usize>>();
+
+        let disconnected = self.edge_component_count(graph) > 1;
+
+        let odd_vertices = unbalanced
+            .iter()
+            .map(|virt_id| self.id_map.real_id_of(*virt_id))
+            .collect::<Vec<usize>>();
+
+        if disconnected {
+            return EulerianDiagnostic::None {
+                odd_vertices,
+                disconnected,
+            };
+        }
+
+        match unbalanced.as_slice() {
+            [] => EulerianDiagnostic::Circuit,
+            [v1, v2] if Ty::is_undirected() => {
+                EulerianDiagnostic::Path(self.id_map.real_id_of(*v1), self.id_map.real_id_of(*v2))
+            }
+            [v1, v2] if self.diff_deg[*v1] * self.diff_deg[*v2] == -1 => {
+                let (start, end) = if self.diff_deg[*v1] == 1 {
+                    (v1, v2)
+                } else {
+                    (v2, v1)
+                };
+
+                EulerianDiagnostic::Path(
+                    self.id_map.real_id_of(*start),
+                    self.id_map.real_id_of(*end),
+                )
+            }
+            _ => EulerianDiagnostic::None {
+                odd_vertices,
+                disconnected,
+            },
+        }
+    }
+
+    // Returns number of (weakly) connected components that contain at least one edge.
+    fn edge_component_count(&self, graph: &G) -> usize {
+        let vertex_count = self.out_deg.len();
+
+        // Disjoint set of vertices by virtual id.
+        let mut parent_of = (0..vertex_count).collect::<Vec<usize>>();
+        fn root_of(parent_of: &mut [usize], mut virt_id: usize) -> usize {
+            while parent_of[virt_id] != virt_id {
+                parent_of[virt_id] = parent_of[parent_of[virt_id]];
+                virt_id = parent_of[virt_id];
+            }
+
+            virt_id
+        }
+
+        for (src_id, dst_id, _) in graph.edges() {
+            let src_root = root_of(&mut parent_of, self.id_map.virt_id_of(src_id));
+            let dst_root = root_of(&mut parent_of, self.id_map.virt_id_of(dst_id));
+            parent_of[src_root] = dst_root;
+        }
+
+        (0..vertex_count)
+            .filter(|virt_id| self.out_deg[*virt_id] + self.in_deg[*virt_id] > 0)
+            .map(|virt_id| root_of(&mut parent_of, virt_id))
+            .collect::<HashSet<usize>>()
+            .len()
+    }
+
     /// Finds the Eulerian trail if there is one.
     ///
     /// # Arguments
@@ -270,6 +402,91 @@ where
     }
 }
 
+/// Finds an Eulerian tour of an undirected graph using Fleury's algorithm.
+///
+/// Starting from a valid start vertex, the tour repeatedly crosses an unused edge,
+/// choosing an edge that is not a bridge of the unused edges unless there is no other choice.
+/// This is slower than the Hierholzer's algorithm used by [`Eulerian`](crate::algo::Eulerian) but each step is easier to follow.
+///
+/// # Arguments
+/// `graph`: Undirected graph to find its Eulerian tour.
+///
+/// # Returns
+/// * `Ok`: Containing ids of the edges in the order they get crossed. Tour is a circuit if graph has an Eulerian circuit.
+/// * `Err`: If graph does not have Eulerian trail.
+///
+/// # Complexity
+/// O(|E| * (|V| + |E|))
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::algo::fleury_tour;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b --- c
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let ab = graph.add_edge(a, b, 1.into()).unwrap();
+/// let bc = graph.add_edge(b, c, 1.into()).unwrap();
+///
+/// // When: Finding the tour.
+/// let tour = fleury_tour(&graph).unwrap();
+///
+/// // Then:
+/// assert_eq!(tour, vec![ab, bc]);
+/// ```
+pub fn fleury_tour<W, E, G>(graph: &G) -> Result<Vec<usize>>
+where
+    E: Edge<W>,
+    G: Graph<W, E, UndirectedEdge> + Vertices + Edges<W, E>,
+{
+    let start_id = match Eulerian::init(graph).diagnose(graph) {
+        EulerianDiagnostic::Path(start_id, _) => start_id,
+        EulerianDiagnostic::Circuit => match graph.edges().first() {
+            Some((src_id, _, _)) => *src_id,
+            None => return Ok(vec![]),
+        },
+        EulerianDiagnostic::None { .. } => Err(Error::new_etnf())?,
+    };
+
+    let mut unused_edges = Bridges::init(graph);
+    let mut tour = vec![];
+
+    let mut v_id = start_id;
+    loop {
+        let edges = unused_edges.edges_from(v_id);
+        if edges.is_empty() {
+            break;
+        }
+
+        let bridge_ids = unused_edges
+            .execute()
+            .into_iter()
+            .map(|(_, _, edge_id)| edge_id)
+            .collect::<HashSet<usize>>();
+
+        // Cross a bridge only if it's the only way out of the current vertex.
+        let (n_id, edge_id) = edges
+            .iter()
+            .copied()
+            .find(|(_, edge_id)| !bridge_ids.contains(edge_id))
+            .unwrap_or(edges[0]);
+
+        unused_edges.remove_edge(edge_id);
+        tour.push(edge_id);
+        v_id = n_id;
+    }
+
+    Ok(tour)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -754,5 +971,266 @@ mod tests {
         assert_eq!(trail, vec![a, b, c, a, g, e, c, d, e, f]);
     }
 
-    // TODO: Add test for graphs that does not have Eulerian circuit or trail and check that algorithm returns error indeed.
+    #[test]
+    fn diagnose_circuit() {
+        // Given: Graph
+        //
+        //      a --- b
+        //      |     |
+        //      d --- c
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+        graph.add_edge(d, a, 1.into()).unwrap();
+
+        // When: Diagnosing the graph.
+        let diagnostic = Eulerian::init(&graph).diagnose(&graph);
+
+        // Then:
+        assert_eq!(diagnostic, EulerianDiagnostic::Circuit);
+    }
+
+    #[test]
+    fn diagnose_path() {
+        // Given: Graph
+        //
+        //      a --- b --- c --- d
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+
+        // When: Diagnosing the graph.
+        let diagnostic = Eulerian::init(&graph).diagnose(&graph);
+
+        // Then:
+        assert_eq!(diagnostic, EulerianDiagnostic::Path(a, d));
+    }
+
+    #[test]
+    fn diagnose_directed_path() {
+        // Given: Graph
+        //
+        //      d <-- a --> b
+        //            ^     |
+        //            |     v
+        //            '---- c
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, a, 1.into()).unwrap();
+        graph.add_edge(a, d, 1.into()).unwrap();
+
+        // When: Diagnosing the graph.
+        let diagnostic = Eulerian::init(&graph).diagnose(&graph);
+
+        // Then:
+        assert_eq!(diagnostic, EulerianDiagnostic::Path(a, d));
+    }
+
+    #[test]
+    fn diagnose_four_odd_vertices() {
+        // Given: Graph
+        //
+        //      a --- b
+        //      | \ / |
+        //      | / \ |
+        //      c --- d
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(a, c, 1.into()).unwrap();
+        graph.add_edge(a, d, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(b, d, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+
+        // When: Diagnosing the graph.
+        let diagnostic = Eulerian::init(&graph).diagnose(&graph);
+
+        // Then: Every vertex has degree of 3.
+        assert_eq!(
+            diagnostic,
+            EulerianDiagnostic::None {
+                odd_vertices: vec![a, b, c, d],
+                disconnected: false
+            }
+        );
+    }
+
+    #[test]
+    fn diagnose_disconnected() {
+        // Given: Graph
+        //
+        //      a --- b     c --- d     e
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+
+        // When: Diagnosing the graph.
+        let diagnostic = Eulerian::init(&graph).diagnose(&graph);
+
+        // Then: Isolated vertex e does not count as a separate component.
+        assert_eq!(
+            diagnostic,
+            EulerianDiagnostic::None {
+                odd_vertices: vec![a, b, c, d],
+                disconnected: true
+            }
+        );
+    }
+
+    // Checks that `tour` crosses every edge of the graph exactly once, and consecutive edges share an end point.
+    fn assert_valid_tour(graph: &MatGraph<usize, UndirectedEdge>, tour: &[usize]) {
+        assert_eq!(tour.len(), graph.edges_count());
+        assert_eq!(
+            tour.iter().copied().collect::<HashSet<usize>>().len(),
+            tour.len()
+        );
+
+        let end_points_of = |edge_id: usize| {
+            graph
+                .edges()
+                .into_iter()
+                .find(|(_, _, edge)| edge.get_id() == edge_id)
+                .map(|(src_id, dst_id, _)| (src_id, dst_id))
+                .unwrap()
+        };
+
+        // Vertex that the tour is at, after crossing the first edge.
+        let (first_src_id, first_dst_id) = end_points_of(tour[0]);
+        let mut candidates = vec![first_src_id, first_dst_id];
+        for edge_id in tour.iter().skip(1).copied() {
+            let (src_id, dst_id) = end_points_of(edge_id);
+            candidates = candidates
+                .into_iter()
+                .filter_map(|v_id| {
+                    if v_id == src_id {
+                        Some(dst_id)
+                    } else if v_id == dst_id {
+                        Some(src_id)
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            assert!(!candidates.is_empty());
+        }
+    }
+
+    #[test]
+    fn fleury_tour_of_graph_with_circuit() {
+        // Given: Graph
+        //
+        //      a --- b --- e
+        //      |   / |     |
+        //      |  /  |     |
+        //      | /   |     |
+        //      c --- d --- f
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(a, c, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(b, d, 1.into()).unwrap();
+        graph.add_edge(b, e, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+        graph.add_edge(d, f, 1.into()).unwrap();
+        graph.add_edge(e, f, 1.into()).unwrap();
+
+        // When: Finding the tour.
+        let tour = fleury_tour(&graph).unwrap();
+
+        // Then:
+        assert_valid_tour(&graph, &tour);
+    }
+
+    #[test]
+    fn fleury_tour_of_graph_with_trail() {
+        // Given: Graph
+        //
+        //      a --- b --.
+        //     /  \       |
+        //    e    \      |
+        //     \    \     |
+        //      c -- d    |
+        //      |_________'
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(a, e, 1.into()).unwrap();
+        graph.add_edge(a, d, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+        graph.add_edge(c, e, 1.into()).unwrap();
+
+        // When: Finding the tour.
+        let tour = fleury_tour(&graph).unwrap();
+
+        // Then:
+        assert_valid_tour(&graph, &tour);
+    }
+
+    #[test]
+    fn fleury_tour_of_graph_without_trail() {
+        // Given: Graph
+        //
+        //      a --- b
+        //      | \ / |
+        //      | / \ |
+        //      c --- d
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(a, c, 1.into()).unwrap();
+        graph.add_edge(a, d, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(b, d, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+
+        // Then:
+        assert!(fleury_tour(&graph).is_err());
+    }
 }
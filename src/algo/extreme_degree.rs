@@ -0,0 +1,184 @@
+use std::cmp::Reverse;
+
+use crate::algo::utils::degrees_of;
+use crate::graph::Edge;
+use crate::provide::{Edges, Vertices};
+
+/// Finds the vertex with the smallest degree.
+///
+/// In directed graphs, degree of a vertex is the sum of its in-degree and out-degree.
+///
+/// # Arguments
+/// `graph`: Graph to search for the vertex in.
+///
+/// # Returns
+/// * `Some`: Containing id of the vertex with the smallest degree. If there are multiple such vertices, the one with the smallest id is returned.
+/// * `None`: If graph has no vertices.
+///
+/// # Complexity
+/// O(|V| + |E|)
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::min_degree_vertex;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b --- c
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+///
+/// // Then: Both a and c have degree 1, and a has the smaller id.
+/// assert_eq!(min_degree_vertex(&graph), Some(a));
+/// ```
+pub fn min_degree_vertex<W, E, G>(graph: &G) -> Option<usize>
+where
+    E: Edge<W>,
+    G: Edges<W, E> + Vertices,
+{
+    degrees_of(graph)
+        .into_iter()
+        .min_by_key(|(vertex_id, degree)| (*degree, *vertex_id))
+        .map(|(vertex_id, _)| vertex_id)
+}
+
+/// Finds the vertex with the largest degree.
+///
+/// Degrees are counted the same way as in [`min_degree_vertex`](crate::algo::min_degree_vertex).
+///
+/// # Arguments
+/// `graph`: Graph to search for the vertex in.
+///
+/// # Returns
+/// * `Some`: Containing id of the vertex with the largest degree. If there are multiple such vertices, the one with the smallest id is returned.
+/// * `None`: If graph has no vertices.
+///
+/// # Complexity
+/// O(|V| + |E|)
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::max_degree_vertex;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b --- c
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+///
+/// // Then:
+/// assert_eq!(max_degree_vertex(&graph), Some(b));
+/// ```
+pub fn max_degree_vertex<W, E, G>(graph: &G) -> Option<usize>
+where
+    E: Edge<W>,
+    G: Edges<W, E> + Vertices,
+{
+    degrees_of(graph)
+        .into_iter()
+        .min_by_key(|(vertex_id, degree)| (Reverse(*degree), *vertex_id))
+        .map(|(vertex_id, _)| vertex_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::provide::Graph;
+    use crate::storage::{DiMat, Mat};
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        assert!(min_degree_vertex(&graph).is_none());
+        assert!(max_degree_vertex(&graph).is_none());
+    }
+
+    #[test]
+    fn star_graph() {
+        // Given: Graph
+        //
+        //      a   b
+        //       \ /
+        //   e -- c -- d
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        for leaf_id in [a, b, d, e].iter() {
+            graph.add_edge(c, *leaf_id, 1.into()).unwrap();
+        }
+
+        // Then: Center has the largest degree and all leaves have degree 1.
+        assert_eq!(max_degree_vertex(&graph), Some(c));
+        assert_eq!(min_degree_vertex(&graph), Some(a));
+
+        // When: Removing the leaf with the smallest id.
+        graph.remove_vertex(a).unwrap();
+
+        // Then: Tie is broken by the next smallest id.
+        assert_eq!(max_degree_vertex(&graph), Some(c));
+        assert_eq!(min_degree_vertex(&graph), Some(b));
+    }
+
+    #[test]
+    fn regular_graph() {
+        // Given: Cycle of 6 vertices, in which every vertex has degree 2.
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let vertex_ids: Vec<usize> = (0..6).map(|_| graph.add_vertex()).collect();
+        for (index, src_id) in vertex_ids.iter().enumerate() {
+            let dst_id = vertex_ids[(index + 1) % vertex_ids.len()];
+            graph.add_edge(*src_id, dst_id, 1.into()).unwrap();
+        }
+
+        // Then: Both return the smallest id, no matter how many times they are called.
+        let smallest_id = *vertex_ids.iter().min().unwrap();
+        for _ in 0..10 {
+            assert_eq!(min_degree_vertex(&graph), Some(smallest_id));
+            assert_eq!(max_degree_vertex(&graph), Some(smallest_id));
+        }
+    }
+
+    #[test]
+    fn directed_graph() {
+        // Given: Graph
+        //
+        //      a --> b <-- c
+        //            |
+        //            v
+        //            d
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(c, b, 1.into()).unwrap();
+        graph.add_edge(b, d, 1.into()).unwrap();
+
+        // Then: Both incoming and outgoing edges are counted.
+        assert_eq!(max_degree_vertex(&graph), Some(b));
+        assert_eq!(min_degree_vertex(&graph), Some(a));
+    }
+}
@@ -0,0 +1,230 @@
+use magnitude::Magnitude;
+use num_traits::{Unsigned, Zero};
+use std::any::Any;
+
+use crate::algo::Dijkstra;
+use crate::graph::{Edge, EdgeDir};
+use crate::provide::{Edges, Graph, Neighbors, Vertices};
+
+/// Finds the 1-median of the graph: the vertex that minimizes the sum of distances from it to all other vertices.
+///
+/// # Arguments
+/// `graph`: Graph to search for the 1-median in.
+///
+/// # Returns
+/// * `Some`: Containing id of the 1-median vertex alongside the sum of distances from it to all other vertices.
+///   If no vertex can reach all other vertices, the sum will be `PosInfinite` for every vertex and the first vertex is returned.
+/// * `None`: If graph is empty.
+///
+/// # Complexity
+/// Runs `Dijkstra` once from every vertex.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::one_median;
+///
+/// // Given: Graph
+/// //
+/// //      a --2-- b --3-- c
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 2.into());
+/// graph.add_edge(b, c, 3.into());
+///
+/// // When: Searching for the 1-median.
+/// let (median_id, total_distance) = one_median(&graph).unwrap();
+///
+/// // Then:
+/// assert_eq!(median_id, b);
+/// assert_eq!(total_distance, 5.into());
+/// ```
+pub fn one_median<W, E, Ty, G>(graph: &G) -> Option<(usize, Magnitude<W>)>
+where
+    W: Copy + Ord + Zero + Any + Unsigned,
+    E: Edge<W>,
+    Ty: EdgeDir,
+    G: Edges<W, E> + Neighbors + Vertices + Graph<W, E, Ty>,
+{
+    best_vertex_by(graph, |distances| {
+        distances
+            .into_iter()
+            .fold(W::zero().into(), |total, distance| total + distance)
+    })
+}
+
+/// Finds the 1-center of the graph: the vertex that minimizes the maximum distance from it to all other vertices.
+///
+/// # Arguments
+/// `graph`: Graph to search for the 1-center in.
+///
+/// # Returns
+/// * `Some`: Containing id of the 1-center vertex alongside its eccentricity(maximum distance from it to any other vertex).
+///   If no vertex can reach all other vertices, the eccentricity will be `PosInfinite` for every vertex and the first vertex is returned.
+/// * `None`: If graph is empty.
+///
+/// # Complexity
+/// Runs `Dijkstra` once from every vertex.
+pub fn one_center<W, E, Ty, G>(graph: &G) -> Option<(usize, Magnitude<W>)>
+where
+    W: Copy + Ord + Zero + Any + Unsigned,
+    E: Edge<W>,
+    Ty: EdgeDir,
+    G: Edges<W, E> + Neighbors + Vertices + Graph<W, E, Ty>,
+{
+    best_vertex_by(graph, |distances| {
+        distances
+            .into_iter()
+            .fold(W::zero().into(), |max_distance, distance| {
+                if distance.is_pos_infinite() || max_distance.is_pos_infinite() {
+                    Magnitude::PosInfinite
+                } else {
+                    std::cmp::max(max_distance, distance)
+                }
+            })
+    })
+}
+
+// Computes `objective` over distances from each vertex to all other vertices and returns the vertex with minimum objective.
+// Ties are broken in favor of the vertex that comes first in `graph.vertices()`.
+fn best_vertex_by<W, E, Ty, G, F>(graph: &G, objective: F) -> Option<(usize, Magnitude<W>)>
+where
+    W: Copy + Ord + Zero + Any + Unsigned,
+    E: Edge<W>,
+    Ty: EdgeDir,
+    G: Edges<W, E> + Neighbors + Vertices + Graph<W, E, Ty>,
+    F: Fn(Vec<Magnitude<W>>) -> Magnitude<W>,
+{
+    let vertices = graph.vertices();
+
+    let mut best: Option<(usize, Magnitude<W>)> = None;
+    for src_id in vertices.iter().copied() {
+        let sp_subgraph = Dijkstra::init(graph).execute(graph, src_id);
+
+        let distances = vertices
+            .iter()
+            .map(|dst_id| sp_subgraph.distance_to(*dst_id).unwrap())
+            .collect();
+        let value = objective(distances);
+
+        // Positive infinities can not be compared with each other, so they're handled separately.
+        let is_better = match best {
+            None => true,
+            Some((_, best_value)) => {
+                value.is_finite() && (best_value.is_pos_infinite() || value < best_value)
+            }
+        };
+
+        if is_better {
+            best = Some((src_id, value));
+        }
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::storage::{DiMat, Mat};
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        assert!(one_median(&graph).is_none());
+        assert!(one_center(&graph).is_none());
+    }
+
+    #[test]
+    fn weighted_star() {
+        // Given: Graph
+        //
+        //            b
+        //            |
+        //          2 |
+        //      c --- a --- d
+        //        3   |   1
+        //          4 |
+        //            e
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        graph.add_edge(a, b, 2.into()).unwrap();
+        graph.add_edge(a, c, 3.into()).unwrap();
+        graph.add_edge(a, d, 1.into()).unwrap();
+        graph.add_edge(a, e, 4.into()).unwrap();
+
+        // When: Searching for 1-median and 1-center.
+        let (median_id, total_distance) = one_median(&graph).unwrap();
+        let (center_id, eccentricity) = one_center(&graph).unwrap();
+
+        // Then: Center of the star is optimal for both.
+        assert_eq!(median_id, a);
+        assert_eq!(total_distance, 10.into());
+        assert_eq!(center_id, a);
+        assert_eq!(eccentricity, 4.into());
+    }
+
+    #[test]
+    fn median_and_center_differ() {
+        // Given: Graph
+        //
+        //      a --1-- b --1-- c --1-- d --5-- e
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+        graph.add_edge(d, e, 5.into()).unwrap();
+
+        // When: Searching for 1-median and 1-center.
+        let (median_id, total_distance) = one_median(&graph).unwrap();
+        let (center_id, eccentricity) = one_center(&graph).unwrap();
+
+        // Then:
+        assert_eq!(median_id, c);
+        assert_eq!(total_distance, 10.into());
+        assert_eq!(center_id, d);
+        assert_eq!(eccentricity, 5.into());
+    }
+
+    #[test]
+    fn not_strongly_connected_directed_graph() {
+        // Given: Graph
+        //
+        //      a --1--> b --1--> c
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+
+        // When: Searching for 1-median and 1-center.
+        let (median_id, total_distance) = one_median(&graph).unwrap();
+        let (center_id, eccentricity) = one_center(&graph).unwrap();
+
+        // Then: Only a can reach all other vertices.
+        assert_eq!(median_id, a);
+        assert_eq!(total_distance, 3.into());
+        assert_eq!(center_id, a);
+        assert_eq!(eccentricity, 2.into());
+    }
+}
@@ -0,0 +1,308 @@
+use std::collections::HashMap;
+
+use crate::algo::MaxFlow;
+use crate::graph::{Edge, FlowEdge, UndirectedEdge};
+use crate::provide::{Edges, Graph, IdMap, Vertices};
+
+/// Builds a Gomory-Hu(equivalent flow) tree of an undirected flow network and answers min cut queries using it.
+///
+/// Tree is built using Gusfield's algorithm which needs |V| - 1 max flow computations.
+/// After that, value of minimum cut between any two vertices is the minimum weight of the edges on the tree path between them.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::FlowMat;
+/// use prepona::graph::{FlowEdge, FlowMatGraph};
+/// use prepona::algo::GomoryHu;
+///
+/// // Given: Graph(numbers are capacities)
+/// //
+/// //      a --3-- b --1-- c
+/// //       \     /
+/// //        2   2
+/// //         \ /
+/// //          d
+/// //
+/// let mut graph = FlowMatGraph::init(FlowMat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// graph.add_edge(a, b, FlowEdge::init_with(1.into(), 3, 0));
+/// graph.add_edge(b, c, FlowEdge::init_with(1.into(), 1, 0));
+/// graph.add_edge(a, d, FlowEdge::init_with(1.into(), 2, 0));
+/// graph.add_edge(b, d, FlowEdge::init_with(1.into(), 2, 0));
+///
+/// // When: Building the tree.
+/// let gomory_hu = GomoryHu::init(&graph);
+///
+/// // Then:
+/// assert_eq!(gomory_hu.min_cut_between(a, b), Some(5));
+/// assert_eq!(gomory_hu.min_cut_between(a, c), Some(1));
+/// assert_eq!(gomory_hu.min_cut_between(a, d), Some(4));
+/// ```
+pub struct GomoryHu {
+    // Parent of each vertex in the tree(by virtual id). Root is its own parent.
+    parent_of: Vec<usize>,
+
+    // Capacity of the tree edge between each vertex and its parent.
+    cut_of: Vec<usize>,
+
+    depth_of: Vec<usize>,
+    id_map: IdMap,
+}
+
+impl GomoryHu {
+    /// Builds the tree for the given graph.
+    ///
+    /// # Arguments
+    /// `graph`: Undirected flow network. Capacities of edges are used as capacities of the network and weights are ignored.
+    ///
+    /// # Complexity
+    /// O(|V|<sup>2</sup>|E|<sup>2</sup>): |V| - 1 runs of [`MaxFlow`](crate::algo::MaxFlow).
+    pub fn init<W, G>(graph: &G) -> Self
+    where
+        G: Vertices + Edges<W, FlowEdge<W>> + Graph<W, FlowEdge<W>, UndirectedEdge>,
+    {
+        let vertex_count = graph.vertex_count();
+        let id_map = graph.continuos_id_map();
+
+        // Each edge is modeled as an arc with capacity of 2c and initial flow of c, the same as `MaxFlow::execute_undirected`.
+        let arcs: Vec<(usize, usize, usize, isize)> = graph
+            .edges()
+            .into_iter()
+            .map(|(src_id, dst_id, edge)| {
+                (
+                    id_map.virt_id_of(src_id),
+                    id_map.virt_id_of(dst_id),
+                    edge.get_id(),
+                    2 * edge.get_capacity() as isize,
+                )
+            })
+            .collect();
+
+        let mut max_flow = MaxFlow::init(graph);
+        let mut parent_of = vec![0; vertex_count];
+        let mut cut_of = vec![0; vertex_count];
+        for src_virt_id in 1..vertex_count {
+            let dst_virt_id = parent_of[src_virt_id];
+
+            let mut flow_of: Vec<isize> = arcs.iter().map(|arc| arc.3 / 2).collect();
+            cut_of[src_virt_id] = max_flow.push_flow(&arcs, &mut flow_of, src_virt_id, dst_virt_id);
+            let src_side = max_flow.source_side(src_virt_id);
+
+            for virt_id in src_virt_id + 1..vertex_count {
+                if src_side[virt_id] && parent_of[virt_id] == dst_virt_id {
+                    parent_of[virt_id] = src_virt_id;
+                }
+            }
+        }
+
+        // Parent of each vertex always has a smaller virtual id, so depths can be computed in one pass.
+        let mut depth_of = vec![0; vertex_count];
+        for virt_id in 1..vertex_count {
+            depth_of[virt_id] = depth_of[parent_of[virt_id]] + 1;
+        }
+
+        GomoryHu {
+            parent_of,
+            cut_of,
+            depth_of,
+            id_map,
+        }
+    }
+
+    /// # Arguments
+    /// * `src_id`: Id of the first vertex.
+    /// * `dst_id`: Id of the second vertex.
+    ///
+    /// # Returns
+    /// * `Some`: Containing value of the minimum cut(maximum flow) between the two vertices.
+    /// * `None`: If `src_id` and `dst_id` are the same.
+    ///
+    /// # Panics
+    /// If either of vertices is not in the graph that tree is built from.
+    ///
+    /// # Complexity
+    /// O(length of the tree path between the two vertices)
+    pub fn min_cut_between(&self, src_id: usize, dst_id: usize) -> Option<usize> {
+        let mut u = self.id_map.virt_id_of(src_id);
+        let mut v = self.id_map.virt_id_of(dst_id);
+
+        if u == v {
+            return None;
+        }
+
+        let mut min_cut = usize::MAX;
+        while u != v {
+            if self.depth_of[u] < self.depth_of[v] {
+                std::mem::swap(&mut u, &mut v);
+            }
+
+            min_cut = min_cut.min(self.cut_of[u]);
+            u = self.parent_of[u];
+        }
+
+        Some(min_cut)
+    }
+
+    /// # Returns
+    /// Value of minimum cut between every pair of distinct vertices. \
+    /// Each pair is stored once in the format of (`smaller_id`, `larger_id`).
+    pub fn all_min_cuts(&self) -> HashMap<(usize, usize), usize> {
+        let vertex_count = self.parent_of.len();
+        let mut min_cuts = HashMap::new();
+
+        for u in 0..vertex_count {
+            for v in u + 1..vertex_count {
+                let u_real_id = self.id_map.real_id_of(u);
+                let v_real_id = self.id_map.real_id_of(v);
+                let key = (u_real_id.min(v_real_id), u_real_id.max(v_real_id));
+
+                min_cuts.insert(key, self.min_cut_between(u_real_id, v_real_id).unwrap());
+            }
+        }
+
+        min_cuts
+    }
+
+    /// # Returns
+    /// Edges of the tree in the format of (`src_id`, `dst_id`, `min_cut`).
+    pub fn tree_edges(&self) -> Vec<(usize, usize, usize)> {
+        (1..self.parent_of.len())
+            .map(|virt_id| {
+                (
+                    self.id_map.real_id_of(virt_id),
+                    self.id_map.real_id_of(self.parent_of[virt_id]),
+                    self.cut_of[virt_id],
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::FlowMatGraph;
+    use crate::storage::FlowMat;
+
+    // Computes min cut between `src_id` and `dst_id` by checking every cut of the graph.
+    fn brute_force_min_cut(
+        graph: &FlowMatGraph<usize, UndirectedEdge>,
+        src_id: usize,
+        dst_id: usize,
+    ) -> usize {
+        let vertices = graph.vertices();
+        let mut min_cut = usize::MAX;
+
+        for mask in 0..(1usize << vertices.len()) {
+            let in_cut = |v_id: usize| {
+                let index = vertices.iter().position(|id| *id == v_id).unwrap();
+                mask & (1 << index) != 0
+            };
+            if !in_cut(src_id) || in_cut(dst_id) {
+                continue;
+            }
+
+            let cut = graph
+                .edges()
+                .into_iter()
+                .filter(|(s_id, d_id, _)| in_cut(*s_id) != in_cut(*d_id))
+                .map(|(_, _, edge)| edge.get_capacity())
+                .sum();
+
+            min_cut = min_cut.min(cut);
+        }
+
+        min_cut
+    }
+
+    #[test]
+    fn empty_graph() {
+        let graph = FlowMatGraph::init(FlowMat::<usize>::init());
+
+        let gomory_hu = GomoryHu::init(&graph);
+
+        assert!(gomory_hu.all_min_cuts().is_empty());
+        assert!(gomory_hu.tree_edges().is_empty());
+    }
+
+    #[test]
+    fn disconnected_graph() {
+        // Given: Graph
+        //
+        //      a --2-- b       c
+        //
+        let mut graph = FlowMatGraph::init(FlowMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph
+            .add_edge(a, b, FlowEdge::init_with(1.into(), 2, 0))
+            .unwrap();
+
+        // When: Building the tree.
+        let gomory_hu = GomoryHu::init(&graph);
+
+        // Then:
+        assert_eq!(gomory_hu.min_cut_between(a, b), Some(2));
+        assert_eq!(gomory_hu.min_cut_between(b, c), Some(0));
+        assert_eq!(gomory_hu.min_cut_between(c, a), Some(0));
+        assert_eq!(gomory_hu.min_cut_between(a, a), None);
+    }
+
+    #[test]
+    fn queries_match_direct_min_cuts() {
+        // Given: Graph(numbers are capacities)
+        //
+        //      a --1-- b --7-- c
+        //      | \     |     / |
+        //      4  2    3   1   2
+        //      |     \ | /     |
+        //      d --6-- e --5-- f
+        //
+        let mut graph = FlowMatGraph::init(FlowMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        let edges = [
+            (a, b, 1),
+            (b, c, 7),
+            (a, d, 4),
+            (a, e, 2),
+            (b, e, 3),
+            (c, e, 1),
+            (c, f, 2),
+            (d, e, 6),
+            (e, f, 5),
+        ];
+        for (src_id, dst_id, capacity) in edges.iter() {
+            graph
+                .add_edge(
+                    *src_id,
+                    *dst_id,
+                    FlowEdge::init_with(1.into(), *capacity, 0),
+                )
+                .unwrap();
+        }
+
+        // When: Building the tree.
+        let gomory_hu = GomoryHu::init(&graph);
+        let all_min_cuts = gomory_hu.all_min_cuts();
+
+        // Then:
+        assert_eq!(gomory_hu.tree_edges().len(), 5);
+        assert_eq!(all_min_cuts.len(), 15);
+        for ((src_id, dst_id), min_cut) in all_min_cuts {
+            let expected = brute_force_min_cut(&graph, src_id, dst_id);
+            assert_eq!(min_cut, expected);
+            assert_eq!(gomory_hu.min_cut_between(src_id, dst_id), Some(expected));
+            assert_eq!(gomory_hu.min_cut_between(dst_id, src_id), Some(expected));
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use magnitude::Magnitude;
+use std::collections::{HashMap, HashSet};
+
+use crate::graph::{Edge, EdgeDir};
+use crate::provide::{Edges, Graph, Vertices};
+
+/// Differences between two versions of a graph, as created by [`diff`](crate::algo::diff).
+///
+/// Edges are in the form of (src_id, dst_id, edge_id). All the lists are sorted.
+#[derive(Debug, PartialEq)]
+pub struct GraphDiff<W> {
+    /// Ids of the vertices that are only in the new graph.
+    pub added_vertices: Vec<usize>,
+
+    /// Ids of the vertices that are only in the old graph.
+    pub removed_vertices: Vec<usize>,
+
+    /// Edges that are only in the new graph.
+    pub added_edges: Vec<(usize, usize, usize)>,
+
+    /// Edges that are only in the old graph.
+    pub removed_edges: Vec<(usize, usize, usize)>,
+
+    /// Edges that are in both graphs but with different weights.
+    pub changed_edges: Vec<ChangedEdge<W>>,
+}
+
+/// Edge that its weight is different in the two versions of a graph.
+#[derive(Debug, PartialEq)]
+pub struct ChangedEdge<W> {
+    /// Id of the source vertex.
+    pub src_id: usize,
+
+    /// Id of the destination vertex.
+    pub dst_id: usize,
+
+    /// Id of the edge.
+    pub edge_id: usize,
+
+    /// Weight of the edge in the old graph.
+    pub old_weight: Magnitude<W>,
+
+    /// Weight of the edge in the new graph.
+    pub new_weight: Magnitude<W>,
+}
+
+impl<W> GraphDiff<W> {
+    /// # Returns
+    /// Whether the two graphs are the same.
+    pub fn is_empty(&self) -> bool {
+        self.added_vertices.is_empty()
+            && self.removed_vertices.is_empty()
+            && self.added_edges.is_empty()
+            && self.removed_edges.is_empty()
+            && self.changed_edges.is_empty()
+    }
+}
+
+/// Finds the differences between two versions of a graph, for example a snapshot taken by cloning the graph and the graph after some changes.
+///
+/// Vertices are matched by their id. Edges are matched by their end points and their id,
+/// so an edge that is removed and added again with a different id counts as both removed and added.
+/// For undirected graphs, end points of each edge are in the form of (smaller id, larger id).
+///
+/// # Arguments
+/// * `old_graph`: Old version of the graph.
+/// * `new_graph`: New version of the graph.
+///
+/// # Returns
+/// Differences that turn `old_graph` into `new_graph`.
+///
+/// # Complexity
+/// O(|V| + |E|) of both graphs, plus sorting the differences.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::DiMat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::diff;
+///
+/// // Given: Graph
+/// //
+/// //      a --1--> b
+/// //
+/// let mut graph = MatGraph::init(DiMat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let ab = graph.add_edge(a, b, 1.into()).unwrap();
+///
+/// // When: Changing weight of the edge in a snapshot.
+/// let mut snapshot = graph.clone();
+/// snapshot.update_edge(a, b, ab, 3.into()).unwrap();
+/// let graph_diff = diff(&graph, &snapshot);
+///
+/// // Then:
+/// assert_eq!(graph_diff.changed_edges.len(), 1);
+/// assert_eq!(graph_diff.changed_edges[0].edge_id, ab);
+/// assert_eq!(graph_diff.changed_edges[0].new_weight, 3.into());
+/// assert!(graph_diff.added_edges.is_empty());
+/// assert!(diff(&graph, &graph).is_empty());
+/// ```
+pub fn diff<W, E, Dir, G>(old_graph: &G, new_graph: &G) -> GraphDiff<W>
+where
+    W: Copy + PartialEq,
+    E: Edge<W>,
+    Dir: EdgeDir,
+    G: Edges<W, E> + Vertices + Graph<W, E, Dir>,
+{
+    let old_vertices: HashSet<usize> = old_graph.vertices().into_iter().collect();
+    let new_vertices: HashSet<usize> = new_graph.vertices().into_iter().collect();
+
+    let mut added_vertices: Vec<usize> = new_vertices.difference(&old_vertices).copied().collect();
+    let mut removed_vertices: Vec<usize> =
+        old_vertices.difference(&new_vertices).copied().collect();
+    added_vertices.sort_unstable();
+    removed_vertices.sort_unstable();
+
+    let old_edges = weight_of_edges(old_graph);
+    let new_edges = weight_of_edges(new_graph);
+
+    let mut added_edges = vec![];
+    let mut changed_edges = vec![];
+    for (key, new_weight) in &new_edges {
+        match old_edges.get(key) {
+            None => added_edges.push(*key),
+            Some(old_weight) if !is_same_weight(old_weight, new_weight) => {
+                changed_edges.push(ChangedEdge {
+                    src_id: key.0,
+                    dst_id: key.1,
+                    edge_id: key.2,
+                    old_weight: *old_weight,
+                    new_weight: *new_weight,
+                })
+            }
+            _ => {}
+        }
+    }
+
+    let mut removed_edges: Vec<(usize, usize, usize)> = old_edges
+        .keys()
+        .filter(|key| !new_edges.contains_key(key))
+        .copied()
+        .collect();
+
+    added_edges.sort_unstable();
+    removed_edges.sort_unstable();
+    changed_edges.sort_unstable_by_key(|changed| (changed.src_id, changed.dst_id, changed.edge_id));
+
+    GraphDiff {
+        added_vertices,
+        removed_vertices,
+        added_edges,
+        removed_edges,
+        changed_edges,
+    }
+}
+
+// Maps (src_id, dst_id, edge_id) of each edge to its weight.
+fn weight_of_edges<W, E, Dir, G>(graph: &G) -> HashMap<(usize, usize, usize), Magnitude<W>>
+where
+    W: Copy,
+    E: Edge<W>,
+    Dir: EdgeDir,
+    G: Edges<W, E> + Graph<W, E, Dir>,
+{
+    graph
+        .edges()
+        .into_iter()
+        .map(|(src_id, dst_id, edge)| {
+            let (src_id, dst_id) = if Dir::is_undirected() && src_id > dst_id {
+                (dst_id, src_id)
+            } else {
+                (src_id, dst_id)
+            };
+
+            ((src_id, dst_id, edge.get_id()), *edge.get_weight())
+        })
+        .collect()
+}
+
+// Comparing two infinite magnitudes with `==` panics, so they are compared by their kind.
+fn is_same_weight<W: PartialEq>(weight1: &Magnitude<W>, weight2: &Magnitude<W>) -> bool {
+    match (weight1, weight2) {
+        (Magnitude::Finite(weight1), Magnitude::Finite(weight2)) => weight1 == weight2,
+        (Magnitude::PosInfinite, Magnitude::PosInfinite)
+        | (Magnitude::NegInfinite, Magnitude::NegInfinite) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{DefaultEdge, MatGraph};
+    use crate::storage::{DiMat, Mat};
+
+    #[test]
+    fn same_graph() {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        graph
+            .add_edge(a, b, DefaultEdge::init(Magnitude::PosInfinite))
+            .unwrap();
+
+        assert!(diff(&graph, &graph).is_empty());
+        assert!(diff(&graph, &graph.clone()).is_empty());
+    }
+
+    #[test]
+    fn added_edge_and_removed_vertex() {
+        // Given: Graph
+        //
+        //      a --1--> b --2--> c
+        //
+        let mut old_graph = MatGraph::init(DiMat::<usize>::init());
+        let a = old_graph.add_vertex();
+        let b = old_graph.add_vertex();
+        let c = old_graph.add_vertex();
+        let ab = old_graph.add_edge(a, b, 1.into()).unwrap();
+        let bc = old_graph.add_edge(b, c, 2.into()).unwrap();
+
+        // When: Adding edge a --> c and removing b(and its edges) in a snapshot.
+        //
+        //      a --4--> c
+        //
+        let mut new_graph = old_graph.clone();
+        let ac = new_graph.add_edge(a, c, 4.into()).unwrap();
+        new_graph.remove_vertex(b).unwrap();
+        let graph_diff = diff(&old_graph, &new_graph);
+
+        // Then:
+        assert!(graph_diff.added_vertices.is_empty());
+        assert_eq!(graph_diff.removed_vertices, vec![b]);
+        assert_eq!(graph_diff.added_edges, vec![(a, c, ac)]);
+        let mut removed_edges = vec![(a, b, ab), (b, c, bc)];
+        removed_edges.sort_unstable();
+        assert_eq!(graph_diff.removed_edges, removed_edges);
+        assert!(graph_diff.changed_edges.is_empty());
+
+        // And: Reversed diff turns the snapshot back into the old graph.
+        let reversed = diff(&new_graph, &old_graph);
+        assert_eq!(reversed.added_vertices, vec![b]);
+        assert_eq!(reversed.removed_edges, vec![(a, c, ac)]);
+        assert_eq!(reversed.added_edges, removed_edges);
+    }
+
+    #[test]
+    fn undirected_changed_weights() {
+        // Given: Graph
+        //
+        //      a --1-- b --2-- c
+        //
+        let mut old_graph = MatGraph::init(Mat::<usize>::init());
+        let a = old_graph.add_vertex();
+        let b = old_graph.add_vertex();
+        let c = old_graph.add_vertex();
+        let ab = old_graph.add_edge(a, b, 1.into()).unwrap();
+        let bc = old_graph.add_edge(b, c, 2.into()).unwrap();
+
+        // When: Changing weights of both edges in a snapshot, one of them to infinity.
+        let mut new_graph = old_graph.clone();
+        new_graph.update_edge(b, a, ab, 5.into()).unwrap();
+        new_graph
+            .update_edge(c, b, bc, DefaultEdge::init(Magnitude::PosInfinite))
+            .unwrap();
+        let graph_diff = diff(&old_graph, &new_graph);
+
+        // Then: End points are in the form of (smaller id, larger id).
+        assert_eq!(graph_diff.changed_edges.len(), 2);
+        assert_eq!(
+            graph_diff.changed_edges[0],
+            ChangedEdge {
+                src_id: a,
+                dst_id: b,
+                edge_id: ab,
+                old_weight: 1.into(),
+                new_weight: 5.into(),
+            }
+        );
+        let changed = &graph_diff.changed_edges[1];
+        assert_eq!(
+            (changed.src_id, changed.dst_id, changed.edge_id),
+            (b, c, bc)
+        );
+        assert_eq!(changed.old_weight, 2.into());
+        assert!(changed.new_weight.is_pos_infinite());
+        assert!(graph_diff.added_edges.is_empty());
+        assert!(graph_diff.removed_edges.is_empty());
+    }
+}
@@ -0,0 +1,330 @@
+use std::collections::{HashMap, HashSet};
+use std::marker::PhantomData;
+
+use crate::algo::utils::degrees_of;
+use crate::algo::{odd_cycle, ConnectedComponents, TarjanSCC};
+use crate::graph::{DirectedEdge, Edge, EdgeDir, UndirectedEdge};
+use crate::provide::{Edges, Graph, Neighbors, Vertices};
+
+/// Summary statistics of a graph, meant to be shown all together(for example on a dashboard).
+///
+/// Statistics that are computed in O(|V| + |E|) from the degrees are computed once by [`init`](crate::algo::GraphReport::init) and stored in the fields.
+/// The rest are computed on demand by the methods, using the existing algorithms:
+/// * Undirected graphs: [`component_count`](crate::algo::GraphReport::component_count), [`clustering_coefficient`](crate::algo::GraphReport::clustering_coefficient),
+///   [`is_tree`](crate::algo::GraphReport::is_tree) and [`is_bipartite`](crate::algo::GraphReport::is_bipartite).
+/// * Directed graphs: [`is_dag`](crate::algo::GraphReport::is_dag).
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::GraphReport;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b --- c
+/// //       \   /
+/// //         d
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(b, d, 1.into());
+/// graph.add_edge(d, a, 1.into());
+///
+/// // When: Creating the report.
+/// let report = GraphReport::init(&graph);
+///
+/// // Then:
+/// assert_eq!(report.vertex_count, 4);
+/// assert_eq!(report.edges_count, 4);
+/// assert_eq!(report.max_degree, 3);
+/// assert_eq!(report.component_count(), 1);
+/// assert!(!report.is_tree());
+/// assert!(!report.is_bipartite());
+/// ```
+pub struct GraphReport<'a, W, E: Edge<W>, Dir: EdgeDir, G: Graph<W, E, Dir>> {
+    /// Number of vertices.
+    pub vertex_count: usize,
+
+    /// Number of edges.
+    pub edges_count: usize,
+
+    /// Ratio of the number of edges to the maximum number of edges a simple graph with the same number of vertices can have.
+    /// It is 0 for graphs with less than two vertices.
+    pub density: f64,
+
+    /// Average degree of the vertices. It is 0 for the empty graph.
+    pub average_degree: f64,
+
+    /// Maximum degree of the vertices, counting both incoming and outgoing edges of directed graphs. It is 0 for the empty graph.
+    pub max_degree: usize,
+
+    graph: &'a G,
+
+    phantom_w: PhantomData<W>,
+    phantom_e: PhantomData<E>,
+    phantom_dir: PhantomData<Dir>,
+}
+
+impl<'a, W, E, Dir, G> GraphReport<'a, W, E, Dir, G>
+where
+    E: Edge<W>,
+    Dir: EdgeDir,
+    G: Graph<W, E, Dir> + Edges<W, E> + Vertices + Neighbors,
+{
+    /// Computes the statistics that are stored in the fields.
+    ///
+    /// # Arguments
+    /// `graph`: Graph to report its statistics.
+    ///
+    /// # Complexity
+    /// O(|V| + |E|)
+    pub fn init(graph: &'a G) -> Self {
+        let vertex_count = graph.vertex_count();
+        let edges_count = graph.edges_count();
+
+        let max_edges_count = if Dir::is_directed() {
+            vertex_count * vertex_count.saturating_sub(1)
+        } else {
+            vertex_count * vertex_count.saturating_sub(1) / 2
+        };
+        let density = if max_edges_count == 0 {
+            0.0
+        } else {
+            edges_count as f64 / max_edges_count as f64
+        };
+
+        let average_degree = if vertex_count == 0 {
+            0.0
+        } else {
+            2.0 * edges_count as f64 / vertex_count as f64
+        };
+
+        GraphReport {
+            vertex_count,
+            edges_count,
+            density,
+            average_degree,
+            max_degree: degrees_of(graph).values().copied().max().unwrap_or(0),
+            graph,
+
+            phantom_w: PhantomData,
+            phantom_e: PhantomData,
+            phantom_dir: PhantomData,
+        }
+    }
+}
+
+impl<'a, W, E, G> GraphReport<'a, W, E, UndirectedEdge, G>
+where
+    E: Edge<W>,
+    G: Graph<W, E, UndirectedEdge> + Edges<W, E> + Vertices + Neighbors,
+{
+    /// # Returns
+    /// Number of connected components, which is 0 for the empty graph.
+    ///
+    /// # Complexity
+    /// O(|V| + |E|)
+    pub fn component_count(&self) -> usize {
+        ConnectedComponents::init(self.graph)
+            .execute(self.graph)
+            .len()
+    }
+
+    /// Computes the average local clustering coefficient.
+    /// Local clustering coefficient of a vertex is the fraction of pairs of its neighbors that are connected to each other,
+    /// and it is 0 for vertices with less than two neighbors.
+    ///
+    /// # Returns
+    /// Average local clustering coefficient of the vertices. It is 0 for the empty graph.
+    ///
+    /// # Complexity
+    /// O(Σ d<sub>v</sub><sup>2</sup>) where d<sub>v</sub> is the degree of vertex v.
+    pub fn clustering_coefficient(&self) -> f64 {
+        if self.vertex_count == 0 {
+            return 0.0;
+        }
+
+        let neighbors_of: HashMap<usize, HashSet<usize>> = self
+            .graph
+            .vertices()
+            .into_iter()
+            .map(|vertex_id| {
+                let neighbors = self.graph.neighbors(vertex_id).unwrap();
+                (vertex_id, neighbors.into_iter().collect())
+            })
+            .collect();
+
+        let sum: f64 = neighbors_of
+            .values()
+            .filter(|neighbors| neighbors.len() >= 2)
+            .map(|neighbors| {
+                let links_count = neighbors
+                    .iter()
+                    .map(|n_id| neighbors_of[n_id].intersection(neighbors).count())
+                    .sum::<usize>()
+                    / 2;
+                let pairs_count = neighbors.len() * (neighbors.len() - 1) / 2;
+
+                links_count as f64 / pairs_count as f64
+            })
+            .sum();
+
+        sum / self.vertex_count as f64
+    }
+
+    /// # Returns
+    /// `true` if graph is connected and has no cycle, `false` otherwise. Empty graph is not a tree.
+    ///
+    /// # Complexity
+    /// O(1) if number of edges is not one less than number of vertices, O(|V| + |E|) otherwise.
+    pub fn is_tree(&self) -> bool {
+        self.vertex_count != 0
+            && self.edges_count == self.vertex_count - 1
+            && self.component_count() == 1
+    }
+
+    /// # Returns
+    /// `true` if vertices can be partitioned into two sets so that every edge is between the two sets, `false` otherwise.
+    ///
+    /// # Complexity
+    /// O(|V| + |E|)
+    pub fn is_bipartite(&self) -> bool {
+        odd_cycle(self.graph).is_none()
+    }
+}
+
+impl<'a, W, E, G> GraphReport<'a, W, E, DirectedEdge, G>
+where
+    E: Edge<W>,
+    G: Graph<W, E, DirectedEdge> + Edges<W, E> + Vertices + Neighbors,
+{
+    /// # Returns
+    /// `true` if graph has no directed cycle, `false` otherwise.
+    ///
+    /// # Complexity
+    /// O(|V| + |E|)
+    pub fn is_dag(&self) -> bool {
+        // Simple graphs have no loop, so a graph is acyclic iff all its strongly connected components are single vertices.
+        TarjanSCC::init(self.graph)
+            .execute(self.graph)
+            .iter()
+            .all(|scc| scc.len() == 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::storage::{DiMat, Mat};
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        let report = GraphReport::init(&graph);
+
+        assert_eq!(report.vertex_count, 0);
+        assert_eq!(report.edges_count, 0);
+        assert_eq!(report.density, 0.0);
+        assert_eq!(report.average_degree, 0.0);
+        assert_eq!(report.max_degree, 0);
+        assert_eq!(report.component_count(), 0);
+        assert_eq!(report.clustering_coefficient(), 0.0);
+        assert!(!report.is_tree());
+        assert!(report.is_bipartite());
+    }
+
+    #[test]
+    fn undirected_graph() {
+        // Given: Graph
+        //
+        //      a --- b --- c       e --- f
+        //       \   /
+        //         d
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(b, d, 1.into()).unwrap();
+        graph.add_edge(d, a, 1.into()).unwrap();
+        graph.add_edge(e, f, 1.into()).unwrap();
+
+        // When: Creating the report.
+        let report = GraphReport::init(&graph);
+
+        // Then:
+        assert_eq!(report.vertex_count, 6);
+        assert_eq!(report.edges_count, 5);
+        assert!((report.density - 5.0 / 15.0).abs() < 1e-12);
+        assert!((report.average_degree - 10.0 / 6.0).abs() < 1e-12);
+        assert_eq!(report.max_degree, 3);
+        assert_eq!(report.component_count(), 2);
+        // a and d have coefficient 1, b has 1/3 and the rest have 0.
+        assert!((report.clustering_coefficient() - (1.0 + 1.0 / 3.0 + 1.0) / 6.0).abs() < 1e-12);
+        assert!(!report.is_tree());
+        assert!(!report.is_bipartite());
+
+        // When: Removing the edge of the triangle and the second component.
+        let da = graph.edges_between(d, a).unwrap()[0].get_id();
+        graph.remove_edge(d, a, da).unwrap();
+        graph.remove_vertex(e).unwrap();
+        graph.remove_vertex(f).unwrap();
+        let report = GraphReport::init(&graph);
+
+        // Then: Graph is a star around b.
+        assert_eq!(report.component_count(), 1);
+        assert_eq!(report.clustering_coefficient(), 0.0);
+        assert!(report.is_tree());
+        assert!(report.is_bipartite());
+    }
+
+    #[test]
+    fn directed_graph() {
+        // Given: Graph
+        //
+        //      a --> b --> c
+        //      |           ^
+        //      '---> d ----'
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(a, d, 1.into()).unwrap();
+        graph.add_edge(d, c, 1.into()).unwrap();
+
+        // When: Creating the report.
+        let report = GraphReport::init(&graph);
+
+        // Then:
+        assert_eq!(report.vertex_count, 4);
+        assert_eq!(report.edges_count, 4);
+        assert!((report.density - 4.0 / 12.0).abs() < 1e-12);
+        assert_eq!(report.average_degree, 2.0);
+        assert_eq!(report.max_degree, 2);
+        assert!(report.is_dag());
+
+        // When: Closing a cycle.
+        graph.add_edge(c, a, 1.into()).unwrap();
+
+        // Then:
+        assert!(!GraphReport::init(&graph).is_dag());
+    }
+}
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+
+use crate::graph::{Edge, UndirectedEdge};
+use crate::provide::{Graph, IdMap, Neighbors, Vertices};
+
+/// Colors vertices of an undirected graph so that no two adjacent vertices have the same color, using the Welsh–Powell heuristic.
+///
+/// Vertices are sorted by descending degree(ties are broken by id), and each vertex in turn gets the smallest color not used by its already colored neighbors.
+/// This is the same as the original formulation, in which each color is given to as many of the remaining vertices as possible in the same order.
+/// So a graph with maximum degree Δ is colored with at most Δ + 1 colors, but the number of colors is not always the minimum possible.
+/// For an exact answer checkout [`k_colorable`](crate::algo::k_colorable).
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::GreedyColoring;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b
+/// //      |   / |
+/// //      | /   |
+/// //      c --- d
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(a, c, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(b, d, 1.into());
+/// graph.add_edge(c, d, 1.into());
+///
+/// // When: Coloring the graph.
+/// let mut greedy_coloring = GreedyColoring::init(&graph);
+/// let color_of = greedy_coloring.execute();
+///
+/// // Then: b and c have the highest degrees, so they get the first two colors, and a and d share the third one.
+/// assert_eq!(greedy_coloring.colors_count(), 3);
+/// assert_eq!(color_of[&b], 0);
+/// assert_eq!(color_of[&c], 1);
+/// assert_eq!(color_of[&a], color_of[&d]);
+/// ```
+pub struct GreedyColoring {
+    id_map: IdMap,
+
+    // Maps each vertex(by virtual id) to virtual id of its neighbors.
+    adjacency: Vec<Vec<usize>>,
+
+    colors_count: usize,
+}
+
+impl GreedyColoring {
+    /// Initializes the structure.
+    ///
+    /// # Arguments
+    /// `graph`: Undirected graph to color.
+    pub fn init<W, E, G>(graph: &G) -> Self
+    where
+        E: Edge<W>,
+        G: Vertices + Neighbors + Graph<W, E, UndirectedEdge>,
+    {
+        let id_map = graph.continuos_id_map();
+
+        let adjacency = (0..graph.vertex_count())
+            .map(|virt_id| {
+                let mut neighbors: Vec<usize> = graph
+                    .neighbors(id_map.real_id_of(virt_id))
+                    .unwrap()
+                    .into_iter()
+                    .map(|n_id| id_map.virt_id_of(n_id))
+                    .collect();
+                neighbors.sort_unstable();
+                neighbors.dedup();
+                neighbors
+            })
+            .collect();
+
+        GreedyColoring {
+            id_map,
+            adjacency,
+            colors_count: 0,
+        }
+    }
+
+    /// Colors the graph.
+    ///
+    /// # Returns
+    /// Maps id of each vertex to its color, which is in range [0, [`colors_count`](crate::algo::GreedyColoring::colors_count)).
+    ///
+    /// # Complexity
+    /// O(|V|log(|V|) + |E|)
+    ///
+    /// # Panics
+    /// If a vertex has a loop, because it can not have a different color than itself.
+    pub fn execute(&mut self) -> HashMap<usize, usize> {
+        let vertex_count = self.adjacency.len();
+
+        let mut order: Vec<usize> = (0..vertex_count).collect();
+        order.sort_by_key(|virt_id| {
+            (
+                std::cmp::Reverse(self.adjacency[*virt_id].len()),
+                self.id_map.real_id_of(*virt_id),
+            )
+        });
+
+        let mut color_of: Vec<Option<usize>> = vec![None; vertex_count];
+        // Marks the colors used by neighbors of the vertex being colored, by the position of the vertex in `order`.
+        let mut used_by = vec![usize::MAX; vertex_count + 1];
+        self.colors_count = 0;
+
+        for (position, virt_id) in order.into_iter().enumerate() {
+            assert!(
+                !self.adjacency[virt_id].contains(&virt_id),
+                "Vertex with id: {} has a loop",
+                self.id_map.real_id_of(virt_id)
+            );
+
+            for n_virt_id in &self.adjacency[virt_id] {
+                if let Some(color) = color_of[*n_virt_id] {
+                    used_by[color] = position;
+                }
+            }
+
+            let color = (0..).find(|color| used_by[*color] != position).unwrap();
+            color_of[virt_id] = Some(color);
+            self.colors_count = self.colors_count.max(color + 1);
+        }
+
+        color_of
+            .into_iter()
+            .enumerate()
+            .map(|(virt_id, color)| (self.id_map.real_id_of(virt_id), color.unwrap()))
+            .collect()
+    }
+
+    /// # Returns
+    /// Number of colors used by the last call to [`execute`](crate::algo::GreedyColoring::execute), which is 0 if it is not called yet.
+    pub fn colors_count(&self) -> usize {
+        self.colors_count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen::CompleteBipartiteGraph;
+    use crate::graph::MatGraph;
+    use crate::provide::Edges;
+    use crate::storage::Mat;
+
+    fn assert_proper(graph: &MatGraph<usize, UndirectedEdge>, color_of: &HashMap<usize, usize>) {
+        assert_eq!(color_of.len(), graph.vertex_count());
+        for (src_id, dst_id, _) in graph.edges() {
+            assert_ne!(color_of[&src_id], color_of[&dst_id]);
+        }
+    }
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+        let mut greedy_coloring = GreedyColoring::init(&graph);
+
+        assert!(greedy_coloring.execute().is_empty());
+        assert_eq!(greedy_coloring.colors_count(), 0);
+    }
+
+    #[test]
+    fn bipartite_graph() {
+        // Given: K(3, 4).
+        let graph = CompleteBipartiteGraph::init(3, 4).generate();
+
+        // When: Coloring the graph.
+        let mut greedy_coloring = GreedyColoring::init(&graph);
+        let color_of = greedy_coloring.execute();
+
+        // Then:
+        assert_proper(&graph, &color_of);
+        assert_eq!(greedy_coloring.colors_count(), 2);
+    }
+
+    #[test]
+    fn complete_graph() {
+        // Given: K5.
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let vertices: Vec<usize> = (0..5).map(|_| graph.add_vertex()).collect();
+        for (index, src_id) in vertices.iter().enumerate() {
+            for dst_id in &vertices[index + 1..] {
+                graph.add_edge(*src_id, *dst_id, 1.into()).unwrap();
+            }
+        }
+
+        // When: Coloring the graph.
+        let mut greedy_coloring = GreedyColoring::init(&graph);
+        let color_of = greedy_coloring.execute();
+
+        // Then:
+        assert_proper(&graph, &color_of);
+        assert_eq!(greedy_coloring.colors_count(), 5);
+    }
+
+    #[test]
+    fn isolated_vertices_share_first_color() {
+        // Given: Graph
+        //
+        //      a --- b     c     d
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+
+        // When: Coloring the graph.
+        let mut greedy_coloring = GreedyColoring::init(&graph);
+        let color_of = greedy_coloring.execute();
+
+        // Then:
+        assert_eq!(greedy_coloring.colors_count(), 2);
+        assert_eq!(color_of[&c], 0);
+        assert_eq!(color_of[&d], 0);
+    }
+}
@@ -85,7 +85,7 @@ impl<'a, W, E: Edge<W>> HasCycle<'a, W, E> {
     /// # Returns
     /// * `Some`: Containing the found cycle in the form of a subgraph.
     /// * `None`: If graph does not have any cycle.
-    pub fn execute<Dir, G>(mut self, graph: &'a G) -> Option<Subgraph<W, E, Dir, G>>
+    pub fn execute<Dir, G>(mut self, graph: &'a G) -> Option<Subgraph<'a, W, E, Dir, G>>
     where
         E: Edge<W>,
         Dir: EdgeDir,
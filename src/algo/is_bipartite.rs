@@ -0,0 +1,243 @@
+use std::collections::VecDeque;
+
+use crate::graph::{Edge, UndirectedEdge};
+use crate::provide::{Graph, IdMap, Neighbors, Vertices};
+
+/// Checks whether an undirected graph is bipartite, by 2-coloring its vertices using breadth-first search.
+///
+/// Each component is colored independently, starting from its vertex with the smallest virtual id,
+/// and each vertex gets the opposite color of the vertex it is discovered from.
+/// Graph is bipartite iff no edge connects two vertices with the same color.
+/// For a witness of a graph not being bipartite checkout [`odd_cycle`](crate::algo::odd_cycle).
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::IsBipartite;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b
+/// //      |     |
+/// //      d --- c
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(c, d, 1.into());
+/// graph.add_edge(d, a, 1.into());
+///
+/// // When: Checking bipartiteness.
+/// let partitions = IsBipartite::init(&graph).execute();
+///
+/// // Then: Opposite corners are in the same partition.
+/// assert_eq!(partitions, Some((vec![a, c], vec![b, d])));
+/// ```
+pub struct IsBipartite {
+    id_map: IdMap,
+
+    // Maps each vertex(by virtual id) to virtual id of its neighbors.
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl IsBipartite {
+    /// Initializes the structure.
+    ///
+    /// # Arguments
+    /// `graph`: Undirected graph to check.
+    pub fn init<W, E, G>(graph: &G) -> Self
+    where
+        E: Edge<W>,
+        G: Vertices + Neighbors + Graph<W, E, UndirectedEdge>,
+    {
+        let id_map = graph.continuos_id_map();
+
+        let adjacency = (0..graph.vertex_count())
+            .map(|virt_id| {
+                graph
+                    .neighbors(id_map.real_id_of(virt_id))
+                    .unwrap()
+                    .into_iter()
+                    .map(|n_id| id_map.virt_id_of(n_id))
+                    .collect()
+            })
+            .collect();
+
+        IsBipartite { id_map, adjacency }
+    }
+
+    /// Performs the check.
+    ///
+    /// # Returns
+    /// * `Some`: Containing the two partitions, each sorted by id. Every edge has one end point in each partition,
+    ///   and the first partition contains the first vertex of each component, so isolated vertices are in the first partition.
+    /// * `None`: If the graph is not bipartite.
+    ///
+    /// # Complexity
+    /// O(|V| + |E|)
+    pub fn execute(&self) -> Option<(Vec<usize>, Vec<usize>)> {
+        let vertex_count = self.adjacency.len();
+
+        let mut color_of: Vec<Option<bool>> = vec![None; vertex_count];
+
+        for root_virt_id in 0..vertex_count {
+            if color_of[root_virt_id].is_some() {
+                continue;
+            }
+
+            color_of[root_virt_id] = Some(false);
+            let mut queue = VecDeque::from(vec![root_virt_id]);
+
+            while let Some(virt_id) = queue.pop_front() {
+                let color = color_of[virt_id].unwrap();
+
+                for n_virt_id in self.adjacency[virt_id].iter().copied() {
+                    match color_of[n_virt_id] {
+                        None => {
+                            color_of[n_virt_id] = Some(!color);
+                            queue.push_back(n_virt_id);
+                        }
+                        Some(n_color) if n_color == color => return None,
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        let (mut first, mut second) = (vec![], vec![]);
+        for (virt_id, color) in color_of.into_iter().enumerate() {
+            let real_id = self.id_map.real_id_of(virt_id);
+            if color.unwrap() {
+                second.push(real_id);
+            } else {
+                first.push(real_id);
+            }
+        }
+        first.sort_unstable();
+        second.sort_unstable();
+
+        Some((first, second))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::provide::Edges;
+    use crate::storage::Mat;
+
+    fn cycle_of(vertex_count: usize) -> MatGraph<usize, UndirectedEdge> {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        for _ in 0..vertex_count {
+            graph.add_vertex();
+        }
+        for vertex_id in 0..vertex_count {
+            graph
+                .add_edge(vertex_id, (vertex_id + 1) % vertex_count, 1.into())
+                .unwrap();
+        }
+
+        graph
+    }
+
+    fn assert_partitions(
+        graph: &MatGraph<usize, UndirectedEdge>,
+        (first, second): &(Vec<usize>, Vec<usize>),
+    ) {
+        assert_eq!(first.len() + second.len(), graph.vertex_count());
+        for (src_id, dst_id, _) in graph.edges() {
+            assert_ne!(first.contains(&src_id), first.contains(&dst_id));
+            assert_ne!(second.contains(&src_id), second.contains(&dst_id));
+        }
+    }
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        assert_eq!(IsBipartite::init(&graph).execute(), Some((vec![], vec![])));
+    }
+
+    #[test]
+    fn even_cycle() {
+        // Given: Cycle with 6 vertices.
+        let graph = cycle_of(6);
+
+        // When: Checking bipartiteness.
+        let partitions = IsBipartite::init(&graph).execute().unwrap();
+
+        // Then:
+        assert_partitions(&graph, &partitions);
+        assert_eq!(partitions, (vec![0, 2, 4], vec![1, 3, 5]));
+    }
+
+    #[test]
+    fn odd_cycle() {
+        // Given: Cycle with 5 vertices.
+        let graph = cycle_of(5);
+
+        // Then:
+        assert!(IsBipartite::init(&graph).execute().is_none());
+    }
+
+    #[test]
+    fn forest() {
+        // Given: Graph
+        //
+        //      a --- b --- c     d --- e     f
+        //            |                 |
+        //            g                 h
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        let g = graph.add_vertex();
+        let h = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(b, g, 1.into()).unwrap();
+        graph.add_edge(d, e, 1.into()).unwrap();
+        graph.add_edge(e, h, 1.into()).unwrap();
+
+        // When: Checking bipartiteness.
+        let partitions = IsBipartite::init(&graph).execute().unwrap();
+
+        // Then: Each tree is colored independently, starting from its first vertex.
+        assert_partitions(&graph, &partitions);
+        assert_eq!(partitions, (vec![a, c, d, f, g, h], vec![b, e]));
+    }
+
+    #[test]
+    fn odd_cycle_in_second_component() {
+        // Given: Graph
+        //
+        //      a --- b     c --- d
+        //                   \   /
+        //                     e
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+        graph.add_edge(d, e, 1.into()).unwrap();
+        graph.add_edge(e, c, 1.into()).unwrap();
+
+        // Then:
+        assert!(IsBipartite::init(&graph).execute().is_none());
+    }
+}
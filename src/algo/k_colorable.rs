@@ -0,0 +1,261 @@
+use std::collections::HashMap;
+
+use crate::graph::{Edge, UndirectedEdge};
+use crate::provide::{Graph, IdMap, Neighbors, Vertices};
+
+/// Finds a coloring of the vertices with at most `k` colors, so that no two adjacent vertices have the same color.
+///
+/// Uses backtracking with forward checking: each uncolored vertex keeps the colors that are still available to it,
+/// and assigning a color removes it from the neighbors. The vertex with the fewest available colors is colored next,
+/// and a branch is abandoned as soon as a vertex runs out of colors.
+/// Running time is exponential in the worst case, so it is intended for small graphs.
+///
+/// # Arguments
+/// * `graph`: Undirected graph to color.
+/// * `k`: Number of available colors.
+///
+/// # Returns
+/// * `Some`: Maps id of each vertex to its color, which is in range [0, `k`).
+/// * `None`: If graph is not `k`-colorable.
+///
+/// # Complexity
+/// O(k<sup>|V|</sup>(|V| + |E|)) in the worst case.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::k_colorable;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b
+/// //      |     |
+/// //      d --- c
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(c, d, 1.into());
+/// graph.add_edge(d, a, 1.into());
+///
+/// // When: Coloring with 2 colors.
+/// let coloring = k_colorable(&graph, 2).unwrap();
+///
+/// // Then: Opposite corners share a color.
+/// assert_eq!(coloring[&a], coloring[&c]);
+/// assert_eq!(coloring[&b], coloring[&d]);
+/// assert_ne!(coloring[&a], coloring[&b]);
+/// ```
+pub fn k_colorable<W, E, G>(graph: &G, k: usize) -> Option<HashMap<usize, usize>>
+where
+    E: Edge<W>,
+    G: Vertices + Neighbors + Graph<W, E, UndirectedEdge>,
+{
+    let id_map = graph.continuos_id_map();
+    let vertex_count = graph.vertex_count();
+
+    let mut adjacency: Vec<Vec<usize>> = vec![vec![]; vertex_count];
+    for (virt_id, neighbors) in adjacency.iter_mut().enumerate() {
+        *neighbors = graph
+            .neighbors(id_map.real_id_of(virt_id))
+            .unwrap()
+            .into_iter()
+            .map(|n_id| id_map.virt_id_of(n_id))
+            .collect();
+        neighbors.sort_unstable();
+        neighbors.dedup();
+
+        // A vertex with a loop can not have a different color than itself.
+        if neighbors.contains(&virt_id) {
+            return None;
+        }
+    }
+
+    let mut coloring = KColoring {
+        adjacency,
+        available: vec![vec![true; k]; vertex_count],
+        available_count: vec![k; vertex_count],
+        color_of: vec![None; vertex_count],
+    };
+
+    if coloring.color_next() {
+        Some(coloring.into_map(&id_map))
+    } else {
+        None
+    }
+}
+
+// State of the backtracking search, in which vertices are identified by their virtual ids.
+struct KColoring {
+    adjacency: Vec<Vec<usize>>,
+
+    // Whether each color is still available to each vertex, and number of the available colors.
+    available: Vec<Vec<bool>>,
+    available_count: Vec<usize>,
+
+    color_of: Vec<Option<usize>>,
+}
+
+impl KColoring {
+    // Colors the uncolored vertex with the fewest available colors and recurses on the rest.
+    //
+    // # Returns
+    // `true` if all vertices got colored, `false` if no coloring exists for the current partial coloring.
+    fn color_next(&mut self) -> bool {
+        let next_virt_id = (0..self.color_of.len())
+            .filter(|virt_id| self.color_of[*virt_id].is_none())
+            .min_by_key(|virt_id| self.available_count[*virt_id]);
+
+        let virt_id = match next_virt_id {
+            Some(virt_id) => virt_id,
+            None => return true,
+        };
+
+        for color in 0..self.available[virt_id].len() {
+            if !self.available[virt_id][color] {
+                continue;
+            }
+
+            self.color_of[virt_id] = Some(color);
+
+            // Forward checking: remove the color from uncolored neighbors, and stop if one of them runs out of colors.
+            let mut removed_from = vec![];
+            let mut is_consistent = true;
+            for n_virt_id in self.adjacency[virt_id].iter().copied() {
+                if self.color_of[n_virt_id].is_none() && self.available[n_virt_id][color] {
+                    self.available[n_virt_id][color] = false;
+                    self.available_count[n_virt_id] -= 1;
+                    removed_from.push(n_virt_id);
+
+                    if self.available_count[n_virt_id] == 0 {
+                        is_consistent = false;
+                        break;
+                    }
+                }
+            }
+
+            if is_consistent && self.color_next() {
+                return true;
+            }
+
+            for n_virt_id in removed_from {
+                self.available[n_virt_id][color] = true;
+                self.available_count[n_virt_id] += 1;
+            }
+            self.color_of[virt_id] = None;
+        }
+
+        false
+    }
+
+    fn into_map(self, id_map: &IdMap) -> HashMap<usize, usize> {
+        self.color_of
+            .into_iter()
+            .enumerate()
+            .map(|(virt_id, color)| (id_map.real_id_of(virt_id), color.unwrap()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{DefaultEdge, MatGraph};
+    use crate::provide::Edges;
+    use crate::storage::Mat;
+
+    // Checks that every vertex has a color less than `k`, and end points of every edge have different colors.
+    fn assert_is_proper_coloring<G>(graph: &G, coloring: &HashMap<usize, usize>, k: usize)
+    where
+        G: Vertices + Edges<usize, DefaultEdge<usize>>,
+    {
+        assert_eq!(coloring.len(), graph.vertex_count());
+        assert!(coloring.values().all(|color| *color < k));
+        for (src_id, dst_id, _) in graph.edges() {
+            assert_ne!(coloring[&src_id], coloring[&dst_id]);
+        }
+    }
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        assert_eq!(k_colorable(&graph, 0), Some(HashMap::new()));
+    }
+
+    #[test]
+    fn triangle() {
+        // Given: Graph
+        //
+        //      a --- b
+        //       \   /
+        //         c
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, a, 1.into()).unwrap();
+
+        // Then:
+        assert!(k_colorable(&graph, 2).is_none());
+        let coloring = k_colorable(&graph, 3).unwrap();
+        assert_is_proper_coloring(&graph, &coloring, 3);
+    }
+
+    #[test]
+    fn bipartite_graph() {
+        // Given: Complete bipartite graph between {a, b, c} and {d, e, f}, with an isolated vertex g.
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let left: Vec<usize> = (0..3).map(|_| graph.add_vertex()).collect();
+        let right: Vec<usize> = (0..3).map(|_| graph.add_vertex()).collect();
+        let g = graph.add_vertex();
+        for src_id in left.iter().copied() {
+            for dst_id in right.iter().copied() {
+                graph.add_edge(src_id, dst_id, 1.into()).unwrap();
+            }
+        }
+
+        // When: Coloring with 2 colors.
+        let coloring = k_colorable(&graph, 2).unwrap();
+
+        // Then:
+        assert_is_proper_coloring(&graph, &coloring, 2);
+        assert!(coloring.contains_key(&g));
+        assert!(k_colorable(&graph, 1).is_none());
+    }
+
+    #[test]
+    fn odd_wheel() {
+        // Given: Cycle of 5 vertices, all connected to a hub.
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let hub = graph.add_vertex();
+        let rim: Vec<usize> = (0..5).map(|_| graph.add_vertex()).collect();
+        for index in 0..rim.len() {
+            graph
+                .add_edge(rim[index], rim[(index + 1) % rim.len()], 1.into())
+                .unwrap();
+            graph.add_edge(hub, rim[index], 1.into()).unwrap();
+        }
+
+        // Then: Odd cycle needs 3 colors, and the hub needs a fourth one.
+        assert!(k_colorable(&graph, 3).is_none());
+        let coloring = k_colorable(&graph, 4).unwrap();
+        assert_is_proper_coloring(&graph, &coloring, 4);
+
+        // When: Removing a vertex of the rim.
+        graph.remove_vertex(rim[0]).unwrap();
+
+        // Then: Rim is a path now, so 3 colors are enough.
+        let coloring = k_colorable(&graph, 3).unwrap();
+        assert_is_proper_coloring(&graph, &coloring, 3);
+    }
+}
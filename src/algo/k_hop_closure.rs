@@ -0,0 +1,178 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::provide::{Neighbors, Vertices};
+
+/// Computes transitive closure of the graph limited to `k` hops: for each vertex, the vertices that can be reached from it by a path of at most `k` edges.
+///
+/// Runs a breadth-first search from each vertex that stops expanding vertices at depth `k`.
+/// Weights of the edges are ignored.
+///
+/// # Arguments
+/// * `graph`: Graph to compute the closure of.
+/// * `k`: Maximum number of edges of the paths.
+///
+/// # Returns
+/// Maps id of each vertex to id of the other vertices within distance `k` of it. A vertex is not included in its own set.
+///
+/// # Complexity
+/// O(|V|(|V| + |E|)), and less when `k` is small compared to the diameter of the graph.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::DiMat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::k_hop_closure;
+///
+/// // Given: Graph
+/// //
+/// //      a --> b --> c --> d
+/// //
+/// let mut graph = MatGraph::init(DiMat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(c, d, 1.into());
+///
+/// // When: Computing the closure with at most two hops.
+/// let closure = k_hop_closure(&graph, 2);
+///
+/// // Then:
+/// assert_eq!(closure[&a], [b, c].iter().copied().collect());
+/// assert_eq!(closure[&c], [d].iter().copied().collect());
+/// assert!(closure[&d].is_empty());
+/// ```
+pub fn k_hop_closure<G>(graph: &G, k: usize) -> HashMap<usize, HashSet<usize>>
+where
+    G: Vertices + Neighbors,
+{
+    let id_map = graph.continuos_id_map();
+    let vertex_count = graph.vertex_count();
+
+    let adjacency: Vec<Vec<usize>> = (0..vertex_count)
+        .map(|virt_id| {
+            graph
+                .neighbors(id_map.real_id_of(virt_id))
+                .unwrap()
+                .into_iter()
+                .map(|n_id| id_map.virt_id_of(n_id))
+                .collect()
+        })
+        .collect();
+
+    // Depth of each vertex in the current search, reset after each search using `reached`.
+    let mut depth_of: Vec<Option<usize>> = vec![None; vertex_count];
+
+    (0..vertex_count)
+        .map(|src_virt_id| {
+            let mut reached = vec![src_virt_id];
+            let mut queue = VecDeque::new();
+
+            depth_of[src_virt_id] = Some(0);
+            queue.push_back(src_virt_id);
+            while let Some(virt_id) = queue.pop_front() {
+                let depth = depth_of[virt_id].unwrap();
+                if depth == k {
+                    continue;
+                }
+
+                for n_virt_id in adjacency[virt_id].iter().copied() {
+                    if depth_of[n_virt_id].is_none() {
+                        depth_of[n_virt_id] = Some(depth + 1);
+                        reached.push(n_virt_id);
+                        queue.push_back(n_virt_id);
+                    }
+                }
+            }
+
+            for virt_id in &reached {
+                depth_of[*virt_id] = None;
+            }
+
+            let closure = reached
+                .into_iter()
+                .skip(1)
+                .map(|virt_id| id_map.real_id_of(virt_id))
+                .collect();
+
+            (id_map.real_id_of(src_virt_id), closure)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::provide::Graph;
+    use crate::storage::{DiMat, Mat};
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        assert!(k_hop_closure(&graph, 3).is_empty());
+    }
+
+    #[test]
+    fn path_graph() {
+        // Given: Path with 7 vertices.
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let vertices: Vec<usize> = (0..7).map(|_| graph.add_vertex()).collect();
+        for window in vertices.windows(2) {
+            graph.add_edge(window[0], window[1], 1.into()).unwrap();
+        }
+
+        for k in 0..8 {
+            // When: Computing the closure with at most k hops.
+            let closure = k_hop_closure(&graph, k);
+
+            // Then: Each vertex reaches exactly the other vertices within k positions of it.
+            for (index, vertex_id) in vertices.iter().enumerate() {
+                let expected: HashSet<usize> = vertices
+                    .iter()
+                    .enumerate()
+                    .filter(|(n_index, _)| {
+                        *n_index != index
+                            && (*n_index as isize - index as isize).abs() <= k as isize
+                    })
+                    .map(|(_, n_id)| *n_id)
+                    .collect();
+                assert_eq!(closure[vertex_id], expected);
+            }
+        }
+    }
+
+    #[test]
+    fn directed_cycle() {
+        // Given: Graph
+        //
+        //      a --> b --> c
+        //      ^           |
+        //      '-----------'
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, a, 1.into()).unwrap();
+
+        // When: Computing the closure with at most one and two hops.
+        let one_hop = k_hop_closure(&graph, 1);
+        let two_hops = k_hop_closure(&graph, 2);
+
+        // Then: Edges are followed only in their direction, and a vertex is never in its own set.
+        assert_eq!(one_hop[&a], [b].iter().copied().collect());
+        assert_eq!(one_hop[&c], [a].iter().copied().collect());
+        assert_eq!(two_hops[&b], [c, a].iter().copied().collect());
+        assert_eq!(
+            k_hop_closure(&graph, 5)[&a],
+            [b, c].iter().copied().collect()
+        );
+    }
+}
@@ -0,0 +1,294 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::{
+    graph::{Edge, UndirectedEdge},
+    provide::{Edges, Graph, IdMap, Vertices},
+};
+
+/// Finds a community around a seed vertex of an undirected graph, without processing the whole graph.
+///
+/// Uses a PageRank-Nibble style method:
+/// 1. Approximates personalized PageRank of the seed using the push method, which only touches vertices around the seed.
+/// 2. Sorts vertices with non-zero rank by their rank divided by their degree.
+/// 3. Sweeps over the sorted vertices and returns the prefix with minimum conductance.
+///
+/// Weights of the edges are ignored.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::LocalCommunity;
+///
+/// // Given: Graph of two triangles connected by an edge.
+/// //
+/// //      a --- b       d --- e
+/// //       \   /         \   /
+/// //         c ---------- f
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// let e = graph.add_vertex();
+/// let f = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(c, a, 1.into());
+/// graph.add_edge(d, e, 1.into());
+/// graph.add_edge(e, f, 1.into());
+/// graph.add_edge(f, d, 1.into());
+/// graph.add_edge(c, f, 1.into());
+///
+/// // When: Finding community of a.
+/// let community = LocalCommunity::init(&graph).execute(a);
+///
+/// // Then:
+/// assert_eq!(community, [a, b, c].iter().copied().collect());
+/// ```
+pub struct LocalCommunity {
+    id_map: IdMap,
+
+    // Maps each vertex(by virtual id) to virtual id of its neighbors.
+    adjacency: Vec<Vec<usize>>,
+
+    alpha: f64,
+    epsilon: f64,
+}
+
+impl LocalCommunity {
+    /// Initializes the structure with teleport probability of 0.15 and tolerance of 10<sup>-4</sup>.
+    ///
+    /// # Arguments
+    /// `graph`: Undirected graph to find communities in.
+    pub fn init<W, E: Edge<W>, G>(graph: &G) -> Self
+    where
+        G: Vertices + Edges<W, E> + Graph<W, E, UndirectedEdge>,
+    {
+        LocalCommunity::init_with(graph, 0.15, 1e-4)
+    }
+
+    /// Initializes the structure.
+    ///
+    /// # Arguments
+    /// * `graph`: Undirected graph to find communities in.
+    /// * `alpha`: Teleport probability of personalized PageRank. Larger values keep the community closer to the seed.
+    /// * `epsilon`: Tolerance of the approximation. Smaller values explore further from the seed at a higher cost.
+    pub fn init_with<W, E: Edge<W>, G>(graph: &G, alpha: f64, epsilon: f64) -> Self
+    where
+        G: Vertices + Edges<W, E> + Graph<W, E, UndirectedEdge>,
+    {
+        let id_map = graph.continuos_id_map();
+
+        let mut adjacency = vec![vec![]; graph.vertex_count()];
+        for (src_id, dst_id, _) in graph.edges() {
+            let src_virt_id = id_map.virt_id_of(src_id);
+            let dst_virt_id = id_map.virt_id_of(dst_id);
+
+            adjacency[src_virt_id].push(dst_virt_id);
+            adjacency[dst_virt_id].push(src_virt_id);
+        }
+
+        LocalCommunity {
+            id_map,
+            adjacency,
+            alpha,
+            epsilon,
+        }
+    }
+
+    /// Finds the community of the seed.
+    ///
+    /// # Arguments
+    /// `seed_id`: Id of the vertex to find its community.
+    ///
+    /// # Returns
+    /// Id of vertices in the community. It always contains the seed.
+    ///
+    /// # Complexity
+    /// O(1 / (`epsilon` * `alpha`)) pushes, independent of the size of the graph.
+    ///
+    /// # Panics
+    /// If graph does not contain vertex with id: `seed_id`.
+    pub fn execute(&self, seed_id: usize) -> HashSet<usize> {
+        let seed_virt_id = self.id_map.virt_id_of(seed_id);
+
+        if self.adjacency[seed_virt_id].is_empty() {
+            return [seed_id].iter().copied().collect();
+        }
+
+        let rank_of = self.approximate_page_rank(seed_virt_id);
+
+        // Sort vertices by degree-normalized rank.
+        let mut sorted: Vec<usize> = (0..self.adjacency.len())
+            .filter(|virt_id| rank_of[*virt_id] > 0.0)
+            .collect();
+        sorted.sort_by(|v1, v2| {
+            let normalized_rank1 = rank_of[*v1] / self.degree_of(*v1);
+            let normalized_rank2 = rank_of[*v2] / self.degree_of(*v2);
+
+            normalized_rank2.partial_cmp(&normalized_rank1).unwrap()
+        });
+
+        // Sweep over prefixes and keep the one with minimum conductance.
+        let total_volume: usize = self.adjacency.iter().map(|neighbors| neighbors.len()).sum();
+        let mut in_prefix = vec![false; self.adjacency.len()];
+        let mut volume = 0;
+        let mut cut = 0;
+        let mut best: Option<(f64, usize)> = None;
+        for (index, virt_id) in sorted.iter().copied().enumerate() {
+            let inside_count = self.adjacency[virt_id]
+                .iter()
+                .filter(|n_virt_id| in_prefix[**n_virt_id])
+                .count();
+
+            in_prefix[virt_id] = true;
+            volume += self.adjacency[virt_id].len();
+            cut = cut + self.adjacency[virt_id].len() - 2 * inside_count;
+
+            let min_volume = volume.min(total_volume - volume);
+            if min_volume == 0 {
+                continue;
+            }
+
+            let conductance = cut as f64 / min_volume as f64;
+            if best.map_or(true, |(best_conductance, _)| conductance < best_conductance) {
+                best = Some((conductance, index + 1));
+            }
+        }
+
+        let prefix_len = best.map_or(sorted.len(), |(_, prefix_len)| prefix_len);
+        sorted
+            .into_iter()
+            .take(prefix_len)
+            .map(|virt_id| self.id_map.real_id_of(virt_id))
+            .collect()
+    }
+
+    fn degree_of(&self, virt_id: usize) -> f64 {
+        self.adjacency[virt_id].len() as f64
+    }
+
+    // Approximates personalized PageRank of the seed using lazy random walks.
+    // Returns rank of each vertex by its virtual id.
+    fn approximate_page_rank(&self, seed_virt_id: usize) -> Vec<f64> {
+        let vertex_count = self.adjacency.len();
+
+        let mut rank_of = vec![0.0; vertex_count];
+        let mut residual_of = vec![0.0; vertex_count];
+        residual_of[seed_virt_id] = 1.0;
+
+        let mut queue = VecDeque::new();
+        let mut is_queued = vec![false; vertex_count];
+        queue.push_back(seed_virt_id);
+        is_queued[seed_virt_id] = true;
+
+        while let Some(virt_id) = queue.pop_front() {
+            is_queued[virt_id] = false;
+
+            let degree = self.degree_of(virt_id);
+            let residual = residual_of[virt_id];
+            if residual < self.epsilon * degree {
+                continue;
+            }
+
+            rank_of[virt_id] += self.alpha * residual;
+            residual_of[virt_id] = (1.0 - self.alpha) * residual / 2.0;
+
+            let share = (1.0 - self.alpha) * residual / (2.0 * degree);
+            for n_virt_id in self.adjacency[virt_id].iter().copied() {
+                residual_of[n_virt_id] += share;
+
+                if !is_queued[n_virt_id]
+                    && residual_of[n_virt_id] >= self.epsilon * self.degree_of(n_virt_id)
+                {
+                    queue.push_back(n_virt_id);
+                    is_queued[n_virt_id] = true;
+                }
+            }
+
+            if !is_queued[virt_id] && residual_of[virt_id] >= self.epsilon * degree {
+                queue.push_back(virt_id);
+                is_queued[virt_id] = true;
+            }
+        }
+
+        rank_of
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::storage::Mat;
+
+    fn add_clique(graph: &mut MatGraph<usize, UndirectedEdge>, size: usize) -> Vec<usize> {
+        let vertices: Vec<usize> = (0..size).map(|_| graph.add_vertex()).collect();
+        for (index, src_id) in vertices.iter().enumerate() {
+            for dst_id in vertices.iter().skip(index + 1) {
+                graph.add_edge(*src_id, *dst_id, 1.into()).unwrap();
+            }
+        }
+
+        vertices
+    }
+
+    #[test]
+    fn isolated_seed() {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        add_clique(&mut graph, 3);
+
+        let community = LocalCommunity::init(&graph).execute(a);
+
+        assert_eq!(community, [a].iter().copied().collect());
+    }
+
+    #[test]
+    fn planted_community() {
+        // Given: Planted community of 6 vertices attached to a sparse cycle of 20 vertices by a single edge.
+        //
+        //      community --- c0 --- c1 --- ... --- c19
+        //                    |                      |
+        //                    '----------------------'
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let community = add_clique(&mut graph, 6);
+        let cycle: Vec<usize> = (0..20).map(|_| graph.add_vertex()).collect();
+        for (index, src_id) in cycle.iter().enumerate() {
+            let dst_id = cycle[(index + 1) % cycle.len()];
+            graph.add_edge(*src_id, dst_id, 1.into()).unwrap();
+        }
+        graph.add_edge(community[0], cycle[0], 1.into()).unwrap();
+        let expected: HashSet<usize> = community.iter().copied().collect();
+
+        for seed_id in community.iter().copied() {
+            // When: Finding community of a vertex inside the planted community.
+            let found = LocalCommunity::init(&graph).execute(seed_id);
+
+            // Then:
+            assert_eq!(found, expected);
+        }
+    }
+
+    #[test]
+    fn community_of_each_clique() {
+        // Given: Two cliques connected by one edge.
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let clique1 = add_clique(&mut graph, 4);
+        let clique2 = add_clique(&mut graph, 7);
+        graph.add_edge(clique1[3], clique2[0], 1.into()).unwrap();
+        let local_community = LocalCommunity::init_with(&graph, 0.1, 1e-5);
+
+        // When: Finding community of a vertex from each clique.
+        let community1 = local_community.execute(clique1[0]);
+        let community2 = local_community.execute(clique2[4]);
+
+        // Then:
+        assert_eq!(community1, clique1.into_iter().collect());
+        assert_eq!(community2, clique2.into_iter().collect());
+    }
+}
@@ -0,0 +1,211 @@
+use crate::graph::Edge;
+use crate::provide::{Edges, Vertices};
+
+/// Finds the longest path starting from `src_id`, in which weights of the edges strictly increase in the order they are traversed.
+///
+/// Edges are processed from the heaviest to the lightest, and for each edge the longest increasing path that starts with it is computed
+/// from the paths already found for the heavier edges. So the result is well-defined even if graph has cycles.
+/// Since weights strictly increase, no edge is traversed twice, but a vertex can be visited more than once.
+///
+/// # Arguments
+/// * `graph`: Graph to search in.
+/// * `src_id`: Id of the vertex that path starts from.
+///
+/// # Returns
+/// Id of the vertices on the path in order, starting with `src_id`. If there are multiple longest paths, one of them is returned.
+///
+/// # Complexity
+/// O(|E|log(|E|))
+///
+/// # Panics
+/// If vertex with id: `src_id` does not exist in the graph.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::DiMat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::longest_increasing_weight_path;
+///
+/// // Given: Graph
+/// //
+/// //      a --1--> b --3--> c --2--> d
+/// //                \
+/// //                 '--2--> e --4--> f
+/// //
+/// let mut graph = MatGraph::init(DiMat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// let e = graph.add_vertex();
+/// let f = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 3.into());
+/// graph.add_edge(c, d, 2.into());
+/// graph.add_edge(b, e, 2.into());
+/// graph.add_edge(e, f, 4.into());
+///
+/// // Then: Path through c stops there, because weight of c -> d is lower than b -> c.
+/// assert_eq!(longest_increasing_weight_path(&graph, a), vec![a, b, e, f]);
+/// ```
+pub fn longest_increasing_weight_path<W, E, G>(graph: &G, src_id: usize) -> Vec<usize>
+where
+    W: Ord,
+    E: Edge<W>,
+    G: Edges<W, E> + Vertices,
+{
+    let id_map = graph.continuos_id_map();
+    let src_virt_id = id_map.virt_id_of(src_id);
+
+    let mut arcs: Vec<(usize, usize, &E)> = graph
+        .vertices()
+        .into_iter()
+        .flat_map(|vertex_id| {
+            graph
+                .edges_from(vertex_id)
+                .unwrap()
+                .into_iter()
+                .map(move |(dst_id, edge)| (vertex_id, dst_id, edge))
+        })
+        .collect();
+    arcs.sort_by(|(_, _, e1), (_, _, e2)| e2.get_weight().cmp(e1.get_weight()));
+
+    // Length of the longest path that starts from each vertex using the edges processed so far, and index of its first edge.
+    let mut length_of = vec![0; graph.vertex_count()];
+    let mut first_arc_of: Vec<Option<usize>> = vec![None; length_of.len()];
+
+    // Index of the next arc on the longest path that starts with each arc.
+    let mut next_arc_of: Vec<Option<usize>> = vec![None; arcs.len()];
+
+    let mut group_start = 0;
+    while group_start < arcs.len() {
+        let weight = arcs[group_start].2.get_weight();
+        let group_end = arcs[group_start..]
+            .iter()
+            .position(|(_, _, edge)| edge.get_weight() != weight)
+            .map_or(arcs.len(), |offset| group_start + offset);
+
+        // Arcs of the same weight can not follow each other, so they all extend the paths found before this group.
+        let updates: Vec<(usize, usize, usize)> = (group_start..group_end)
+            .map(|index| {
+                let (src_id, dst_id, _) = arcs[index];
+                let dst_virt_id = id_map.virt_id_of(dst_id);
+                next_arc_of[index] = first_arc_of[dst_virt_id];
+
+                (id_map.virt_id_of(src_id), length_of[dst_virt_id] + 1, index)
+            })
+            .collect();
+
+        for (src_virt_id, length, index) in updates {
+            if length > length_of[src_virt_id] {
+                length_of[src_virt_id] = length;
+                first_arc_of[src_virt_id] = Some(index);
+            }
+        }
+
+        group_start = group_end;
+    }
+
+    let mut path = vec![src_id];
+    let mut arc = first_arc_of[src_virt_id];
+    while let Some(index) = arc {
+        path.push(arcs[index].1);
+        arc = next_arc_of[index];
+    }
+
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::provide::Graph;
+    use crate::storage::{DiMat, Mat};
+
+    #[test]
+    fn single_vertex() {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+
+        assert_eq!(longest_increasing_weight_path(&graph, a), vec![a]);
+    }
+
+    #[test]
+    fn cycle_is_traversed_once() {
+        // Given: Graph
+        //
+        //      a --1--> b --2--> c
+        //               ^        |
+        //               4        3
+        //               |        v
+        //               e <--5-- d
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 2.into()).unwrap();
+        graph.add_edge(c, d, 3.into()).unwrap();
+        graph.add_edge(d, e, 5.into()).unwrap();
+        graph.add_edge(e, b, 4.into()).unwrap();
+
+        // Then: Path can not go around the cycle again, because e -> b is lighter than d -> e.
+        assert_eq!(
+            longest_increasing_weight_path(&graph, a),
+            vec![a, b, c, d, e]
+        );
+        assert_eq!(longest_increasing_weight_path(&graph, e), vec![e, b]);
+    }
+
+    #[test]
+    fn undirected_graph_revisits_vertices() {
+        // Given: Graph
+        //
+        //      c --4-- a --1-- b
+        //               \     /
+        //                3   2
+        //                 \ /
+        //                  d
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, d, 2.into()).unwrap();
+        graph.add_edge(d, a, 3.into()).unwrap();
+        graph.add_edge(a, c, 4.into()).unwrap();
+
+        // Then: Path from a goes around the triangle and returns to a before leaving to c.
+        assert_eq!(
+            longest_increasing_weight_path(&graph, a),
+            vec![a, b, d, a, c]
+        );
+        assert_eq!(longest_increasing_weight_path(&graph, b), vec![b, d, a, c]);
+    }
+
+    #[test]
+    fn equal_weights_do_not_increase() {
+        // Given: Graph
+        //
+        //      a --2--> b --2--> c --3--> d
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 2.into()).unwrap();
+        graph.add_edge(b, c, 2.into()).unwrap();
+        graph.add_edge(c, d, 3.into()).unwrap();
+
+        assert_eq!(longest_increasing_weight_path(&graph, a), vec![a, b]);
+        assert_eq!(longest_increasing_weight_path(&graph, b), vec![b, c, d]);
+    }
+}
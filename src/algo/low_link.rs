@@ -0,0 +1,120 @@
+use crate::graph::{Edge, UndirectedEdge};
+use crate::provide::{Edges, Graph, IdMap, Vertices};
+
+/// Trait for structures that want to listen to events of the low-link dfs.
+/// Vertices are passed by their virtual ids, which can be mapped to real ids by the `IdMap` of `LowLink`.
+#[allow(unused_variables)]
+pub(crate) trait LowLinkListener {
+    /// Gets called before the dfs goes from `virt_id` down to `child_virt_id`, through the edge with id: `edge_id`.
+    fn on_tree_edge(&mut self, virt_id: usize, child_virt_id: usize, edge_id: usize) {}
+
+    /// Gets called once for each edge from `virt_id` back to its proper ancestor `ancestor_virt_id`, except the one used to enter `virt_id`.
+    fn on_back_edge(&mut self, virt_id: usize, ancestor_virt_id: usize, edge_id: usize) {}
+
+    /// Gets called when the subtree of `child_virt_id` is searched completely.
+    ///
+    /// # Arguments
+    /// * `depth`: Depth of `virt_id` in the dfs tree, which is 0 for the root.
+    /// * `child_low`: Smallest depth that can be reached from the subtree of `child_virt_id` by at most one back edge.
+    fn on_child_finished(
+        &mut self,
+        virt_id: usize,
+        child_virt_id: usize,
+        edge_id: usize,
+        depth: usize,
+        child_low: usize,
+    ) {
+    }
+}
+
+/// Low-link dfs of Tarjan over an undirected graph, shared by the algorithms that find cut vertices, cut edges and blocks.
+///
+/// Only the edge that is used to enter a vertex is excluded when computing low-links,
+/// so parallel edges between two vertices count as a way back.
+pub(crate) struct LowLink {
+    pub(crate) id_map: IdMap,
+
+    // Maps each vertex(by virtual id) to list of (neighbor virtual id, edge id).
+    pub(crate) adjacency: Vec<Vec<(usize, usize)>>,
+}
+
+impl LowLink {
+    /// Initializes the structure.
+    ///
+    /// # Arguments
+    /// `graph`: Undirected graph to search.
+    pub fn init<W, E: Edge<W>, G>(graph: &G) -> Self
+    where
+        G: Vertices + Edges<W, E> + Graph<W, E, UndirectedEdge>,
+    {
+        let id_map = graph.continuos_id_map();
+
+        let mut adjacency = vec![vec![]; graph.vertex_count()];
+        for (src_id, dst_id, edge) in graph.edges() {
+            let src_virt_id = id_map.virt_id_of(src_id);
+            let dst_virt_id = id_map.virt_id_of(dst_id);
+
+            adjacency[src_virt_id].push((dst_virt_id, edge.get_id()));
+            adjacency[dst_virt_id].push((src_virt_id, edge.get_id()));
+        }
+
+        LowLink { id_map, adjacency }
+    }
+
+    /// Searches each connected component, starting from its vertex with the smallest virtual id, and calls the listener on every event.
+    ///
+    /// # Complexity
+    /// O(|V| + |E|)
+    pub fn execute(&self, listener: &mut impl LowLinkListener) {
+        let vertex_count = self.adjacency.len();
+
+        let mut depth_of: Vec<Option<usize>> = vec![None; vertex_count];
+        let mut low_of = vec![0; vertex_count];
+
+        for root_virt_id in 0..vertex_count {
+            if depth_of[root_virt_id].is_none() {
+                self.search(root_virt_id, None, 0, &mut depth_of, &mut low_of, listener);
+            }
+        }
+    }
+
+    fn search(
+        &self,
+        virt_id: usize,
+        parent_edge_id: Option<usize>,
+        depth: usize,
+        depth_of: &mut Vec<Option<usize>>,
+        low_of: &mut Vec<usize>,
+        listener: &mut impl LowLinkListener,
+    ) {
+        depth_of[virt_id] = Some(depth);
+        low_of[virt_id] = depth;
+
+        for (n_virt_id, edge_id) in self.adjacency[virt_id].iter().copied() {
+            if Some(edge_id) == parent_edge_id {
+                continue;
+            }
+
+            if let Some(n_depth) = depth_of[n_virt_id] {
+                // Back edges are also seen from the ancestor after the subtree is searched, which must be skipped.
+                if n_depth < depth {
+                    listener.on_back_edge(virt_id, n_virt_id, edge_id);
+                    low_of[virt_id] = low_of[virt_id].min(n_depth);
+                }
+            } else {
+                listener.on_tree_edge(virt_id, n_virt_id, edge_id);
+                self.search(
+                    n_virt_id,
+                    Some(edge_id),
+                    depth + 1,
+                    depth_of,
+                    low_of,
+                    listener,
+                );
+                low_of[virt_id] = low_of[virt_id].min(low_of[n_virt_id]);
+
+                listener.on_child_finished(virt_id, n_virt_id, edge_id, depth, low_of[n_virt_id]);
+            }
+        }
+    }
+}
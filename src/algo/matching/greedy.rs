@@ -0,0 +1,152 @@
+use std::collections::HashSet;
+
+use crate::graph::Edge;
+use crate::provide::Edges;
+
+/// Finds a maximal matching of the graph by greedily picking edges whose end points are not matched yet.
+///
+/// Result is maximal, meaning no edge of the graph can be added to it, but it is not necessarily maximum.
+/// Size of the result is at least half the size of a maximum matching, which makes it a good warm start for exact algorithms.
+/// Works on general graphs, not just bipartite ones. Direction of the edges is ignored.
+///
+/// # Arguments
+/// `graph`: Graph to find a matching in.
+///
+/// # Returns
+/// Matched pairs as (`src_id`, `dst_id`) of the chosen edges, in the order they were picked.
+///
+/// # Complexity
+/// O(|E|)
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::greedy_maximal_matching;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b --- c
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+///
+/// // When: Finding a maximal matching.
+/// let matching = greedy_maximal_matching(&graph);
+///
+/// // Then: Both edges share b, so only one of them is picked.
+/// assert_eq!(matching.len(), 1);
+/// ```
+pub fn greedy_maximal_matching<W, E, G>(graph: &G) -> Vec<(usize, usize)>
+where
+    E: Edge<W>,
+    G: Edges<W, E>,
+{
+    let mut is_matched = HashSet::new();
+    let mut matching = vec![];
+
+    for (src_id, dst_id, _) in graph.edges() {
+        if src_id != dst_id && !is_matched.contains(&src_id) && !is_matched.contains(&dst_id) {
+            is_matched.insert(src_id);
+            is_matched.insert(dst_id);
+            matching.push((src_id, dst_id));
+        }
+    }
+
+    matching
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::provide::{Graph, Vertices};
+    use crate::storage::{DiMat, Mat};
+
+    // Checks that no two pairs share a vertex, every pair is an edge and no edge can be added to the matching.
+    fn assert_is_maximal_matching<G, E>(graph: &G, matching: &[(usize, usize)])
+    where
+        E: Edge<usize>,
+        G: Edges<usize, E>,
+    {
+        let mut is_matched = HashSet::new();
+        for (src_id, dst_id) in matching {
+            assert!(graph.has_any_edge(*src_id, *dst_id).unwrap());
+            assert!(is_matched.insert(*src_id));
+            assert!(is_matched.insert(*dst_id));
+        }
+
+        for (src_id, dst_id, _) in graph.edges() {
+            assert!(is_matched.contains(&src_id) || is_matched.contains(&dst_id));
+        }
+    }
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        assert!(greedy_maximal_matching(&graph).is_empty());
+    }
+
+    #[test]
+    fn odd_cycle_with_pendant_vertices() {
+        // Given: Graph that is not bipartite.
+        //
+        //      f     g
+        //      |     |
+        //      a --- b
+        //       \   /
+        //         c --- d --- e
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        let g = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, a, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+        graph.add_edge(d, e, 1.into()).unwrap();
+        graph.add_edge(a, f, 1.into()).unwrap();
+        graph.add_edge(b, g, 1.into()).unwrap();
+
+        // When: Finding a maximal matching.
+        let matching = greedy_maximal_matching(&graph);
+
+        // Then: It is a valid maximal matching, with at least half the size of the maximum matching(3).
+        assert_is_maximal_matching(&graph, &matching);
+        assert!(matching.len() * 2 >= 3);
+    }
+
+    #[test]
+    fn directed_graph() {
+        // Given: Graph
+        //
+        //      a --> b <-- c --> d
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(c, b, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+
+        // When: Finding a maximal matching.
+        let matching = greedy_maximal_matching(&graph);
+
+        // Then:
+        assert_is_maximal_matching(&graph, &matching);
+        assert!(matching.len() <= graph.vertex_count() / 2);
+    }
+}
@@ -0,0 +1,429 @@
+use anyhow::Result;
+use std::collections::{HashMap, VecDeque};
+
+use crate::algo::{Error, IsBipartite};
+use crate::graph::{Edge, UndirectedEdge};
+use crate::provide::{Graph, Neighbors, Vertices};
+
+/// Finds a maximum cardinality matching of a bipartite graph using Hopcroft–Karp algorithm.
+///
+/// In each phase, a breadth-first search from the unmatched left vertices layers the graph by the length of the shortest augmenting paths,
+/// and then a depth-first search augments the matching along a maximal set of vertex disjoint shortest augmenting paths.
+/// Only O(√|V|) phases are needed, because the length of the shortest augmenting path grows after each phase.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::BipartiteMatching;
+///
+/// // Given: Graph
+/// //
+/// //      a --- x
+/// //        \
+/// //          \
+/// //      b --- y
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let x = graph.add_vertex();
+/// let y = graph.add_vertex();
+/// graph.add_edge(a, x, 1.into());
+/// graph.add_edge(a, y, 1.into());
+/// graph.add_edge(b, y, 1.into());
+///
+/// // When: Finding a maximum matching.
+/// let matching = BipartiteMatching::init_with(&graph, vec![a, b], vec![x, y])
+///     .unwrap()
+///     .execute();
+///
+/// // Then: a must be matched with x, so b can be matched with y.
+/// assert_eq!(matching, vec![(a, x), (b, y)]);
+/// ```
+pub struct BipartiteMatching {
+    left: Vec<usize>,
+    right: Vec<usize>,
+
+    // Maps each left vertex(by index in `left`) to index of its neighbors in `right`.
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl BipartiteMatching {
+    /// Initializes the structure, using the partitions found by [`IsBipartite`](crate::algo::IsBipartite).
+    ///
+    /// # Arguments
+    /// `graph`: Undirected graph to search for the matching in.
+    ///
+    /// # Returns
+    /// * `Err`: If graph is not bipartite.
+    /// * `Ok`: Containing the initialized structure.
+    pub fn init<W, E, G>(graph: &G) -> Result<Self>
+    where
+        E: Edge<W>,
+        G: Vertices + Neighbors + Graph<W, E, UndirectedEdge>,
+    {
+        let (left, right) = IsBipartite::init(graph)
+            .execute()
+            .ok_or_else(Error::new_nb)?;
+
+        BipartiteMatching::init_with(graph, left, right)
+    }
+
+    /// Initializes the structure with the given partitions.
+    ///
+    /// # Arguments
+    /// * `graph`: Undirected graph to search for the matching in.
+    /// * `left`: Ids of vertices in the left partition.
+    /// * `right`: Ids of vertices in the right partition.
+    ///
+    /// # Returns
+    /// * `Err`:
+    ///     * If any of the vertices in partitions does not exist in the graph.
+    ///     * If a vertex is in both partitions, or an edge connects two vertices of the same partition.
+    /// * `Ok`: Containing the initialized structure.
+    ///
+    /// # Note
+    /// Edges from a vertex in the partitions to a vertex that is in none of them are ignored.
+    pub fn init_with<W, E, G>(graph: &G, left: Vec<usize>, right: Vec<usize>) -> Result<Self>
+    where
+        E: Edge<W>,
+        G: Vertices + Neighbors + Graph<W, E, UndirectedEdge>,
+    {
+        let index_of: HashMap<usize, usize> = right
+            .iter()
+            .enumerate()
+            .map(|(index, right_id)| (*right_id, index))
+            .collect();
+
+        if left.iter().any(|left_id| index_of.contains_key(left_id)) {
+            Err(Error::new_nb())?
+        }
+
+        let left_index_of: HashMap<usize, usize> = left
+            .iter()
+            .enumerate()
+            .map(|(index, left_id)| (*left_id, index))
+            .collect();
+        for right_id in &right {
+            for n_id in graph.neighbors(*right_id)? {
+                if index_of.contains_key(&n_id) {
+                    Err(Error::new_nb())?
+                }
+            }
+        }
+
+        let mut adjacency = Vec::with_capacity(left.len());
+        for left_id in &left {
+            let mut neighbors = vec![];
+            for n_id in graph.neighbors(*left_id)? {
+                if left_index_of.contains_key(&n_id) {
+                    Err(Error::new_nb())?
+                } else if let Some(index) = index_of.get(&n_id) {
+                    neighbors.push(*index);
+                }
+            }
+            adjacency.push(neighbors);
+        }
+
+        Ok(BipartiteMatching {
+            left,
+            right,
+            adjacency,
+        })
+    }
+
+    /// Finds a maximum cardinality matching between left and right partitions.
+    ///
+    /// # Returns
+    /// Matched pairs in the form of (left_id, right_id), sorted in the order of `left`.
+    ///
+    /// # Complexity
+    /// O(|E|√|V|)
+    pub fn execute(&self) -> Vec<(usize, usize)> {
+        let mut match_of_left: Vec<Option<usize>> = vec![None; self.left.len()];
+        let mut match_of_right: Vec<Option<usize>> = vec![None; self.right.len()];
+
+        loop {
+            let layer_of = self.layers(&match_of_left, &match_of_right);
+            if layer_of.is_none() {
+                break;
+            }
+            let layer_of = layer_of.unwrap();
+
+            // Each left vertex is tried at most once per phase: either it finds a path or it is a dead end for the rest of the phase.
+            let mut is_dead = vec![false; self.left.len()];
+            for left_index in 0..self.left.len() {
+                if match_of_left[left_index].is_none() {
+                    self.augment(
+                        left_index,
+                        &layer_of,
+                        &mut is_dead,
+                        &mut match_of_left,
+                        &mut match_of_right,
+                    );
+                }
+            }
+        }
+
+        match_of_left
+            .into_iter()
+            .enumerate()
+            .filter_map(|(left_index, right_index)| {
+                right_index.map(|right_index| (self.left[left_index], self.right[right_index]))
+            })
+            .collect()
+    }
+
+    // Layers the left vertices by their distance from the unmatched left vertices, along alternating paths.
+    //
+    // # Returns
+    // * `Some`: Containing layer of each left vertex, if there is an augmenting path. Vertices beyond the shortest augmenting paths are not layered.
+    // * `None`: If there is no augmenting path, so the matching is maximum.
+    fn layers(
+        &self,
+        match_of_left: &[Option<usize>],
+        match_of_right: &[Option<usize>],
+    ) -> Option<Vec<Option<usize>>> {
+        let mut layer_of: Vec<Option<usize>> = vec![None; self.left.len()];
+        let mut queue = VecDeque::new();
+
+        for (left_index, right_index) in match_of_left.iter().enumerate() {
+            if right_index.is_none() {
+                layer_of[left_index] = Some(0);
+                queue.push_back(left_index);
+            }
+        }
+
+        let mut augmenting_layer = None;
+        while let Some(left_index) = queue.pop_front() {
+            let layer = layer_of[left_index].unwrap();
+            if matches!(augmenting_layer, Some(augmenting_layer) if layer >= augmenting_layer) {
+                break;
+            }
+
+            for right_index in &self.adjacency[left_index] {
+                match match_of_right[*right_index] {
+                    None => augmenting_layer = Some(layer + 1),
+                    Some(next_left_index) if layer_of[next_left_index].is_none() => {
+                        layer_of[next_left_index] = Some(layer + 1);
+                        queue.push_back(next_left_index);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        augmenting_layer.map(|_| layer_of)
+    }
+
+    // Searches for a shortest augmenting path from `left_index` through the layers, and flips the matching along it.
+    //
+    // # Returns
+    // `true` if a path is found.
+    fn augment(
+        &self,
+        left_index: usize,
+        layer_of: &[Option<usize>],
+        is_dead: &mut Vec<bool>,
+        match_of_left: &mut Vec<Option<usize>>,
+        match_of_right: &mut Vec<Option<usize>>,
+    ) -> bool {
+        let layer = layer_of[left_index].unwrap();
+
+        for right_index in self.adjacency[left_index].iter().copied() {
+            let is_found = match match_of_right[right_index] {
+                None => true,
+                Some(next_left_index) => {
+                    !is_dead[next_left_index]
+                        && layer_of[next_left_index] == Some(layer + 1)
+                        && self.augment(
+                            next_left_index,
+                            layer_of,
+                            is_dead,
+                            match_of_left,
+                            match_of_right,
+                        )
+                }
+            };
+
+            if is_found {
+                match_of_left[left_index] = Some(right_index);
+                match_of_right[right_index] = Some(left_index);
+                return true;
+            }
+        }
+
+        is_dead[left_index] = true;
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::ErrorKind;
+    use crate::gen::CompleteBipartiteGraph;
+    use crate::graph::MatGraph;
+    use crate::provide::Edges;
+    use crate::storage::Mat;
+    use std::collections::HashSet;
+
+    // Checks that no two pairs share a vertex and every pair is an edge.
+    fn assert_is_matching(graph: &MatGraph<usize, UndirectedEdge>, matching: &[(usize, usize)]) {
+        let mut is_matched = HashSet::new();
+        for (left_id, right_id) in matching {
+            assert!(graph.has_any_edge(*left_id, *right_id).unwrap());
+            assert!(is_matched.insert(*left_id));
+            assert!(is_matched.insert(*right_id));
+        }
+    }
+
+    fn is_not_bipartite(result: Result<BipartiteMatching>) -> bool {
+        matches!(
+            result
+                .err()
+                .unwrap()
+                .downcast_ref::<Error>()
+                .unwrap()
+                .kind(),
+            ErrorKind::NotBipartite
+        )
+    }
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        assert!(BipartiteMatching::init(&graph)
+            .unwrap()
+            .execute()
+            .is_empty());
+    }
+
+    #[test]
+    fn complete_bipartite_graph() {
+        // Given: K(3, 3).
+        let generator = CompleteBipartiteGraph::init(3, 3);
+        let graph = generator.generate();
+
+        // When: Finding a maximum matching with auto detected partitions.
+        let matching = BipartiteMatching::init(&graph).unwrap().execute();
+
+        // Then: Matching is perfect.
+        assert_eq!(matching.len(), 3);
+        assert_is_matching(&graph, &matching);
+    }
+
+    #[test]
+    fn greedy_choice_must_be_undone() {
+        // Given: Graph
+        //
+        //      a --- x
+        //        \
+        //          \
+        //      b --- y
+        //        \
+        //          \
+        //      c --- z
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let x = graph.add_vertex();
+        let y = graph.add_vertex();
+        let z = graph.add_vertex();
+        graph.add_edge(a, y, 1.into()).unwrap();
+        graph.add_edge(a, x, 1.into()).unwrap();
+        graph.add_edge(b, z, 1.into()).unwrap();
+        graph.add_edge(b, y, 1.into()).unwrap();
+        graph.add_edge(c, z, 1.into()).unwrap();
+
+        // When: Finding a maximum matching.
+        let matching = BipartiteMatching::init_with(&graph, vec![a, b, c], vec![x, y, z])
+            .unwrap()
+            .execute();
+
+        // Then: Only one perfect matching exists.
+        assert_eq!(matching, vec![(a, x), (b, y), (c, z)]);
+    }
+
+    #[test]
+    fn unmatchable_vertices() {
+        // Given: Graph
+        //
+        //      a --- x --- b
+        //            |
+        //            c     y
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let x = graph.add_vertex();
+        let y = graph.add_vertex();
+        graph.add_edge(a, x, 1.into()).unwrap();
+        graph.add_edge(b, x, 1.into()).unwrap();
+        graph.add_edge(c, x, 1.into()).unwrap();
+
+        // When: Finding a maximum matching.
+        let matching = BipartiteMatching::init_with(&graph, vec![a, b, c], vec![x, y])
+            .unwrap()
+            .execute();
+
+        // Then: Only one of the left vertices can be matched with x.
+        assert_eq!(matching.len(), 1);
+        assert_is_matching(&graph, &matching);
+    }
+
+    #[test]
+    fn sparse_complete_bipartite_graph() {
+        // Given: K(4, 5) with some of its edges removed.
+        let generator = CompleteBipartiteGraph::init(4, 5);
+        let mut graph = generator.generate();
+        for (src_id, dst_id) in [(0, 4), (0, 5), (1, 4), (1, 5), (2, 4), (2, 5), (3, 6)] {
+            let edge_id = graph.edges_between(src_id, dst_id).unwrap()[0].get_id();
+            graph.remove_edge(src_id, dst_id, edge_id).unwrap();
+        }
+
+        // When: Finding a maximum matching.
+        let matching = BipartiteMatching::init(&graph).unwrap().execute();
+
+        // Then: 4 and 5 can only be matched with 3, so one of them stays unmatched, but every left vertex is matched.
+        assert_is_matching(&graph, &matching);
+        assert_eq!(matching.len(), 4);
+        assert!(matching.contains(&(3, 4)) || matching.contains(&(3, 5)));
+    }
+
+    #[test]
+    fn not_bipartite() {
+        // Given: Graph
+        //
+        //      a --- b
+        //       \   /
+        //         c
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, a, 1.into()).unwrap();
+
+        // Then: Neither auto detected nor given partitions work.
+        assert!(is_not_bipartite(BipartiteMatching::init(&graph)));
+        assert!(is_not_bipartite(BipartiteMatching::init_with(
+            &graph,
+            vec![a],
+            vec![b, c]
+        )));
+        assert!(is_not_bipartite(BipartiteMatching::init_with(
+            &graph,
+            vec![a, b],
+            vec![b, c]
+        )));
+    }
+}
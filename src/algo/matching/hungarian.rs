@@ -0,0 +1,375 @@
+use anyhow::Result;
+use magnitude::Magnitude;
+use num_traits::Zero;
+use std::any::Any;
+use std::ops::Sub;
+
+use crate::algo::Error;
+use crate::graph::{Edge, EdgeDir};
+use crate::provide::{Edges, Graph, Vertices};
+
+/// Finds a minimum weight perfect matching between two partitions of a graph using hungarian algorithm.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::Hungarian;
+///
+/// // Given: Graph
+/// //          1
+/// //      a ----- c
+/// //        \   /
+/// //       3 \ / 2
+/// //          X
+/// //        /   \
+/// //      b ----- d
+/// //          5
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// graph.add_edge(a, c, 1.into());
+/// graph.add_edge(a, d, 3.into());
+/// graph.add_edge(b, c, 2.into());
+/// graph.add_edge(b, d, 5.into());
+///
+/// // When: Performing hungarian algorithm.
+/// let (matching, cost) = Hungarian::init(&graph, vec![a, b], vec![c, d])
+///     .execute(&graph)
+///     .unwrap();
+///
+/// // Then:
+/// assert_eq!(matching, vec![(a, d), (b, c)]);
+/// assert_eq!(cost, 5.into());
+/// ```
+pub struct Hungarian<W> {
+    left: Vec<usize>,
+    right: Vec<usize>,
+
+    // Potentials of left vertices.
+    u: Vec<Magnitude<W>>,
+
+    // Negated potentials of right vertices, so they never drop below zero for unsigned weights.
+    v: Vec<Magnitude<W>>,
+
+    // For each right vertex(1-based), index(1-based) of the left vertex matched to it or 0 if it's free.
+    matched_to: Vec<usize>,
+}
+
+impl<W: Copy + Ord + Zero + Any + Sub<Output = W>> Hungarian<W> {
+    /// Initializes the structure.
+    ///
+    /// # Arguments
+    /// * `graph`: Graph to search for the matching in.
+    /// * `left`: Ids of vertices in the left partition.
+    /// * `right`: Ids of vertices in the right partition.
+    pub fn init<E, Ty, G>(_: &G, left: Vec<usize>, right: Vec<usize>) -> Self
+    where
+        E: Edge<W>,
+        Ty: EdgeDir,
+        G: Edges<W, E> + Vertices + Graph<W, E, Ty>,
+    {
+        let vertex_count = left.len();
+
+        Hungarian {
+            left,
+            right,
+            u: vec![W::zero().into(); vertex_count + 1],
+            v: vec![W::zero().into(); vertex_count + 1],
+            matched_to: vec![0; vertex_count + 1],
+        }
+    }
+
+    /// Finds a perfect matching between left and right partitions that minimizes the total weight.
+    ///
+    /// # Arguments
+    /// `graph`: Graph to search for the matching in.
+    ///
+    /// # Returns
+    /// * `Err`:
+    ///     * If partitions do not have the same number of vertices.
+    ///     * If any of the vertices in partitions does not exist in the graph.
+    ///     * If there is no perfect matching between the partitions.
+    /// * `Ok`: Containing matched pairs in the form of (left_id, right_id), sorted in the order of `left`, alongside the total weight of the matching.
+    ///
+    /// # Note
+    /// When there are multiple edges between two vertices, the one with minimum weight is considered.
+    /// In directed graphs only edges from left partition to right partition are considered.
+    #[allow(clippy::type_complexity)]
+    pub fn execute<E, Ty, G>(mut self, graph: &G) -> Result<(Vec<(usize, usize)>, Magnitude<W>)>
+    where
+        E: Edge<W>,
+        Ty: EdgeDir,
+        G: Edges<W, E> + Vertices + Graph<W, E, Ty>,
+    {
+        if self.left.len() != self.right.len() {
+            Err(Error::new_up(self.left.len(), self.right.len()))?
+        }
+
+        let vertex_count = self.left.len();
+        let cost = self.cost_matrix(graph)?;
+
+        for i in 1..=vertex_count {
+            self.augment(&cost, i)?;
+        }
+
+        let mut matching = vec![0; vertex_count];
+        let mut total_cost = W::zero().into();
+        for (j, &i) in self.matched_to.iter().enumerate().skip(1) {
+            matching[i - 1] = j - 1;
+            total_cost += cost[i][j];
+        }
+
+        let matching = matching
+            .into_iter()
+            .enumerate()
+            .map(|(i, j)| (self.left[i], self.right[j]))
+            .collect();
+
+        Ok((matching, total_cost))
+    }
+
+    // Builds a 1-based matrix where `cost[i][j]` is the minimum weight of edges between `left[i - 1]` and `right[j - 1]`.
+    // Missing edges are represented by positive infinity.
+    fn cost_matrix<E, Ty, G>(&self, graph: &G) -> Result<Vec<Vec<Magnitude<W>>>>
+    where
+        E: Edge<W>,
+        Ty: EdgeDir,
+        G: Edges<W, E> + Vertices + Graph<W, E, Ty>,
+    {
+        let vertex_count = self.left.len();
+        let mut cost = vec![vec![Magnitude::PosInfinite; vertex_count + 1]; vertex_count + 1];
+
+        for (i, left_id) in self.left.iter().enumerate() {
+            for (j, right_id) in self.right.iter().enumerate() {
+                for edge in graph.edges_between(*left_id, *right_id)? {
+                    if Self::is_less(*edge.get_weight(), cost[i + 1][j + 1]) {
+                        cost[i + 1][j + 1] = *edge.get_weight();
+                    }
+                }
+            }
+        }
+
+        Ok(cost)
+    }
+
+    // Finds an augmenting path for left vertex `i` and updates potentials and matching along the way.
+    fn augment(&mut self, cost: &[Vec<Magnitude<W>>], i: usize) -> Result<()> {
+        let vertex_count = self.left.len();
+
+        let mut min_value = vec![Magnitude::PosInfinite; vertex_count + 1];
+        let mut is_used = vec![false; vertex_count + 1];
+        let mut way = vec![0; vertex_count + 1];
+
+        // Column 0 is a virtual right vertex that current left vertex is matched to.
+        self.matched_to[0] = i;
+        let mut j0 = 0;
+
+        loop {
+            is_used[j0] = true;
+            let i0 = self.matched_to[j0];
+
+            let mut delta = Magnitude::PosInfinite;
+            let mut j1 = 0;
+            for j in 1..=vertex_count {
+                if is_used[j] {
+                    continue;
+                }
+
+                // Reduced cost is never negative so it's computed in an order that never underflows.
+                let reduced_cost = if cost[i0][j].is_finite() {
+                    cost[i0][j] + self.v[j] - self.u[i0]
+                } else {
+                    Magnitude::PosInfinite
+                };
+
+                if Self::is_less(reduced_cost, min_value[j]) {
+                    min_value[j] = reduced_cost;
+                    way[j] = j0;
+                }
+
+                if Self::is_less(min_value[j], delta) {
+                    delta = min_value[j];
+                    j1 = j;
+                }
+            }
+
+            if !delta.is_finite() {
+                Err(Error::new_pmnf())?
+            }
+
+            for j in 0..=vertex_count {
+                if is_used[j] {
+                    let matched_id = self.matched_to[j];
+                    self.u[matched_id] += delta;
+                    self.v[j] += delta;
+                } else if min_value[j].is_finite() {
+                    min_value[j] -= delta;
+                }
+            }
+
+            j0 = j1;
+            if self.matched_to[j0] == 0 {
+                break;
+            }
+        }
+
+        // Flip edges along the augmenting path.
+        while j0 != 0 {
+            let j1 = way[j0];
+            self.matched_to[j0] = self.matched_to[j1];
+            j0 = j1;
+        }
+
+        Ok(())
+    }
+
+    // Positive infinities can not be compared with each other, so they're handled separately.
+    fn is_less(value: Magnitude<W>, other: Magnitude<W>) -> bool {
+        value.is_finite() && (other.is_pos_infinite() || value < other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::ErrorKind;
+    use crate::graph::MatGraph;
+    use crate::storage::{DiMat, Mat};
+
+    #[test]
+    fn empty_partitions() {
+        // Given: Empty graph.
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        // When: Performing hungarian algorithm.
+        let (matching, cost) = Hungarian::init(&graph, vec![], vec![])
+            .execute(&graph)
+            .unwrap();
+
+        // Then:
+        assert!(matching.is_empty());
+        assert_eq!(cost, 0.into());
+    }
+
+    #[test]
+    fn unequal_partitions() {
+        // Given: Graph
+        //
+        //      a --- c
+        //        \
+        //          - d
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, c, 1.into()).unwrap();
+        graph.add_edge(a, d, 1.into()).unwrap();
+
+        // When: Performing hungarian algorithm.
+        let result = Hungarian::init(&graph, vec![a], vec![c, d]).execute(&graph);
+
+        // Then:
+        let error = result.unwrap_err().downcast::<Error>().unwrap();
+        assert!(matches!(error.kind(), ErrorKind::UnequalPartitions));
+    }
+
+    #[test]
+    fn no_perfect_matching() {
+        // Given: Graph
+        //
+        //      a --- c
+        //          /
+        //      b -'    d
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, c, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+
+        // When: Performing hungarian algorithm.
+        let result = Hungarian::init(&graph, vec![a, b], vec![c, d]).execute(&graph);
+
+        // Then:
+        let error = result.unwrap_err().downcast::<Error>().unwrap();
+        assert!(matches!(error.kind(), ErrorKind::PerfectMatchingNotFound));
+    }
+
+    #[test]
+    fn hand_solved_3x3_assignment() {
+        // Given: Complete bipartite graph with weights:
+        //
+        //           x    y    z
+        //      a    4    1    3
+        //      b    2    0    5
+        //      c    3    2    2
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let left: Vec<usize> = (0..3).map(|_| graph.add_vertex()).collect();
+        let right: Vec<usize> = (0..3).map(|_| graph.add_vertex()).collect();
+        let weights = [[4, 1, 3], [2, 0, 5], [3, 2, 2]];
+        for (i, row) in weights.iter().enumerate() {
+            for (j, weight) in row.iter().enumerate() {
+                graph.add_edge(left[i], right[j], (*weight).into()).unwrap();
+            }
+        }
+
+        // When: Performing hungarian algorithm.
+        let (matching, cost) = Hungarian::init(&graph, left.clone(), right.clone())
+            .execute(&graph)
+            .unwrap();
+
+        // Then: a -> y, b -> x, c -> z.
+        assert_eq!(
+            matching,
+            vec![
+                (left[0], right[1]),
+                (left[1], right[0]),
+                (left[2], right[2])
+            ]
+        );
+        assert_eq!(cost, 5.into());
+    }
+
+    #[test]
+    fn directed_graph_with_missing_edges() {
+        // Given: Directed graph with weights of edges from left to right(- means no edge):
+        //
+        //           d    e    f
+        //      a    7    1    -
+        //      b    2    1    3
+        //      c    -    -    9
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        graph.add_edge(a, d, 7.into()).unwrap();
+        graph.add_edge(a, e, 1.into()).unwrap();
+        graph.add_edge(b, d, 2.into()).unwrap();
+        graph.add_edge(b, e, 1.into()).unwrap();
+        graph.add_edge(b, f, 3.into()).unwrap();
+        graph.add_edge(c, f, 9.into()).unwrap();
+        // Edges from right to left must be ignored.
+        graph.add_edge(f, a, 0.into()).unwrap();
+
+        // When: Performing hungarian algorithm.
+        let (matching, cost) = Hungarian::init(&graph, vec![a, b, c], vec![d, e, f])
+            .execute(&graph)
+            .unwrap();
+
+        // Then:
+        assert_eq!(matching, vec![(a, e), (b, d), (c, f)]);
+        assert_eq!(cost, 12.into());
+    }
+}
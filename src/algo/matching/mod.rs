@@ -0,0 +1,9 @@
+mod greedy;
+mod hopcroft_karp;
+mod hungarian;
+mod report;
+
+pub use greedy::greedy_maximal_matching;
+pub use hopcroft_karp::BipartiteMatching;
+pub use hungarian::Hungarian;
+pub use report::{matching_report, MatchingReport};
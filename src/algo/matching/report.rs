@@ -0,0 +1,288 @@
+use anyhow::Result;
+use magnitude::Magnitude;
+use num_traits::Zero;
+use std::any::Any;
+use std::collections::HashSet;
+
+use crate::algo::Error;
+use crate::graph::{Edge, EdgeDir};
+use crate::provide::{Edges, Graph, Vertices};
+
+/// Quality of a matching between two partitions of a graph, as created by [`matching_report`](crate::algo::matching_report).
+#[derive(Debug, PartialEq)]
+pub struct MatchingReport<W> {
+    /// Number of matched pairs.
+    pub size: usize,
+
+    /// Whether every vertex of both partitions is matched.
+    pub is_perfect: bool,
+
+    /// Sum of the weights of the matched pairs.
+    pub total_weight: Magnitude<W>,
+
+    /// Ids of the vertices in the left partition that are not matched, in the order of the partition.
+    pub unmatched_left: Vec<usize>,
+
+    /// Ids of the vertices in the right partition that are not matched, in the order of the partition.
+    pub unmatched_right: Vec<usize>,
+}
+
+/// Creates a quality report of a matching between two partitions of a graph, like the ones found by [`Hungarian`](crate::algo::Hungarian) or [`BipartiteMatching`](crate::algo::BipartiteMatching).
+///
+/// # Arguments
+/// * `graph`: Graph that the matching is found in.
+/// * `left`: Ids of vertices in the left partition.
+/// * `right`: Ids of vertices in the right partition.
+/// * `matching`: Matched pairs in the form of (left_id, right_id).
+///
+/// # Returns
+/// * `Err`:
+///     * If any of the vertices in the pairs does not exist in the graph.
+///     * If a pair is not from the left partition to the right partition, or there is no edge between its vertices.
+///     * If a vertex is in more than one pair.
+/// * `Ok`: Containing the report.
+///
+/// # Complexity
+/// O(|V| + |M|·e) where M is `matching` and e is the cost of [`edges_between`](crate::provide::Edges::edges_between).
+///
+/// # Note
+/// When there are multiple edges between two vertices, the one with minimum weight is considered.
+/// In directed graphs only edges from left partition to right partition are considered.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::matching_report;
+///
+/// // Given: Graph
+/// //          1
+/// //      a ----- c
+/// //        \
+/// //       3 \
+/// //          \
+/// //      b    d
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// graph.add_edge(a, c, 1.into());
+/// graph.add_edge(a, d, 3.into());
+///
+/// // When: Reporting the matching of a with d.
+/// let report = matching_report(&graph, &[a, b], &[c, d], &[(a, d)]).unwrap();
+///
+/// // Then:
+/// assert_eq!(report.size, 1);
+/// assert!(!report.is_perfect);
+/// assert_eq!(report.total_weight, 3.into());
+/// assert_eq!(report.unmatched_left, vec![b]);
+/// assert_eq!(report.unmatched_right, vec![c]);
+/// ```
+pub fn matching_report<W, E, Dir, G>(
+    graph: &G,
+    left: &[usize],
+    right: &[usize],
+    matching: &[(usize, usize)],
+) -> Result<MatchingReport<W>>
+where
+    W: Copy + Ord + Zero + Any,
+    E: Edge<W>,
+    Dir: EdgeDir,
+    G: Edges<W, E> + Vertices + Graph<W, E, Dir>,
+{
+    let left_set: HashSet<usize> = left.iter().copied().collect();
+    let right_set: HashSet<usize> = right.iter().copied().collect();
+
+    let mut matched = HashSet::new();
+    let mut total_weight = W::zero().into();
+    for (left_id, right_id) in matching.iter().copied() {
+        if !left_set.contains(&left_id) || !right_set.contains(&right_id) {
+            Err(Error::new_im(&format!(
+                "Pair ({}, {}) is not from the left partition to the right partition",
+                left_id, right_id
+            )))?
+        }
+
+        for vertex_id in [left_id, right_id] {
+            if !matched.insert(vertex_id) {
+                Err(Error::new_im(&format!(
+                    "Vertex with id: {} is matched more than once",
+                    vertex_id
+                )))?
+            }
+        }
+
+        let weight = graph
+            .edges_between(left_id, right_id)?
+            .into_iter()
+            .map(|edge| *edge.get_weight())
+            .min()
+            .ok_or_else(|| {
+                Error::new_im(&format!(
+                    "There is no edge between {} and {}",
+                    left_id, right_id
+                ))
+            })?;
+        total_weight += weight;
+    }
+
+    let unmatched_of = |partition: &[usize]| -> Vec<usize> {
+        partition
+            .iter()
+            .copied()
+            .filter(|vertex_id| !matched.contains(vertex_id))
+            .collect()
+    };
+    let unmatched_left = unmatched_of(left);
+    let unmatched_right = unmatched_of(right);
+
+    Ok(MatchingReport {
+        size: matching.len(),
+        is_perfect: unmatched_left.is_empty() && unmatched_right.is_empty(),
+        total_weight,
+        unmatched_left,
+        unmatched_right,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::{ErrorKind, Hungarian};
+    use crate::graph::MatGraph;
+    use crate::storage::{DiMat, Mat};
+
+    #[test]
+    fn perfect_matching_of_hungarian() {
+        // Given: Graph
+        //          1
+        //      a ----- c
+        //        \   /
+        //       3 \ / 2
+        //          X
+        //        /   \
+        //      b ----- d
+        //          5
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, c, 1.into()).unwrap();
+        graph.add_edge(a, d, 3.into()).unwrap();
+        graph.add_edge(b, c, 2.into()).unwrap();
+        graph.add_edge(b, d, 5.into()).unwrap();
+        let (matching, cost) = Hungarian::init(&graph, vec![a, b], vec![c, d])
+            .execute(&graph)
+            .unwrap();
+
+        // When: Reporting the matching.
+        let report = matching_report(&graph, &[a, b], &[c, d], &matching).unwrap();
+
+        // Then:
+        assert_eq!(
+            report,
+            MatchingReport {
+                size: 2,
+                is_perfect: true,
+                total_weight: cost,
+                unmatched_left: vec![],
+                unmatched_right: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn unmatchable_vertex() {
+        // Given: Graph
+        //
+        //      a ----- x
+        //        \
+        //          \
+        //      b ----- y
+        //
+        //      c       z
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let x = graph.add_vertex();
+        let y = graph.add_vertex();
+        let z = graph.add_vertex();
+        graph.add_edge(a, x, 1.into()).unwrap();
+        graph.add_edge(a, y, 2.into()).unwrap();
+        graph.add_edge(b, y, 4.into()).unwrap();
+
+        // When: Reporting a maximum matching.
+        let report = matching_report(&graph, &[a, b, c], &[x, y, z], &[(a, x), (b, y)]).unwrap();
+
+        // Then: c and z have no edges, so they can not be matched.
+        assert_eq!(report.size, 2);
+        assert!(!report.is_perfect);
+        assert_eq!(report.total_weight, 5.into());
+        assert_eq!(report.unmatched_left, vec![c]);
+        assert_eq!(report.unmatched_right, vec![z]);
+    }
+
+    #[test]
+    fn empty_matching() {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+
+        let report = matching_report(&graph, &[a], &[b], &[]).unwrap();
+
+        assert_eq!(report.size, 0);
+        assert!(!report.is_perfect);
+        assert_eq!(report.total_weight, 0.into());
+        assert_eq!(report.unmatched_left, vec![a]);
+        assert_eq!(report.unmatched_right, vec![b]);
+    }
+
+    #[test]
+    fn invalid_matchings() {
+        // Given: Graph
+        //
+        //      a --> x     b     y
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let x = graph.add_vertex();
+        let y = graph.add_vertex();
+        graph.add_edge(a, x, 1.into()).unwrap();
+        let is_invalid = |result: Result<MatchingReport<usize>>| {
+            matches!(
+                result.unwrap_err().downcast_ref::<Error>().unwrap().kind(),
+                ErrorKind::InvalidMatching
+            )
+        };
+
+        // Then: Edge must exist from left to right, and each vertex can be matched once.
+        assert!(matching_report(&graph, &[a, b], &[x, y], &[(a, x)]).is_ok());
+        assert!(is_invalid(matching_report(&graph, &[x], &[a], &[(x, a)])));
+        assert!(is_invalid(matching_report(
+            &graph,
+            &[a, b],
+            &[x, y],
+            &[(b, y)]
+        )));
+        assert!(is_invalid(matching_report(
+            &graph,
+            &[a, b],
+            &[x, y],
+            &[(x, a)]
+        )));
+        assert!(is_invalid(matching_report(
+            &graph,
+            &[a],
+            &[x],
+            &[(a, x), (a, x)]
+        )));
+    }
+}
@@ -0,0 +1,656 @@
+use std::collections::VecDeque;
+
+use crate::graph::{DirectedEdge, Edge, EdgeDir, FlowEdge, UndirectedEdge, UndirectedFlowEdge};
+use crate::provide::{Edges, Graph, IdMap, Vertices};
+
+/// Finds maximum flow from a source to a sink using Edmonds-Karp algorithm.
+///
+/// Flow is repeatedly pushed along the shortest augmenting path(in number of edges) of the residual network, until sink is unreachable from source.
+/// Each edge provides a forward residual edge with capacity - flow and a backward residual edge with flow,
+/// so parallel edges and edges in opposite directions are treated independently.
+/// Undirected networks of [`UndirectedFlowEdge`](crate::graph::UndirectedFlowEdge)s are supported using [`execute_undirected`](crate::algo::MaxFlow::execute_undirected).
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::DiFlowMat;
+/// use prepona::graph::{FlowEdge, SimpleGraph};
+/// use prepona::algo::MaxFlow;
+///
+/// // Given: Network with capacities on the edges.
+/// //
+/// //      .--3--> a --2--.
+/// //      |       |      v
+/// //      s       1      t
+/// //      |       v      ^
+/// //      '--2--> b --3--'
+/// //
+/// let mut graph = SimpleGraph::init(DiFlowMat::<usize>::init());
+/// let s = graph.add_vertex();
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let t = graph.add_vertex();
+/// graph.add_edge(s, a, FlowEdge::init_with(1.into(), 3, 0));
+/// graph.add_edge(s, b, FlowEdge::init_with(1.into(), 2, 0));
+/// let ab = graph.add_edge(a, b, FlowEdge::init_with(1.into(), 1, 0)).unwrap();
+/// graph.add_edge(a, t, FlowEdge::init_with(1.into(), 2, 0));
+/// graph.add_edge(b, t, FlowEdge::init_with(1.into(), 3, 0));
+///
+/// // When: Finding the max flow.
+/// let flow = MaxFlow::init(&graph).execute(&mut graph, s, t);
+///
+/// // Then:
+/// assert_eq!(flow, 5);
+/// assert_eq!(graph.edge(ab).unwrap().get_flow(), 1);
+/// ```
+pub struct MaxFlow {
+    id_map: IdMap,
+    parent_of: Vec<Option<(usize, bool)>>,
+}
+
+impl MaxFlow {
+    /// Initializes the structure.
+    pub fn init<W, E, Dir, G>(graph: &G) -> Self
+    where
+        E: Edge<W>,
+        Dir: EdgeDir,
+        G: Vertices + Edges<W, E> + Graph<W, E, Dir>,
+    {
+        MaxFlow {
+            id_map: graph.continuos_id_map(),
+            parent_of: vec![None; graph.vertex_count()],
+        }
+    }
+
+    /// Finds the maximum flow and stores flow of each edge in it.
+    ///
+    /// # Arguments
+    /// * `graph`: Directed flow network. Capacities of edges are used as capacities of the network and weights are ignored.
+    ///   Flow of every edge is overwritten, so the residual network can be inspected afterward using flow and capacity of the edges.
+    /// * `src_id`: Id of the source vertex.
+    /// * `dst_id`: Id of the sink vertex.
+    ///
+    /// # Returns
+    /// Value of the maximum flow, which is 0 if `src_id` and `dst_id` are the same.
+    ///
+    /// # Complexity
+    /// O(|V||E|<sup>2</sup>)
+    ///
+    /// # Panics
+    /// If `src_id` or `dst_id` is not in the graph.
+    pub fn execute<W, G>(mut self, graph: &mut G, src_id: usize, dst_id: usize) -> usize
+    where
+        W: Clone,
+        G: Vertices + Edges<W, FlowEdge<W>> + Graph<W, FlowEdge<W>, DirectedEdge>,
+    {
+        let src_virt_id = self.id_map.virt_id_of(src_id);
+        let dst_virt_id = self.id_map.virt_id_of(dst_id);
+
+        // Each edge is stored as (src_virt_id, dst_virt_id, edge_id, capacity), and its flow at the same index in `flow_of`.
+        let arcs: Vec<(usize, usize, usize, isize)> = graph
+            .edges()
+            .into_iter()
+            .map(|(src_id, dst_id, edge)| {
+                (
+                    self.id_map.virt_id_of(src_id),
+                    self.id_map.virt_id_of(dst_id),
+                    edge.get_id(),
+                    edge.get_capacity() as isize,
+                )
+            })
+            .collect();
+        let mut flow_of = vec![0isize; arcs.len()];
+
+        let total_flow = self.push_flow(&arcs, &mut flow_of, src_virt_id, dst_virt_id);
+
+        for ((src_virt_id, dst_virt_id, edge_id, _), flow) in arcs.iter().zip(flow_of) {
+            let src_id = self.id_map.real_id_of(*src_virt_id);
+            let dst_id = self.id_map.real_id_of(*dst_virt_id);
+
+            let mut edge = graph.edge(*edge_id).unwrap().clone();
+            edge.set_flow(flow);
+            graph.update_edge(src_id, dst_id, *edge_id, edge).unwrap();
+        }
+
+        total_flow
+    }
+
+    /// Finds the maximum flow of an undirected network and stores flow of each edge in it.
+    ///
+    /// Flow can go through each edge in either direction, up to its capacity.
+    /// So an edge with capacity of c is modeled as an arc from its end point with smaller id to the other one,
+    /// with capacity of 2c and initial flow of c: pushing flow forward and backward on the arc is the same as pushing it in either direction on the edge.
+    ///
+    /// # Arguments
+    /// * `graph`: Undirected flow network. Capacities of edges are used as capacities of the network and weights are ignored.
+    ///   Flow of every edge is overwritten, so the residual network can be inspected afterward using flow and capacity of the edges.
+    /// * `src_id`: Id of the source vertex.
+    /// * `dst_id`: Id of the sink vertex.
+    ///
+    /// # Returns
+    /// Value of the maximum flow, which is 0 if `src_id` and `dst_id` are the same.
+    ///
+    /// # Complexity
+    /// O(|V||E|<sup>2</sup>)
+    ///
+    /// # Panics
+    /// If `src_id` or `dst_id` is not in the graph.
+    ///
+    /// # Examples
+    /// ```
+    /// use prepona::prelude::*;
+    /// use prepona::storage::AdjMatrix;
+    /// use prepona::graph::{SimpleGraph, UndirectedEdge, UndirectedFlowEdge};
+    /// use prepona::algo::MaxFlow;
+    ///
+    /// // Given: Network with capacities on the edges.
+    /// //
+    /// //      s --3-- a --2-- t
+    /// //
+    /// let mut graph = SimpleGraph::init(AdjMatrix::<usize, UndirectedFlowEdge<usize>, UndirectedEdge>::init());
+    /// let s = graph.add_vertex();
+    /// let a = graph.add_vertex();
+    /// let t = graph.add_vertex();
+    /// graph.add_edge(s, a, UndirectedFlowEdge::init_with(1.into(), 3));
+    /// let at = graph.add_edge(t, a, UndirectedFlowEdge::init_with(1.into(), 2)).unwrap();
+    ///
+    /// // When: Finding the max flow.
+    /// let flow = MaxFlow::init(&graph).execute_undirected(&mut graph, s, t);
+    ///
+    /// // Then:
+    /// assert_eq!(flow, 2);
+    /// assert_eq!(graph.edge(at).unwrap().get_flow(a, t), 2);
+    /// ```
+    pub fn execute_undirected<W, G>(mut self, graph: &mut G, src_id: usize, dst_id: usize) -> usize
+    where
+        W: Clone,
+        G: Vertices
+            + Edges<W, UndirectedFlowEdge<W>>
+            + Graph<W, UndirectedFlowEdge<W>, UndirectedEdge>,
+    {
+        let src_virt_id = self.id_map.virt_id_of(src_id);
+        let dst_virt_id = self.id_map.virt_id_of(dst_id);
+
+        // Edges are returned once, from the end point with smaller id, which is the direction flow of the edge is stored relative to.
+        let arcs: Vec<(usize, usize, usize, isize)> = graph
+            .edges()
+            .into_iter()
+            .map(|(src_id, dst_id, edge)| {
+                (
+                    self.id_map.virt_id_of(src_id),
+                    self.id_map.virt_id_of(dst_id),
+                    edge.get_id(),
+                    2 * edge.get_capacity() as isize,
+                )
+            })
+            .collect();
+        let mut flow_of: Vec<isize> = arcs.iter().map(|arc| arc.3 / 2).collect();
+
+        let total_flow = self.push_flow(&arcs, &mut flow_of, src_virt_id, dst_virt_id);
+
+        for ((src_virt_id, dst_virt_id, edge_id, capacity), flow) in arcs.iter().zip(flow_of) {
+            let src_id = self.id_map.real_id_of(*src_virt_id);
+            let dst_id = self.id_map.real_id_of(*dst_virt_id);
+
+            let mut edge = graph.edge(*edge_id).unwrap().clone();
+            edge.set_flow(src_id, dst_id, flow - capacity / 2);
+            graph.update_edge(src_id, dst_id, *edge_id, edge).unwrap();
+        }
+
+        total_flow
+    }
+
+    // Pushes flow along shortest augmenting paths until sink is unreachable from source.
+    //
+    // # Returns
+    // Value of the pushed flow.
+    pub(crate) fn push_flow(
+        &mut self,
+        arcs: &[(usize, usize, usize, isize)],
+        flow_of: &mut [isize],
+        src_virt_id: usize,
+        dst_virt_id: usize,
+    ) -> usize {
+        // Residual edges going out of each vertex as (index of the arc, is forward).
+        let mut residuals_of: Vec<Vec<(usize, bool)>> = vec![vec![]; self.parent_of.len()];
+        for (index, (src_virt_id, dst_virt_id, _, _)) in arcs.iter().enumerate() {
+            residuals_of[*src_virt_id].push((index, true));
+            residuals_of[*dst_virt_id].push((index, false));
+        }
+
+        let mut total_flow = 0;
+        while src_virt_id != dst_virt_id
+            && self.has_augmenting_path(arcs, &residuals_of, flow_of, src_virt_id, dst_virt_id)
+        {
+            let mut bottleneck = isize::MAX;
+            let mut virt_id = dst_virt_id;
+            while let Some((index, is_forward)) = self.parent_of[virt_id] {
+                bottleneck = bottleneck.min(residual_of(arcs, flow_of, index, is_forward));
+                virt_id = if is_forward {
+                    arcs[index].0
+                } else {
+                    arcs[index].1
+                };
+            }
+
+            let mut virt_id = dst_virt_id;
+            while let Some((index, is_forward)) = self.parent_of[virt_id] {
+                if is_forward {
+                    flow_of[index] += bottleneck;
+                    virt_id = arcs[index].0;
+                } else {
+                    flow_of[index] -= bottleneck;
+                    virt_id = arcs[index].1;
+                }
+            }
+
+            total_flow += bottleneck as usize;
+        }
+
+        total_flow
+    }
+
+    // Bfs in residual network to find the shortest augmenting path.
+    // Residual edge used to reach each vertex is stored in `parent_of`.
+    //
+    // # Returns
+    // `true` if sink is reachable from source in residual network, `false` otherwise.
+    fn has_augmenting_path(
+        &mut self,
+        arcs: &[(usize, usize, usize, isize)],
+        residuals_of: &[Vec<(usize, bool)>],
+        flow_of: &[isize],
+        src_virt_id: usize,
+        dst_virt_id: usize,
+    ) -> bool {
+        let mut is_visited = vec![false; self.parent_of.len()];
+        is_visited[src_virt_id] = true;
+        self.parent_of.iter_mut().for_each(|parent| *parent = None);
+
+        let mut queue = VecDeque::from(vec![src_virt_id]);
+        while let Some(virt_id) = queue.pop_front() {
+            for (index, is_forward) in residuals_of[virt_id].iter().copied() {
+                let n_virt_id = if is_forward {
+                    arcs[index].1
+                } else {
+                    arcs[index].0
+                };
+
+                if !is_visited[n_virt_id] && residual_of(arcs, flow_of, index, is_forward) > 0 {
+                    is_visited[n_virt_id] = true;
+                    self.parent_of[n_virt_id] = Some((index, is_forward));
+                    queue.push_back(n_virt_id);
+                }
+            }
+        }
+
+        is_visited[dst_virt_id]
+    }
+
+    // Vertices reached by the last bfs, which after `push_flow` are the source side of a minimum cut.
+    pub(crate) fn source_side(&self, src_virt_id: usize) -> Vec<bool> {
+        self.parent_of
+            .iter()
+            .enumerate()
+            .map(|(virt_id, parent)| virt_id == src_virt_id || parent.is_some())
+            .collect()
+    }
+}
+
+// Returns capacity of the forward or backward residual edge of the arc at `index`.
+pub(crate) fn residual_of(
+    arcs: &[(usize, usize, usize, isize)],
+    flow_of: &[isize],
+    index: usize,
+    is_forward: bool,
+) -> isize {
+    if is_forward {
+        arcs[index].3 - flow_of[index]
+    } else {
+        flow_of[index]
+    }
+}
+
+// Checks capacity constraints, and that flow is conserved in every vertex except source and sink.
+// Shared by tests of the max flow algorithms.
+#[cfg(test)]
+pub(crate) fn assert_is_valid_flow<G>(graph: &G, src_id: usize, dst_id: usize, value: usize)
+where
+    G: Vertices + Edges<usize, FlowEdge<usize>>,
+{
+    for vertex_id in graph.vertices() {
+        let mut balance = 0;
+        for (s_id, d_id, edge) in graph.edges() {
+            assert!(edge.get_flow() >= 0 && edge.get_flow() <= edge.get_capacity() as isize);
+
+            if s_id == vertex_id {
+                balance -= edge.get_flow();
+            }
+            if d_id == vertex_id {
+                balance += edge.get_flow();
+            }
+        }
+
+        let expected = if vertex_id == src_id {
+            -(value as isize)
+        } else if vertex_id == dst_id {
+            value as isize
+        } else {
+            0
+        };
+        assert_eq!(balance, expected);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::SimpleGraph;
+    use crate::storage::{AdjMatrix, DiFlowList, DiFlowMat, GraphStorage};
+    use anyhow::Result;
+
+    // Graph that allows multiple edges between two vertices, since `SimpleGraph` rejects them but `AdjList` does not.
+    struct MultiGraph(DiFlowList<usize>);
+
+    impl Vertices for MultiGraph {
+        fn vertices(&self) -> Vec<usize> {
+            self.0.vertices()
+        }
+
+        fn contains_vertex(&self, vertex_id: usize) -> bool {
+            self.0.contains_vertex(vertex_id)
+        }
+    }
+
+    impl Edges<usize, FlowEdge<usize>> for MultiGraph {
+        fn edges_from(&self, src_id: usize) -> Result<Vec<(usize, &FlowEdge<usize>)>> {
+            self.0.edges_from(src_id)
+        }
+
+        fn edges_between(&self, src_id: usize, dst_id: usize) -> Result<Vec<&FlowEdge<usize>>> {
+            self.0.edges_between(src_id, dst_id)
+        }
+
+        fn edge_between(
+            &self,
+            src_id: usize,
+            dst_id: usize,
+            edge_id: usize,
+        ) -> Result<&FlowEdge<usize>> {
+            self.0.edge_between(src_id, dst_id, edge_id)
+        }
+
+        fn edge(&self, edge_id: usize) -> Result<&FlowEdge<usize>> {
+            self.0.edge(edge_id)
+        }
+
+        fn has_any_edge(&self, src_id: usize, dst_id: usize) -> Result<bool> {
+            self.0.has_any_edge(src_id, dst_id)
+        }
+
+        fn edges(&self) -> Vec<(usize, usize, &FlowEdge<usize>)> {
+            self.0.edges()
+        }
+
+        fn as_directed_edges(&self) -> Vec<(usize, usize, &FlowEdge<usize>)> {
+            self.0.as_directed_edges()
+        }
+
+        fn edges_count(&self) -> usize {
+            self.0.edge_count()
+        }
+
+        fn contains_edge(&self, edge_id: usize) -> bool {
+            self.0.contains_edge(edge_id)
+        }
+    }
+
+    impl Graph<usize, FlowEdge<usize>, DirectedEdge> for MultiGraph {
+        fn add_vertex(&mut self) -> usize {
+            self.0.add_vertex()
+        }
+
+        fn remove_vertex(&mut self, vertex_id: usize) -> Result<()> {
+            self.0.remove_vertex(vertex_id)
+        }
+
+        fn add_edge(
+            &mut self,
+            src_id: usize,
+            dst_id: usize,
+            edge: FlowEdge<usize>,
+        ) -> Result<usize> {
+            self.0.add_edge(src_id, dst_id, edge)
+        }
+
+        fn update_edge(
+            &mut self,
+            src_id: usize,
+            dst_id: usize,
+            edge_id: usize,
+            edge: FlowEdge<usize>,
+        ) -> Result<()> {
+            self.0.update_edge(src_id, dst_id, edge_id, edge)
+        }
+
+        fn remove_edge(
+            &mut self,
+            src_id: usize,
+            dst_id: usize,
+            edge_id: usize,
+        ) -> Result<FlowEdge<usize>> {
+            self.0.remove_edge(src_id, dst_id, edge_id)
+        }
+
+        fn filter(
+            &self,
+            vertex_filter: impl FnMut(&usize) -> bool,
+            edge_filter: impl FnMut(&usize, &usize, &FlowEdge<usize>) -> bool,
+        ) -> Self {
+            MultiGraph(self.0.filter(vertex_filter, edge_filter))
+        }
+    }
+
+    #[test]
+    fn classic_network() {
+        // Given: Network with six vertices, in which edges are listed as (src, dst, capacity).
+        let mut graph = SimpleGraph::init(DiFlowList::<usize>::init());
+        let s = graph.add_vertex();
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let t = graph.add_vertex();
+        let capacities = vec![
+            (s, a, 16),
+            (s, c, 13),
+            (a, b, 12),
+            (c, a, 4),
+            (a, c, 10),
+            (b, c, 9),
+            (c, d, 14),
+            (d, b, 7),
+            (b, t, 20),
+            (d, t, 4),
+        ];
+        for (src_id, dst_id, capacity) in capacities {
+            graph
+                .add_edge(src_id, dst_id, FlowEdge::init_with(1.into(), capacity, 0))
+                .unwrap();
+        }
+
+        // When: Finding the max flow.
+        let flow = MaxFlow::init(&graph).execute(&mut graph, s, t);
+
+        // Then: Cut {s, a, c, d} -> {b, t} has capacity 12 + 7 + 4.
+        assert_eq!(flow, 23);
+        assert_is_valid_flow(&graph, s, t, flow);
+        for (src_id, dst_id) in [(a, b), (d, b), (d, t)].iter().copied() {
+            assert!(graph.edges_between(src_id, dst_id).unwrap()[0].is_saturated());
+        }
+    }
+
+    #[test]
+    fn previous_flow_is_overwritten() {
+        // Given: s --> a --> t with capacities 3 and 2, and some flow already on it.
+        let mut graph = SimpleGraph::init(DiFlowMat::<usize>::init());
+        let s = graph.add_vertex();
+        let a = graph.add_vertex();
+        let t = graph.add_vertex();
+        graph
+            .add_edge(s, a, FlowEdge::init_with(1.into(), 3, 3))
+            .unwrap();
+        graph
+            .add_edge(a, t, FlowEdge::init_with(1.into(), 2, 1))
+            .unwrap();
+
+        // Then:
+        assert_eq!(MaxFlow::init(&graph).execute(&mut graph, s, t), 2);
+        assert_is_valid_flow(&graph, s, t, 2);
+    }
+
+    #[test]
+    fn unreachable_sink() {
+        let mut graph = SimpleGraph::init(DiFlowMat::<usize>::init());
+        let s = graph.add_vertex();
+        let t = graph.add_vertex();
+        graph
+            .add_edge(t, s, FlowEdge::init_with(1.into(), 5, 0))
+            .unwrap();
+
+        assert_eq!(MaxFlow::init(&graph).execute(&mut graph, s, t), 0);
+        assert_eq!(graph.edges_between(t, s).unwrap()[0].get_flow(), 0);
+    }
+
+    #[test]
+    fn source_is_sink() {
+        let mut graph = SimpleGraph::init(DiFlowMat::<usize>::init());
+        let s = graph.add_vertex();
+        let a = graph.add_vertex();
+        graph
+            .add_edge(s, a, FlowEdge::init_with(1.into(), 5, 0))
+            .unwrap();
+        graph
+            .add_edge(a, s, FlowEdge::init_with(1.into(), 5, 0))
+            .unwrap();
+
+        assert_eq!(MaxFlow::init(&graph).execute(&mut graph, s, s), 0);
+        assert_is_valid_flow(&graph, s, s, 0);
+    }
+
+    #[test]
+    fn undirected_network() {
+        // Given: Network with capacities on the edges, in which vertices are added from t to s.
+        //
+        //      .--4-- a --1--.
+        //      |      |      |
+        //      s      3      t
+        //      |      |      |
+        //      '--1-- b --4--'
+        //
+        let mut graph =
+            SimpleGraph::init(
+                AdjMatrix::<usize, UndirectedFlowEdge<usize>, UndirectedEdge>::init(),
+            );
+        let t = graph.add_vertex();
+        let b = graph.add_vertex();
+        let a = graph.add_vertex();
+        let s = graph.add_vertex();
+        let capacities = vec![(s, a, 4), (s, b, 1), (b, a, 3), (a, t, 1), (t, b, 4)];
+        for (src_id, dst_id, capacity) in capacities {
+            graph
+                .add_edge(
+                    src_id,
+                    dst_id,
+                    UndirectedFlowEdge::init_with(1.into(), capacity),
+                )
+                .unwrap();
+        }
+
+        // When: Finding the max flow.
+        let flow = MaxFlow::init(&graph).execute_undirected(&mut graph, s, t);
+
+        // Then: Cut {s, a} -> {b, t} has capacity 1 + 3 + 1.
+        assert_eq!(flow, 5);
+        for vertex_id in graph.vertices() {
+            let mut balance = 0;
+            for (s_id, d_id, edge) in graph.edges() {
+                assert!(edge.get_flow(s_id, d_id).abs() <= edge.get_capacity() as isize);
+
+                if s_id == vertex_id {
+                    balance -= edge.get_flow(s_id, d_id);
+                }
+                if d_id == vertex_id {
+                    balance += edge.get_flow(s_id, d_id);
+                }
+            }
+
+            let expected = if vertex_id == s {
+                -(flow as isize)
+            } else if vertex_id == t {
+                flow as isize
+            } else {
+                0
+            };
+            assert_eq!(balance, expected);
+        }
+
+        // And: Flow goes from a to b, although the edge was added from b to a.
+        assert_eq!(graph.edges_between(a, b).unwrap()[0].get_flow(a, b), 3);
+        assert_eq!(graph.edges_between(b, a).unwrap()[0].get_flow(b, a), -3);
+    }
+
+    #[test]
+    fn undirected_previous_flow_is_overwritten() {
+        // Given: s --- a --- t with capacities 3 and 2, and some flow already on it in the wrong direction.
+        let mut graph =
+            SimpleGraph::init(
+                AdjMatrix::<usize, UndirectedFlowEdge<usize>, UndirectedEdge>::init(),
+            );
+        let s = graph.add_vertex();
+        let a = graph.add_vertex();
+        let t = graph.add_vertex();
+        let mut edge = UndirectedFlowEdge::init_with(1.into(), 3);
+        edge.set_flow(a, s, 3);
+        let sa = graph.add_edge(s, a, edge).unwrap();
+        graph
+            .add_edge(a, t, UndirectedFlowEdge::init_with(1.into(), 2))
+            .unwrap();
+
+        // Then:
+        assert_eq!(
+            MaxFlow::init(&graph).execute_undirected(&mut graph, s, t),
+            2
+        );
+        assert_eq!(graph.edge(sa).unwrap().get_flow(s, a), 2);
+    }
+
+    #[test]
+    fn parallel_edges() {
+        // Given: Network
+        //
+        //       .--3--.
+        //      s       t
+        //       '--4--'
+        //
+        let mut graph = MultiGraph(DiFlowList::<usize>::init());
+        let s = graph.add_vertex();
+        let t = graph.add_vertex();
+        let upper = graph
+            .add_edge(s, t, FlowEdge::init_with(1.into(), 3, 0))
+            .unwrap();
+        let lower = graph
+            .add_edge(s, t, FlowEdge::init_with(1.into(), 4, 0))
+            .unwrap();
+        assert_eq!(graph.edges_between(s, t).unwrap().len(), 2);
+
+        // When: Finding the max flow.
+        let flow = MaxFlow::init(&graph).execute(&mut graph, s, t);
+
+        // Then: Both edges are saturated.
+        assert_eq!(flow, 7);
+        assert_eq!(graph.edge(upper).unwrap().get_flow(), 3);
+        assert_eq!(graph.edge(lower).unwrap().get_flow(), 4);
+        assert_is_valid_flow(&graph, s, t, 7);
+    }
+}
@@ -0,0 +1,248 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::collections::HashMap;
+
+use crate::graph::{Edge, UndirectedEdge};
+use crate::provide::{Graph, Neighbors, Vertices};
+
+/// Colors vertices of an undirected graph with at most `k` colors, minimizing the number of conflicting edges(edges whose end points have the same color).
+///
+/// Uses the min-conflicts local search: Starting from a random coloring, in each iteration a random vertex with a conflict is recolored
+/// with the color that has the least conflicts with its neighbors(ties are broken at random).
+/// Best coloring seen is kept, and the search stops early once there are no conflicts.
+/// Result is not necessarily optimal. For an exact check of whether `k` colors are enough checkout [`k_colorable`](crate::algo::k_colorable).
+///
+/// # Arguments
+/// * `graph`: Undirected graph to color.
+/// * `k`: Number of colors.
+/// * `iterations`: Maximum number of recolorings.
+/// * `seed`: Seed of the random number generator, so the same arguments result in the same coloring.
+///
+/// # Returns
+/// Maps id of each vertex to its color in range [0, `k`), alongside the number of conflicting edges of the coloring.
+///
+/// # Complexity
+/// O(|V| + |E| + iterations · (|V| + d + k)) where d is the maximum degree.
+///
+/// # Panics
+/// If `k` is 0 and the graph has vertices.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::min_conflict_coloring;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b
+/// //       \   /
+/// //         c
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(c, a, 1.into());
+///
+/// // When: Coloring the triangle with two colors.
+/// let (color_of, conflict_count) = min_conflict_coloring(&graph, 2, 100, 7);
+///
+/// // Then: Two of the vertices must share a color.
+/// assert_eq!(color_of.len(), 3);
+/// assert_eq!(conflict_count, 1);
+/// ```
+pub fn min_conflict_coloring<W, E, G>(
+    graph: &G,
+    k: usize,
+    iterations: usize,
+    seed: u64,
+) -> (HashMap<usize, usize>, usize)
+where
+    E: Edge<W>,
+    G: Vertices + Neighbors + Graph<W, E, UndirectedEdge>,
+{
+    let id_map = graph.continuos_id_map();
+    let vertex_count = graph.vertex_count();
+    assert!(
+        k > 0 || vertex_count == 0,
+        "At least one color is needed to color the vertices"
+    );
+
+    // Maps each vertex(by virtual id) to virtual id of its neighbors.
+    let adjacency: Vec<Vec<usize>> = (0..vertex_count)
+        .map(|virt_id| {
+            graph
+                .neighbors(id_map.real_id_of(virt_id))
+                .unwrap()
+                .into_iter()
+                .map(|n_id| id_map.virt_id_of(n_id))
+                .collect()
+        })
+        .collect();
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut color_of: Vec<usize> = (0..vertex_count).map(|_| rng.gen_range(0..k)).collect();
+
+    // Number of neighbors of each vertex that have the same color as it.
+    let mut conflicts_of: Vec<usize> = (0..vertex_count)
+        .map(|virt_id| {
+            adjacency[virt_id]
+                .iter()
+                .filter(|n_virt_id| color_of[**n_virt_id] == color_of[virt_id])
+                .count()
+        })
+        .collect();
+    let mut conflict_count = conflicts_of.iter().sum::<usize>() / 2;
+
+    let mut best = (color_of.clone(), conflict_count);
+    let mut count_of_color = vec![0; k];
+    for _ in 0..iterations {
+        if conflict_count == 0 {
+            break;
+        }
+
+        let conflicted: Vec<usize> = (0..vertex_count)
+            .filter(|virt_id| conflicts_of[*virt_id] > 0)
+            .collect();
+        let virt_id = conflicted[rng.gen_range(0..conflicted.len())];
+
+        count_of_color.iter_mut().for_each(|count| *count = 0);
+        for n_virt_id in &adjacency[virt_id] {
+            count_of_color[color_of[*n_virt_id]] += 1;
+        }
+        let min_count = *count_of_color.iter().min().unwrap();
+        let candidates: Vec<usize> = (0..k)
+            .filter(|color| count_of_color[*color] == min_count)
+            .collect();
+        let new_color = candidates[rng.gen_range(0..candidates.len())];
+
+        let old_color = color_of[virt_id];
+        if new_color == old_color {
+            continue;
+        }
+
+        for n_virt_id in adjacency[virt_id].iter().copied() {
+            if color_of[n_virt_id] == old_color {
+                conflicts_of[n_virt_id] -= 1;
+            } else if color_of[n_virt_id] == new_color {
+                conflicts_of[n_virt_id] += 1;
+            }
+        }
+        conflict_count = conflict_count - conflicts_of[virt_id] + min_count;
+        conflicts_of[virt_id] = min_count;
+        color_of[virt_id] = new_color;
+
+        if conflict_count < best.1 {
+            best = (color_of.clone(), conflict_count);
+        }
+    }
+
+    let (color_of, conflict_count) = best;
+    let color_of = color_of
+        .into_iter()
+        .enumerate()
+        .map(|(virt_id, color)| (id_map.real_id_of(virt_id), color))
+        .collect();
+
+    (color_of, conflict_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen::GridGraphGenerator;
+    use crate::graph::MatGraph;
+    use crate::provide::Edges;
+    use crate::storage::Mat;
+
+    fn count_conflicts(
+        graph: &MatGraph<usize, UndirectedEdge>,
+        color_of: &HashMap<usize, usize>,
+    ) -> usize {
+        graph
+            .edges()
+            .into_iter()
+            .filter(|(src_id, dst_id, _)| color_of[src_id] == color_of[dst_id])
+            .count()
+    }
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        let (color_of, conflict_count) = min_conflict_coloring(&graph, 0, 10, 1);
+
+        assert!(color_of.is_empty());
+        assert_eq!(conflict_count, 0);
+    }
+
+    #[test]
+    fn three_chromatic_graph_with_two_colors() {
+        // Given: Cycle with 9 vertices, which needs 3 colors.
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let vertices: Vec<usize> = (0..9).map(|_| graph.add_vertex()).collect();
+        for (index, vertex_id) in vertices.iter().enumerate() {
+            graph
+                .add_edge(*vertex_id, vertices[(index + 1) % vertices.len()], 1.into())
+                .unwrap();
+        }
+
+        for seed in 0..5 {
+            // When: Coloring with 2 colors, with and without local search.
+            let (random_coloring, random_conflicts) = min_conflict_coloring(&graph, 2, 0, seed);
+            let (color_of, conflict_count) = min_conflict_coloring(&graph, 2, 200, seed);
+
+            // Then: Returned counts are correct, and search never gets worse than the random start.
+            // Best possible is a single conflict, as the cycle is odd.
+            assert_eq!(count_conflicts(&graph, &random_coloring), random_conflicts);
+            assert_eq!(count_conflicts(&graph, &color_of), conflict_count);
+            assert!(color_of.values().all(|color| *color < 2));
+            assert!(conflict_count <= random_conflicts);
+            assert!(conflict_count >= 1);
+        }
+
+        // And: Over the seeds, the search reduces conflicts compared to random assignments.
+        let random_total: usize = (0..5)
+            .map(|seed| min_conflict_coloring(&graph, 2, 0, seed).1)
+            .sum();
+        let search_total: usize = (0..5)
+            .map(|seed| min_conflict_coloring(&graph, 2, 200, seed).1)
+            .sum();
+        assert!(search_total < random_total);
+    }
+
+    #[test]
+    fn colorable_graph_has_no_conflicts() {
+        // Given: 5 by 5 grid, which is bipartite.
+        let graph = GridGraphGenerator::init(5, 5).generate();
+
+        // When: Coloring with 2 and 3 colors.
+        let (color_of, conflict_count) = min_conflict_coloring(&graph, 2, 1000, 3);
+
+        // Then:
+        assert_eq!(conflict_count, 0);
+        assert_eq!(count_conflicts(&graph, &color_of), 0);
+        assert_eq!(min_conflict_coloring(&graph, 3, 1000, 3).1, 0);
+    }
+
+    #[test]
+    fn same_seed_same_coloring() {
+        let graph = GridGraphGenerator::init(4, 4).generate();
+
+        assert_eq!(
+            min_conflict_coloring(&graph, 2, 5, 11),
+            min_conflict_coloring(&graph, 2, 5, 11)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_colors() {
+        let graph = GridGraphGenerator::init(2, 2).generate();
+
+        min_conflict_coloring(&graph, 0, 10, 1);
+    }
+}
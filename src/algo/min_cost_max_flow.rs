@@ -0,0 +1,434 @@
+use anyhow::Result;
+use magnitude::Magnitude;
+use num_traits::{FromPrimitive, Zero};
+use std::any::Any;
+use std::collections::HashMap;
+use std::ops::{Mul, Neg};
+
+use crate::algo::max_flow::residual_of;
+use crate::algo::BellmanFord;
+use crate::graph::{DirectedEdge, Edge, FlowEdge, MatGraph};
+use crate::provide::{Edges, Graph, IdMap, Vertices};
+use crate::storage::DiMat;
+
+/// Finds maximum flow of minimum cost from a source to a sink using successive shortest augmenting paths.
+///
+/// Cost of a flow is sum of weight × flow of the edges. Flow is repeatedly pushed along the cheapest augmenting path of the residual network,
+/// until sink is unreachable from source. Each edge provides a forward residual edge with capacity - flow and cost equal to its weight,
+/// and a backward residual edge with flow and negated weight as cost, so pushing flow backward refunds the cost of the edge.
+/// Since backward residual edges have negative costs, cheapest paths are found using [`BellmanFord`](crate::algo::BellmanFord).
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::DiFlowMat;
+/// use prepona::graph::{FlowEdge, SimpleGraph};
+/// use prepona::algo::MinCostMaxFlow;
+///
+/// // Given: Network with (cost, capacity) on the edges.
+/// //
+/// //      .--(1, 2)--> a --(1, 2)--.
+/// //      |                        v
+/// //      s                        t
+/// //      |                        ^
+/// //      '--(3, 3)--> b --(3, 3)--'
+/// //
+/// let mut graph = SimpleGraph::init(DiFlowMat::<isize>::init());
+/// let s = graph.add_vertex();
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let t = graph.add_vertex();
+/// graph.add_edge(s, a, FlowEdge::init_with(1.into(), 2, 0));
+/// graph.add_edge(a, t, FlowEdge::init_with(1.into(), 2, 0));
+/// let sb = graph.add_edge(s, b, FlowEdge::init_with(3.into(), 3, 0)).unwrap();
+/// graph.add_edge(b, t, FlowEdge::init_with(3.into(), 3, 0));
+///
+/// // When: Finding the min cost max flow.
+/// let (flow, cost) = MinCostMaxFlow::init(&graph).execute(&mut graph, s, t).unwrap();
+///
+/// // Then:
+/// assert_eq!(flow, 5);
+/// assert_eq!(cost, (2 * 2 + 3 * 6).into());
+/// assert_eq!(graph.edge(sb).unwrap().get_flow(), 3);
+/// ```
+pub struct MinCostMaxFlow {
+    id_map: IdMap,
+    vertex_count: usize,
+}
+
+impl MinCostMaxFlow {
+    /// Initializes the structure.
+    pub fn init<W, G>(graph: &G) -> Self
+    where
+        G: Vertices + Edges<W, FlowEdge<W>> + Graph<W, FlowEdge<W>, DirectedEdge>,
+    {
+        MinCostMaxFlow {
+            id_map: graph.continuos_id_map(),
+            vertex_count: graph.vertex_count(),
+        }
+    }
+
+    /// Finds the maximum flow of minimum cost and stores flow of each edge in it.
+    ///
+    /// # Arguments
+    /// * `graph`: Directed flow network. Capacities of edges are used as capacities of the network and weights are used as cost of a unit of flow.
+    ///   Weights must be of a signed type, since backward residual edges have negated weights. Edges with infinite weight are never used.
+    ///   Flow of every edge is overwritten, so the residual network can be inspected afterward using flow and capacity of the edges.
+    /// * `src_id`: Id of the source vertex.
+    /// * `dst_id`: Id of the sink vertex.
+    ///
+    /// # Returns
+    /// * `Ok`: Containing value of the maximum flow alongside its cost, which are both 0 if `src_id` and `dst_id` are the same.
+    /// * `Err`: If there is a cycle of negative cost reachable from the source, in which case flow of the edges is left unchanged.
+    ///
+    /// # Complexity
+    /// O(F|V||E|) where F is value of the maximum flow.
+    ///
+    /// # Panics
+    /// * If `src_id` or `dst_id` is not in the graph.
+    /// * If value of an augmenting path's flow can not be represented by `W`.
+    pub fn execute<W, G>(
+        self,
+        graph: &mut G,
+        src_id: usize,
+        dst_id: usize,
+    ) -> Result<(usize, Magnitude<W>)>
+    where
+        W: Copy + Any + Zero + Ord + Neg<Output = W> + Mul<Output = W> + FromPrimitive,
+        G: Vertices + Edges<W, FlowEdge<W>> + Graph<W, FlowEdge<W>, DirectedEdge>,
+    {
+        let src_virt_id = self.id_map.virt_id_of(src_id);
+        let dst_virt_id = self.id_map.virt_id_of(dst_id);
+
+        // Each edge is stored as (src_virt_id, dst_virt_id, edge_id, capacity), and its flow at the same index in `flow_of`.
+        // Edges with infinite weight get no capacity, so they are never used but their flow is still overwritten.
+        let mut arcs = vec![];
+        let mut cost_of = vec![];
+        for (src_id, dst_id, edge) in graph.edges() {
+            let (capacity, cost) = match edge.get_weight() {
+                Magnitude::Finite(weight) => (edge.get_capacity() as isize, *weight),
+                _ => (0, W::zero()),
+            };
+
+            arcs.push((
+                self.id_map.virt_id_of(src_id),
+                self.id_map.virt_id_of(dst_id),
+                edge.get_id(),
+                capacity,
+            ));
+            cost_of.push(cost);
+        }
+        let mut flow_of = vec![0isize; arcs.len()];
+
+        let mut total_flow = 0;
+        let mut total_cost = W::zero();
+        while let Some(path) =
+            self.cheapest_path(&arcs, &cost_of, &flow_of, src_virt_id, dst_virt_id)?
+        {
+            let bottleneck = path
+                .iter()
+                .map(|(index, is_forward)| residual_of(&arcs, &flow_of, *index, *is_forward))
+                .min()
+                .unwrap();
+
+            let mut path_cost = W::zero();
+            for (index, is_forward) in path {
+                if is_forward {
+                    flow_of[index] += bottleneck;
+                    path_cost = path_cost + cost_of[index];
+                } else {
+                    flow_of[index] -= bottleneck;
+                    path_cost = path_cost + -cost_of[index];
+                }
+            }
+
+            total_flow += bottleneck as usize;
+            total_cost = total_cost + path_cost * W::from_isize(bottleneck).unwrap();
+        }
+
+        for ((src_virt_id, dst_virt_id, edge_id, _), flow) in arcs.iter().zip(flow_of) {
+            let src_id = self.id_map.real_id_of(*src_virt_id);
+            let dst_id = self.id_map.real_id_of(*dst_virt_id);
+
+            let mut edge = *graph.edge(*edge_id).unwrap();
+            edge.set_flow(flow);
+            graph.update_edge(src_id, dst_id, *edge_id, edge).unwrap();
+        }
+
+        Ok((total_flow, total_cost.into()))
+    }
+
+    // Builds the residual network and searches it for the cheapest augmenting path using bellman-ford algorithm.
+    // Between each pair of vertices only the cheapest residual edge is kept, since the others are never on a cheapest path.
+    //
+    // # Returns
+    // * `Ok`: Containing residual edges of the path as (index of the arc, is forward) in order from source to sink,
+    //   or `None` if sink is the source itself or is unreachable from source.
+    // * `Err`: If residual network has a negative cycle reachable from source.
+    fn cheapest_path<W>(
+        &self,
+        arcs: &[(usize, usize, usize, isize)],
+        cost_of: &[W],
+        flow_of: &[isize],
+        src_virt_id: usize,
+        dst_virt_id: usize,
+    ) -> Result<Option<Vec<(usize, bool)>>>
+    where
+        W: Copy + Any + Zero + Ord + Neg<Output = W>,
+    {
+        if src_virt_id == dst_virt_id {
+            return Ok(None);
+        }
+
+        let mut cheapest_of: HashMap<(usize, usize), (usize, bool, W)> = HashMap::new();
+        for (index, (src_virt_id, dst_virt_id, _, _)) in arcs.iter().enumerate() {
+            let residuals = [
+                (*src_virt_id, *dst_virt_id, true, cost_of[index]),
+                (*dst_virt_id, *src_virt_id, false, -cost_of[index]),
+            ];
+
+            for (u_virt_id, v_virt_id, is_forward, cost) in residuals.iter().copied() {
+                if residual_of(arcs, flow_of, index, is_forward) == 0 {
+                    continue;
+                }
+
+                let cheapest = cheapest_of
+                    .entry((u_virt_id, v_virt_id))
+                    .or_insert((index, is_forward, cost));
+                if cost < cheapest.2 {
+                    *cheapest = (index, is_forward, cost);
+                }
+            }
+        }
+
+        // Vertices of the residual network have the same ids as virtual ids of the network.
+        let mut residual_network = MatGraph::init(DiMat::<W>::init());
+        for _ in 0..self.vertex_count {
+            residual_network.add_vertex();
+        }
+        for ((u_virt_id, v_virt_id), (_, _, cost)) in &cheapest_of {
+            residual_network
+                .add_edge(*u_virt_id, *v_virt_id, (*cost).into())
+                .unwrap();
+        }
+
+        let mut bellman_ford = BellmanFord::init(&residual_network);
+        bellman_ford.search(&residual_network, src_virt_id)?;
+
+        Ok(bellman_ford.reconstruct_path(dst_virt_id).map(|path| {
+            path.windows(2)
+                .map(|pair| {
+                    let (index, is_forward, _) = cheapest_of[&(pair[0], pair[1])];
+                    (index, is_forward)
+                })
+                .collect()
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::MaxFlow;
+    use crate::graph::SimpleGraph;
+    use crate::storage::DiFlowMat;
+
+    #[test]
+    fn cheaper_path_is_filled_first() {
+        // Given: Network with (cost, capacity) on the edges.
+        //
+        //      .--(1, 2)--> a --(1, 2)--.
+        //      |                        v
+        //      s                        t
+        //      |                        ^
+        //      '--(3, 3)--> b --(3, 3)--'
+        //
+        let mut graph = SimpleGraph::init(DiFlowMat::<isize>::init());
+        let s = graph.add_vertex();
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let t = graph.add_vertex();
+        let sa = graph
+            .add_edge(s, a, FlowEdge::init_with(1.into(), 2, 0))
+            .unwrap();
+        let at = graph
+            .add_edge(a, t, FlowEdge::init_with(1.into(), 2, 0))
+            .unwrap();
+        let sb = graph
+            .add_edge(s, b, FlowEdge::init_with(3.into(), 3, 0))
+            .unwrap();
+        let bt = graph
+            .add_edge(b, t, FlowEdge::init_with(3.into(), 3, 0))
+            .unwrap();
+
+        // When: Finding the min cost max flow.
+        let (flow, cost) = MinCostMaxFlow::init(&graph)
+            .execute(&mut graph, s, t)
+            .unwrap();
+
+        // Then: Both paths are saturated.
+        assert_eq!(flow, 5);
+        assert_eq!(cost, 22.into());
+        for (edge_id, expected_flow) in [(sa, 2), (at, 2), (sb, 3), (bt, 3)].iter() {
+            assert_eq!(graph.edge(*edge_id).unwrap().get_flow(), *expected_flow);
+        }
+    }
+
+    #[test]
+    fn flow_is_cancelled_along_backward_edge() {
+        // Given: Network with (cost, capacity) on the edges.
+        //
+        //      .--(1, 1)--> a --(4, 1)--.
+        //      |            |           v
+        //      s         (1, 1)         t
+        //      |            v           ^
+        //      '--(4, 1)--> b --(1, 1)--'
+        //
+        let mut graph = SimpleGraph::init(DiFlowMat::<isize>::init());
+        let s = graph.add_vertex();
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let t = graph.add_vertex();
+        graph
+            .add_edge(s, a, FlowEdge::init_with(1.into(), 1, 0))
+            .unwrap();
+        graph
+            .add_edge(s, b, FlowEdge::init_with(4.into(), 1, 0))
+            .unwrap();
+        let ab = graph
+            .add_edge(a, b, FlowEdge::init_with(1.into(), 1, 0))
+            .unwrap();
+        graph
+            .add_edge(a, t, FlowEdge::init_with(4.into(), 1, 0))
+            .unwrap();
+        graph
+            .add_edge(b, t, FlowEdge::init_with(1.into(), 1, 0))
+            .unwrap();
+
+        // When: Finding the min cost max flow.
+        let (flow, cost) = MinCostMaxFlow::init(&graph)
+            .execute(&mut graph, s, t)
+            .unwrap();
+
+        // Then: First path s -> a -> b -> t is cancelled on a -> b by the second path s -> b -> a -> t.
+        assert_eq!(flow, 2);
+        assert_eq!(cost, 10.into());
+        assert_eq!(graph.edge(ab).unwrap().get_flow(), 0);
+    }
+
+    #[test]
+    fn negative_costs() {
+        // Given: Network with (cost, capacity) on the edges.
+        //
+        //      s --(2, 3)--> a --(-5, 2)--> t
+        //      |                            ^
+        //      '-----------(1, 4)-----------'
+        //
+        let mut graph = SimpleGraph::init(DiFlowMat::<isize>::init());
+        let s = graph.add_vertex();
+        let a = graph.add_vertex();
+        let t = graph.add_vertex();
+        graph
+            .add_edge(s, a, FlowEdge::init_with(2.into(), 3, 0))
+            .unwrap();
+        graph
+            .add_edge(a, t, FlowEdge::init_with((-5).into(), 2, 0))
+            .unwrap();
+        graph
+            .add_edge(s, t, FlowEdge::init_with(1.into(), 4, 0))
+            .unwrap();
+
+        // When: Finding the min cost max flow.
+        let (flow, cost) = MinCostMaxFlow::init(&graph)
+            .execute(&mut graph, s, t)
+            .unwrap();
+
+        // Then: Value of the flow is the same as the max flow.
+        assert_eq!(flow, 6);
+        assert_eq!(cost, (2 * -3 + 4).into());
+        let mut copy = graph.clone();
+        assert_eq!(MaxFlow::init(&copy).execute(&mut copy, s, t), flow);
+    }
+
+    #[test]
+    fn negative_cycle() {
+        // Given: Network with (cost, capacity) on the edges.
+        //
+        //      s --(1, 1)--> a --(-3, 1)--> b --(1, 1)--> t
+        //                    ^              |
+        //                    '---(1, 1)-----'
+        //
+        let mut graph = SimpleGraph::init(DiFlowMat::<isize>::init());
+        let s = graph.add_vertex();
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let t = graph.add_vertex();
+        graph
+            .add_edge(s, a, FlowEdge::init_with(1.into(), 1, 0))
+            .unwrap();
+        let ab = graph
+            .add_edge(a, b, FlowEdge::init_with((-3).into(), 1, 0))
+            .unwrap();
+        graph
+            .add_edge(b, a, FlowEdge::init_with(1.into(), 1, 0))
+            .unwrap();
+        graph
+            .add_edge(b, t, FlowEdge::init_with(1.into(), 1, 0))
+            .unwrap();
+
+        let result = MinCostMaxFlow::init(&graph).execute(&mut graph, s, t);
+
+        assert!(result.is_err());
+        assert_eq!(graph.edge(ab).unwrap().get_flow(), 0);
+    }
+
+    #[test]
+    fn infinite_weight_edge() {
+        // Given: Network with (cost, capacity) on the edges, and a stale flow of 2 on s -> t.
+        //
+        //      s --(inf, 2)--> t
+        //      |               ^
+        //      '--(1, 1)--> a -'(1, 1)
+        //
+        let mut graph = SimpleGraph::init(DiFlowMat::<isize>::init());
+        let s = graph.add_vertex();
+        let a = graph.add_vertex();
+        let t = graph.add_vertex();
+        let st = graph
+            .add_edge(s, t, FlowEdge::init_with(Magnitude::PosInfinite, 2, 2))
+            .unwrap();
+        graph
+            .add_edge(s, a, FlowEdge::init_with(1.into(), 1, 0))
+            .unwrap();
+        graph
+            .add_edge(a, t, FlowEdge::init_with(1.into(), 1, 0))
+            .unwrap();
+
+        // When: Finding the min cost max flow.
+        let (flow, cost) = MinCostMaxFlow::init(&graph)
+            .execute(&mut graph, s, t)
+            .unwrap();
+
+        // Then: Edge with infinite weight is not used and its flow is reset.
+        assert_eq!(flow, 1);
+        assert_eq!(cost, 2.into());
+        assert_eq!(graph.edge(st).unwrap().get_flow(), 0);
+    }
+
+    #[test]
+    fn same_source_and_sink() {
+        let mut graph = SimpleGraph::init(DiFlowMat::<isize>::init());
+        let s = graph.add_vertex();
+        let t = graph.add_vertex();
+        graph
+            .add_edge(s, t, FlowEdge::init_with(1.into(), 1, 0))
+            .unwrap();
+
+        let (flow, cost) = MinCostMaxFlow::init(&graph)
+            .execute(&mut graph, s, s)
+            .unwrap();
+
+        assert_eq!(flow, 0);
+        assert_eq!(cost, 0.into());
+    }
+}
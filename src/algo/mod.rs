@@ -1,22 +1,117 @@
+mod acyclic_orientation;
+mod adjacency_list;
+mod approximate_diameter;
+mod articulation_points;
+mod betweenness_centrality;
+mod biconnected_components;
+mod bridges;
 mod cc;
+mod closeness_centrality;
+mod core_periphery;
+mod degree_entropy;
+mod diametral_path;
+mod dinic;
+mod disjoint_paths;
 mod error;
 mod eulerian;
+mod extreme_degree;
+mod facility_location;
+mod gomory_hu;
+mod graph_diff;
+mod graph_report;
+mod greedy_coloring;
 mod has_cycle;
+mod is_bipartite;
+mod k_colorable;
+mod k_hop_closure;
+mod local_community;
+mod longest_increasing_weight_path;
+mod low_link;
+mod matching;
+mod min_conflict_coloring;
+mod max_flow;
+mod min_cost_max_flow;
 mod mst;
+mod odd_cycle;
+mod page_rank;
+mod path_weight;
+mod percolation;
+mod prop_tests;
+mod rich_club;
+mod shortest_cycle_through;
 mod shortest_path;
+mod single_linkage;
+mod split_vertices;
+mod temporal_reachability;
 mod topological_sort;
+mod transitive_closure;
+mod tree_isomorphic;
 mod traversal;
+mod utils;
 mod vertex_edge_cut;
-mod prop_tests;
+mod wiener_index;
 
-pub use cc::{ConnectedComponents, TarjanSCC};
+pub use acyclic_orientation::{acyclic_orientation, acyclic_orientation_by};
+pub use adjacency_list::{adjacency_list, AdjacencyList};
+pub use approximate_diameter::approximate_diameter;
+pub use articulation_points::ArticulationPoints;
+pub use betweenness_centrality::BetweennessCentrality;
+pub use biconnected_components::BiconnectedComponents;
+pub use bridges::Bridges;
+pub use cc::{component_of, condensation, ConnectedComponents, TarjanSCC};
+pub use closeness_centrality::ClosenessCentrality;
+pub use core_periphery::CorePeriphery;
+pub use degree_entropy::degree_entropy;
+pub use diametral_path::diametral_path;
+pub use dinic::Dinic;
+pub use disjoint_paths::{edge_disjoint_paths, vertex_disjoint_paths};
 pub use error::{Error, ErrorKind};
-pub use eulerian::Eulerian;
+pub use eulerian::{fleury_tour, Eulerian, EulerianDiagnostic};
+pub use extreme_degree::{max_degree_vertex, min_degree_vertex};
+pub use facility_location::{one_center, one_median};
+pub use gomory_hu::GomoryHu;
+pub use graph_diff::{diff, ChangedEdge, GraphDiff};
+pub use graph_report::GraphReport;
+pub use greedy_coloring::GreedyColoring;
 pub use has_cycle::HasCycle;
-pub use mst::Kruskal;
+pub use is_bipartite::IsBipartite;
+pub use k_colorable::k_colorable;
+pub use k_hop_closure::k_hop_closure;
+pub use local_community::LocalCommunity;
+pub use longest_increasing_weight_path::longest_increasing_weight_path;
+pub use matching::{
+    greedy_maximal_matching, matching_report, BipartiteMatching, Hungarian, MatchingReport,
+};
+pub use max_flow::MaxFlow;
+pub use min_conflict_coloring::min_conflict_coloring;
+pub use min_cost_max_flow::MinCostMaxFlow;
+pub use mst::{
+    constrained_mst, is_mst_unique, mst_edge_weight_range, IncrementalMst, Kruskal,
+    MinBottleneckSpanningTree, Prim, SecondBestMst,
+};
+pub use odd_cycle::odd_cycle;
+pub use page_rank::PageRank;
+pub use path_weight::path_weight;
+pub use percolation::{percolation_study, site_percolation_study};
+pub use rich_club::{rich_club_coefficient, rich_club_curve};
+pub use shortest_cycle_through::shortest_cycle_through;
+pub use shortest_path::all_pairs_dijkstra;
+pub use shortest_path::constrained_shortest_path;
+pub use shortest_path::{AStar, AltHeuristic};
+pub use shortest_path::ContractionHierarchies;
 pub use shortest_path::BellmanFord;
 pub use shortest_path::Dijkstra;
 pub use shortest_path::FloydWarshall;
+pub use shortest_path::Johnson;
+pub use shortest_path::shortest_path_count;
+pub use shortest_path::time_window_shortest_path;
+pub use shortest_path::turn_penalty_shortest_path;
+pub use single_linkage::single_linkage_clusters;
+pub use split_vertices::split_vertices;
+pub use temporal_reachability::temporal_reachability;
 pub use topological_sort::TopologicalSort;
-pub use traversal::{Bfs, BfsListener, Color, Dfs, DfsListener};
+pub use transitive_closure::TransitiveClosure;
+pub use tree_isomorphic::tree_isomorphic;
+pub use traversal::{bfs_until, dfs_until, Bfs, BfsListener, Color, Dfs, DfsListener};
 pub use vertex_edge_cut::VertexEdgeCut;
+pub use wiener_index::wiener_index;
@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+
+use magnitude::Magnitude;
+
+use super::tree_path::{heaviest_edges_on_paths, partition_by_mst};
+use crate::algo::Kruskal;
+use crate::graph::{Edge, UndirectedEdge};
+use crate::provide;
+
+/// Finds a minimum bottleneck spanning tree and answers minimax path queries using it.
+///
+/// Any minimum spanning tree is also a minimum bottleneck spanning tree, so the tree is computed using `Kruskal`.
+/// The path between two vertices in this tree minimizes the weight of the heaviest edge among all paths between them in the graph.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::MinBottleneckSpanningTree;
+///
+/// // Given: Graph
+/// //
+/// //      a --1-- b --5-- c
+/// //       \             /
+/// //        '-----3-----'
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 5.into());
+/// graph.add_edge(a, c, 3.into());
+///
+/// // When: Building the tree.
+/// let tree = MinBottleneckSpanningTree::init(&graph);
+///
+/// // Then: Going from b to c through a avoids the edge with weight of 5.
+/// assert_eq!(tree.max_edge(), Some(3.into()));
+/// assert_eq!(tree.bottleneck_between(b, c), Some(3.into()));
+/// ```
+pub struct MinBottleneckSpanningTree<W> {
+    // Tree edges in the format of (src_id, dst_id, edge_id).
+    edges: Vec<(usize, usize, usize)>,
+
+    max_edge: Option<Magnitude<W>>,
+
+    // Maps each pair of connected vertices to weight of the heaviest edge on the tree path between them.
+    bottlenecks: HashMap<(usize, usize), Magnitude<W>>,
+}
+
+impl<W: Copy + Ord> MinBottleneckSpanningTree<W> {
+    /// Builds the tree for the given graph.
+    ///
+    /// # Arguments
+    /// `graph`: Graph to find its minimum bottleneck spanning tree.
+    ///
+    /// # Complexity
+    /// O(|V|<sup>2</sup> + |E|log(|E|))
+    pub fn init<G, E: Edge<W>>(graph: &G) -> Self
+    where
+        G: provide::Edges<W, E>
+            + provide::Neighbors
+            + provide::Vertices
+            + provide::Graph<W, E, UndirectedEdge>,
+    {
+        let (tree_edges, _) = partition_by_mst(Kruskal::init(graph), graph);
+
+        let edges = tree_edges
+            .iter()
+            .map(|(src_id, dst_id, edge)| (*src_id, *dst_id, edge.get_id()))
+            .collect();
+
+        let max_edge = tree_edges
+            .iter()
+            .map(|(_, _, edge)| *edge.get_weight())
+            .max();
+
+        let bottlenecks = heaviest_edges_on_paths(&tree_edges)
+            .into_iter()
+            .map(|(pair, (_, _, edge))| (pair, *edge.get_weight()))
+            .collect();
+
+        MinBottleneckSpanningTree {
+            edges,
+            max_edge,
+            bottlenecks,
+        }
+    }
+
+    /// # Returns
+    /// * `Some`: Containing weight of the heaviest edge of the tree, which is the minimum possible among all spanning trees.
+    /// * `None`: If tree has no edges.
+    pub fn max_edge(&self) -> Option<Magnitude<W>> {
+        self.max_edge
+    }
+
+    /// # Arguments
+    /// * `src_id`: Id of the first vertex.
+    /// * `dst_id`: Id of the second vertex.
+    ///
+    /// # Returns
+    /// * `Some`: Containing the minimum, over all paths between `src_id` and `dst_id`, of the heaviest edge weight on the path.
+    /// * `None`: If `src_id` and `dst_id` are the same or not connected.
+    ///
+    /// # Complexity
+    /// O(1)
+    pub fn bottleneck_between(&self, src_id: usize, dst_id: usize) -> Option<Magnitude<W>> {
+        self.bottlenecks.get(&(src_id, dst_id)).copied()
+    }
+
+    /// # Returns
+    /// Edges of the tree in the format of (`src_id`, `dst_id`, `edge_id`).
+    pub fn edges(&self) -> Vec<(usize, usize, usize)> {
+        self.edges.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::provide::*;
+    use crate::storage::Mat;
+
+    // Computes minimax distance between every pair of vertices using a variation of Floyd-Warshall.
+    fn brute_force_bottlenecks(graph: &MatGraph<usize, UndirectedEdge>) -> Vec<Vec<Option<usize>>> {
+        let vertex_count = graph.vertex_count();
+        let id_map = graph.continuos_id_map();
+
+        let mut bottleneck = vec![vec![None; vertex_count]; vertex_count];
+        for (src_id, dst_id, edge) in graph.edges() {
+            let src_virt_id = id_map.virt_id_of(src_id);
+            let dst_virt_id = id_map.virt_id_of(dst_id);
+            let weight = edge.get_weight().unwrap();
+
+            bottleneck[src_virt_id][dst_virt_id] = Some(weight);
+            bottleneck[dst_virt_id][src_virt_id] = Some(weight);
+        }
+
+        for k in 0..vertex_count {
+            for i in 0..vertex_count {
+                for j in 0..vertex_count {
+                    if let (Some(ik), Some(kj)) = (bottleneck[i][k], bottleneck[k][j]) {
+                        let through_k = ik.max(kj);
+                        if bottleneck[i][j].map_or(true, |ij| through_k < ij) {
+                            bottleneck[i][j] = Some(through_k);
+                        }
+                    }
+                }
+            }
+        }
+
+        bottleneck
+    }
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        let tree = MinBottleneckSpanningTree::init(&graph);
+
+        assert!(tree.max_edge().is_none());
+        assert!(tree.edges().is_empty());
+    }
+
+    #[test]
+    fn disconnected_graph() {
+        // Given: Graph
+        //
+        //      a --2-- b       c
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 2.into()).unwrap();
+
+        // When: Building the tree.
+        let tree = MinBottleneckSpanningTree::init(&graph);
+
+        // Then:
+        assert_eq!(tree.max_edge(), Some(2.into()));
+        assert_eq!(tree.bottleneck_between(a, b), Some(2.into()));
+        assert_eq!(tree.bottleneck_between(a, c), None);
+        assert_eq!(tree.bottleneck_between(a, a), None);
+    }
+
+    #[test]
+    fn bottlenecks_match_widest_paths() {
+        // Given: Graph
+        //
+        //      a --9-- b --8-- c
+        //      |     / |       |
+        //      7   3   2       6
+        //      | /     |       |
+        //      d --10- e --5-- f
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        graph.add_edge(a, b, 9.into()).unwrap();
+        graph.add_edge(b, c, 8.into()).unwrap();
+        graph.add_edge(a, d, 7.into()).unwrap();
+        graph.add_edge(b, d, 3.into()).unwrap();
+        graph.add_edge(b, e, 2.into()).unwrap();
+        graph.add_edge(c, f, 6.into()).unwrap();
+        graph.add_edge(d, e, 10.into()).unwrap();
+        graph.add_edge(e, f, 5.into()).unwrap();
+
+        // When: Building the tree.
+        let tree = MinBottleneckSpanningTree::init(&graph);
+
+        // Then:
+        assert_eq!(tree.max_edge(), Some(7.into()));
+        assert_eq!(tree.bottleneck_between(a, c), Some(7.into()));
+        assert_eq!(tree.bottleneck_between(d, f), Some(5.into()));
+
+        let id_map = graph.continuos_id_map();
+        let expected = brute_force_bottlenecks(&graph);
+        for src_id in graph.vertices() {
+            for dst_id in graph.vertices() {
+                if src_id == dst_id {
+                    continue;
+                }
+
+                let src_virt_id = id_map.virt_id_of(src_id);
+                let dst_virt_id = id_map.virt_id_of(dst_id);
+                assert_eq!(
+                    tree.bottleneck_between(src_id, dst_id),
+                    expected[src_virt_id][dst_virt_id].map(|weight| weight.into())
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,231 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+
+use crate::algo::{Error, Kruskal};
+use crate::graph::{subgraph::Subgraph, Edge, UndirectedEdge};
+use crate::provide::{self, Edges};
+
+/// Finds the minimum spanning tree(forest) that contains all of the forced edges and none of the forbidden ones.
+///
+/// Forced edges get contracted first, then Kruskal runs on the remaining edges that are not forbidden.
+///
+/// # Arguments
+/// * `graph`: Graph to find its constrained MST.
+/// * `forced_edges`: Ids of the edges that must be in the tree.
+/// * `forbidden_edges`: Ids of the edges that must not be in the tree.
+///
+/// # Returns
+/// * `Ok`: Containing the constrained MST as a subgraph of the original graph.
+/// * `Err`:
+///   * If a forced edge is not in the graph or is also forbidden.
+///   * If forced edges form a cycle.
+///   * If removing the forbidden edges disconnects vertices that are connected in the graph.
+///
+/// # Complexity
+/// O(|E|log(|E|))
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::constrained_mst;
+///
+/// // Given: Graph
+/// //
+/// //      a --1-- b --2-- c
+/// //       \             /
+/// //        '-----4-----'
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// let bc = graph.add_edge(b, c, 2.into()).unwrap();
+/// let ac = graph.add_edge(a, c, 4.into()).unwrap();
+///
+/// // When: Forcing the edge between a and c.
+/// let forced_edges = [ac].iter().copied().collect();
+/// let mst = constrained_mst(&graph, &forced_edges, &HashSet::new()).unwrap();
+///
+/// // Then:
+/// assert!(mst.edge(ac).is_ok());
+/// assert!(mst.edge(bc).is_err());
+/// ```
+pub fn constrained_mst<'a, G, W: Ord, E: Edge<W>>(
+    graph: &'a G,
+    forced_edges: &HashSet<usize>,
+    forbidden_edges: &HashSet<usize>,
+) -> Result<Subgraph<'a, W, E, UndirectedEdge, G>>
+where
+    G: provide::Edges<W, E>
+        + provide::Neighbors
+        + provide::Vertices
+        + provide::Graph<W, E, UndirectedEdge>,
+{
+    if let Some(edge_id) = forced_edges
+        .iter()
+        .find(|edge_id| forbidden_edges.contains(edge_id))
+    {
+        Err(Error::new_ic(&format!(
+            "Edge with id: {} is both forced and forbidden",
+            edge_id
+        )))?
+    }
+
+    if let Some(edge_id) = forced_edges
+        .iter()
+        .find(|edge_id| !graph.contains_edge(**edge_id))
+    {
+        Err(Error::new_ic(&format!(
+            "Forced edge with id: {} is not in the graph",
+            edge_id
+        )))?
+    }
+
+    let id_map = graph.continuos_id_map();
+    let mut kruskal = Kruskal::init(graph);
+    let mut mst = Vec::<(usize, usize, &'a E)>::new();
+
+    let (forced, mut rest): (Vec<_>, Vec<_>) = graph
+        .edges()
+        .into_iter()
+        .filter(|(_, _, edge)| !forbidden_edges.contains(&edge.get_id()))
+        .partition(|(_, _, edge)| forced_edges.contains(&edge.get_id()));
+
+    // Contract forced edges.
+    for (v_real_id, u_real_id, edge) in forced {
+        if !kruskal.merge(id_map.virt_id_of(v_real_id), id_map.virt_id_of(u_real_id)) {
+            Err(Error::new_ic("Forced edges form a cycle"))?
+        }
+
+        mst.push((v_real_id, u_real_id, edge));
+    }
+
+    rest.sort_by(|(_, _, e1), (_, _, e2)| e1.get_weight().cmp(e2.get_weight()));
+
+    for (v_real_id, u_real_id, edge) in rest {
+        if kruskal.merge(id_map.virt_id_of(v_real_id), id_map.virt_id_of(u_real_id)) {
+            mst.push((v_real_id, u_real_id, edge));
+        }
+    }
+
+    // Spanning forest of the graph always has the same number of edges, unless forbidden edges disconnected some vertices.
+    if mst.len() < Kruskal::init(graph).execute(graph).edges_count() {
+        Err(Error::new_ic(
+            "Forbidden edges disconnect vertices that are connected in the graph",
+        ))?
+    }
+
+    let vertices = mst
+        .iter()
+        .flat_map(|(src_id, dst_id, _)| vec![*src_id, *dst_id])
+        .collect::<HashSet<usize>>();
+
+    Ok(Subgraph::init(graph, mst, vertices))
+}
+
+#[cfg(test)]
+mod tests {
+    use magnitude::Magnitude;
+
+    use super::*;
+    use crate::graph::{DefaultEdge, MatGraph};
+    use crate::provide::*;
+    use crate::storage::Mat;
+
+    fn weight_of<G: Edges<usize, DefaultEdge<usize>>>(mst: &G) -> Magnitude<usize> {
+        mst.edges()
+            .into_iter()
+            .fold(0.into(), |total, (_, _, edge)| total + *edge.get_weight())
+    }
+
+    // Given: Graph
+    //
+    //      a --1-- b --6-- d
+    //       \      |      /
+    //        3     2     4
+    //         \    |    /
+    //          '-- c --'
+    //
+    fn graph() -> (MatGraph<usize, UndirectedEdge>, Vec<usize>) {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let ab = graph.add_edge(a, b, 1.into()).unwrap();
+        let bc = graph.add_edge(b, c, 2.into()).unwrap();
+        let ac = graph.add_edge(a, c, 3.into()).unwrap();
+        let cd = graph.add_edge(c, d, 4.into()).unwrap();
+        let bd = graph.add_edge(b, d, 6.into()).unwrap();
+
+        (graph, vec![ab, bc, ac, cd, bd])
+    }
+
+    #[test]
+    fn without_constraints() {
+        let (graph, _) = graph();
+
+        let mst = constrained_mst(&graph, &HashSet::new(), &HashSet::new()).unwrap();
+
+        assert_eq!(
+            weight_of(&mst),
+            weight_of(&Kruskal::init(&graph).execute(&graph))
+        );
+    }
+
+    #[test]
+    fn forced_edge_changes_tree() {
+        // Given: Graph with MST of {ab, bc, cd}.
+        let (graph, edge_ids) = graph();
+        let (ab, bc, ac, cd, bd) = (
+            edge_ids[0],
+            edge_ids[1],
+            edge_ids[2],
+            edge_ids[3],
+            edge_ids[4],
+        );
+
+        // When: Forcing bd and forbidding ab.
+        let forced_edges = [bd].iter().copied().collect();
+        let forbidden_edges = [ab].iter().copied().collect();
+        let mst = constrained_mst(&graph, &forced_edges, &forbidden_edges).unwrap();
+
+        // Then:
+        assert_eq!(weight_of(&mst), 11.into());
+        assert_eq!(mst.edges_count(), 3);
+        assert!(vec![bd, bc, ac]
+            .into_iter()
+            .all(|edge_id| mst.edge(edge_id).is_ok()));
+        assert!(mst.edge(ab).is_err());
+        assert!(mst.edge(cd).is_err());
+    }
+
+    #[test]
+    fn forced_edges_form_cycle() {
+        let (graph, edge_ids) = graph();
+        let forced_edges = edge_ids[..3].iter().copied().collect();
+
+        assert!(constrained_mst(&graph, &forced_edges, &HashSet::new()).is_err());
+    }
+
+    #[test]
+    fn forbidden_edges_disconnect_graph() {
+        let (graph, edge_ids) = graph();
+        let forbidden_edges = [edge_ids[3], edge_ids[4]].iter().copied().collect();
+
+        assert!(constrained_mst(&graph, &HashSet::new(), &forbidden_edges).is_err());
+    }
+
+    #[test]
+    fn forced_and_forbidden_edge() {
+        let (graph, edge_ids) = graph();
+        let edges = [edge_ids[0]].iter().copied().collect();
+
+        assert!(constrained_mst(&graph, &edges, &edges).is_err());
+    }
+}
@@ -0,0 +1,326 @@
+use std::any::Any;
+use std::collections::HashMap;
+
+use magnitude::Magnitude;
+use num_traits::Zero;
+
+use crate::algo::Kruskal;
+use crate::graph::{Edge, UndirectedEdge};
+use crate::provide::{self, Edges};
+
+/// Maintains a minimum spanning tree(forest) of a graph while new edges get inserted into the graph.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::IncrementalMst;
+///
+/// // Given: Graph
+/// //
+/// //      a --1-- b --4-- c
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 4.into());
+/// let mut mst = IncrementalMst::init(&graph);
+///
+/// // When: Inserting a lighter edge between a and c.
+/// let ac = graph.add_edge(a, c, 2.into()).unwrap();
+/// let is_inserted = mst.update_on_insert(a, c, graph.edge(ac).unwrap());
+///
+/// // Then: Edge between b and c gets replaced.
+/// assert!(is_inserted);
+/// assert_eq!(mst.total_weight(), 3.into());
+/// ```
+pub struct IncrementalMst<W> {
+    // Tree edges in the format of (src_id, dst_id, edge_id, weight).
+    edges: Vec<(usize, usize, usize, Magnitude<W>)>,
+
+    // Maps each vertex to list of (neighbor_id, index of the edge in `edges`).
+    adjacency: HashMap<usize, Vec<(usize, usize)>>,
+}
+
+impl<W: Copy + Ord + Zero + Any> IncrementalMst<W> {
+    /// Computes the initial minimum spanning tree of the graph using `Kruskal`.
+    ///
+    /// # Arguments
+    /// `graph`: Graph to maintain its MST.
+    pub fn init<G, E: Edge<W>>(graph: &G) -> Self
+    where
+        G: provide::Edges<W, E>
+            + provide::Neighbors
+            + provide::Vertices
+            + provide::Graph<W, E, UndirectedEdge>,
+    {
+        let mut mst = IncrementalMst {
+            edges: vec![],
+            adjacency: HashMap::new(),
+        };
+
+        for (src_id, dst_id, edge) in Kruskal::init(graph).execute(graph).edges() {
+            mst.push_edge(src_id, dst_id, edge.get_id(), *edge.get_weight());
+        }
+
+        mst
+    }
+
+    /// Updates the tree after `edge` got inserted into the graph.
+    ///
+    /// If `src_id` and `dst_id` are not connected in the tree, `edge` gets added to the tree.
+    /// Otherwise the heaviest edge on the tree path between them is found and gets replaced by `edge` if `edge` is lighter.
+    ///
+    /// # Arguments
+    /// * `src_id`: Id of the source vertex of the inserted edge.
+    /// * `dst_id`: Id of the destination vertex of the inserted edge.
+    /// * `edge`: The inserted edge.
+    ///
+    /// # Returns
+    /// * `true`: If `edge` is now part of the tree.
+    /// * `false`: Otherwise.
+    ///
+    /// # Complexity
+    /// O(|V|)
+    pub fn update_on_insert<E: Edge<W>>(&mut self, src_id: usize, dst_id: usize, edge: &E) -> bool {
+        if src_id == dst_id {
+            return false;
+        }
+
+        let weight = *edge.get_weight();
+
+        match self.path_between(src_id, dst_id) {
+            None => {
+                self.push_edge(src_id, dst_id, edge.get_id(), weight);
+
+                true
+            }
+            Some(path) => {
+                let heaviest_index = path
+                    .into_iter()
+                    .max_by(|index1, index2| self.edges[*index1].3.cmp(&self.edges[*index2].3))
+                    .unwrap();
+
+                if weight < self.edges[heaviest_index].3 {
+                    self.remove_edge(heaviest_index);
+                    self.push_edge(src_id, dst_id, edge.get_id(), weight);
+
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    /// # Returns
+    /// Edges of the tree in the format of (`src_id`, `dst_id`, `edge_id`).
+    pub fn edges(&self) -> Vec<(usize, usize, usize)> {
+        self.edges
+            .iter()
+            .map(|(src_id, dst_id, edge_id, _)| (*src_id, *dst_id, *edge_id))
+            .collect()
+    }
+
+    /// # Returns
+    /// Sum of weights of the tree edges.
+    pub fn total_weight(&self) -> Magnitude<W> {
+        self.edges
+            .iter()
+            .fold(W::zero().into(), |total, (_, _, _, weight)| total + *weight)
+    }
+
+    fn push_edge(&mut self, src_id: usize, dst_id: usize, edge_id: usize, weight: Magnitude<W>) {
+        let index = self.edges.len();
+        self.edges.push((src_id, dst_id, edge_id, weight));
+
+        self.adjacency
+            .entry(src_id)
+            .or_default()
+            .push((dst_id, index));
+        self.adjacency
+            .entry(dst_id)
+            .or_default()
+            .push((src_id, index));
+    }
+
+    fn remove_edge(&mut self, index: usize) {
+        let (src_id, dst_id, _, _) = self.edges.swap_remove(index);
+        let moved_index = self.edges.len();
+
+        for v_id in [src_id, dst_id].iter() {
+            self.adjacency
+                .get_mut(v_id)
+                .unwrap()
+                .retain(|(_, edge_index)| *edge_index != index);
+        }
+
+        // Last edge moved into `index` so references to it must get updated.
+        if index != moved_index {
+            let (moved_src_id, moved_dst_id, _, _) = self.edges[index];
+            for v_id in [moved_src_id, moved_dst_id].iter() {
+                for (_, edge_index) in self.adjacency.get_mut(v_id).unwrap().iter_mut() {
+                    if *edge_index == moved_index {
+                        *edge_index = index;
+                    }
+                }
+            }
+        }
+    }
+
+    // Returns indices of the edges on the tree path from `src_id` to `dst_id`, or `None` if they're not connected.
+    fn path_between(&self, src_id: usize, dst_id: usize) -> Option<Vec<usize>> {
+        // Maps each visited vertex to (parent, index of the edge to parent).
+        let mut parent_of = HashMap::new();
+        parent_of.insert(src_id, None);
+
+        let mut stack = vec![src_id];
+        while let Some(v_id) = stack.pop() {
+            if v_id == dst_id {
+                break;
+            }
+
+            for (n_id, edge_index) in self.adjacency.get(&v_id).into_iter().flatten() {
+                if !parent_of.contains_key(n_id) {
+                    parent_of.insert(*n_id, Some((v_id, *edge_index)));
+                    stack.push(*n_id);
+                }
+            }
+        }
+
+        if !parent_of.contains_key(&dst_id) {
+            return None;
+        }
+
+        let mut path = vec![];
+        let mut v_id = dst_id;
+        while let Some((parent_id, edge_index)) = parent_of[&v_id] {
+            path.push(edge_index);
+            v_id = parent_id;
+        }
+
+        Some(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::provide::*;
+    use crate::storage::Mat;
+
+    fn sorted_edge_ids(edges: Vec<usize>) -> Vec<usize> {
+        let mut edges = edges;
+        edges.sort_unstable();
+        edges
+    }
+
+    #[test]
+    fn insert_into_empty_tree() {
+        // Given: Graph
+        //
+        //      a   b
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let mut mst = IncrementalMst::init(&graph);
+
+        // When: Connecting the two vertices.
+        let ab = graph.add_edge(a, b, 5.into()).unwrap();
+        let is_inserted = mst.update_on_insert(a, b, graph.edge(ab).unwrap());
+
+        // Then:
+        assert!(is_inserted);
+        assert_eq!(mst.edges(), vec![(a, b, ab)]);
+        assert_eq!(mst.total_weight(), 5.into());
+    }
+
+    #[test]
+    fn heavier_edge_is_ignored() {
+        // Given: Graph
+        //
+        //      a --1-- b --2-- c
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 2.into()).unwrap();
+        let mut mst = IncrementalMst::init(&graph);
+
+        // When: Inserting an edge heavier than every edge on the path.
+        let ac = graph.add_edge(a, c, 3.into()).unwrap();
+        let is_inserted = mst.update_on_insert(a, c, graph.edge(ac).unwrap());
+
+        // Then:
+        assert!(!is_inserted);
+        assert_eq!(mst.total_weight(), 3.into());
+        assert!(mst.edges().iter().all(|(_, _, edge_id)| *edge_id != ac));
+    }
+
+    #[test]
+    fn matches_fresh_kruskal_after_insertions() {
+        // Given: Graph
+        //
+        //      a --9-- b --8-- c
+        //      |               |
+        //      7               6
+        //      |               |
+        //      d ------10----- e       f
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        graph.add_edge(a, b, 9.into()).unwrap();
+        graph.add_edge(b, c, 8.into()).unwrap();
+        graph.add_edge(a, d, 7.into()).unwrap();
+        graph.add_edge(c, e, 6.into()).unwrap();
+        graph.add_edge(d, e, 10.into()).unwrap();
+        let mut mst = IncrementalMst::init(&graph);
+
+        // When: Inserting edges one by one.
+        let insertions = [
+            (b, e, 3),
+            (a, c, 5),
+            (d, f, 11),
+            (b, d, 1),
+            (c, f, 4),
+            (a, e, 2),
+        ];
+        for (src_id, dst_id, weight) in insertions.iter() {
+            let edge_id = graph.add_edge(*src_id, *dst_id, (*weight).into()).unwrap();
+            mst.update_on_insert(*src_id, *dst_id, graph.edge(edge_id).unwrap());
+
+            // Then: Maintained tree is the same as the one computed from scratch.
+            let fresh_mst = Kruskal::init(&graph).execute(&graph);
+            let fresh_weight = fresh_mst
+                .edges()
+                .into_iter()
+                .fold(0.into(), |total: Magnitude<usize>, (_, _, edge)| {
+                    total + *edge.get_weight()
+                });
+
+            assert_eq!(mst.total_weight(), fresh_weight);
+            assert_eq!(
+                sorted_edge_ids(mst.edges().into_iter().map(|(_, _, id)| id).collect()),
+                sorted_edge_ids(
+                    fresh_mst
+                        .edges()
+                        .into_iter()
+                        .map(|(_, _, edge)| edge.get_id())
+                        .collect()
+                )
+            );
+        }
+    }
+}
@@ -73,8 +73,8 @@ impl Kruskal {
         let mut sets = vec![];
         sets.resize_with(vertex_count, || Rc::new(RefCell::new(HashSet::new())));
 
-        for virt_id in 0..vertex_count {
-            sets[virt_id].borrow_mut().insert(virt_id);
+        for (virt_id, set) in sets.iter().enumerate() {
+            set.borrow_mut().insert(virt_id);
         }
 
         Kruskal { sets }
@@ -90,7 +90,7 @@ impl Kruskal {
     pub fn execute<'a, G, W: Ord, E: Edge<W>>(
         mut self,
         graph: &'a G,
-    ) -> Subgraph<W, E, UndirectedEdge, G>
+    ) -> Subgraph<'a, W, E, UndirectedEdge, G>
     where
         G: provide::Edges<W, E>
             + provide::Neighbors
@@ -106,26 +106,8 @@ impl Kruskal {
         edges.sort_by(|(_, _, e1), (_, _, e2)| e1.get_weight().cmp(e2.get_weight()));
 
         for (v_real_id, u_real_id, edge) in edges {
-            let v_virt_id = id_map.virt_id_of(v_real_id);
-            let u_virt_id = id_map.virt_id_of(u_real_id);
-
-            if !self.sets[v_virt_id]
-                .borrow()
-                .eq(&*self.sets[u_virt_id].borrow())
-            {
+            if self.merge(id_map.virt_id_of(v_real_id), id_map.virt_id_of(u_real_id)) {
                 mst.push((v_real_id, u_real_id, edge));
-
-                let union_set = self.sets[v_virt_id]
-                    .borrow()
-                    .union(&*self.sets[u_virt_id].borrow())
-                    .copied()
-                    .collect::<HashSet<usize>>();
-
-                let sharable_set = Rc::new(RefCell::new(union_set));
-
-                for member in sharable_set.borrow().iter() {
-                    self.sets[*member] = sharable_set.clone();
-                }
             }
         }
 
@@ -136,6 +118,38 @@ impl Kruskal {
 
         Subgraph::init(graph, mst, vertices)
     }
+
+    /// Merges sets of the two vertices.
+    ///
+    /// # Arguments
+    /// * `v_virt_id`: Virtual id of the first vertex.
+    /// * `u_virt_id`: Virtual id of the second vertex.
+    ///
+    /// # Returns
+    /// * `true`: If vertices were in different sets before merging.
+    /// * `false`: Otherwise.
+    pub(super) fn merge(&mut self, v_virt_id: usize, u_virt_id: usize) -> bool {
+        if self.sets[v_virt_id]
+            .borrow()
+            .eq(&*self.sets[u_virt_id].borrow())
+        {
+            return false;
+        }
+
+        let union_set = self.sets[v_virt_id]
+            .borrow()
+            .union(&*self.sets[u_virt_id].borrow())
+            .copied()
+            .collect::<HashSet<usize>>();
+
+        let sharable_set = Rc::new(RefCell::new(union_set));
+
+        for member in sharable_set.borrow().iter() {
+            self.sets[*member] = sharable_set.clone();
+        }
+
+        true
+    }
 }
 
 #[cfg(test)]
@@ -1,3 +1,18 @@
+mod bottleneck;
+mod constrained;
+mod incremental;
 mod kruskal;
+mod prim;
+mod second_best;
+mod sensitivity;
+mod tree_path;
+mod uniqueness;
 
+pub use bottleneck::MinBottleneckSpanningTree;
+pub use constrained::constrained_mst;
+pub use incremental::IncrementalMst;
 pub use kruskal::Kruskal;
+pub use prim::Prim;
+pub use second_best::SecondBestMst;
+pub use sensitivity::mst_edge_weight_range;
+pub use uniqueness::is_mst_unique;
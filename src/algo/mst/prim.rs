@@ -0,0 +1,245 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+
+use crate::graph::{subgraph::Subgraph, Edge, UndirectedEdge};
+use crate::provide;
+
+/// Finds minimum spanning tree using prim algorithm.
+///
+/// Tree grows from a root vertex by repeatedly adding the lightest edge that connects it to a vertex outside of it.
+/// The frontier edges are kept in a binary heap, which makes it a better fit than [`Kruskal`](crate::algo::Kruskal) for dense graphs.
+/// If graph is disconnected, a tree is grown from an unvisited vertex of each component, so a minimum spanning forest is found.
+/// Edges with infinite weight are never added to the tree.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::Prim;
+///
+/// //  Given: Graph
+/// //                5
+/// //      f ----------------.
+/// //      |                 |
+/// //    3 |  1     1     4  |
+/// //      a --- b --- d --- e
+/// //    3 |   5 |   2 |   1 |
+/// //      |     |     |     |
+/// //      c ----'-----'-----'
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// let e = graph.add_vertex();
+/// let f = graph.add_vertex();
+///
+/// let ab = graph.add_edge(a, b, 1.into()).unwrap();
+/// graph.add_edge(a, c, 3.into());
+/// let af = graph.add_edge(a, f, 3.into()).unwrap();
+///
+/// graph.add_edge(b, c, 5.into());
+/// let bd = graph.add_edge(b, d, 1.into()).unwrap();
+///
+/// let dc = graph.add_edge(d, c, 2.into()).unwrap();
+/// graph.add_edge(d, e, 4.into());
+///
+/// let ec = graph.add_edge(e, c, 1.into()).unwrap();
+/// graph.add_edge(e, f, 5.into());
+///
+/// let mst = Prim::init(&graph).execute(&graph);
+///
+/// assert_eq!(mst.vertex_count(), 6);
+/// assert_eq!(mst.edges_count(), 5);
+/// assert!(vec![ab, af, bd, dc, ec].into_iter().all(|edge_id| mst.edge(edge_id).is_ok()))
+/// ```
+pub struct Prim {
+    is_visited: Vec<bool>,
+}
+
+impl Prim {
+    /// Initializes the structure.
+    pub fn init<G, W: Ord, E: Edge<W>>(graph: &G) -> Self
+    where
+        G: provide::Vertices + provide::Edges<W, E> + provide::Graph<W, E, UndirectedEdge>,
+    {
+        Prim {
+            is_visited: vec![false; graph.vertex_count()],
+        }
+    }
+
+    /// Finds minimum spanning tree.
+    ///
+    /// # Arguments
+    /// `graph`: Graph to find its MST.
+    ///
+    /// # Returns
+    /// MST as a subgraph of the original graph(containing all vertices and selected edges).
+    /// If graph is disconnected, it contains a spanning tree for each connected component.
+    ///
+    /// # Complexity
+    /// O(|E|log(|E|))
+    pub fn execute<'a, G, W: Ord, E: Edge<W>>(
+        mut self,
+        graph: &'a G,
+    ) -> Subgraph<'a, W, E, UndirectedEdge, G>
+    where
+        G: provide::Edges<W, E>
+            + provide::Neighbors
+            + provide::Vertices
+            + provide::Graph<W, E, UndirectedEdge>,
+    {
+        let mut mst = Vec::<(usize, usize, &'a E)>::new();
+
+        let id_map = graph.continuos_id_map();
+
+        for root_virt_id in 0..graph.vertex_count() {
+            if self.is_visited[root_virt_id] {
+                continue;
+            }
+
+            // Frontier edges are kept outside of the heap, and heap only holds their weight and index.
+            let mut frontier = Vec::<(usize, usize, &'a E)>::new();
+            let mut heap = BinaryHeap::new();
+
+            let mut vertex_id = id_map.real_id_of(root_virt_id);
+            self.is_visited[root_virt_id] = true;
+
+            loop {
+                for (dst_id, edge) in graph.edges_from(vertex_id).unwrap() {
+                    if !self.is_visited[id_map.virt_id_of(dst_id)] && edge.get_weight().is_finite()
+                    {
+                        heap.push(Reverse((edge.get_weight(), frontier.len())));
+                        frontier.push((vertex_id, dst_id, edge));
+                    }
+                }
+
+                // Pops edges until one of them leads to an unvisited vertex.
+                let next = std::iter::from_fn(|| heap.pop())
+                    .map(|Reverse((_, index))| frontier[index])
+                    .find(|(_, dst_id, _)| !self.is_visited[id_map.virt_id_of(*dst_id)]);
+
+                match next {
+                    Some((src_id, dst_id, edge)) => {
+                        self.is_visited[id_map.virt_id_of(dst_id)] = true;
+                        mst.push((src_id, dst_id, edge));
+                        vertex_id = dst_id;
+                    }
+                    None => break,
+                }
+            }
+        }
+
+        let vertices = graph.vertices().into_iter().collect::<HashSet<usize>>();
+
+        Subgraph::init(graph, mst, vertices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::Kruskal;
+    use crate::graph::{DefaultEdge, MatGraph};
+    use crate::provide::*;
+    use crate::storage::Mat;
+    use magnitude::Magnitude;
+
+    fn total_weight<G: Edges<usize, E>, E: Edge<usize>>(graph: &G) -> usize {
+        graph
+            .edges()
+            .into_iter()
+            .map(|(_, _, edge)| *edge.get_weight().as_ref().unwrap())
+            .sum()
+    }
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        let mst = Prim::init(&graph).execute(&graph);
+
+        assert_eq!(mst.vertex_count(), 0);
+    }
+
+    #[test]
+    fn same_weight_as_kruskal() {
+        // Given: Graph with 9 vertices and 14 edges.
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let vertices: Vec<usize> = (0..9).map(|_| graph.add_vertex()).collect();
+        let edges = vec![
+            (0, 1, 4),
+            (0, 7, 8),
+            (1, 2, 8),
+            (1, 7, 11),
+            (2, 3, 7),
+            (2, 5, 4),
+            (2, 8, 2),
+            (3, 4, 9),
+            (3, 5, 14),
+            (4, 5, 10),
+            (5, 6, 2),
+            (6, 7, 1),
+            (6, 8, 6),
+            (7, 8, 7),
+        ];
+        for (src_index, dst_index, weight) in edges {
+            graph
+                .add_edge(vertices[src_index], vertices[dst_index], weight.into())
+                .unwrap();
+        }
+
+        // When: Finding MST with both algorithms.
+        let prim_mst = Prim::init(&graph).execute(&graph);
+        let kruskal_mst = Kruskal::init(&graph).execute(&graph);
+
+        // Then:
+        assert_eq!(prim_mst.vertex_count(), 9);
+        assert_eq!(prim_mst.edges_count(), 8);
+        assert_eq!(total_weight(&prim_mst), 37);
+        assert_eq!(total_weight(&prim_mst), total_weight(&kruskal_mst));
+    }
+
+    #[test]
+    fn disconnected_graph() {
+        // Given: Graph
+        //
+        //      a --1-- b --inf-- c --2-- d       f
+        //       \     /
+        //        3   2           e --5-- g
+        //         \ /
+        //          h
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        let g = graph.add_vertex();
+        let h = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph
+            .add_edge(b, c, DefaultEdge::init(Magnitude::PosInfinite))
+            .unwrap();
+        graph.add_edge(c, d, 2.into()).unwrap();
+        graph.add_edge(a, h, 3.into()).unwrap();
+        graph.add_edge(b, h, 2.into()).unwrap();
+        graph.add_edge(e, g, 5.into()).unwrap();
+
+        // When: Finding MST.
+        let mst = Prim::init(&graph).execute(&graph);
+
+        // Then: Infinite edge is skipped, so there is a tree for {a, b, h}, {c, d}, {e, g} and {f}.
+        assert_eq!(mst.vertex_count(), 8);
+        assert_eq!(mst.edges_count(), 4);
+        assert_eq!(total_weight(&mst), 10);
+        assert!(mst.has_any_edge(a, b).unwrap());
+        assert!(mst.has_any_edge(b, h).unwrap());
+        assert!(mst.has_any_edge(c, d).unwrap());
+        assert!(mst.has_any_edge(e, g).unwrap());
+        assert!(mst.contains_vertex(f));
+    }
+}
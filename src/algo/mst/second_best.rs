@@ -0,0 +1,202 @@
+use std::any::Any;
+use std::collections::HashSet;
+
+use magnitude::Magnitude;
+use num_traits::Zero;
+
+use super::tree_path::{heaviest_edges_on_paths, partition_by_mst};
+use crate::algo::Kruskal;
+use crate::graph::{subgraph::Subgraph, Edge, UndirectedEdge};
+use crate::provide;
+
+/// Finds the second best minimum spanning tree: the spanning tree with minimum weight among the ones other than the MST.
+///
+/// Second best MST differs from the MST in exactly one edge.
+/// So for every non-tree edge, the heaviest edge on the tree cycle it would create is swapped out,
+/// and the swap that increases the weight of the tree the least is picked.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::SecondBestMst;
+///
+/// // Given: Graph
+/// //
+/// //      a --1-- b --2-- c
+/// //       \             /
+/// //        '-----4-----'
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 2.into());
+/// let ac = graph.add_edge(a, c, 4.into()).unwrap();
+///
+/// // When: Finding the second best MST.
+/// let (tree, weight) = SecondBestMst::init(&graph).execute(&graph).unwrap();
+///
+/// // Then: Edge between b and c is replaced by the edge between a and c.
+/// assert_eq!(weight, 5.into());
+/// assert!(tree.edge(ac).is_ok());
+/// ```
+pub struct SecondBestMst {
+    kruskal: Kruskal,
+}
+
+impl SecondBestMst {
+    /// Initializes the structure.
+    pub fn init<G, W: Ord, E: Edge<W>>(graph: &G) -> Self
+    where
+        G: provide::Vertices + provide::Edges<W, E> + provide::Graph<W, E, UndirectedEdge>,
+    {
+        SecondBestMst {
+            kruskal: Kruskal::init(graph),
+        }
+    }
+
+    /// Finds the second best minimum spanning tree.
+    ///
+    /// # Arguments
+    /// `graph`: Graph to find its second best MST.
+    ///
+    /// # Returns
+    /// * `Some`: Containing the second best MST as a subgraph of the original graph alongside its weight.
+    /// * `None`: If there is no edge outside of the MST that can be swapped in, so MST is the only spanning tree.
+    ///
+    /// # Complexity
+    /// O(|V|<sup>2</sup> + |E|log(|E|))
+    #[allow(clippy::type_complexity)]
+    pub fn execute<'a, G, W, E: Edge<W>>(
+        self,
+        graph: &'a G,
+    ) -> Option<(Subgraph<'a, W, E, UndirectedEdge, G>, Magnitude<W>)>
+    where
+        W: Copy + Ord + Zero + Any,
+        G: provide::Edges<W, E>
+            + provide::Neighbors
+            + provide::Vertices
+            + provide::Graph<W, E, UndirectedEdge>,
+    {
+        let (tree_edges, other_edges) = partition_by_mst(self.kruskal, graph);
+
+        let heaviest = heaviest_edges_on_paths(&tree_edges);
+
+        // Best swap in the format of (edge to add, edge to remove).
+        let mut best_swap: Option<((usize, usize, &'a E), &'a E)> = None;
+        for (src_id, dst_id, edge) in other_edges {
+            if !edge.get_weight().is_finite() {
+                continue;
+            }
+
+            if let Some((_, _, removed_edge)) = heaviest.get(&(src_id, dst_id)).copied() {
+                // Comparing w(added) - w(removed) of the two swaps without subtraction.
+                let is_better = match best_swap {
+                    None => true,
+                    Some(((_, _, best_added), best_removed)) => {
+                        *edge.get_weight() + *best_removed.get_weight()
+                            < *best_added.get_weight() + *removed_edge.get_weight()
+                    }
+                };
+
+                if is_better {
+                    best_swap = Some(((src_id, dst_id, edge), removed_edge));
+                }
+            }
+        }
+
+        let (added_edge, removed_edge) = best_swap?;
+
+        let mut edges = tree_edges
+            .into_iter()
+            .filter(|(_, _, edge)| edge.get_id() != removed_edge.get_id())
+            .collect::<Vec<(usize, usize, &'a E)>>();
+        edges.push(added_edge);
+
+        let weight = edges
+            .iter()
+            .fold(W::zero().into(), |total: Magnitude<W>, (_, _, edge)| {
+                total + *edge.get_weight()
+            });
+
+        let vertices = edges
+            .iter()
+            .flat_map(|(src_id, dst_id, _)| vec![*src_id, *dst_id])
+            .collect::<HashSet<usize>>();
+
+        Some((Subgraph::init(graph, edges, vertices), weight))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::provide::*;
+    use crate::storage::Mat;
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        assert!(SecondBestMst::init(&graph).execute(&graph).is_none());
+    }
+
+    #[test]
+    fn tree_graph() {
+        // Given: Graph
+        //
+        //      a --1-- b --2-- c
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 2.into()).unwrap();
+
+        // When: Finding the second best MST.
+        let second_best = SecondBestMst::init(&graph).execute(&graph);
+
+        // Then: Graph itself is the only spanning tree.
+        assert!(second_best.is_none());
+    }
+
+    #[test]
+    fn hand_verified_graph() {
+        // Given: Graph
+        //
+        //      a --1-- b --6-- d
+        //       \      |      /
+        //        3     2     4
+        //         \    |    /
+        //          '-- c --'
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let ab = graph.add_edge(a, b, 1.into()).unwrap();
+        let bc = graph.add_edge(b, c, 2.into()).unwrap();
+        let ac = graph.add_edge(a, c, 3.into()).unwrap();
+        let cd = graph.add_edge(c, d, 4.into()).unwrap();
+        graph.add_edge(b, d, 6.into()).unwrap();
+
+        // When: Finding the second best MST.
+        let (tree, weight) = SecondBestMst::init(&graph).execute(&graph).unwrap();
+
+        // Then: MST is {ab, bc, cd} with weight of 7.
+        // Swapping bc for ac costs 1 more and swapping cd for bd costs 2 more.
+        assert_eq!(weight, 8.into());
+        assert_eq!(tree.vertex_count(), 4);
+        assert_eq!(tree.edges_count(), 3);
+        assert!(vec![ab, ac, cd]
+            .into_iter()
+            .all(|edge_id| tree.edge(edge_id).is_ok()));
+        assert!(tree.edge(bc).is_err());
+    }
+}
@@ -0,0 +1,231 @@
+use std::collections::{HashMap, HashSet};
+
+use magnitude::Magnitude;
+
+use super::tree_path::{heaviest_edges_on_paths, partition_by_mst};
+use crate::algo::Kruskal;
+use crate::graph::{Edge, UndirectedEdge};
+use crate::provide;
+
+/// Finds the range of weights that edge with id: `edge_id` can take without changing the MST computed by `Kruskal`.
+///
+/// * If edge is in the MST, it stays in the tree as long as it's not heavier than the lightest non-tree edge that can replace it.
+///   So the range is (`NegInfinite`, weight of the lightest replacement) or (`NegInfinite`, `PosInfinite`) if edge is a bridge.
+/// * Otherwise, edge stays out of the tree as long as it's not lighter than the heaviest edge on the tree path between its end points.
+///   So the range is (weight of the heaviest edge on the path, `PosInfinite`).
+///
+/// Both ends of the range are inclusive, but at either end a different MST with the same weight appears.
+///
+/// # Arguments
+/// * `graph`: Graph to analyze its MST.
+/// * `edge_id`: Id of the edge to find its range.
+///
+/// # Returns
+/// (`lower_bound`, `upper_bound`) of the weight of the edge.
+///
+/// # Panics
+/// If there is no edge with id: `edge_id` in the graph.
+///
+/// # Complexity
+/// O(|V|<sup>2</sup> + |E|log(|E|))
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::mst_edge_weight_range;
+///
+/// // Given: Graph
+/// //
+/// //      a --1-- b --2-- c
+/// //       \             /
+/// //        '-----4-----'
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// let bc = graph.add_edge(b, c, 2.into()).unwrap();
+/// let ac = graph.add_edge(a, c, 4.into()).unwrap();
+///
+/// // When: Computing ranges of the edges.
+/// let (bc_lower, bc_upper) = mst_edge_weight_range(&graph, bc);
+/// let (ac_lower, ac_upper) = mst_edge_weight_range(&graph, ac);
+///
+/// // Then:
+/// assert!(bc_lower.is_neg_infinite());
+/// assert_eq!(bc_upper, 4.into());
+/// assert_eq!(ac_lower, 2.into());
+/// assert!(ac_upper.is_pos_infinite());
+/// ```
+pub fn mst_edge_weight_range<G, W: Copy + Ord, E: Edge<W>>(
+    graph: &G,
+    edge_id: usize,
+) -> (Magnitude<W>, Magnitude<W>)
+where
+    G: provide::Edges<W, E>
+        + provide::Neighbors
+        + provide::Vertices
+        + provide::Graph<W, E, UndirectedEdge>,
+{
+    let (tree_edges, other_edges) = partition_by_mst(Kruskal::init(graph), graph);
+
+    if let Some((src_id, _, _)) = tree_edges
+        .iter()
+        .find(|(_, _, edge)| edge.get_id() == edge_id)
+    {
+        // Vertices that are still reachable from `src_id` after removing the edge from the tree.
+        let component = tree_component_without(&tree_edges, *src_id, edge_id);
+
+        let lightest_replacement = other_edges
+            .iter()
+            .filter(|(s_id, d_id, edge)| {
+                edge.get_weight().is_finite()
+                    && component.contains(s_id) != component.contains(d_id)
+            })
+            .map(|(_, _, edge)| *edge.get_weight())
+            .min()
+            .unwrap_or(Magnitude::PosInfinite);
+
+        (Magnitude::NegInfinite, lightest_replacement)
+    } else {
+        let (src_id, dst_id, _) = other_edges
+            .iter()
+            .find(|(_, _, edge)| edge.get_id() == edge_id)
+            .unwrap_or_else(|| panic!("There is no edge with id: {} in the graph", edge_id));
+
+        // Non-tree edge always connects two vertices of the same tree, because `Kruskal` finds a spanning forest.
+        let heaviest = heaviest_edges_on_paths(&tree_edges);
+        let (_, _, heaviest_edge) = heaviest[&(*src_id, *dst_id)];
+
+        (*heaviest_edge.get_weight(), Magnitude::PosInfinite)
+    }
+}
+
+// Returns vertices that are connected to `root_id` in the forest after removing edge with id: `edge_id`.
+fn tree_component_without<E, W>(
+    tree_edges: &[(usize, usize, &E)],
+    root_id: usize,
+    edge_id: usize,
+) -> HashSet<usize>
+where
+    E: Edge<W>,
+{
+    let mut adjacency: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (src_id, dst_id, edge) in tree_edges.iter() {
+        if edge.get_id() != edge_id {
+            adjacency.entry(*src_id).or_default().push(*dst_id);
+            adjacency.entry(*dst_id).or_default().push(*src_id);
+        }
+    }
+
+    let mut component = HashSet::new();
+    component.insert(root_id);
+
+    let mut stack = vec![root_id];
+    while let Some(v_id) = stack.pop() {
+        for n_id in adjacency.get(&v_id).into_iter().flatten() {
+            if component.insert(*n_id) {
+                stack.push(*n_id);
+            }
+        }
+    }
+
+    component
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::provide::*;
+    use crate::storage::Mat;
+
+    fn is_in_mst(graph: &MatGraph<usize, UndirectedEdge>, edge_id: usize) -> bool {
+        Kruskal::init(graph).execute(graph).edge(edge_id).is_ok()
+    }
+
+    #[test]
+    #[should_panic(expected = "There is no edge with id: 7 in the graph")]
+    fn unknown_edge() {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+
+        mst_edge_weight_range(&graph, 7);
+    }
+
+    #[test]
+    fn ranges_match_perturbation() {
+        // Given: Graph
+        //
+        //      a --1-- b --6-- d --5-- e
+        //       \      |      /
+        //        3     2     4
+        //         \    |    /
+        //          '-- c --'
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let ab = graph.add_edge(a, b, 1.into()).unwrap();
+        let bc = graph.add_edge(b, c, 2.into()).unwrap();
+        let ac = graph.add_edge(a, c, 3.into()).unwrap();
+        let cd = graph.add_edge(c, d, 4.into()).unwrap();
+        let bd = graph.add_edge(b, d, 6.into()).unwrap();
+        let de = graph.add_edge(d, e, 5.into()).unwrap();
+
+        // When: Computing ranges of all edges.
+        let (ab_lower, ab_upper) = mst_edge_weight_range(&graph, ab);
+        let (bc_lower, bc_upper) = mst_edge_weight_range(&graph, bc);
+        let (cd_lower, cd_upper) = mst_edge_weight_range(&graph, cd);
+        let (de_lower, de_upper) = mst_edge_weight_range(&graph, de);
+        let (ac_lower, ac_upper) = mst_edge_weight_range(&graph, ac);
+        let (bd_lower, bd_upper) = mst_edge_weight_range(&graph, bd);
+
+        // Then: MST is {ab, bc, cd, de}.
+        assert!(ab_lower.is_neg_infinite());
+        assert_eq!(ab_upper, 3.into());
+        assert!(bc_lower.is_neg_infinite());
+        assert_eq!(bc_upper, 3.into());
+        assert!(cd_lower.is_neg_infinite());
+        assert_eq!(cd_upper, 6.into());
+        assert!(de_lower.is_neg_infinite());
+        assert!(de_upper.is_pos_infinite());
+        assert_eq!(ac_lower, 2.into());
+        assert!(ac_upper.is_pos_infinite());
+        assert_eq!(bd_lower, 4.into());
+        assert!(bd_upper.is_pos_infinite());
+
+        // When: Perturbing weights just outside of the computed ranges.
+        // Then: MST changes.
+        graph.update_edge(b, c, bc, 4.into()).unwrap();
+        assert!(!is_in_mst(&graph, bc));
+        graph.update_edge(b, c, bc, 2.into()).unwrap();
+
+        graph.update_edge(c, d, cd, 7.into()).unwrap();
+        assert!(!is_in_mst(&graph, cd));
+        graph.update_edge(c, d, cd, 4.into()).unwrap();
+
+        graph.update_edge(b, d, bd, 3.into()).unwrap();
+        assert!(is_in_mst(&graph, bd));
+        graph.update_edge(b, d, bd, 6.into()).unwrap();
+
+        // When: Perturbing weights inside of the computed ranges.
+        // Then: MST stays the same.
+        graph.update_edge(d, e, de, 100.into()).unwrap();
+        assert!(is_in_mst(&graph, de));
+
+        graph.update_edge(a, c, ac, 10.into()).unwrap();
+        assert!(!is_in_mst(&graph, ac));
+
+        graph.update_edge(a, b, ab, 0.into()).unwrap();
+        assert!(is_in_mst(&graph, ab));
+    }
+}
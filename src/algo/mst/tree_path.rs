@@ -0,0 +1,92 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::algo::Kruskal;
+use crate::graph::{Edge, UndirectedEdge};
+use crate::provide;
+
+type TreeEdge<'a, E> = (usize, usize, &'a E);
+
+/// Splits edges of the graph into the ones that are in the MST found by `kruskal` and the ones that are not.
+///
+/// # Returns
+/// (`tree_edges`, `non_tree_edges`), both in the format of (`src_id`, `dst_id`, `edge`).
+#[allow(clippy::type_complexity)]
+pub(super) fn partition_by_mst<'a, G, W: Ord, E: Edge<W>>(
+    kruskal: Kruskal,
+    graph: &'a G,
+) -> (Vec<TreeEdge<'a, E>>, Vec<TreeEdge<'a, E>>)
+where
+    G: provide::Edges<W, E>
+        + provide::Neighbors
+        + provide::Vertices
+        + provide::Graph<W, E, UndirectedEdge>,
+{
+    let tree_edge_ids = provide::Edges::edges(&kruskal.execute(graph))
+        .into_iter()
+        .map(|(_, _, edge)| edge.get_id())
+        .collect::<HashSet<usize>>();
+
+    graph
+        .edges()
+        .into_iter()
+        .partition(|(_, _, edge)| tree_edge_ids.contains(&edge.get_id()))
+}
+
+/// Finds heaviest edge on the path between every pair of connected vertices of a spanning forest.
+///
+/// # Arguments
+/// `tree_edges`: Edges of the forest in the format of (`src_id`, `dst_id`, `edge`).
+///
+/// # Returns
+/// Maps each (`src_id`, `dst_id`) to the heaviest edge on the tree path between them. \
+/// Both (`src_id`, `dst_id`) and (`dst_id`, `src_id`) are present for every pair of distinct vertices that are in the same tree.
+///
+/// # Complexity
+/// O(|V|<sup>2</sup>)
+pub(super) fn heaviest_edges_on_paths<'a, W: Ord, E: Edge<W>>(
+    tree_edges: &[TreeEdge<'a, E>],
+) -> HashMap<(usize, usize), TreeEdge<'a, E>> {
+    // Maps each vertex to list of (neighbor_id, tree edge between them).
+    let mut adjacency: HashMap<usize, Vec<(usize, TreeEdge<'a, E>)>> = HashMap::new();
+    for tree_edge in tree_edges.iter().copied() {
+        let (src_id, dst_id, _) = tree_edge;
+        adjacency
+            .entry(src_id)
+            .or_default()
+            .push((dst_id, tree_edge));
+        adjacency
+            .entry(dst_id)
+            .or_default()
+            .push((src_id, tree_edge));
+    }
+
+    let mut heaviest = HashMap::new();
+    for root_id in adjacency.keys().copied() {
+        let mut visited = HashSet::new();
+        visited.insert(root_id);
+
+        // Each item is (vertex_id, heaviest edge on the path from root to vertex).
+        let mut stack: Vec<(usize, Option<TreeEdge<'a, E>>)> = vec![(root_id, None)];
+        while let Some((v_id, heaviest_so_far)) = stack.pop() {
+            if let Some(edge) = heaviest_so_far {
+                heaviest.insert((root_id, v_id), edge);
+            }
+
+            for (n_id, tree_edge) in adjacency[&v_id].iter().copied() {
+                if visited.insert(n_id) {
+                    let next = match heaviest_so_far {
+                        Some((_, _, max_edge))
+                            if max_edge.get_weight() >= tree_edge.2.get_weight() =>
+                        {
+                            heaviest_so_far
+                        }
+                        _ => Some(tree_edge),
+                    };
+                    stack.push((n_id, next));
+                }
+            }
+        }
+    }
+
+    heaviest
+}
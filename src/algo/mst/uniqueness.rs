@@ -0,0 +1,133 @@
+use super::tree_path::{heaviest_edges_on_paths, partition_by_mst};
+use crate::algo::Kruskal;
+use crate::graph::{Edge, UndirectedEdge};
+use crate::provide;
+
+/// Checks whether the minimum spanning tree(forest) of the graph is unique.
+///
+/// Every non-tree edge is at least as heavy as the heaviest edge on the tree path between its end points.
+/// If it's exactly as heavy, the two edges can be swapped and another MST with the same weight is found.
+///
+/// # Arguments
+/// `graph`: Graph to check uniqueness of its MST.
+///
+/// # Returns
+/// * `true`: If graph has only one MST.
+/// * `false`: Otherwise.
+///
+/// # Complexity
+/// O(|V|<sup>2</sup> + |E|log(|E|))
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::is_mst_unique;
+///
+/// // Given: Graph
+/// //
+/// //      a --1-- b --1-- c
+/// //       \             /
+/// //        '-----1-----'
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(a, c, 1.into());
+///
+/// // Then: Any two of the edges form an MST.
+/// assert!(!is_mst_unique(&graph));
+/// ```
+pub fn is_mst_unique<G, W: Ord, E: Edge<W>>(graph: &G) -> bool
+where
+    G: provide::Edges<W, E>
+        + provide::Neighbors
+        + provide::Vertices
+        + provide::Graph<W, E, UndirectedEdge>,
+{
+    let (tree_edges, other_edges) = partition_by_mst(Kruskal::init(graph), graph);
+
+    let heaviest = heaviest_edges_on_paths(&tree_edges);
+
+    other_edges.into_iter().all(
+        |(src_id, dst_id, edge)| match heaviest.get(&(src_id, dst_id)) {
+            Some((_, _, tree_edge)) => tree_edge.get_weight() != edge.get_weight(),
+            None => true,
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::provide::*;
+    use crate::storage::Mat;
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        assert!(is_mst_unique(&graph));
+    }
+
+    #[test]
+    fn distinct_weights() {
+        // Given: Graph
+        //
+        //      a --1-- b --6-- d
+        //       \      |      /
+        //        3     2     4
+        //         \    |    /
+        //          '-- c --'
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 2.into()).unwrap();
+        graph.add_edge(a, c, 3.into()).unwrap();
+        graph.add_edge(c, d, 4.into()).unwrap();
+        graph.add_edge(b, d, 6.into()).unwrap();
+
+        // When: Checking uniqueness of the MST.
+        let is_unique = is_mst_unique(&graph);
+
+        // Then: Graph with distinct weights always has a unique MST.
+        assert!(is_unique);
+    }
+
+    #[test]
+    fn tie_with_tree_path_edge() {
+        // Given: Graph
+        //
+        //      a --1-- b --6-- d
+        //       \      |      /
+        //        2     2     4
+        //         \    |    /
+        //          '-- c --'
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 2.into()).unwrap();
+        graph.add_edge(a, c, 2.into()).unwrap();
+        graph.add_edge(c, d, 4.into()).unwrap();
+        graph.add_edge(b, d, 6.into()).unwrap();
+
+        // When: Checking uniqueness of the MST.
+        let is_unique = is_mst_unique(&graph);
+
+        // Then: Both {ab, bc, cd} and {ab, ac, cd} are MSTs with weight of 7.
+        assert!(!is_unique);
+    }
+}
@@ -0,0 +1,234 @@
+use std::collections::VecDeque;
+
+use crate::graph::{Edge, UndirectedEdge};
+use crate::provide::{Graph, Neighbors, Vertices};
+
+/// Finds an odd cycle of an undirected graph, which is a witness that the graph is not bipartite.
+///
+/// Vertices are 2-colored by their depth in a breath-first search tree.
+/// Once an edge between two vertices of the same color is found, the tree paths from its end points to their lowest common ancestor,
+/// together with the edge, form an odd cycle.
+///
+/// # Arguments
+/// `graph`: Undirected graph to search in.
+///
+/// # Returns
+/// * `Some`: Containing vertices of an odd cycle in order. Last vertex is connected back to the first one, which is not repeated at the end.
+/// * `None`: If graph is bipartite.
+///
+/// # Complexity
+/// O(|V| + |E|)
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::odd_cycle;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b --- d
+/// //       \   /
+/// //         c
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(c, a, 1.into());
+/// graph.add_edge(b, d, 1.into());
+///
+/// // When: Searching for an odd cycle.
+/// let mut cycle = odd_cycle(&graph).unwrap();
+///
+/// // Then:
+/// cycle.sort_unstable();
+/// assert_eq!(cycle, vec![a, b, c]);
+/// ```
+pub fn odd_cycle<W, E, G>(graph: &G) -> Option<Vec<usize>>
+where
+    E: Edge<W>,
+    G: Graph<W, E, UndirectedEdge> + Vertices + Neighbors,
+{
+    let id_map = graph.continuos_id_map();
+    let vertex_count = graph.vertex_count();
+
+    // Depth of each vertex in the bfs tree. Parity of the depth is the color of the vertex.
+    let mut depth_of: Vec<Option<usize>> = vec![None; vertex_count];
+    let mut parent_of: Vec<Option<usize>> = vec![None; vertex_count];
+
+    for root_virt_id in 0..vertex_count {
+        if depth_of[root_virt_id].is_some() {
+            continue;
+        }
+
+        depth_of[root_virt_id] = Some(0);
+        let mut queue = VecDeque::from(vec![root_virt_id]);
+
+        while let Some(virt_id) = queue.pop_front() {
+            let depth = depth_of[virt_id].unwrap();
+
+            for n_id in graph.neighbors(id_map.real_id_of(virt_id)).unwrap() {
+                let n_virt_id = id_map.virt_id_of(n_id);
+
+                match depth_of[n_virt_id] {
+                    None => {
+                        depth_of[n_virt_id] = Some(depth + 1);
+                        parent_of[n_virt_id] = Some(virt_id);
+                        queue.push_back(n_virt_id);
+                    }
+                    Some(n_depth) if n_depth % 2 == depth % 2 => {
+                        let cycle = tree_cycle(virt_id, n_virt_id, &depth_of, &parent_of);
+
+                        return Some(
+                            cycle
+                                .into_iter()
+                                .map(|virt_id| id_map.real_id_of(virt_id))
+                                .collect(),
+                        );
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+
+    None
+}
+
+// Builds the cycle formed by the edge between `u` and `v` and the tree paths from them to their lowest common ancestor.
+// Cycle starts from `u`, goes up to the ancestor and then down to `v`.
+fn tree_cycle(
+    mut u: usize,
+    mut v: usize,
+    depth_of: &[Option<usize>],
+    parent_of: &[Option<usize>],
+) -> Vec<usize> {
+    let mut u_path = vec![];
+    let mut v_path = vec![];
+
+    while depth_of[u] > depth_of[v] {
+        u_path.push(u);
+        u = parent_of[u].unwrap();
+    }
+    while depth_of[v] > depth_of[u] {
+        v_path.push(v);
+        v = parent_of[v].unwrap();
+    }
+    while u != v {
+        u_path.push(u);
+        v_path.push(v);
+        u = parent_of[u].unwrap();
+        v = parent_of[v].unwrap();
+    }
+
+    // `u` is now the lowest common ancestor.
+    u_path.push(u);
+    u_path.extend(v_path.into_iter().rev());
+
+    u_path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::storage::Mat;
+
+    fn add_cycle(graph: &mut MatGraph<usize, UndirectedEdge>, len: usize) -> Vec<usize> {
+        let vertices: Vec<usize> = (0..len).map(|_| graph.add_vertex()).collect();
+        for (index, src_id) in vertices.iter().enumerate() {
+            let dst_id = vertices[(index + 1) % len];
+            graph.add_edge(*src_id, dst_id, 1.into()).unwrap();
+        }
+
+        vertices
+    }
+
+    // Checks that consecutive vertices of the cycle are connected, and the last one is connected to the first one.
+    fn assert_is_cycle(graph: &MatGraph<usize, UndirectedEdge>, cycle: &[usize]) {
+        for (index, src_id) in cycle.iter().enumerate() {
+            let dst_id = cycle[(index + 1) % cycle.len()];
+
+            assert!(graph.neighbors(*src_id).unwrap().contains(&dst_id));
+        }
+    }
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        assert!(odd_cycle(&graph).is_none());
+    }
+
+    #[test]
+    fn five_cycle() {
+        // Given: Cycle of length 5.
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let vertices = add_cycle(&mut graph, 5);
+
+        // When: Searching for an odd cycle.
+        let cycle = odd_cycle(&graph).unwrap();
+
+        // Then: Whole graph is returned.
+        assert_eq!(cycle.len(), 5);
+        assert_is_cycle(&graph, &cycle);
+        let mut sorted = cycle.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, vertices);
+    }
+
+    #[test]
+    fn bipartite_graph() {
+        // Given: Even cycle with a chord that keeps it bipartite, and a separate path.
+        //
+        //      a --- b --- c
+        //      |     |     |
+        //      f --- e --- d       g --- h --- i
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let vertices = add_cycle(&mut graph, 6);
+        graph.add_edge(vertices[1], vertices[4], 1.into()).unwrap();
+        let path: Vec<usize> = (0..3).map(|_| graph.add_vertex()).collect();
+        graph.add_edge(path[0], path[1], 1.into()).unwrap();
+        graph.add_edge(path[1], path[2], 1.into()).unwrap();
+
+        assert!(odd_cycle(&graph).is_none());
+    }
+
+    #[test]
+    fn odd_cycle_in_another_component() {
+        // Given: Bipartite component and a component with an odd cycle hanging from a tree.
+        //
+        //      a --- b         c --- d --- e --- f
+        //                                  |     |
+        //                                  h --- g
+        //                                   \   /
+        //                                     i
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(c, d, 1.into()).unwrap();
+        let square = add_cycle(&mut graph, 4);
+        graph.add_edge(d, square[0], 1.into()).unwrap();
+        let i = graph.add_vertex();
+        graph.add_edge(square[2], i, 1.into()).unwrap();
+        graph.add_edge(i, square[3], 1.into()).unwrap();
+
+        // When: Searching for an odd cycle.
+        let cycle = odd_cycle(&graph).unwrap();
+
+        // Then: Square is bipartite on its own, so every odd cycle goes through i.
+        assert_eq!(cycle.len() % 2, 1);
+        assert_is_cycle(&graph, &cycle);
+        assert!(cycle.contains(&i));
+    }
+}
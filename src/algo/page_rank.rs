@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+
+use crate::{
+    graph::{DirectedEdge, Edge},
+    provide::{Edges, Graph, IdMap, Vertices},
+};
+
+/// Computes PageRank of the vertices of a directed graph using power iteration.
+///
+/// In each iteration, every vertex passes `damping` fraction of its rank equally along its outgoing edges,
+/// and the remaining 1 - `damping` fraction is spread uniformly across all vertices.
+/// Dangling vertices(vertices with no outgoing edges) spread all of their rank uniformly across all vertices.
+/// So the ranks always sum to 1.
+///
+/// Weights of the edges are ignored, but parallel edges are counted separately.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::DiMat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::PageRank;
+///
+/// // Given: Graph
+/// //
+/// //      a --> b --> c
+/// //      ^           |
+/// //      '-----------'
+/// //
+/// let mut graph = MatGraph::init(DiMat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(c, a, 1.into());
+///
+/// // When: Computing the ranks.
+/// let rank_of = PageRank::init(&graph).execute();
+///
+/// // Then:
+/// assert!((rank_of[&a] - 1.0 / 3.0).abs() < 1e-6);
+/// assert!((rank_of[&b] - 1.0 / 3.0).abs() < 1e-6);
+/// assert!((rank_of[&c] - 1.0 / 3.0).abs() < 1e-6);
+/// ```
+pub struct PageRank {
+    id_map: IdMap,
+
+    // Maps each vertex(by virtual id) to virtual id of the destination of its outgoing edges.
+    adjacency: Vec<Vec<usize>>,
+
+    damping: f64,
+    max_iterations: usize,
+    tolerance: f64,
+}
+
+impl PageRank {
+    /// Initializes the structure with damping factor of 0.85, at most 100 iterations and tolerance of 10<sup>-6</sup>.
+    ///
+    /// # Arguments
+    /// `graph`: Directed graph to rank its vertices.
+    pub fn init<W, E: Edge<W>, G>(graph: &G) -> Self
+    where
+        G: Vertices + Edges<W, E> + Graph<W, E, DirectedEdge>,
+    {
+        PageRank::init_with(graph, 0.85, 100, 1e-6)
+    }
+
+    /// Initializes the structure.
+    ///
+    /// # Arguments
+    /// * `graph`: Directed graph to rank its vertices.
+    /// * `damping`: Probability of following an outgoing edge instead of jumping to a random vertex. Must be in range [0, 1].
+    /// * `max_iterations`: Maximum number of iterations.
+    /// * `tolerance`: Iteration stops as soon as sum of absolute changes of the ranks(L1 distance) drops below it.
+    pub fn init_with<W, E: Edge<W>, G>(
+        graph: &G,
+        damping: f64,
+        max_iterations: usize,
+        tolerance: f64,
+    ) -> Self
+    where
+        G: Vertices + Edges<W, E> + Graph<W, E, DirectedEdge>,
+    {
+        let id_map = graph.continuos_id_map();
+
+        let mut adjacency = vec![vec![]; graph.vertex_count()];
+        for (src_id, dst_id, _) in graph.edges() {
+            adjacency[id_map.virt_id_of(src_id)].push(id_map.virt_id_of(dst_id));
+        }
+
+        PageRank {
+            id_map,
+            adjacency,
+            damping,
+            max_iterations,
+            tolerance,
+        }
+    }
+
+    /// Computes the ranks.
+    ///
+    /// # Returns
+    /// Maps id of each vertex to its rank. Ranks sum to 1, unless graph is empty.
+    ///
+    /// # Complexity
+    /// O(`max_iterations` * (|V| + |E|))
+    pub fn execute(&self) -> HashMap<usize, f64> {
+        let vertex_count = self.adjacency.len();
+        if vertex_count == 0 {
+            return HashMap::new();
+        }
+
+        let uniform = 1.0 / vertex_count as f64;
+        let mut rank_of = vec![uniform; vertex_count];
+        let mut next_rank_of = vec![0.0; vertex_count];
+
+        for _ in 0..self.max_iterations {
+            let dangling_rank: f64 = (0..vertex_count)
+                .filter(|virt_id| self.adjacency[*virt_id].is_empty())
+                .map(|virt_id| rank_of[virt_id])
+                .sum();
+
+            let base = (1.0 - self.damping) * uniform + self.damping * dangling_rank * uniform;
+            next_rank_of.iter_mut().for_each(|rank| *rank = base);
+
+            for (virt_id, dst_virt_ids) in self.adjacency.iter().enumerate() {
+                let share = self.damping * rank_of[virt_id] / dst_virt_ids.len() as f64;
+                for dst_virt_id in dst_virt_ids {
+                    next_rank_of[*dst_virt_id] += share;
+                }
+            }
+
+            let delta: f64 = rank_of
+                .iter()
+                .zip(next_rank_of.iter())
+                .map(|(rank, next_rank)| (rank - next_rank).abs())
+                .sum();
+
+            std::mem::swap(&mut rank_of, &mut next_rank_of);
+
+            if delta < self.tolerance {
+                break;
+            }
+        }
+
+        rank_of
+            .into_iter()
+            .enumerate()
+            .map(|(virt_id, rank)| (self.id_map.real_id_of(virt_id), rank))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::storage::DiMat;
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(DiMat::<usize>::init());
+
+        assert!(PageRank::init(&graph).execute().is_empty());
+    }
+
+    #[test]
+    fn directed_cycle() {
+        // Given: Graph
+        //
+        //      a --> b
+        //      ^     |
+        //      |     v
+        //      d <-- c
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+        graph.add_edge(d, a, 1.into()).unwrap();
+
+        // When: Computing the ranks.
+        let rank_of = PageRank::init(&graph).execute();
+
+        // Then:
+        assert_eq!(rank_of.len(), 4);
+        for rank in rank_of.values() {
+            assert!((rank - 0.25).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn dangling_vertex() {
+        // Given: Graph
+        //
+        //      a --> b --> c
+        //      ^     |
+        //      '-----'
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, a, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+
+        // When: Computing the ranks.
+        let rank_of = PageRank::init_with(&graph, 0.5, 1000, 1e-12).execute();
+
+        // Then: Ranks satisfy the equations in which rank of dangling c is spread uniformly:
+        //  a = 1/6 + 1/2 * (b/2 + c/3)
+        //  b = 1/6 + 1/2 * (a + c/3)
+        //  c = 1/6 + 1/2 * (b/2 + c/3)
+        let (ra, rb, rc) = (rank_of[&a], rank_of[&b], rank_of[&c]);
+        assert!((ra + rb + rc - 1.0).abs() < 1e-9);
+        assert!((ra - (1.0 / 6.0 + 0.5 * (rb / 2.0 + rc / 3.0))).abs() < 1e-9);
+        assert!((rb - (1.0 / 6.0 + 0.5 * (ra + rc / 3.0))).abs() < 1e-9);
+        assert!((rc - (1.0 / 6.0 + 0.5 * (rb / 2.0 + rc / 3.0))).abs() < 1e-9);
+        assert!(rb > ra);
+    }
+
+    #[test]
+    fn stops_early() {
+        // Given: Graph
+        //
+        //      a --> b
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+
+        // When: Computing the ranks with one iteration, and with a tolerance that is met after the first iteration.
+        let one_iteration = PageRank::init_with(&graph, 0.85, 1, 0.0).execute();
+        let loose_tolerance = PageRank::init_with(&graph, 0.85, 100, 1.0).execute();
+
+        // Then:
+        assert_eq!(one_iteration, loose_tolerance);
+        assert!(one_iteration[&b] > one_iteration[&a]);
+    }
+}
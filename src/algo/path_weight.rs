@@ -0,0 +1,150 @@
+use magnitude::Magnitude;
+use num_traits::Zero;
+use std::any::Any;
+
+use crate::graph::{Edge, EdgeDir};
+use crate::provide::{Edges, Graph};
+
+/// Computes total weight of a path.
+///
+/// # Arguments
+/// * `graph`: Graph that contains the path.
+/// * `path`: Id of vertices of the path in order of traversal.
+///
+/// # Returns
+/// * `Some`: Containing sum of weights of edges between consecutive vertices of the path.
+///   If there are multiple edges between two consecutive vertices, the one with minimum weight is picked.
+/// * `None`: If `path` is empty, contains a vertex that is not in the graph or two consecutive vertices that have no edge between them.
+///
+/// # Complexity
+/// O(sum of |E<sub>src->dst</sub>| of consecutive vertices)
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::DiMat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::path_weight;
+///
+/// // Given: Graph
+/// //
+/// //      a --2--> b --3--> c
+/// //
+/// let mut graph = MatGraph::init(DiMat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 2.into());
+/// graph.add_edge(b, c, 3.into());
+///
+/// // Then:
+/// assert_eq!(path_weight(&graph, &[a, b, c]), Some(5.into()));
+/// assert_eq!(path_weight(&graph, &[c, b, a]), None);
+/// ```
+pub fn path_weight<W, E, Ty, G>(graph: &G, path: &[usize]) -> Option<Magnitude<W>>
+where
+    W: Copy + Ord + Zero + Any,
+    E: Edge<W>,
+    Ty: EdgeDir,
+    G: Edges<W, E> + Graph<W, E, Ty>,
+{
+    match path {
+        [] => None,
+        [vertex_id] => {
+            // Checking existence of the vertex without requiring `Vertices`.
+            graph.edges_from(*vertex_id).ok()?;
+
+            Some(W::zero().into())
+        }
+        _ => path
+            .windows(2)
+            .try_fold(W::zero().into(), |total: Magnitude<W>, pair| {
+                let lightest = graph
+                    .edges_between(pair[0], pair[1])
+                    .ok()?
+                    .into_iter()
+                    .map(|edge| *edge.get_weight())
+                    .min()?;
+
+                Some(total + lightest)
+            }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{ListGraph, MatGraph};
+    use crate::storage::{DiList, DiMat, GraphStorage, Mat};
+
+    #[test]
+    fn empty_and_single_vertex_paths() {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+
+        assert_eq!(path_weight(&graph, &[]), None);
+        assert_eq!(path_weight(&graph, &[a]), Some(0.into()));
+        assert_eq!(path_weight(&graph, &[a + 1]), None);
+    }
+
+    #[test]
+    fn valid_path() {
+        // Given: Graph
+        //
+        //      a --1-- b --4-- c --2-- d
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 4.into()).unwrap();
+        graph.add_edge(c, d, 2.into()).unwrap();
+
+        // Then: Undirected edges can be traversed in both directions.
+        assert_eq!(path_weight(&graph, &[a, b, c, d]), Some(7.into()));
+        assert_eq!(path_weight(&graph, &[d, c, b]), Some(6.into()));
+        assert_eq!(path_weight(&graph, &[a, b, a]), Some(2.into()));
+    }
+
+    #[test]
+    fn invalid_path() {
+        // Given: Graph
+        //
+        //      a --1--> b --4--> c
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 4.into()).unwrap();
+
+        // Then:
+        assert_eq!(path_weight(&graph, &[a, c]), None);
+        assert_eq!(path_weight(&graph, &[b, a]), None);
+        assert_eq!(path_weight(&graph, &[a, b, c + 1]), None);
+    }
+
+    #[test]
+    fn multiple_edges() {
+        // Given: Storage(SimpleGraph does not allow multiple edges)
+        //
+        //         .--5--.
+        //        /       \
+        //      a --2----> b --3--> c
+        //
+        let mut list = DiList::<usize>::init();
+        let a = list.add_vertex();
+        let b = list.add_vertex();
+        let c = list.add_vertex();
+        list.add_edge(a, b, 5.into()).unwrap();
+        list.add_edge(a, b, 2.into()).unwrap();
+        list.add_edge(b, c, 3.into()).unwrap();
+        let graph = ListGraph::init(list);
+
+        // Then: Lighter edge between a and b is picked.
+        assert_eq!(path_weight(&graph, &[a, b, c]), Some(5.into()));
+    }
+}
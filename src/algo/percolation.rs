@@ -0,0 +1,338 @@
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+use crate::graph::Edge;
+use crate::provide::{Edges, Vertices};
+
+/// Estimates how the giant component of the graph shrinks as its edges are removed at random(bond percolation).
+///
+/// In each trial edges are shuffled, and for each fraction f the first f·|E| of them(rounded) are removed.
+/// So the removed edges of a smaller fraction are a subset of the removed edges of a larger one in the same trial,
+/// which makes the curve of each trial, and so the averaged curve, non-increasing in f.
+/// Direction of the edges is ignored, so components are weakly connected components.
+///
+/// # Arguments
+/// * `graph`: Graph to study.
+/// * `fractions`: Fractions of the edges to remove, each in range [0, 1].
+/// * `trials`: Number of random trials to average over.
+/// * `seed`: Seed of the random number generator, so the same arguments result in the same curve.
+///
+/// # Returns
+/// Entry at index i is the size of the largest component after removing `fractions[i]` of the edges,
+/// divided by |V| and averaged over the trials. It is 0 for the graph without vertices or when `trials` is 0.
+///
+/// # Complexity
+/// O(trials · (|V| + |E|α(|V|) + |F|)) where F is `fractions`.
+///
+/// # Panics
+/// If a fraction is not in range [0, 1].
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::percolation_study;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b --- c --- d
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(c, d, 1.into());
+///
+/// // When: Removing none and all of the edges.
+/// let curve = percolation_study(&graph, &[0.0, 1.0], 10, 7);
+///
+/// // Then: Giant component is the whole path, and then a single vertex.
+/// assert_eq!(curve, vec![1.0, 0.25]);
+/// ```
+pub fn percolation_study<W, E, G>(
+    graph: &G,
+    fractions: &[f64],
+    trials: usize,
+    seed: u64,
+) -> Vec<f64>
+where
+    E: Edge<W>,
+    G: Edges<W, E> + Vertices,
+{
+    let (vertex_count, edges) = virt_edges(graph);
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    average_curve(fractions, trials, vertex_count, |kept_count_of| {
+        let mut edges = edges.clone();
+        edges.shuffle(&mut rng);
+
+        // Edges are added back in the shuffled order, so the last ones are the removed ones.
+        let mut components = Components::init(vertex_count);
+        let mut giant_size_of = vec![components.giant_size];
+        for (src_virt_id, dst_virt_id) in edges {
+            components.merge(src_virt_id, dst_virt_id);
+            giant_size_of.push(components.giant_size);
+        }
+
+        kept_count_of(giant_size_of.len() - 1)
+            .into_iter()
+            .map(|kept_count| giant_size_of[kept_count])
+            .collect()
+    })
+}
+
+/// Estimates how the giant component of the graph shrinks as its vertices are removed at random(site percolation).
+///
+/// Works the same as [`percolation_study`](crate::algo::percolation_study), except that vertices are removed instead of edges,
+/// along with the edges incident to them.
+///
+/// # Arguments
+/// * `graph`: Graph to study.
+/// * `fractions`: Fractions of the vertices to remove, each in range [0, 1].
+/// * `trials`: Number of random trials to average over.
+/// * `seed`: Seed of the random number generator, so the same arguments result in the same curve.
+///
+/// # Returns
+/// Entry at index i is the size of the largest component after removing `fractions[i]` of the vertices,
+/// divided by the original |V| and averaged over the trials. It is 0 for the graph without vertices or when `trials` is 0.
+///
+/// # Complexity
+/// O(trials · (|V| + |E|α(|V|) + |F|)) where F is `fractions`.
+///
+/// # Panics
+/// If a fraction is not in range [0, 1].
+pub fn site_percolation_study<W, E, G>(
+    graph: &G,
+    fractions: &[f64],
+    trials: usize,
+    seed: u64,
+) -> Vec<f64>
+where
+    E: Edge<W>,
+    G: Edges<W, E> + Vertices,
+{
+    let (vertex_count, edges) = virt_edges(graph);
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut adjacency = vec![vec![]; vertex_count];
+    for (src_virt_id, dst_virt_id) in edges {
+        adjacency[src_virt_id].push(dst_virt_id);
+        adjacency[dst_virt_id].push(src_virt_id);
+    }
+
+    average_curve(fractions, trials, vertex_count, |kept_count_of| {
+        let mut vertices: Vec<usize> = (0..vertex_count).collect();
+        vertices.shuffle(&mut rng);
+
+        // Vertices are added back in the shuffled order, and each one is merged with its neighbors that are already added.
+        let mut components = Components::init(vertex_count);
+        let mut is_added = vec![false; vertex_count];
+        let mut giant_size_of = vec![0];
+        for virt_id in vertices {
+            is_added[virt_id] = true;
+            for n_virt_id in &adjacency[virt_id] {
+                if is_added[*n_virt_id] {
+                    components.merge(virt_id, *n_virt_id);
+                }
+            }
+            giant_size_of.push(components.giant_size);
+        }
+
+        kept_count_of(vertex_count)
+            .into_iter()
+            .map(|kept_count| giant_size_of[kept_count])
+            .collect()
+    })
+}
+
+// Edges of the graph in the format of (src virtual id, dst virtual id), along with the number of vertices.
+fn virt_edges<W, E, G>(graph: &G) -> (usize, Vec<(usize, usize)>)
+where
+    E: Edge<W>,
+    G: Edges<W, E> + Vertices,
+{
+    let id_map = graph.continuos_id_map();
+
+    let edges = graph
+        .edges()
+        .into_iter()
+        .map(|(src_id, dst_id, _)| (id_map.virt_id_of(src_id), id_map.virt_id_of(dst_id)))
+        .collect();
+
+    (graph.vertex_count(), edges)
+}
+
+// Runs `trial` for `trials` times and averages the giant component sizes it returns, divided by `vertex_count`.
+// `trial` gets a function that maps the total number of edges or vertices to how many of them are kept for each fraction,
+// and returns the giant component size for each fraction.
+fn average_curve<F>(fractions: &[f64], trials: usize, vertex_count: usize, mut trial: F) -> Vec<f64>
+where
+    F: FnMut(&dyn Fn(usize) -> Vec<usize>) -> Vec<usize>,
+{
+    assert!(
+        fractions
+            .iter()
+            .all(|fraction| (0.0..=1.0).contains(fraction)),
+        "Fractions must be in range [0, 1]"
+    );
+
+    let mut curve = vec![0.0; fractions.len()];
+    if vertex_count == 0 || trials == 0 {
+        return curve;
+    }
+
+    let kept_count_of = |total_count: usize| {
+        fractions
+            .iter()
+            .map(|fraction| total_count - (fraction * total_count as f64).round() as usize)
+            .collect()
+    };
+
+    for _ in 0..trials {
+        for (value, giant_size) in curve.iter_mut().zip(trial(&kept_count_of)) {
+            *value += giant_size as f64;
+        }
+    }
+
+    curve
+        .into_iter()
+        .map(|value| value / (trials * vertex_count) as f64)
+        .collect()
+}
+
+// Disjoint sets of vertices that keep track of the size of the largest set.
+struct Components {
+    parent_of: Vec<usize>,
+    size_of: Vec<usize>,
+    giant_size: usize,
+}
+
+impl Components {
+    fn init(vertex_count: usize) -> Self {
+        Components {
+            parent_of: (0..vertex_count).collect(),
+            size_of: vec![1; vertex_count],
+            giant_size: vertex_count.min(1),
+        }
+    }
+
+    fn root_of(&mut self, mut virt_id: usize) -> usize {
+        while self.parent_of[virt_id] != virt_id {
+            self.parent_of[virt_id] = self.parent_of[self.parent_of[virt_id]];
+            virt_id = self.parent_of[virt_id];
+        }
+
+        virt_id
+    }
+
+    fn merge(&mut self, v_virt_id: usize, u_virt_id: usize) {
+        let mut v_root = self.root_of(v_virt_id);
+        let mut u_root = self.root_of(u_virt_id);
+        if v_root == u_root {
+            return;
+        }
+
+        if self.size_of[v_root] < self.size_of[u_root] {
+            std::mem::swap(&mut v_root, &mut u_root);
+        }
+        self.parent_of[u_root] = v_root;
+        self.size_of[v_root] += self.size_of[u_root];
+        self.giant_size = self.giant_size.max(self.size_of[v_root]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen::{GridGraphGenerator, RandomGnp};
+    use crate::graph::MatGraph;
+    use crate::provide::Graph;
+    use crate::storage::Mat;
+
+    fn assert_non_increasing(curve: &[f64]) {
+        for window in curve.windows(2) {
+            assert!(window[0] >= window[1], "{:?} is not non-increasing", curve);
+        }
+    }
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        assert_eq!(percolation_study(&graph, &[0.0, 0.5], 5, 1), vec![0.0, 0.0]);
+        assert_eq!(
+            site_percolation_study(&graph, &[0.0, 0.5], 5, 1),
+            vec![0.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn giant_component_shrinks_as_edges_are_removed() {
+        // Given: 10 by 10 grid.
+        let graph = GridGraphGenerator::init(10, 10).generate();
+        let fractions: Vec<f64> = (0..=10).map(|step| step as f64 / 10.0).collect();
+
+        // When: Removing more and more of the edges.
+        let curve = percolation_study(&graph, &fractions, 20, 3);
+
+        // Then: Giant component is the whole grid at first, and a single vertex at the end.
+        assert_non_increasing(&curve);
+        assert_eq!(curve[0], 1.0);
+        assert_eq!(curve[10], 0.01);
+        assert!(curve[2] > curve[8]);
+    }
+
+    #[test]
+    fn giant_component_shrinks_as_vertices_are_removed() {
+        // Given: Random graph.
+        let graph = RandomGnp::init(60, 0.1).generate(&mut StdRng::seed_from_u64(11));
+        let fractions = [0.0, 0.25, 0.5, 0.75, 1.0];
+
+        // When: Removing more and more of the vertices.
+        let curve = site_percolation_study(&graph, &fractions, 20, 5);
+
+        // Then: Nothing is left at the end.
+        assert_non_increasing(&curve);
+        assert_eq!(curve[4], 0.0);
+        assert!(curve[0] > curve[3]);
+    }
+
+    #[test]
+    fn same_seed_same_curve() {
+        let graph = GridGraphGenerator::init(5, 5).generate();
+        let fractions = [0.3, 0.6];
+
+        assert_eq!(
+            percolation_study(&graph, &fractions, 4, 9),
+            percolation_study(&graph, &fractions, 4, 9)
+        );
+    }
+
+    #[test]
+    fn fractions_are_evaluated_in_given_order() {
+        // Given: Graph
+        //
+        //      a --- b     c
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+
+        // Then:
+        let curve = percolation_study(&graph, &[1.0, 0.0], 3, 2);
+        assert_eq!(curve, vec![1.0 / 3.0, 2.0 / 3.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn fraction_out_of_range() {
+        let graph = GridGraphGenerator::init(2, 2).generate();
+
+        percolation_study(&graph, &[1.5], 1, 0);
+    }
+}
@@ -1,12 +1,14 @@
 #[cfg(test)]
 mod tests {
     use crate::{
-        algo::{ConnectedComponents, TopologicalSort, VertexEdgeCut},
-        graph::SimpleGraph,
+        algo::{ConnectedComponents, Kruskal, Prim, TopologicalSort, VertexEdgeCut},
+        graph::{MatGraph, SimpleGraph},
         prelude::*,
-        storage::AdjMatrix,
+        storage::{AdjMatrix, Mat},
     };
 
+    use quickcheck::TestResult;
+
     use rand::seq::SliceRandom;
 
     #[test]
@@ -128,4 +130,90 @@ mod tests {
             ) -> bool,
         );
     }
+
+    #[test]
+    fn prim_and_kruskal() {
+        // Generated graphs are built from explicit weighted edge lists, so that every
+        // run has edges to span and is often split into several connected components.
+        fn prop(vertex_count: u8, edges: Vec<(u8, u8, i32)>) -> TestResult {
+            fn total_weight<G: Edges<i32, DefaultEdge<i32>>>(graph: &G) -> i64 {
+                graph
+                    .edges()
+                    .into_iter()
+                    .map(|(_, _, edge)| *edge.get_weight().as_ref().unwrap() as i64)
+                    .sum()
+            }
+
+            let vertex_count = vertex_count as usize % 16 + 2;
+            let mut graph = MatGraph::init(Mat::<i32>::init());
+            let vertex_ids: Vec<usize> = (0..vertex_count).map(|_| graph.add_vertex()).collect();
+
+            for (src_index, dst_index, weight) in edges {
+                let src_id = vertex_ids[src_index as usize % vertex_count];
+                let dst_id = vertex_ids[dst_index as usize % vertex_count];
+
+                // No loops and no multiple edges
+                if src_id != dst_id && graph.has_any_edge(src_id, dst_id).is_err() {
+                    graph.add_edge(src_id, dst_id, weight.into()).unwrap();
+                }
+            }
+
+            if graph.edges_count() == 0 {
+                return TestResult::discard();
+            }
+
+            let prim_mst = Prim::init(&graph).execute(&graph);
+            let kruskal_mst = Kruskal::init(&graph).execute(&graph);
+
+            // Forest has one edge less than vertices for each connected component.
+            let ccs_count = ConnectedComponents::init(&graph).execute(&graph).len();
+            assert_eq!(prim_mst.edges_count(), graph.vertex_count() - ccs_count);
+            assert_eq!(prim_mst.edges_count(), kruskal_mst.edges_count());
+
+            TestResult::from_bool(total_weight(&prim_mst) == total_weight(&kruskal_mst))
+        }
+
+        quickcheck::quickcheck(prop as fn(u8, Vec<(u8, u8, i32)>) -> TestResult);
+    }
+
+    #[test]
+    fn prim_and_kruskal_on_disconnected_graph() {
+        // Given: Graph
+        //
+        //      a --(4)-- b        d --(2)-- e
+        //       \       /          \       /
+        //       (1)   (3)          (7)   (5)
+        //         \   /              \   /
+        //           c                  f
+        //
+        let mut graph = MatGraph::init(Mat::<i32>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        graph.add_edge(a, b, 4.into()).unwrap();
+        graph.add_edge(a, c, 1.into()).unwrap();
+        graph.add_edge(b, c, 3.into()).unwrap();
+        graph.add_edge(d, e, 2.into()).unwrap();
+        graph.add_edge(d, f, 7.into()).unwrap();
+        graph.add_edge(e, f, 5.into()).unwrap();
+
+        // When: Performing Prim and Kruskal.
+        let prim_mst = Prim::init(&graph).execute(&graph);
+        let kruskal_mst = Kruskal::init(&graph).execute(&graph);
+
+        // Then: Both span each component with weight 1 + 3 + 2 + 5.
+        for mst in [prim_mst, kruskal_mst].iter() {
+            let total_weight: i32 = mst
+                .edges()
+                .into_iter()
+                .map(|(_, _, edge)| *edge.get_weight().as_ref().unwrap())
+                .sum();
+
+            assert_eq!(mst.edges_count(), 4);
+            assert_eq!(total_weight, 11);
+        }
+    }
 }
@@ -0,0 +1,213 @@
+use crate::algo::utils::degrees_of;
+use crate::graph::{Edge, EdgeDir};
+use crate::provide::{Edges, Graph, Vertices};
+
+/// Computes rich-club coefficient of the graph for degree `k`: density of the subgraph induced by vertices with degree more than `k`.
+///
+/// In directed graphs, degree of a vertex counts both its incoming and outgoing edges.
+///
+/// # Arguments
+/// * `graph`: Graph to compute its rich-club coefficient.
+/// * `k`: Only vertices with degree more than `k` are considered rich.
+///
+/// # Returns
+/// Number of edges between rich vertices divided by the maximum number of edges possible between them.
+/// It is 0 if there are less than two rich vertices.
+///
+/// # Complexity
+/// O(|V| + |E|)
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::rich_club_coefficient;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b --- c --- d
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(c, d, 1.into());
+///
+/// // Then: Only b and c have degree more than 1, and they are connected.
+/// assert_eq!(rich_club_coefficient(&graph, 1), 1.0);
+/// assert_eq!(rich_club_coefficient(&graph, 0), 0.5);
+/// ```
+pub fn rich_club_coefficient<W, E, Dir, G>(graph: &G, k: usize) -> f64
+where
+    E: Edge<W>,
+    Dir: EdgeDir,
+    G: Edges<W, E> + Vertices + Graph<W, E, Dir>,
+{
+    let degree_of = degrees_of(graph);
+
+    let rich_count = degree_of.values().filter(|degree| **degree > k).count();
+    let rich_edges_count = graph
+        .edges()
+        .into_iter()
+        .filter(|(src_id, dst_id, _)| degree_of[src_id] > k && degree_of[dst_id] > k)
+        .count();
+
+    coefficient_of::<Dir>(rich_count, rich_edges_count)
+}
+
+/// Computes rich-club coefficient of the graph for every degree `k` from 0 up to, but not including, the maximum degree.
+///
+/// # Arguments
+/// `graph`: Graph to compute its rich-club curve.
+///
+/// # Returns
+/// Entry at index `k` is the rich-club coefficient for degree `k`, as computed by [`rich_club_coefficient`](crate::algo::rich_club_coefficient).
+///
+/// # Complexity
+/// O(|V| + |E|)
+pub fn rich_club_curve<W, E, Dir, G>(graph: &G) -> Vec<f64>
+where
+    E: Edge<W>,
+    Dir: EdgeDir,
+    G: Edges<W, E> + Vertices + Graph<W, E, Dir>,
+{
+    let degree_of = degrees_of(graph);
+    let max_degree = degree_of.values().copied().max().unwrap_or(0);
+
+    // At first, entry d counts the vertices with degree d, and the edges whose smaller end point degree is d.
+    // Suffix sums then turn entry d into the number of vertices and edges that are rich for degree d - 1.
+    let mut rich_count_of = vec![0; max_degree + 1];
+    let mut rich_edges_count_of = vec![0; max_degree + 1];
+    for degree in degree_of.values() {
+        rich_count_of[*degree] += 1;
+    }
+    for (src_id, dst_id, _) in graph.edges() {
+        rich_edges_count_of[degree_of[&src_id].min(degree_of[&dst_id])] += 1;
+    }
+    for degree in (0..max_degree).rev() {
+        rich_count_of[degree] += rich_count_of[degree + 1];
+        rich_edges_count_of[degree] += rich_edges_count_of[degree + 1];
+    }
+
+    (0..max_degree)
+        .map(|k| coefficient_of::<Dir>(rich_count_of[k + 1], rich_edges_count_of[k + 1]))
+        .collect()
+}
+
+// Returns the number of edges between rich vertices divided by the maximum number of edges possible between them,
+// or 0 if there are less than two rich vertices.
+fn coefficient_of<Dir: EdgeDir>(rich_count: usize, rich_edges_count: usize) -> f64 {
+    if rich_count < 2 {
+        return 0.0;
+    }
+
+    let mut max_edges_count = rich_count * (rich_count - 1);
+    if Dir::is_undirected() {
+        max_edges_count /= 2;
+    }
+
+    rich_edges_count as f64 / max_edges_count as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen::RandomGnp;
+    use crate::graph::MatGraph;
+    use crate::storage::{DiMat, Mat};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        assert_eq!(rich_club_coefficient(&graph, 0), 0.0);
+        assert!(rich_club_curve(&graph).is_empty());
+    }
+
+    #[test]
+    fn densely_connected_hubs() {
+        // Given: Four hubs that form a clique, each with three leaves of its own.
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let hubs: Vec<usize> = (0..4).map(|_| graph.add_vertex()).collect();
+        for (index, src_id) in hubs.iter().enumerate() {
+            for dst_id in hubs.iter().skip(index + 1) {
+                graph.add_edge(*src_id, *dst_id, 1.into()).unwrap();
+            }
+
+            for _ in 0..3 {
+                let leaf = graph.add_vertex();
+                graph.add_edge(*src_id, leaf, 1.into()).unwrap();
+            }
+        }
+
+        // When: Computing the coefficients.
+        let curve = rich_club_curve(&graph);
+
+        // Then: Hubs(degree 6) are fully connected, while considering leaves(degree 1) makes it sparse.
+        assert_eq!(curve.len(), 6);
+        assert_eq!(rich_club_coefficient(&graph, 5), 1.0);
+        assert_eq!(rich_club_coefficient(&graph, 3), 1.0);
+        assert_eq!(rich_club_coefficient(&graph, 0), 18.0 / (16.0 * 15.0 / 2.0));
+        assert_eq!(curve[1..], [1.0; 5]);
+        assert_eq!(curve[0], rich_club_coefficient(&graph, 0));
+    }
+
+    #[test]
+    fn coefficient_above_max_degree() {
+        // Given: Graph
+        //
+        //      a --- b
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+
+        assert_eq!(rich_club_coefficient(&graph, 0), 1.0);
+        assert_eq!(rich_club_coefficient(&graph, 1), 0.0);
+        assert_eq!(rich_club_curve(&graph), vec![1.0]);
+    }
+
+    #[test]
+    fn directed_graph() {
+        // Given: Graph
+        //
+        //      a --> b --> c
+        //      ^           |
+        //      '-----------'
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, a, 1.into()).unwrap();
+
+        // Then: 3 of the 6 possible directed edges are present.
+        assert_eq!(rich_club_coefficient(&graph, 1), 0.5);
+    }
+
+    #[test]
+    fn curve_matches_coefficients() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..5 {
+            // Given: Random graph.
+            let graph = RandomGnp::init(40, 0.2).generate(&mut rng);
+
+            // When: Computing the curve.
+            let curve = rich_club_curve(&graph);
+
+            // Then: Each entry is the coefficient of its degree.
+            assert!(!curve.is_empty());
+            for (k, coefficient) in curve.iter().enumerate() {
+                assert_eq!(*coefficient, rich_club_coefficient(&graph, k));
+            }
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::graph::Edge;
+use crate::provide::Edges;
+
+/// Finds a cycle with minimum number of edges that passes through `vertex_id`.
+///
+/// For each edge from `vertex_id` to a neighbor, finds the shortest path from the neighbor back to `vertex_id` without using that edge.
+/// The edge and the path back form a cycle, and the shortest of these cycles is returned.
+/// Weights of the edges are ignored.
+///
+/// # Arguments
+/// * `graph`: Graph to search in.
+/// * `vertex_id`: Id of the vertex that cycle must pass through.
+///
+/// # Returns
+/// * `Some`: Containing vertices of the cycle in order, starting with `vertex_id`. Last vertex is connected back to `vertex_id`, which is not repeated at the end.
+/// * `None`: If no cycle passes through `vertex_id`.
+///
+/// # Complexity
+/// O(d * (|V| + |E|)) where d is the number of edges from `vertex_id`.
+///
+/// # Panics
+/// If vertex with id: `vertex_id` does not exist in the graph.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::shortest_cycle_through;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b --- c
+/// //       \   /
+/// //         d
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(b, d, 1.into());
+/// graph.add_edge(d, a, 1.into());
+///
+/// // Then:
+/// assert_eq!(shortest_cycle_through(&graph, a).unwrap().len(), 3);
+/// assert!(shortest_cycle_through(&graph, c).is_none());
+/// ```
+pub fn shortest_cycle_through<W, E, G>(graph: &G, vertex_id: usize) -> Option<Vec<usize>>
+where
+    E: Edge<W>,
+    G: Edges<W, E>,
+{
+    let mut shortest: Option<Vec<usize>> = None;
+
+    for (n_id, edge) in graph.edges_from(vertex_id).unwrap() {
+        let path_back = match shortest_path_without(graph, n_id, vertex_id, edge.get_id()) {
+            Some(path_back) => path_back,
+            None => continue,
+        };
+
+        // Path back ends with `vertex_id`, so it is moved to the start of the cycle.
+        let mut cycle = vec![vertex_id];
+        cycle.extend(path_back.iter().take(path_back.len() - 1));
+
+        if shortest
+            .as_ref()
+            .map_or(true, |shortest| cycle.len() < shortest.len())
+        {
+            shortest = Some(cycle);
+        }
+    }
+
+    shortest
+}
+
+// Finds a path with minimum number of edges from `src_id` to `dst_id` that does not use the edge with id: `excluded_edge_id`.
+// Returned path starts with `src_id` and ends with `dst_id`.
+fn shortest_path_without<W, E, G>(
+    graph: &G,
+    src_id: usize,
+    dst_id: usize,
+    excluded_edge_id: usize,
+) -> Option<Vec<usize>>
+where
+    E: Edge<W>,
+    G: Edges<W, E>,
+{
+    let mut parent_of: HashMap<usize, Option<usize>> = HashMap::new();
+    parent_of.insert(src_id, None);
+    let mut queue = VecDeque::from(vec![src_id]);
+
+    while let Some(vertex_id) = queue.pop_front() {
+        if vertex_id == dst_id {
+            let mut path = vec![dst_id];
+            while let Some(Some(parent_id)) = parent_of.get(path.last().unwrap()) {
+                path.push(*parent_id);
+            }
+            path.reverse();
+
+            return Some(path);
+        }
+
+        for (n_id, edge) in graph.edges_from(vertex_id).unwrap() {
+            if edge.get_id() != excluded_edge_id && !parent_of.contains_key(&n_id) {
+                parent_of.insert(n_id, Some(vertex_id));
+                queue.push_back(n_id);
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::provide::Graph;
+    use crate::storage::{DiMat, Mat};
+
+    // Checks that consecutive vertices of the cycle are connected, and the last one is connected to the first one.
+    fn assert_is_cycle<G: Edges<usize, E>, E: Edge<usize>>(graph: &G, cycle: &[usize]) {
+        for (index, src_id) in cycle.iter().enumerate() {
+            let dst_id = cycle[(index + 1) % cycle.len()];
+
+            assert!(graph.has_any_edge(*src_id, dst_id).unwrap());
+        }
+    }
+
+    #[test]
+    fn tree_has_no_cycle() {
+        // Given: Graph
+        //
+        //      a --- b --- c
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+
+        for vertex_id in [a, b, c].iter() {
+            assert!(shortest_cycle_through(&graph, *vertex_id).is_none());
+        }
+    }
+
+    #[test]
+    fn cycles_of_different_lengths() {
+        // Given: Graph in which a is on a cycle of length 6 and a cycle of length 4.
+        // There is also a triangle that a is not on.
+        //
+        //      b --- c --- d
+        //      |            \
+        //      a --- f ----- e
+        //      |     |
+        //      g --- h --- i
+        //             \   /
+        //               j
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        let g = graph.add_vertex();
+        let h = graph.add_vertex();
+        let i = graph.add_vertex();
+        let j = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+        graph.add_edge(d, e, 1.into()).unwrap();
+        graph.add_edge(e, f, 1.into()).unwrap();
+        graph.add_edge(f, a, 1.into()).unwrap();
+        graph.add_edge(a, g, 1.into()).unwrap();
+        graph.add_edge(g, h, 1.into()).unwrap();
+        graph.add_edge(h, f, 1.into()).unwrap();
+        graph.add_edge(h, i, 1.into()).unwrap();
+        graph.add_edge(i, j, 1.into()).unwrap();
+        graph.add_edge(j, h, 1.into()).unwrap();
+
+        // When: Finding shortest cycle through a.
+        let cycle = shortest_cycle_through(&graph, a).unwrap();
+
+        // Then: a -> g -> h -> f -> a(or the reverse).
+        assert_eq!(cycle.len(), 4);
+        assert_eq!(cycle[0], a);
+        assert_is_cycle(&graph, &cycle);
+        let mut vertices = cycle.clone();
+        vertices.sort_unstable();
+        assert_eq!(vertices, vec![a, f, g, h]);
+
+        // Shortest cycle through h is the triangle.
+        let cycle = shortest_cycle_through(&graph, h).unwrap();
+        assert_eq!(cycle.len(), 3);
+        assert_is_cycle(&graph, &cycle);
+    }
+
+    #[test]
+    fn directed_cycles() {
+        // Given: Graph
+        //
+        //      a --> b --> c --> d
+        //      ^     |           |
+        //      |     v           |
+        //      '---- e <---------'
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+        graph.add_edge(d, e, 1.into()).unwrap();
+        graph.add_edge(b, e, 1.into()).unwrap();
+        graph.add_edge(e, a, 1.into()).unwrap();
+
+        // Then: Directions of the edges are respected.
+        assert_eq!(shortest_cycle_through(&graph, a).unwrap(), vec![a, b, e]);
+        assert_eq!(
+            shortest_cycle_through(&graph, d).unwrap(),
+            vec![d, e, a, b, c]
+        );
+    }
+}
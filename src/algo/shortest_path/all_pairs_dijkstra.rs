@@ -0,0 +1,308 @@
+use magnitude::Magnitude;
+use num_traits::{Unsigned, Zero};
+use std::any::Any;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::graph::Edge;
+use crate::provide::{Edges, IdMap, Vertices};
+
+/// Finds shortest path from all vertices to all the other ones by running dijkstra algorithm from every vertex.
+///
+/// Unlike calling [`Dijkstra`](crate::algo::Dijkstra) once per source, adjacency of the graph is collected once,
+/// and the same distance array and heap are reused for all the sources. So no allocation happens per source.
+///
+/// # Arguments
+/// `graph`: Graph to search for the shortest paths in. Edges with infinite weight are ignored.
+///
+/// # Returns
+/// Shortest path information in the form of: (src_id, dst_id) -> distance, which is `PosInfinite` if `dst_id` is not reachable from `src_id`.
+///
+/// # Complexity
+/// O(|V|(|V| + |E|)log(|V|))
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::DiMat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::all_pairs_dijkstra;
+///
+/// // Given: Graph
+/// //
+/// //      a --1--> b --2--> c
+/// //      |                 ^
+/// //      '--------4--------'
+/// //
+/// let mut graph = MatGraph::init(DiMat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 2.into());
+/// graph.add_edge(a, c, 4.into());
+///
+/// // When: Finding all the shortest distances.
+/// let distance_map = all_pairs_dijkstra(&graph);
+///
+/// // Then:
+/// assert_eq!(distance_map.len(), 9);
+/// assert_eq!(distance_map[&(a, c)], 3.into());
+/// assert_eq!(distance_map[&(b, b)], 0.into());
+/// assert!(distance_map[&(c, a)].is_pos_infinite());
+/// ```
+pub fn all_pairs_dijkstra<W, E, G>(graph: &G) -> HashMap<(usize, usize), Magnitude<W>>
+where
+    W: Copy + Ord + Zero + Any + Unsigned,
+    E: Edge<W>,
+    G: Edges<W, E> + Vertices,
+{
+    let vertex_count = graph.vertex_count();
+    let id_map = graph.continuos_id_map();
+
+    let adjacency = finite_adjacency(graph, &id_map);
+
+    let mut distance_map = HashMap::with_capacity(vertex_count * vertex_count);
+
+    let mut dist: Vec<Option<W>> = vec![None; vertex_count];
+    let mut heap = BinaryHeap::with_capacity(vertex_count);
+    for src_virt_id in 0..vertex_count {
+        dijkstra_from(
+            &adjacency,
+            src_virt_id,
+            &mut dist,
+            &mut heap,
+            |_, _, weight| weight,
+        );
+
+        let src_id = id_map.real_id_of(src_virt_id);
+        for (dst_virt_id, distance) in dist.iter().enumerate() {
+            let distance = distance.map_or(Magnitude::PosInfinite, |d| d.into());
+
+            distance_map.insert((src_id, id_map.real_id_of(dst_virt_id)), distance);
+        }
+    }
+
+    distance_map
+}
+
+// Maps each vertex(by virtual id) to (virtual id, weight) of the edges going out of it.
+// Edges with infinite weight are left out.
+pub(crate) fn finite_adjacency<W, E, G>(graph: &G, id_map: &IdMap) -> Vec<Vec<(usize, W)>>
+where
+    W: Copy,
+    E: Edge<W>,
+    G: Edges<W, E> + Vertices,
+{
+    (0..graph.vertex_count())
+        .map(|virt_id| {
+            graph
+                .edges_from(id_map.real_id_of(virt_id))
+                .unwrap()
+                .into_iter()
+                .filter_map(|(dst_id, edge)| {
+                    edge.get_weight()
+                        .as_ref()
+                        .map(|weight| (id_map.virt_id_of(dst_id), *weight))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// Runs dijkstra from `src_virt_id` and leaves distance of each vertex in `dist`, which is `None` for unreachable vertices.
+// `dist` and `heap` are only passed in to be reused across sources, and their previous content is discarded.
+// `cost` maps (virtual id of source, virtual id of destination, weight) of each edge to the non-negative cost that is used for it.
+pub(crate) fn dijkstra_from<W, F>(
+    adjacency: &[Vec<(usize, W)>],
+    src_virt_id: usize,
+    dist: &mut [Option<W>],
+    heap: &mut BinaryHeap<Reverse<(W, usize)>>,
+    cost: F,
+) where
+    W: Copy + Ord + Zero,
+    F: Fn(usize, usize, W) -> W,
+{
+    dist.iter_mut().for_each(|distance| *distance = None);
+    heap.clear();
+
+    dist[src_virt_id] = Some(W::zero());
+    heap.push(Reverse((W::zero(), src_virt_id)));
+
+    while let Some(Reverse((distance, virt_id))) = heap.pop() {
+        // Skip stale entries of vertices that got a shorter distance after being pushed.
+        if dist[virt_id].map_or(false, |d| d < distance) {
+            continue;
+        }
+
+        for (n_virt_id, weight) in adjacency[virt_id].iter().copied() {
+            let alt = distance + cost(virt_id, n_virt_id, weight);
+            if dist[n_virt_id].map_or(true, |d| alt < d) {
+                dist[n_virt_id] = Some(alt);
+                heap.push(Reverse((alt, n_virt_id)));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::{Dijkstra, FloydWarshall};
+    use crate::graph::{DefaultEdge, MatGraph, SimpleGraph};
+    use crate::provide::Graph;
+    use crate::storage::{DiList, Mat};
+    use std::time::Instant;
+
+    // Infinite magnitudes can not be compared, so they are checked separately.
+    fn assert_same_distances(
+        distance_map: &HashMap<(usize, usize), Magnitude<usize>>,
+        expected: &HashMap<(usize, usize), Magnitude<usize>>,
+    ) {
+        assert_eq!(distance_map.len(), expected.len());
+        for (pair, distance) in expected {
+            match distance {
+                Magnitude::Finite(_) => assert_eq!(distance_map[pair], *distance),
+                _ => assert!(distance_map[pair].is_pos_infinite()),
+            }
+        }
+    }
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        assert!(all_pairs_dijkstra(&graph).is_empty());
+    }
+
+    #[test]
+    fn matches_floyd_warshall() {
+        // Given: Graph
+        //
+        //      a --4-- b --1-- c
+        //      |     / |       |
+        //      1   2   7       3
+        //      | /     |       |
+        //      d --9-- e --1-- f       g
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        graph.add_vertex();
+        graph.add_edge(a, b, 4.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(a, d, 1.into()).unwrap();
+        graph.add_edge(b, d, 2.into()).unwrap();
+        graph.add_edge(b, e, 7.into()).unwrap();
+        graph.add_edge(c, f, 3.into()).unwrap();
+        graph.add_edge(d, e, 9.into()).unwrap();
+        graph.add_edge(e, f, 1.into()).unwrap();
+        graph
+            .add_edge(a, f, DefaultEdge::init(Magnitude::PosInfinite))
+            .unwrap();
+
+        // When: Removing a vertex so ids are not continuous.
+        graph.remove_vertex(a).unwrap();
+
+        // Then:
+        let distance_map = all_pairs_dijkstra(&graph);
+        assert_same_distances(
+            &distance_map,
+            &FloydWarshall::init().execute(&graph).unwrap(),
+        );
+        assert_eq!(distance_map[&(d, f)], 6.into());
+    }
+
+    #[test]
+    fn matches_repeated_dijkstra() {
+        // Given: Graph
+        //
+        //      a --3--> b --1--> c
+        //      ^        |        |
+        //      2        5        1
+        //      |        v        v
+        //      e <--1-- d <--1-- f
+        //
+        let mut graph = SimpleGraph::init(DiList::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        graph.add_edge(a, b, 3.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(b, d, 5.into()).unwrap();
+        graph.add_edge(c, f, 1.into()).unwrap();
+        graph.add_edge(f, d, 1.into()).unwrap();
+        graph.add_edge(d, e, 1.into()).unwrap();
+        graph.add_edge(e, a, 2.into()).unwrap();
+
+        // When: Finding all the shortest distances.
+        let distance_map = all_pairs_dijkstra(&graph);
+
+        // Then:
+        for src_id in [a, b, c, d, e, f] {
+            let sp_subgraph = Dijkstra::init(&graph).execute(&graph, src_id);
+
+            for dst_id in [a, b, c, d, e, f] {
+                assert_eq!(
+                    distance_map[&(src_id, dst_id)],
+                    sp_subgraph.distance_to(dst_id).unwrap()
+                );
+            }
+        }
+        assert_eq!(distance_map[&(b, d)], 3.into());
+    }
+
+    // Benchmark of reusing the distance array and heap across sources.
+    // Run with `cargo test --release -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn faster_than_repeated_dijkstra() {
+        // Given: Sparse random graph with 500 vertices and about 5 edges going out of each vertex.
+        let mut graph = SimpleGraph::init(DiList::<usize>::init());
+        let vertex_ids: Vec<usize> = (0..500).map(|_| graph.add_vertex()).collect();
+        for src_id in &vertex_ids {
+            for dst_id in &vertex_ids {
+                if src_id != dst_id && rand::random::<f64>() < 0.01 {
+                    let weight = rand::random::<usize>() % 100 + 1;
+                    graph.add_edge(*src_id, *dst_id, weight.into()).unwrap();
+                }
+            }
+        }
+
+        // When: Timing both approaches.
+        let start = Instant::now();
+        let distance_map = all_pairs_dijkstra(&graph);
+        let all_pairs_elapsed = start.elapsed();
+
+        let start = Instant::now();
+        let sp_subgraphs: Vec<_> = vertex_ids
+            .iter()
+            .map(|src_id| Dijkstra::init(&graph).execute(&graph, *src_id))
+            .collect();
+        let repeated_elapsed = start.elapsed();
+
+        println!(
+            "all_pairs_dijkstra: {:?}, repeated Dijkstra: {:?}",
+            all_pairs_elapsed, repeated_elapsed
+        );
+
+        // Then: Both find the same distances.
+        for (src_id, sp_subgraph) in vertex_ids.iter().zip(sp_subgraphs) {
+            for dst_id in &vertex_ids {
+                match sp_subgraph.distance_to(*dst_id) {
+                    Some(Magnitude::Finite(distance)) => {
+                        assert_eq!(distance_map[&(*src_id, *dst_id)], distance.into())
+                    }
+                    _ => assert!(distance_map[&(*src_id, *dst_id)].is_pos_infinite()),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,349 @@
+use magnitude::Magnitude;
+use num_traits::{Unsigned, Zero};
+use std::any::Any;
+
+use crate::graph::{Edge, EdgeDir};
+use crate::provide::{Edges, Graph, IdMap, Vertices};
+
+/// Landmark based heuristic(ALT) for [`AStar`](crate::algo::AStar).
+///
+/// Distances from and to a few landmark vertices are computed beforehand.
+/// Then because of triangle inequality, for every landmark L:
+/// * d(v, t) >= d(L, t) - d(L, v)
+/// * d(v, t) >= d(v, L) - d(t, L)
+///
+/// So the largest of these bounds is an estimate of d(v, t) that never overestimates it.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::{AStar, AltHeuristic};
+///
+/// // Given: Graph
+/// //
+/// //      a --1-- b --2-- c --3-- d
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 2.into());
+/// graph.add_edge(c, d, 3.into());
+///
+/// // When: Using a as the only landmark.
+/// let alt = AltHeuristic::init(&graph, 1);
+///
+/// // Then: Estimates are exact on a path graph.
+/// assert_eq!(alt.landmarks(), vec![a]);
+/// assert_eq!(alt.estimate(b, d), 5.into());
+///
+/// let (weight, path) = AStar::init(&graph)
+///     .execute(&graph, b, d, alt.heuristic_to(d))
+///     .unwrap();
+/// assert_eq!(weight, 5.into());
+/// assert_eq!(path, vec![b, c, d]);
+/// ```
+pub struct AltHeuristic<W> {
+    id_map: IdMap,
+
+    // Landmarks by virtual id.
+    landmarks: Vec<usize>,
+
+    // Distance from each landmark to every vertex(by virtual id).
+    from_landmark: Vec<Vec<Magnitude<W>>>,
+
+    // Distance from every vertex(by virtual id) to each landmark.
+    to_landmark: Vec<Vec<Magnitude<W>>>,
+}
+
+impl<W: Copy + Ord + Zero + Any + Unsigned> AltHeuristic<W> {
+    /// Picks the landmarks and computes distances from and to them.
+    ///
+    /// First landmark is the vertex with the smallest virtual id. Each next landmark is the vertex that is farthest from the already picked ones.
+    ///
+    /// # Arguments
+    /// * `graph`: Graph to compute the heuristic for.
+    /// * `landmark_count`: Number of landmarks to pick. More landmarks give better estimates but need more memory and preprocessing.
+    ///
+    /// # Complexity
+    /// O(`landmark_count` * |V|<sup>2</sup>)
+    pub fn init<E, Ty, G>(graph: &G, landmark_count: usize) -> Self
+    where
+        E: Edge<W>,
+        Ty: EdgeDir,
+        G: Edges<W, E> + Vertices + Graph<W, E, Ty>,
+    {
+        let vertex_count = graph.vertex_count();
+        let id_map = graph.continuos_id_map();
+
+        // Edges going out of and into each vertex, in the format of (neighbor_virt_id, weight).
+        let mut out_edges = vec![vec![]; vertex_count];
+        let mut in_edges = vec![vec![]; vertex_count];
+        for src_id in graph.vertices() {
+            let src_virt_id = id_map.virt_id_of(src_id);
+
+            for (dst_id, edge) in graph.edges_from(src_id).unwrap() {
+                let dst_virt_id = id_map.virt_id_of(dst_id);
+                if edge.get_weight().is_finite() {
+                    out_edges[src_virt_id].push((dst_virt_id, *edge.get_weight()));
+                    in_edges[dst_virt_id].push((src_virt_id, *edge.get_weight()));
+                }
+            }
+        }
+
+        let mut landmarks = vec![];
+        let mut from_landmark = vec![];
+        let mut to_landmark = vec![];
+
+        // Distance of each vertex to the closest picked landmark.
+        let mut closest_dist = vec![Magnitude::PosInfinite; vertex_count];
+        while landmarks.len() < landmark_count.min(vertex_count) {
+            let landmark = Self::farthest_vertex(&landmarks, &closest_dist);
+
+            let dist = Self::distances(&out_edges, landmark);
+            for (virt_id, dist) in dist.iter().enumerate() {
+                if dist.is_finite()
+                    && (closest_dist[virt_id].is_pos_infinite() || *dist < closest_dist[virt_id])
+                {
+                    closest_dist[virt_id] = *dist;
+                }
+            }
+
+            landmarks.push(landmark);
+            from_landmark.push(dist);
+            to_landmark.push(Self::distances(&in_edges, landmark));
+        }
+
+        AltHeuristic {
+            id_map,
+            landmarks,
+            from_landmark,
+            to_landmark,
+        }
+    }
+
+    /// # Returns
+    /// Id of the picked landmarks.
+    pub fn landmarks(&self) -> Vec<usize> {
+        self.landmarks
+            .iter()
+            .map(|virt_id| self.id_map.real_id_of(*virt_id))
+            .collect()
+    }
+
+    /// # Arguments
+    /// * `vertex_id`: Id of the vertex to estimate its distance to `dst_id`.
+    /// * `dst_id`: Id of the destination vertex.
+    ///
+    /// # Returns
+    /// A lower bound of the distance from `vertex_id` to `dst_id`.
+    ///
+    /// # Panics
+    /// If either of vertices is not in the graph that structure is initialized with.
+    pub fn estimate(&self, vertex_id: usize, dst_id: usize) -> Magnitude<W> {
+        let virt_id = self.id_map.virt_id_of(vertex_id);
+        let dst_virt_id = self.id_map.virt_id_of(dst_id);
+
+        // Returns a - b if both are finite and a > b.
+        let bound = |a: Magnitude<W>, b: Magnitude<W>| {
+            if a.is_finite() && b.is_finite() && a > b {
+                Some(a - b)
+            } else {
+                None
+            }
+        };
+
+        (0..self.landmarks.len())
+            .flat_map(|index| {
+                let from = &self.from_landmark[index];
+                let to = &self.to_landmark[index];
+
+                vec![
+                    bound(from[dst_virt_id], from[virt_id]),
+                    bound(to[virt_id], to[dst_virt_id]),
+                ]
+            })
+            .flatten()
+            .max()
+            .unwrap_or_else(|| W::zero().into())
+    }
+
+    /// # Arguments
+    /// `dst_id`: Id of the destination vertex.
+    ///
+    /// # Returns
+    /// Heuristic to pass to [`AStar::execute`](crate::algo::AStar::execute) when searching for a path to `dst_id`.
+    pub fn heuristic_to(&self, dst_id: usize) -> impl Fn(usize) -> Magnitude<W> + '_ {
+        move |vertex_id| self.estimate(vertex_id, dst_id)
+    }
+
+    // Returns the vertex with the largest distance to its closest landmark.
+    // Vertices that are not reachable from any landmark are the farthest ones.
+    fn farthest_vertex(landmarks: &[usize], closest_dist: &[Magnitude<W>]) -> usize {
+        let mut farthest: Option<usize> = None;
+
+        for virt_id in (0..closest_dist.len()).filter(|virt_id| !landmarks.contains(virt_id)) {
+            let is_farther = match farthest {
+                None => true,
+                Some(farthest_id) => {
+                    let (dist, farthest_dist) = (closest_dist[virt_id], closest_dist[farthest_id]);
+
+                    !farthest_dist.is_pos_infinite()
+                        && (dist.is_pos_infinite() || dist > farthest_dist)
+                }
+            };
+
+            if is_farther {
+                farthest = Some(virt_id);
+            }
+        }
+
+        farthest.unwrap()
+    }
+
+    // Dijkstra from `src_virt_id` over `edges`.
+    fn distances(edges: &[Vec<(usize, Magnitude<W>)>], src_virt_id: usize) -> Vec<Magnitude<W>> {
+        let mut visited = vec![false; edges.len()];
+        let mut dist = vec![Magnitude::PosInfinite; edges.len()];
+        dist[src_virt_id] = W::zero().into();
+
+        while let Some(virt_id) = dist
+            .iter()
+            .enumerate()
+            .filter(|(virt_id, dist)| dist.is_finite() && !visited[*virt_id])
+            .min_by(|(_, dist1), (_, dist2)| dist1.cmp(dist2))
+            .map(|(virt_id, _)| virt_id)
+        {
+            visited[virt_id] = true;
+
+            for (n_virt_id, weight) in &edges[virt_id] {
+                let alt = dist[virt_id] + *weight;
+                if dist[*n_virt_id].is_pos_infinite() || alt < dist[*n_virt_id] {
+                    dist[*n_virt_id] = alt;
+                }
+            }
+        }
+
+        dist
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::algo::{AStar, Dijkstra};
+    use crate::graph::{MatGraph, UndirectedEdge};
+    use crate::storage::Mat;
+
+    // Computes the exact distance using dijkstra.
+    fn expected_distance(
+        graph: &MatGraph<usize, UndirectedEdge>,
+        src_id: usize,
+        dst_id: usize,
+    ) -> Magnitude<usize> {
+        Dijkstra::init(graph)
+            .execute(graph, src_id)
+            .distance_to(dst_id)
+            .unwrap()
+    }
+
+    #[test]
+    fn alt_a_star_matches_dijkstra_and_explores_less() {
+        // Given: A 10 x 10 grid with weighted edges between neighboring cells.
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let cells: Vec<Vec<usize>> = (0..10)
+            .map(|_| (0..10).map(|_| graph.add_vertex()).collect())
+            .collect();
+        for row in 0..10 {
+            for col in 0..10 {
+                if col + 1 < 10 {
+                    let weight = (row * 7 + col * 3) % 5 + 1;
+                    graph
+                        .add_edge(cells[row][col], cells[row][col + 1], weight.into())
+                        .unwrap();
+                }
+                if row + 1 < 10 {
+                    let weight = (row * 3 + col * 7) % 5 + 1;
+                    graph
+                        .add_edge(cells[row][col], cells[row + 1][col], weight.into())
+                        .unwrap();
+                }
+            }
+        }
+        let alt = AltHeuristic::init(&graph, 4);
+        let queries = [
+            (cells[0][0], cells[9][9]),
+            (cells[9][0], cells[0][9]),
+            (cells[2][3], cells[8][7]),
+            (cells[5][5], cells[0][1]),
+        ];
+
+        let mut alt_explored = 0;
+        let mut dijkstra_explored = 0;
+        for (src_id, dst_id) in queries.iter().copied() {
+            // When: Performing A* with ALT heuristic and with a zero heuristic.
+            let alt_reached = RefCell::new(HashSet::new());
+            let (alt_weight, alt_path) = AStar::init(&graph)
+                .execute(&graph, src_id, dst_id, |vertex_id| {
+                    alt_reached.borrow_mut().insert(vertex_id);
+                    alt.estimate(vertex_id, dst_id)
+                })
+                .unwrap();
+
+            let zero_reached = RefCell::new(HashSet::new());
+            AStar::init(&graph)
+                .execute(&graph, src_id, dst_id, |vertex_id| {
+                    zero_reached.borrow_mut().insert(vertex_id);
+                    0.into()
+                })
+                .unwrap();
+
+            alt_explored += alt_reached.borrow().len();
+            dijkstra_explored += zero_reached.borrow().len();
+
+            // Then:
+            assert_eq!(alt_weight, expected_distance(&graph, src_id, dst_id));
+            assert_eq!(alt_path.first(), Some(&src_id));
+            assert_eq!(alt_path.last(), Some(&dst_id));
+            for vertex_id in graph.vertices() {
+                assert!(
+                    alt.estimate(vertex_id, dst_id) <= expected_distance(&graph, vertex_id, dst_id)
+                );
+            }
+        }
+
+        assert!(alt_explored < dijkstra_explored);
+    }
+
+    #[test]
+    fn disconnected_graph() {
+        // Given: Graph
+        //
+        //      a --1-- b       c --1-- d
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+
+        // When: Picking two landmarks.
+        let alt = AltHeuristic::init(&graph, 2);
+
+        // Then: Second landmark is in the other component.
+        assert_eq!(alt.landmarks().len(), 2);
+        assert!(alt.landmarks().contains(&a));
+        assert!(alt.landmarks().iter().any(|id| *id == c || *id == d));
+        assert_eq!(alt.estimate(a, c), 0.into());
+        assert_eq!(alt.estimate(a, b), 1.into());
+    }
+}
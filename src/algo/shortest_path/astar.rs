@@ -0,0 +1,339 @@
+use magnitude::Magnitude;
+use num_traits::{Unsigned, Zero};
+use std::any::Any;
+
+use crate::graph::{Edge, EdgeDir};
+use crate::provide::{Edges, Graph, Neighbors, Vertices};
+
+/// Finds shortest path between two vertices using A* algorithm.
+///
+/// A* is dijkstra algorithm that visits vertices in the order of their distance from the source
+/// plus an estimate of their remaining distance to the destination, given by a heuristic.
+/// If heuristic never overestimates the remaining distance, the found path is the shortest one.
+/// With a heuristic that always returns zero, A* is the same as dijkstra.
+///
+/// Heuristic must also be consistent: estimate of a vertex must not exceed weight of an edge from it plus estimate of the other end of the edge.
+/// Otherwise a vertex may be reached by a shorter path after it is visited, which is caught by a panic in debug builds.
+/// Admissibility itself is not checked: a heuristic that overestimates the remaining distance without breaking
+/// consistency on any visited edge silently returns a path that may not be the shortest one.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::AStar;
+///
+/// // Given: Graph
+/// //
+/// //      a --1-- b --1-- c
+/// //       \             /
+/// //        '-----3-----'
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(a, c, 3.into());
+///
+/// // When: Performing A* with a heuristic that estimates every remaining distance as 0.
+/// let (weight, path) = AStar::init(&graph)
+///     .execute(&graph, a, c, |_| 0.into())
+///     .unwrap();
+///
+/// // Then:
+/// assert_eq!(weight, 2.into());
+/// assert_eq!(path, vec![a, b, c]);
+/// ```
+pub struct AStar<W> {
+    visited: Vec<bool>,
+    dist: Vec<Magnitude<W>>,
+    estimate: Vec<Magnitude<W>>,
+    prev: Vec<Option<usize>>,
+}
+
+impl<W: Copy + Ord + Zero + Any + Unsigned> AStar<W> {
+    /// Initializes the structure.
+    pub fn init<E, Ty, G>(graph: &G) -> Self
+    where
+        E: Edge<W>,
+        Ty: EdgeDir,
+        G: Edges<W, E> + Vertices + Graph<W, E, Ty>,
+    {
+        let vertex_count = graph.vertex_count();
+
+        AStar {
+            visited: vec![false; vertex_count],
+            dist: vec![Magnitude::PosInfinite; vertex_count],
+            estimate: vec![Magnitude::PosInfinite; vertex_count],
+            prev: vec![None; vertex_count],
+        }
+    }
+
+    // Returns the vertex that is not visited yet and has the minimum distance plus estimate.
+    fn next_id(&self) -> Option<usize> {
+        self.dist
+            .iter()
+            .enumerate()
+            .filter(|(virt_id, dist)| dist.is_finite() && !self.visited[*virt_id])
+            .map(|(virt_id, dist)| (virt_id, *dist + self.estimate[virt_id]))
+            .filter(|(_, score)| score.is_finite())
+            .min_by(|(_, score1), (_, score2)| score1.cmp(score2))
+            .map(|(virt_id, _)| virt_id)
+    }
+
+    /// Finds shortest path from source to destination.
+    ///
+    /// # Arguments
+    /// * `graph`: Graph to search for the shortest path in.
+    /// * `src_id`: Id of the source vertex.
+    /// * `dst_id`: Id of the destination vertex.
+    /// * `heuristic`: Maps id of each vertex to an estimate of its distance to `dst_id`.
+    ///   It gets called at most once for each vertex, when the vertex gets reached for the first time.
+    ///   Vertices estimated as `PosInfinite` are considered unable to reach `dst_id` and never get visited.
+    ///
+    /// # Panics
+    /// In debug builds, if `heuristic` is inconsistent and a vertex gets a shorter distance after it is visited.
+    ///
+    /// # Returns
+    /// * `Some`: Containing weight of the path alongside id of vertices on the path, starting from `src_id` and ending with `dst_id`.
+    /// * `None`: If `dst_id` is not reachable from `src_id`.
+    ///
+    /// # Complexity
+    /// O(|V|<sup>2</sup> + |E|)
+    pub fn execute<E, Ty, G, F>(
+        mut self,
+        graph: &G,
+        src_id: usize,
+        dst_id: usize,
+        heuristic: F,
+    ) -> Option<(Magnitude<W>, Vec<usize>)>
+    where
+        E: Edge<W>,
+        Ty: EdgeDir,
+        G: Edges<W, E> + Neighbors + Vertices + Graph<W, E, Ty>,
+        F: Fn(usize) -> Magnitude<W>,
+    {
+        let id_map = graph.continuos_id_map();
+
+        let src_virt_id = id_map.virt_id_of(src_id);
+        let dst_virt_id = id_map.virt_id_of(dst_id);
+
+        self.dist[src_virt_id] = W::zero().into();
+        self.estimate[src_virt_id] = heuristic(src_id);
+
+        while let Some(virt_id) = self.next_id() {
+            if virt_id == dst_virt_id {
+                break;
+            }
+
+            self.visited[virt_id] = true;
+
+            let real_id = id_map.real_id_of(virt_id);
+            for (n_id, edge) in graph.edges_from(real_id).unwrap() {
+                let n_virt_id = id_map.virt_id_of(n_id);
+                if !edge.get_weight().is_finite() {
+                    continue;
+                }
+
+                let alt = self.dist[virt_id] + *edge.get_weight();
+                if self.visited[n_virt_id] {
+                    // With a consistent heuristic, distance of a visited vertex is final.
+                    debug_assert!(
+                        alt >= self.dist[n_virt_id],
+                        "Heuristic is inconsistent: vertex with id: {} got relaxed after being visited",
+                        n_id
+                    );
+                    continue;
+                }
+
+                if self.dist[n_virt_id].is_pos_infinite() {
+                    self.estimate[n_virt_id] = heuristic(n_id);
+                }
+                if self.dist[n_virt_id].is_pos_infinite() || alt < self.dist[n_virt_id] {
+                    self.dist[n_virt_id] = alt;
+                    self.prev[n_virt_id] = Some(virt_id);
+                }
+            }
+        }
+
+        if self.dist[dst_virt_id].is_pos_infinite() {
+            return None;
+        }
+
+        let mut path = vec![dst_id];
+        let mut virt_id = dst_virt_id;
+        while let Some(prev_virt_id) = self.prev[virt_id] {
+            path.push(id_map.real_id_of(prev_virt_id));
+            virt_id = prev_virt_id;
+        }
+        path.reverse();
+
+        Some((self.dist[dst_virt_id], path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::Dijkstra;
+    use crate::gen::GridGraphGenerator;
+    use crate::graph::MatGraph;
+    use crate::storage::{DiMat, Mat};
+    use std::collections::HashMap;
+
+    #[test]
+    fn unreachable_destination() {
+        // Given: Graph
+        //
+        //      a --> b     c
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+
+        assert!(AStar::init(&graph)
+            .execute(&graph, a, c, |_| 0.into())
+            .is_none());
+        assert!(AStar::init(&graph)
+            .execute(&graph, b, a, |_| 0.into())
+            .is_none());
+    }
+
+    #[test]
+    fn same_source_and_destination() {
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+
+        let (weight, path) = AStar::init(&graph)
+            .execute(&graph, a, a, |_| 0.into())
+            .unwrap();
+
+        assert_eq!(weight, 0.into());
+        assert_eq!(path, vec![a]);
+    }
+
+    #[test]
+    fn manhattan_distance_on_grid() {
+        // Given: 6 by 7 grid with a wall that leaves openings at the first and last columns.
+        //
+        //      s . . . . . .
+        //      . . . . . . .
+        //      . # # # # # .
+        //      . . . . . . .
+        //      . . . . . . t
+        //      . . . . . . .
+        //
+        let blocked = (1..6).map(|col| (2, col)).collect();
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let cells = GridGraphGenerator::init_with(6, 7, blocked).generate_into(&mut graph);
+
+        let mut cell_of = HashMap::new();
+        for (row, cells) in cells.iter().enumerate() {
+            for (col, vertex_id) in cells.iter().enumerate() {
+                if let Some(vertex_id) = vertex_id {
+                    cell_of.insert(*vertex_id, (row, col));
+                }
+            }
+        }
+
+        let sp_subgraph = Dijkstra::init(&graph).execute(&graph, cells[0][0].unwrap());
+        for (dst_id, (dst_row, dst_col)) in cell_of.iter() {
+            // Manhattan distance never overestimates, since each edge moves one cell vertically or horizontally.
+            let manhattan = |vertex_id: usize| {
+                let (row, col) = cell_of[&vertex_id];
+                let distance = (row as isize - *dst_row as isize).abs()
+                    + (col as isize - *dst_col as isize).abs();
+
+                (distance as usize).into()
+            };
+
+            // When: Finding the shortest path with manhattan distance as the heuristic.
+            let (weight, path) = AStar::init(&graph)
+                .execute(&graph, cells[0][0].unwrap(), *dst_id, manhattan)
+                .unwrap();
+
+            // Then: Weight is the same as what dijkstra finds, and path is made of weight + 1 adjacent cells.
+            assert_eq!(weight, sp_subgraph.distance_to(*dst_id).unwrap());
+            assert_eq!(path.len(), weight.unwrap() + 1);
+            for window in path.windows(2) {
+                assert!(graph.has_any_edge(window[0], window[1]).unwrap());
+            }
+        }
+
+        let (weight, _) = AStar::init(&graph)
+            .execute(&graph, cells[0][0].unwrap(), cells[4][6].unwrap(), |_| {
+                0.into()
+            })
+            .unwrap();
+        assert_eq!(weight, 10.into());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Heuristic is inconsistent")]
+    fn inconsistent_heuristic() {
+        // Given: Graph
+        //
+        //      s --3-- a --20-- t
+        //       \     /
+        //        1   1
+        //         \ /
+        //          b
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let s = graph.add_vertex();
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let t = graph.add_vertex();
+        graph.add_edge(s, a, 3.into()).unwrap();
+        graph.add_edge(s, b, 1.into()).unwrap();
+        graph.add_edge(b, a, 1.into()).unwrap();
+        graph.add_edge(a, t, 20.into()).unwrap();
+
+        // When: Overestimating distance of b, so a is visited before the shorter path through b is found.
+        AStar::init(&graph).execute(&graph, s, t, |vertex_id| {
+            if vertex_id == b {
+                10.into()
+            } else {
+                0.into()
+            }
+        });
+    }
+
+    #[test]
+    fn inadmissible_heuristic() {
+        // Given: Graph
+        //
+        //      s --1-- a --1-- t
+        //       \             /
+        //        '-----3-----'
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let s = graph.add_vertex();
+        let a = graph.add_vertex();
+        let t = graph.add_vertex();
+        graph.add_edge(s, a, 1.into()).unwrap();
+        graph.add_edge(a, t, 1.into()).unwrap();
+        graph.add_edge(s, t, 3.into()).unwrap();
+
+        // When: Overestimating distance of a, without a getting relaxed after being visited.
+        let (weight, path) = AStar::init(&graph)
+            .execute(&graph, s, t, |vertex_id| {
+                if vertex_id == a {
+                    10.into()
+                } else {
+                    0.into()
+                }
+            })
+            .unwrap();
+
+        // Then: Longer path is returned without any panic.
+        assert_eq!(weight, 3.into());
+        assert_eq!(path, vec![s, t]);
+    }
+}
@@ -4,8 +4,9 @@ use num_traits::Zero;
 use std::collections::HashMap;
 use std::{any::Any, collections::HashSet};
 
+use crate::algo::shortest_path::predecessors::path_to;
 use crate::algo::Error;
-use crate::provide::{Edges, Graph, Vertices};
+use crate::provide::{Edges, Graph, IdMap, Vertices};
 use crate::{
     graph::{subgraph::ShortestPathSubgraph, Edge, EdgeDir},
     prelude::Neighbors,
@@ -65,6 +66,10 @@ use crate::{
 pub struct BellmanFord<W> {
     distance: Vec<Magnitude<W>>,
     prev: Vec<Magnitude<usize>>,
+    id_map: IdMap,
+
+    // Virtual id of the source, set only after a successful execution.
+    src_virt_id: Option<usize>,
 }
 
 impl<W: Copy + Any + Zero + Ord> BellmanFord<W> {
@@ -79,6 +84,8 @@ impl<W: Copy + Any + Zero + Ord> BellmanFord<W> {
         BellmanFord {
             distance: vec![Magnitude::PosInfinite; vertex_count],
             prev: vec![Magnitude::PosInfinite; vertex_count],
+            id_map: IdMap::init(0),
+            src_virt_id: None,
         }
     }
 
@@ -90,13 +97,29 @@ impl<W: Copy + Any + Zero + Ord> BellmanFord<W> {
     ///
     /// # Returns
     /// * `Ok`: The shortest path as a subgraph of the original graph.
-    /// You can query shortest path from source to each destination using api provided by `ShortestPathSubgraph`.
+    ///   You can query shortest path from source to each destination using api provided by `ShortestPathSubgraph`.
     /// * `Err`: If graph contains negative cycle.
-    pub fn execute<E, Ty, G>(
+    pub fn execute<'a, E, Ty, G>(
         mut self,
-        graph: &G,
+        graph: &'a G,
+        src_id: usize,
+    ) -> Result<ShortestPathSubgraph<'a, W, E, Ty, G>>
+    where
+        E: Edge<W>,
+        Ty: EdgeDir,
+        G: Vertices + Edges<W, E> + Neighbors + Graph<W, E, Ty>,
+    {
+        self.search(graph, src_id)
+    }
+
+    /// Same as [`execute`](crate::algo::BellmanFord::execute), but keeps the structure,
+    /// so after a successful search paths can be queried using [`predecessors`](crate::algo::BellmanFord::predecessors)
+    /// and [`reconstruct_path`](crate::algo::BellmanFord::reconstruct_path).
+    pub fn search<'a, E, Ty, G>(
+        &mut self,
+        graph: &'a G,
         src_id: usize,
-    ) -> Result<ShortestPathSubgraph<W, E, Ty, G>>
+    ) -> Result<ShortestPathSubgraph<'a, W, E, Ty, G>>
     where
         E: Edge<W>,
         Ty: EdgeDir,
@@ -110,29 +133,27 @@ impl<W: Copy + Any + Zero + Ord> BellmanFord<W> {
 
         let src_virt_id = id_map.virt_id_of(src_id);
 
+        self.distance = vec![Magnitude::PosInfinite; vertex_count];
+        self.prev = vec![Magnitude::PosInfinite; vertex_count];
+        self.src_virt_id = None;
         self.distance[src_virt_id] = W::zero().into();
 
         let edges = graph.as_directed_edges();
 
-        for _ in 0..vertex_count - 1 {
-            for (u_real_id, v_real_id, edge) in &edges {
-                let u_virt_id = id_map.virt_id_of(*u_real_id);
-                let v_virt_id = id_map.virt_id_of(*v_real_id);
-
-                let alt = self.distance[u_virt_id] + *edge.get_weight();
-                if alt < self.distance[v_virt_id] {
-                    self.distance[v_virt_id] = alt;
-                    self.prev[v_virt_id] = u_virt_id.into();
-
-                    sp_edges.retain(|(_, dst_id, _)| dst_id != v_real_id); // remove edge to neighbor
-                    sp_edges.push((*u_real_id, *v_real_id, *edge)); // add new edge
-                }
+        // Distances are final as soon as a pass does not shorten any of them.
+        for _ in 1..vertex_count {
+            if !self.relax(&edges, &id_map, &mut sp_edges) {
+                break;
             }
         }
 
         for (u_real_id, v_real_id, edge) in &edges {
-            let u_virt_id = id_map.real_id_of(*u_real_id);
-            let v_virt_id = id_map.real_id_of(*v_real_id);
+            let u_virt_id = id_map.virt_id_of(*u_real_id);
+            let v_virt_id = id_map.virt_id_of(*v_real_id);
+
+            if !self.distance[u_virt_id].is_finite() {
+                continue;
+            }
 
             let alt = self.distance[u_virt_id] + *edge.get_weight();
             if alt < self.distance[v_virt_id] {
@@ -146,6 +167,9 @@ impl<W: Copy + Any + Zero + Ord> BellmanFord<W> {
             distance_map.insert(real_id, self.distance[virt_id]);
         }
 
+        self.id_map = id_map;
+        self.src_virt_id = Some(src_virt_id);
+
         let vertices = edges
             .iter()
             .flat_map(|(src_id, dst_id, _)| vec![*src_id, *dst_id])
@@ -159,11 +183,93 @@ impl<W: Copy + Any + Zero + Ord> BellmanFord<W> {
             distance_map,
         ))
     }
+
+    /// # Returns
+    /// Maps id of each vertex reachable from the source(except the source itself) to id of the vertex before it on its shortest path.
+    /// It is empty if [`search`](crate::algo::BellmanFord::search) is not called successfully yet.
+    ///
+    /// # Complexity
+    /// O(|V|)
+    pub fn predecessors(&self) -> HashMap<usize, usize> {
+        if self.src_virt_id.is_none() {
+            return HashMap::new();
+        }
+
+        self.prev
+            .iter()
+            .enumerate()
+            .filter_map(|(virt_id, prev)| {
+                prev.as_ref().map(|prev_virt_id| {
+                    (
+                        self.id_map.real_id_of(virt_id),
+                        self.id_map.real_id_of(*prev_virt_id),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// # Arguments
+    /// `dst_id`: Id of the destination vertex.
+    ///
+    /// # Returns
+    /// * `Some`: Containing id of the vertices on the shortest path from the source to `dst_id` in order, starting with the source.
+    /// * `None`: If `dst_id` is not reachable from the source, or [`search`](crate::algo::BellmanFord::search) is not called successfully yet.
+    ///
+    /// # Complexity
+    /// O(|V|)
+    ///
+    /// # Panics
+    /// If `search` is called successfully and `dst_id` is not in the graph it searched.
+    pub fn reconstruct_path(&self, dst_id: usize) -> Option<Vec<usize>> {
+        let src_virt_id = self.src_virt_id?;
+        let virt_id = self.id_map.virt_id_of(dst_id);
+        if !self.distance[virt_id].is_finite() {
+            return None;
+        }
+
+        Some(path_to(&self.id_map, &self.prev, src_virt_id, virt_id))
+    }
+
+    // Relaxes every edge once.
+    // Returns whether distance of any vertex got shorter.
+    fn relax<'a, E: Edge<W>>(
+        &mut self,
+        edges: &[(usize, usize, &'a E)],
+        id_map: &IdMap,
+        sp_edges: &mut Vec<(usize, usize, &'a E)>,
+    ) -> bool {
+        let mut is_relaxed = false;
+
+        for (u_real_id, v_real_id, edge) in edges {
+            let u_virt_id = id_map.virt_id_of(*u_real_id);
+            let v_virt_id = id_map.virt_id_of(*v_real_id);
+
+            // Edges from vertices that are not reached yet can not shorten any distance.
+            if !self.distance[u_virt_id].is_finite() {
+                continue;
+            }
+
+            let alt = self.distance[u_virt_id] + *edge.get_weight();
+            if alt < self.distance[v_virt_id] {
+                self.distance[v_virt_id] = alt;
+                self.prev[v_virt_id] = u_virt_id.into();
+
+                sp_edges.retain(|(_, dst_id, _)| dst_id != v_real_id); // remove edge to neighbor
+                sp_edges.push((*u_real_id, *v_real_id, *edge)); // add new edge
+
+                is_relaxed = true;
+            }
+        }
+
+        is_relaxed
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::algo::path_weight;
     use crate::graph::MatGraph;
     use crate::storage::{DiMat, Mat};
 
@@ -299,6 +405,84 @@ mod tests {
         assert_eq!(sp_subgraph.distance_to(e).unwrap(), 2.into());
     }
 
+    #[test]
+    fn reconstruct_paths_with_negative_edges() {
+        // Given: Graph
+        //
+        //      a --4--> b --2--> d --(-1)--> e
+        //      |        ^        ^           |
+        //      2      (-3)       5           |
+        //      |        |        |           |
+        //      '------> c -------'           |
+        //               ^                    |
+        //               '---------3----------'     f
+        //
+        let mut graph = MatGraph::init(DiMat::<isize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        graph.add_edge(a, b, 4.into()).unwrap();
+        graph.add_edge(a, c, 2.into()).unwrap();
+        graph.add_edge(c, b, (-3).into()).unwrap();
+        graph.add_edge(b, d, 2.into()).unwrap();
+        graph.add_edge(c, d, 5.into()).unwrap();
+        graph.add_edge(d, e, (-1).into()).unwrap();
+        graph.add_edge(e, c, 3.into()).unwrap();
+
+        // When: Performing BellmanFord algorithm.
+        let mut bellman_ford = BellmanFord::init(&graph);
+        let sp_subgraph = bellman_ford.search(&graph, a).unwrap();
+
+        // Then:
+        assert_eq!(bellman_ford.reconstruct_path(a), Some(vec![a]));
+        assert_eq!(bellman_ford.reconstruct_path(b), Some(vec![a, c, b]));
+        assert_eq!(bellman_ford.reconstruct_path(e), Some(vec![a, c, b, d, e]));
+        assert_eq!(bellman_ford.reconstruct_path(f), None);
+        for vertex_id in [a, b, c, d, e] {
+            let path = bellman_ford.reconstruct_path(vertex_id).unwrap();
+
+            assert_eq!(
+                path_weight(&graph, &path),
+                sp_subgraph.distance_to(vertex_id)
+            );
+        }
+
+        let predecessors = bellman_ford.predecessors();
+        assert_eq!(predecessors.len(), 4);
+        assert_eq!(predecessors[&b], c);
+        assert_eq!(predecessors[&c], a);
+        assert_eq!(predecessors[&d], b);
+        assert_eq!(predecessors[&e], d);
+    }
+
+    #[test]
+    fn no_path_after_negative_cycle() {
+        // Given: Graph
+        //
+        //      a --1--> b --(-2)--> c
+        //               ^           |
+        //               '-----1-----'
+        //
+        let mut graph = MatGraph::init(DiMat::<isize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, (-2).into()).unwrap();
+        graph.add_edge(c, b, 1.into()).unwrap();
+
+        // When: Performing BellmanFord algorithm.
+        let mut bellman_ford = BellmanFord::init(&graph);
+        assert!(bellman_ford.search(&graph, a).is_err());
+
+        // Then:
+        assert!(bellman_ford.predecessors().is_empty());
+        assert_eq!(bellman_ford.reconstruct_path(c), None);
+    }
+
     #[test]
     fn undirected_graph_with_negative_cycle() {
         // Given: Graph
@@ -361,4 +545,105 @@ mod tests {
 
         assert!(shortest_paths.is_err());
     }
+
+    #[test]
+    fn early_exit_on_chain_graph() {
+        // Given: Graph
+        //
+        //      a --1--> b --2--> c --3--> d --4--> e
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let vertices: Vec<usize> = (0..5).map(|_| graph.add_vertex()).collect();
+        for (index, window) in vertices.windows(2).enumerate() {
+            graph
+                .add_edge(window[0], window[1], (index + 1).into())
+                .unwrap();
+        }
+        let id_map = graph.continuos_id_map();
+        let edges = graph.as_directed_edges();
+
+        // When: Relaxing edges for all |V| - 1 passes, and until no distance changes.
+        let mut full_pass = BellmanFord::init(&graph);
+        full_pass.distance[id_map.virt_id_of(vertices[0])] = 0.into();
+        for _ in 1..graph.vertex_count() {
+            full_pass.relax(&edges, &id_map, &mut vec![]);
+        }
+
+        let mut early_exit = BellmanFord::init(&graph);
+        early_exit.distance[id_map.virt_id_of(vertices[0])] = 0.into();
+        let mut pass_count = 0;
+        while early_exit.relax(&edges, &id_map, &mut vec![]) {
+            pass_count += 1;
+        }
+
+        let sp_subgraph = BellmanFord::init(&graph)
+            .execute(&graph, vertices[0])
+            .unwrap();
+
+        // Then: Edges are in order of the chain, so one pass is enough to find all the distances.
+        assert_eq!(pass_count, 1);
+        assert_eq!(early_exit.distance, full_pass.distance);
+        // Source(with virtual id of 0) has no previous vertex to compare.
+        assert_eq!(early_exit.prev[1..], full_pass.prev[1..]);
+        for (vertex_id, distance) in vertices.iter().zip([0, 1, 3, 6, 10].iter()) {
+            assert_eq!(
+                sp_subgraph.distance_to(*vertex_id).unwrap(),
+                (*distance).into()
+            );
+        }
+    }
+
+    #[test]
+    fn edges_between_unreachable_vertices() {
+        // Given: Graph
+        //
+        //      a --1--> b      c --2--> d
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(c, d, 2.into()).unwrap();
+        graph.add_edge(a, b, 1.into()).unwrap();
+
+        // When: Performing BellmanFord algorithm.
+        let sp_subgraph = BellmanFord::init(&graph).execute(&graph, a).unwrap();
+
+        // Then: Edge between c and d is never relaxed.
+        assert_eq!(sp_subgraph.distance_to(b).unwrap(), 1.into());
+        assert!(sp_subgraph.distance_to(c).unwrap().is_pos_infinite());
+        assert!(sp_subgraph.distance_to(d).unwrap().is_pos_infinite());
+    }
+
+    #[test]
+    fn removed_vertex() {
+        // Given: Graph
+        //
+        //      b --(-1)--> c --2--> d
+        //
+        let mut graph = MatGraph::init(DiMat::<isize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.remove_vertex(a).unwrap();
+        graph.add_edge(b, c, (-1).into()).unwrap();
+        graph.add_edge(c, d, 2.into()).unwrap();
+
+        // When: Performing BellmanFord algorithm, while ids of the vertices are not continuous.
+        let mut bellman_ford = BellmanFord::init(&graph);
+        let sp_subgraph = bellman_ford.search(&graph, b).unwrap();
+
+        // Then:
+        assert_eq!(sp_subgraph.distance_to(c).unwrap(), (-1).into());
+        assert_eq!(sp_subgraph.distance_to(d).unwrap(), 1.into());
+        assert_eq!(bellman_ford.reconstruct_path(d), Some(vec![b, c, d]));
+
+        // When: Closing a negative cycle.
+        graph.add_edge(d, b, (-2).into()).unwrap();
+
+        // Then:
+        assert!(BellmanFord::init(&graph).execute(&graph, b).is_err());
+    }
 }
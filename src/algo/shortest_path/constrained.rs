@@ -0,0 +1,260 @@
+use magnitude::Magnitude;
+use num_traits::{Unsigned, Zero};
+use std::any::Any;
+use std::collections::HashSet;
+
+use crate::graph::{Edge, EdgeDir};
+use crate::provide::{Edges, Graph, Neighbors, Vertices};
+
+/// Finds shortest path between two vertices using dijkstra algorithm, without passing through forbidden vertices and edges.
+///
+/// # Arguments
+/// * `graph`: Graph to search for the shortest path in.
+/// * `src_id`: Id of the source vertex.
+/// * `dst_id`: Id of the destination vertex.
+/// * `forbidden_vertices`: Id of vertices that path must not pass through.
+/// * `forbidden_edges`: Id of edges that path must not use.
+///
+/// # Returns
+/// * `Some`: Containing weight of the shortest path alongside id of vertices on the path, starting from `src_id` and ending with `dst_id`.
+/// * `None`: If there is no path from `src_id` to `dst_id` that avoids forbidden vertices and edges.
+///   This includes when `src_id` or `dst_id` is forbidden.
+///
+/// # Complexity
+/// O(|V|<sup>2</sup> + |E|)
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::constrained_shortest_path;
+///
+/// // Given: Graph
+/// //
+/// //      a --1-- b --1-- c
+/// //       \             /
+/// //        '-----5-----'
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(a, c, 5.into());
+///
+/// // When: Forbidding b.
+/// let forbidden_vertices = vec![b].into_iter().collect();
+/// let (weight, path) =
+///     constrained_shortest_path(&graph, a, c, &forbidden_vertices, &HashSet::new()).unwrap();
+///
+/// // Then:
+/// assert_eq!(weight, 5.into());
+/// assert_eq!(path, vec![a, c]);
+/// ```
+pub fn constrained_shortest_path<W, E, Ty, G>(
+    graph: &G,
+    src_id: usize,
+    dst_id: usize,
+    forbidden_vertices: &HashSet<usize>,
+    forbidden_edges: &HashSet<usize>,
+) -> Option<(Magnitude<W>, Vec<usize>)>
+where
+    W: Copy + Ord + Zero + Any + Unsigned,
+    E: Edge<W>,
+    Ty: EdgeDir,
+    G: Edges<W, E> + Neighbors + Vertices + Graph<W, E, Ty>,
+{
+    if !graph.contains_vertex(src_id)
+        || !graph.contains_vertex(dst_id)
+        || forbidden_vertices.contains(&src_id)
+        || forbidden_vertices.contains(&dst_id)
+    {
+        return None;
+    }
+
+    let vertex_count = graph.vertex_count();
+    let id_map = graph.continuos_id_map();
+
+    // Forbidden vertices are marked as visited so they never get relaxed or expanded.
+    let mut visited = vec![false; vertex_count];
+    for vertex_id in forbidden_vertices {
+        if graph.contains_vertex(*vertex_id) {
+            visited[id_map.virt_id_of(*vertex_id)] = true;
+        }
+    }
+
+    let mut dist = vec![Magnitude::PosInfinite; vertex_count];
+    let mut prev: Vec<Option<usize>> = vec![None; vertex_count];
+
+    let src_virt_id = id_map.virt_id_of(src_id);
+    let dst_virt_id = id_map.virt_id_of(dst_id);
+    dist[src_virt_id] = W::zero().into();
+
+    while let Some(virt_id) = dist
+        .iter()
+        .enumerate()
+        .filter(|(virt_id, dist)| dist.is_finite() && !visited[*virt_id])
+        .min_by(|(_, dist1), (_, dist2)| dist1.cmp(dist2))
+        .map(|(virt_id, _)| virt_id)
+    {
+        if virt_id == dst_virt_id {
+            break;
+        }
+
+        visited[virt_id] = true;
+
+        let real_id = id_map.real_id_of(virt_id);
+        for (n_id, edge) in graph.edges_from(real_id).unwrap() {
+            let n_virt_id = id_map.virt_id_of(n_id);
+            if visited[n_virt_id]
+                || forbidden_edges.contains(&edge.get_id())
+                || !edge.get_weight().is_finite()
+            {
+                continue;
+            }
+
+            let alt = dist[virt_id] + *edge.get_weight();
+            if dist[n_virt_id].is_pos_infinite() || alt < dist[n_virt_id] {
+                dist[n_virt_id] = alt;
+                prev[n_virt_id] = Some(virt_id);
+            }
+        }
+    }
+
+    if dist[dst_virt_id].is_pos_infinite() {
+        return None;
+    }
+
+    let mut path = vec![dst_id];
+    let mut virt_id = dst_virt_id;
+    while let Some(prev_virt_id) = prev[virt_id] {
+        path.push(id_map.real_id_of(prev_virt_id));
+        virt_id = prev_virt_id;
+    }
+    path.reverse();
+
+    Some((dist[dst_virt_id], path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::storage::{DiMat, Mat};
+
+    #[test]
+    fn same_source_and_destination() {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+
+        let (weight, path) =
+            constrained_shortest_path(&graph, a, a, &HashSet::new(), &HashSet::new()).unwrap();
+
+        assert_eq!(weight, 0.into());
+        assert_eq!(path, vec![a]);
+    }
+
+    #[test]
+    fn blocking_optimal_path_forces_longer_one() {
+        // Given: Graph
+        //
+        //      a --1--> b --1--> c
+        //      |        |        ^
+        //      4        2        |
+        //      v        v        |
+        //      d --3--> e --2----'
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        let bc = graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(a, d, 4.into()).unwrap();
+        graph.add_edge(b, e, 2.into()).unwrap();
+        graph.add_edge(d, e, 3.into()).unwrap();
+        graph.add_edge(e, c, 2.into()).unwrap();
+
+        // When: Finding paths with no constraint, without edge bc and without vertex b.
+        let (free_weight, free_path) =
+            constrained_shortest_path(&graph, a, c, &HashSet::new(), &HashSet::new()).unwrap();
+        let (no_bc_weight, no_bc_path) = constrained_shortest_path(
+            &graph,
+            a,
+            c,
+            &HashSet::new(),
+            &vec![bc].into_iter().collect(),
+        )
+        .unwrap();
+        let (no_b_weight, no_b_path) = constrained_shortest_path(
+            &graph,
+            a,
+            c,
+            &vec![b].into_iter().collect(),
+            &HashSet::new(),
+        )
+        .unwrap();
+
+        // Then:
+        assert_eq!(free_weight, 2.into());
+        assert_eq!(free_path, vec![a, b, c]);
+        assert_eq!(no_bc_weight, 5.into());
+        assert_eq!(no_bc_path, vec![a, b, e, c]);
+        assert_eq!(no_b_weight, 9.into());
+        assert_eq!(no_b_path, vec![a, d, e, c]);
+    }
+
+    #[test]
+    fn blocking_disconnects_source_from_destination() {
+        // Given: Graph
+        //
+        //      a --1-- b --1-- c
+        //       \     /
+        //        1   1
+        //         \ /
+        //          d
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        let bc = graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(a, d, 1.into()).unwrap();
+        graph.add_edge(b, d, 1.into()).unwrap();
+
+        // When: Blocking the only vertex and the only edge that lead to c.
+        let without_b = constrained_shortest_path(
+            &graph,
+            a,
+            c,
+            &vec![b].into_iter().collect(),
+            &HashSet::new(),
+        );
+        let without_bc = constrained_shortest_path(
+            &graph,
+            a,
+            c,
+            &HashSet::new(),
+            &vec![bc].into_iter().collect(),
+        );
+        let without_c = constrained_shortest_path(
+            &graph,
+            a,
+            c,
+            &vec![c].into_iter().collect(),
+            &HashSet::new(),
+        );
+
+        // Then:
+        assert!(without_b.is_none());
+        assert!(without_bc.is_none());
+        assert!(without_c.is_none());
+    }
+}
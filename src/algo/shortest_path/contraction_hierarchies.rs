@@ -0,0 +1,425 @@
+use magnitude::Magnitude;
+use num_traits::{Unsigned, Zero};
+use std::any::Any;
+use std::collections::HashMap;
+
+use crate::graph::{Edge, EdgeDir};
+use crate::provide::{Edges, Graph, IdMap, Vertices};
+
+/// Preprocesses a graph using contraction hierarchies so shortest distance between any two vertices can be queried fast.
+///
+/// Vertices get contracted one by one, in the order of their edge difference(number of shortcuts needed minus number of removed edges).
+/// Contracting a vertex removes it from the graph and adds a shortcut between each pair of its neighbors
+/// whose shortest path passes through it. \
+/// Each query is then answered by two dijkstra searches, one from the source and one from the destination,
+/// that only move towards vertices contracted later.
+///
+/// Graph must not change after preprocessing, otherwise query results are not valid.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::DiMat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::ContractionHierarchies;
+///
+/// // Given: Graph
+/// //
+/// //      a --1--> b --1--> c --1--> d
+/// //      |                          ^
+/// //      '------------5-------------'
+/// //
+/// let mut graph = MatGraph::init(DiMat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(c, d, 1.into());
+/// graph.add_edge(a, d, 5.into());
+///
+/// // When: Preprocessing the graph.
+/// let ch = ContractionHierarchies::init(&graph);
+///
+/// // Then:
+/// assert_eq!(ch.distance(a, d), 3.into());
+/// assert_eq!(ch.distance(b, d), 2.into());
+/// assert!(ch.distance(d, a).is_pos_infinite());
+/// ```
+pub struct ContractionHierarchies<W> {
+    id_map: IdMap,
+
+    // Edges(including shortcuts) from each vertex(by virtual id) to vertices contracted after it, in the format of (dst_virt_id, weight).
+    upward_edges: Vec<Vec<(usize, Magnitude<W>)>>,
+
+    // Edges(including shortcuts) into each vertex(by virtual id) from vertices contracted after it, in the format of (src_virt_id, weight).
+    downward_edges: Vec<Vec<(usize, Magnitude<W>)>>,
+}
+
+impl<W: Copy + Ord + Zero + Any + Unsigned> ContractionHierarchies<W> {
+    /// Preprocesses the graph.
+    ///
+    /// # Arguments
+    /// `graph`: Graph to preprocess. Edges with infinite weight are ignored.
+    ///
+    /// # Complexity
+    /// Depends on the structure of the graph.
+    /// Each contraction runs a bounded dijkstra search from every neighbor of the contracted vertex to look for witness paths.
+    pub fn init<E, Ty, G>(graph: &G) -> Self
+    where
+        E: Edge<W>,
+        Ty: EdgeDir,
+        G: Edges<W, E> + Vertices + Graph<W, E, Ty>,
+    {
+        let vertex_count = graph.vertex_count();
+        let id_map = graph.continuos_id_map();
+
+        // Remaining graph, keeping only the lightest edge between each pair of vertices.
+        let mut out_edges: Vec<HashMap<usize, Magnitude<W>>> = vec![HashMap::new(); vertex_count];
+        let mut in_edges: Vec<HashMap<usize, Magnitude<W>>> = vec![HashMap::new(); vertex_count];
+        for src_id in graph.vertices() {
+            let src_virt_id = id_map.virt_id_of(src_id);
+
+            for (dst_id, edge) in graph.edges_from(src_id).unwrap() {
+                let dst_virt_id = id_map.virt_id_of(dst_id);
+                let weight = *edge.get_weight();
+                if src_virt_id == dst_virt_id || !weight.is_finite() {
+                    continue;
+                }
+
+                Self::put_lighter(&mut out_edges[src_virt_id], dst_virt_id, weight);
+                Self::put_lighter(&mut in_edges[dst_virt_id], src_virt_id, weight);
+            }
+        }
+
+        let mut upward_edges = vec![vec![]; vertex_count];
+        let mut downward_edges = vec![vec![]; vertex_count];
+        let mut is_contracted = vec![false; vertex_count];
+
+        let mut priority_of = (0..vertex_count)
+            .map(|virt_id| Self::priority(&out_edges, &in_edges, virt_id))
+            .collect::<Vec<isize>>();
+
+        while let Some(virt_id) = Self::next_vertex(&is_contracted, &priority_of) {
+            // Priorities are updated lazily, so check the priority of the candidate once more before contracting it.
+            priority_of[virt_id] = Self::priority(&out_edges, &in_edges, virt_id);
+            if Self::next_vertex(&is_contracted, &priority_of) != Some(virt_id) {
+                continue;
+            }
+
+            for (dst_virt_id, weight) in &out_edges[virt_id] {
+                upward_edges[virt_id].push((*dst_virt_id, *weight));
+            }
+            for (src_virt_id, weight) in &in_edges[virt_id] {
+                downward_edges[virt_id].push((*src_virt_id, *weight));
+            }
+
+            let shortcuts = Self::shortcuts_of(&out_edges, &in_edges, virt_id);
+
+            // Remove the vertex from the remaining graph.
+            let neighbors = out_edges[virt_id]
+                .keys()
+                .chain(in_edges[virt_id].keys())
+                .copied()
+                .collect::<Vec<usize>>();
+            for (dst_virt_id, _) in std::mem::take(&mut out_edges[virt_id]) {
+                in_edges[dst_virt_id].remove(&virt_id);
+            }
+            for (src_virt_id, _) in std::mem::take(&mut in_edges[virt_id]) {
+                out_edges[src_virt_id].remove(&virt_id);
+            }
+            is_contracted[virt_id] = true;
+
+            for (src_virt_id, dst_virt_id, weight) in shortcuts {
+                Self::put_lighter(&mut out_edges[src_virt_id], dst_virt_id, weight);
+                Self::put_lighter(&mut in_edges[dst_virt_id], src_virt_id, weight);
+            }
+
+            for n_virt_id in neighbors {
+                priority_of[n_virt_id] = Self::priority(&out_edges, &in_edges, n_virt_id);
+            }
+        }
+
+        ContractionHierarchies {
+            id_map,
+            upward_edges,
+            downward_edges,
+        }
+    }
+
+    /// # Arguments
+    /// * `src_id`: Id of the source vertex.
+    /// * `dst_id`: Id of the destination vertex.
+    ///
+    /// # Returns
+    /// Shortest distance from `src_id` to `dst_id`, or `PosInfinite` if `dst_id` is not reachable from `src_id`.
+    ///
+    /// # Panics
+    /// If either of vertices is not in the graph that structure is initialized with.
+    ///
+    /// # Complexity
+    /// Two dijkstra searches over the edges that go towards vertices contracted later, which are usually a small part of the graph.
+    pub fn distance(&self, src_id: usize, dst_id: usize) -> Magnitude<W> {
+        let forward = Self::upward_search(&self.upward_edges, self.id_map.virt_id_of(src_id));
+        let backward = Self::upward_search(&self.downward_edges, self.id_map.virt_id_of(dst_id));
+
+        // Shortest path goes up from source to the vertex contracted last on it, then goes down to destination.
+        forward
+            .iter()
+            .filter_map(|(virt_id, forward_dist)| {
+                backward
+                    .get(virt_id)
+                    .map(|backward_dist| *forward_dist + *backward_dist)
+            })
+            .min()
+            .unwrap_or(Magnitude::PosInfinite)
+    }
+
+    // Dijkstra from `src_virt_id` over `edges`.
+    // Returns distance of every reached vertex.
+    fn upward_search(
+        edges: &[Vec<(usize, Magnitude<W>)>],
+        src_virt_id: usize,
+    ) -> HashMap<usize, Magnitude<W>> {
+        let mut dist = HashMap::new();
+        let mut settled = HashMap::new();
+        dist.insert(src_virt_id, W::zero().into());
+
+        while let Some((virt_id, virt_dist)) = dist
+            .iter()
+            .min_by(|(_, dist1): &(&usize, &Magnitude<W>), (_, dist2)| dist1.cmp(dist2))
+            .map(|(virt_id, virt_dist)| (*virt_id, *virt_dist))
+        {
+            dist.remove(&virt_id);
+            settled.insert(virt_id, virt_dist);
+
+            for (n_virt_id, weight) in &edges[virt_id] {
+                let alt = virt_dist + *weight;
+                if !settled.contains_key(n_virt_id)
+                    && dist.get(n_virt_id).map_or(true, |n_dist| alt < *n_dist)
+                {
+                    dist.insert(*n_virt_id, alt);
+                }
+            }
+        }
+
+        settled
+    }
+
+    // Finds shortcuts needed to contract `virt_id`, in the format of (src_virt_id, dst_virt_id, weight).
+    fn shortcuts_of(
+        out_edges: &[HashMap<usize, Magnitude<W>>],
+        in_edges: &[HashMap<usize, Magnitude<W>>],
+        virt_id: usize,
+    ) -> Vec<(usize, usize, Magnitude<W>)> {
+        let max_out_weight = match out_edges[virt_id].values().max() {
+            Some(weight) => *weight,
+            None => return vec![],
+        };
+
+        let mut shortcuts = vec![];
+        for (src_virt_id, in_weight) in &in_edges[virt_id] {
+            let witness_dist = Self::witness_search(
+                out_edges,
+                *src_virt_id,
+                virt_id,
+                *in_weight + max_out_weight,
+            );
+
+            for (dst_virt_id, out_weight) in &out_edges[virt_id] {
+                if dst_virt_id == src_virt_id {
+                    continue;
+                }
+
+                let through_weight = *in_weight + *out_weight;
+                let has_witness = witness_dist
+                    .get(dst_virt_id)
+                    .map_or(false, |dist| *dist <= through_weight);
+                if !has_witness {
+                    shortcuts.push((*src_virt_id, *dst_virt_id, through_weight));
+                }
+            }
+        }
+
+        shortcuts
+    }
+
+    // Dijkstra from `src_virt_id` in the remaining graph that does not pass through `excluded_virt_id`.
+    // Vertices farther than `limit` are not settled.
+    fn witness_search(
+        out_edges: &[HashMap<usize, Magnitude<W>>],
+        src_virt_id: usize,
+        excluded_virt_id: usize,
+        limit: Magnitude<W>,
+    ) -> HashMap<usize, Magnitude<W>> {
+        let mut dist = HashMap::new();
+        let mut settled = HashMap::new();
+        dist.insert(src_virt_id, W::zero().into());
+
+        while let Some((virt_id, virt_dist)) = dist
+            .iter()
+            .min_by(|(_, dist1): &(&usize, &Magnitude<W>), (_, dist2)| dist1.cmp(dist2))
+            .map(|(virt_id, virt_dist)| (*virt_id, *virt_dist))
+        {
+            if virt_dist > limit {
+                break;
+            }
+
+            dist.remove(&virt_id);
+            settled.insert(virt_id, virt_dist);
+
+            for (n_virt_id, weight) in &out_edges[virt_id] {
+                let alt = virt_dist + *weight;
+                if *n_virt_id != excluded_virt_id
+                    && !settled.contains_key(n_virt_id)
+                    && dist.get(n_virt_id).map_or(true, |n_dist| alt < *n_dist)
+                {
+                    dist.insert(*n_virt_id, alt);
+                }
+            }
+        }
+
+        settled
+    }
+
+    // Edge difference of contracting `virt_id`: Number of added shortcuts minus number of removed edges.
+    fn priority(
+        out_edges: &[HashMap<usize, Magnitude<W>>],
+        in_edges: &[HashMap<usize, Magnitude<W>>],
+        virt_id: usize,
+    ) -> isize {
+        let shortcut_count = Self::shortcuts_of(out_edges, in_edges, virt_id).len();
+        let removed_count = out_edges[virt_id].len() + in_edges[virt_id].len();
+
+        shortcut_count as isize - removed_count as isize
+    }
+
+    // Returns the vertex that is not contracted yet and has the lowest priority.
+    fn next_vertex(is_contracted: &[bool], priority_of: &[isize]) -> Option<usize> {
+        (0..is_contracted.len())
+            .filter(|virt_id| !is_contracted[*virt_id])
+            .min_by_key(|virt_id| priority_of[*virt_id])
+    }
+
+    // Inserts the edge to `virt_id` into `edges` if there is no lighter edge to `virt_id` already.
+    fn put_lighter(edges: &mut HashMap<usize, Magnitude<W>>, virt_id: usize, weight: Magnitude<W>) {
+        let current = edges.entry(virt_id).or_insert(weight);
+        if weight < *current {
+            *current = weight;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use super::*;
+    use crate::algo::Dijkstra;
+    use crate::graph::MatGraph;
+    use crate::provide::Neighbors;
+    use crate::storage::{DiMat, Mat};
+
+    fn assert_matches_dijkstra<E, Ty, G>(graph: &G)
+    where
+        E: Edge<usize>,
+        Ty: EdgeDir,
+        G: Edges<usize, E> + Neighbors + Vertices + Graph<usize, E, Ty>,
+    {
+        let ch = ContractionHierarchies::init(graph);
+
+        for src_id in graph.vertices() {
+            let sp_subgraph = Dijkstra::init(graph).execute(graph, src_id);
+
+            for dst_id in graph.vertices() {
+                let expected = sp_subgraph.distance_to(dst_id).unwrap();
+                let distance = ch.distance(src_id, dst_id);
+
+                if expected.is_pos_infinite() {
+                    assert!(distance.is_pos_infinite());
+                } else {
+                    assert_eq!(distance, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn one_vertex_graph() {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+
+        let ch = ContractionHierarchies::init(&graph);
+
+        assert_eq!(ch.distance(a, a), 0.into());
+    }
+
+    #[test]
+    fn undirected_graph() {
+        // Given: Graph
+        //
+        //      a --1-- b --2-- c
+        //      |     / |       |
+        //      4   1   3       1
+        //      | /     |       |
+        //      d --5-- e --1-- f       g
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        graph.add_vertex();
+        let edges = [
+            (a, b, 1),
+            (b, c, 2),
+            (a, d, 4),
+            (b, d, 1),
+            (b, e, 3),
+            (c, f, 1),
+            (d, e, 5),
+            (e, f, 1),
+        ];
+        for (src_id, dst_id, weight) in edges.iter() {
+            graph.add_edge(*src_id, *dst_id, (*weight).into()).unwrap();
+        }
+
+        assert_matches_dijkstra(&graph);
+    }
+
+    #[test]
+    fn random_graphs() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..10 {
+            let vertex_count = rng.gen_range(1..15);
+
+            let mut graph = MatGraph::init(Mat::<usize>::init());
+            let mut di_graph = MatGraph::init(DiMat::<usize>::init());
+            for _ in 0..vertex_count {
+                graph.add_vertex();
+                di_graph.add_vertex();
+            }
+
+            for _ in 0..rng.gen_range(0..vertex_count * 3) {
+                let src_id = rng.gen_range(0..vertex_count);
+                let dst_id = rng.gen_range(0..vertex_count);
+                let weight = rng.gen_range(0..10usize);
+
+                // Graphs do not allow loops and multiple edges.
+                if src_id != dst_id && !graph.is_adjacent(src_id, dst_id) {
+                    graph.add_edge(src_id, dst_id, weight.into()).unwrap();
+                }
+                if src_id != dst_id && !di_graph.is_adjacent(src_id, dst_id) {
+                    di_graph.add_edge(src_id, dst_id, weight.into()).unwrap();
+                }
+            }
+
+            assert_matches_dijkstra(&graph);
+            assert_matches_dijkstra(&di_graph);
+        }
+    }
+}
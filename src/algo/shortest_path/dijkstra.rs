@@ -3,7 +3,8 @@ use num_traits::{Unsigned, Zero};
 use std::collections::HashMap;
 use std::{any::Any, collections::HashSet};
 
-use crate::provide::{Edges, Graph, Vertices};
+use crate::algo::shortest_path::predecessors::path_to;
+use crate::provide::{Edges, Graph, IdMap, Vertices};
 use crate::{
     graph::{subgraph::ShortestPathSubgraph, Edge, EdgeDir},
     prelude::Neighbors,
@@ -64,6 +65,10 @@ pub struct Dijkstra<W> {
     visited: Vec<bool>,
     dist: Vec<Magnitude<W>>,
     prev: Vec<Magnitude<usize>>,
+    id_map: IdMap,
+
+    // Virtual id of the source, set only after an execution.
+    src_virt_id: Option<usize>,
 }
 
 impl<W: Copy + Ord + Zero + Any + Unsigned> Dijkstra<W> {
@@ -80,6 +85,8 @@ impl<W: Copy + Ord + Zero + Any + Unsigned> Dijkstra<W> {
             visited: vec![false; vertex_count],
             dist: vec![Magnitude::PosInfinite; vertex_count],
             prev: vec![Magnitude::PosInfinite; vertex_count],
+            id_map: IdMap::init(0),
+            src_virt_id: None,
         }
     }
 
@@ -87,9 +94,9 @@ impl<W: Copy + Ord + Zero + Any + Unsigned> Dijkstra<W> {
         self.dist
             .iter()
             .enumerate()
-            .filter(|(virt_id, dist)| dist.is_finite() && self.visited[*virt_id] == false)
+            .filter(|(virt_id, dist)| dist.is_finite() && !self.visited[*virt_id])
             .min_by(|(_, dist1), (_, dist2)| dist1.cmp(dist2))
-            .and_then(|(v_id, _)| Some(v_id))
+            .map(|(v_id, _)| v_id)
     }
 
     /// Finds shortest path from a single source to all other vertices.
@@ -101,11 +108,27 @@ impl<W: Copy + Ord + Zero + Any + Unsigned> Dijkstra<W> {
     /// # Returns
     /// The shortest path as a subgraph of the original graph.
     /// You can query shortest path from source to each destination using api provided by `ShortestPathSubgraph`.
-    pub fn execute<E, Ty, G>(
+    ///
+    pub fn execute<'a, E, Ty, G>(
         mut self,
-        graph: &G,
+        graph: &'a G,
         src_id: usize,
-    ) -> ShortestPathSubgraph<W, E, Ty, G>
+    ) -> ShortestPathSubgraph<'a, W, E, Ty, G>
+    where
+        E: Edge<W>,
+        Ty: EdgeDir,
+        G: Edges<W, E> + Neighbors + Vertices + Graph<W, E, Ty>,
+    {
+        self.search(graph, src_id)
+    }
+
+    /// Same as [`execute`](crate::algo::Dijkstra::execute), but keeps the structure,
+    /// so paths can be queried afterward using [`shortest_path`](crate::algo::Dijkstra::shortest_path).
+    pub fn search<'a, E, Ty, G>(
+        &mut self,
+        graph: &'a G,
+        src_id: usize,
+    ) -> ShortestPathSubgraph<'a, W, E, Ty, G>
     where
         E: Edge<W>,
         Ty: EdgeDir,
@@ -117,6 +140,10 @@ impl<W: Copy + Ord + Zero + Any + Unsigned> Dijkstra<W> {
 
         let src_virt_id = id_map.virt_id_of(src_id);
 
+        let vertex_count = graph.vertex_count();
+        self.visited = vec![false; vertex_count];
+        self.dist = vec![Magnitude::PosInfinite; vertex_count];
+        self.prev = vec![Magnitude::PosInfinite; vertex_count];
         self.dist[src_virt_id] = W::zero().into();
 
         while let Some(virt_id) = self.next_id() {
@@ -144,6 +171,9 @@ impl<W: Copy + Ord + Zero + Any + Unsigned> Dijkstra<W> {
             distance_map.insert(real_id, self.dist[virt_id]);
         }
 
+        self.id_map = id_map;
+        self.src_virt_id = Some(src_virt_id);
+
         let vertices = edges
             .iter()
             .flat_map(|(src_id, dst_id, _)| vec![*src_id, *dst_id])
@@ -152,6 +182,28 @@ impl<W: Copy + Ord + Zero + Any + Unsigned> Dijkstra<W> {
 
         ShortestPathSubgraph::init(graph, edges, vertices, distance_map)
     }
+
+    /// # Arguments
+    /// `dst_id`: Id of the destination vertex.
+    ///
+    /// # Returns
+    /// * `Some`: Containing id of the vertices on the shortest path from the source to `dst_id` in order, starting with the source and ending with `dst_id`.
+    /// * `None`: If `dst_id` is not reachable from the source, or [`search`](crate::algo::Dijkstra::search) is not called yet.
+    ///
+    /// # Complexity
+    /// O(|V|)
+    ///
+    /// # Panics
+    /// If `search` is called and `dst_id` is not in the graph it searched.
+    pub fn shortest_path(&self, dst_id: usize) -> Option<Vec<usize>> {
+        let src_virt_id = self.src_virt_id?;
+        let virt_id = self.id_map.virt_id_of(dst_id);
+        if !self.dist[virt_id].is_finite() {
+            return None;
+        }
+
+        Some(path_to(&self.id_map, &self.prev, src_virt_id, virt_id))
+    }
 }
 
 #[cfg(test)]
@@ -284,4 +336,47 @@ mod tests {
         assert_eq!(sp_subgraph.distance_to(d).unwrap(), 1.into());
         assert_eq!(sp_subgraph.distance_to(e).unwrap(), 2.into());
     }
+
+    #[test]
+    fn shortest_path_in_diamond_graph() {
+        // Given: Graph
+        //          1
+        //      a  -->  b
+        //    1 |       | 1
+        //      v       v
+        //      c  -->  d      e
+        //          1
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(a, c, 1.into()).unwrap();
+        graph.add_edge(b, d, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+
+        // When: Performing Dijkstra algorithm.
+        let mut dijkstra = Dijkstra::init(&graph);
+        assert!(dijkstra.shortest_path(d).is_none());
+        let sp_subgraph = dijkstra.search(&graph, a);
+
+        // Then: Either of the routes through b and c is accepted.
+        assert_eq!(sp_subgraph.distance_to(d).unwrap(), 2.into());
+        let path = dijkstra.shortest_path(d).unwrap();
+        assert!(path == vec![a, b, d] || path == vec![a, c, d]);
+        assert_eq!(dijkstra.shortest_path(a).unwrap(), vec![a]);
+        assert_eq!(dijkstra.shortest_path(b).unwrap(), vec![a, b]);
+        assert!(dijkstra.shortest_path(e).is_none());
+
+        // And: Executing again from another source replaces the paths.
+        dijkstra.search(&graph, b);
+        assert_eq!(dijkstra.shortest_path(d).unwrap(), vec![b, d]);
+        assert!(dijkstra.shortest_path(a).is_none());
+
+        // And: Structure can still be consumed by `execute`, which finds the same distances.
+        let sp_subgraph = dijkstra.execute(&graph, a);
+        assert_eq!(sp_subgraph.distance_to(d).unwrap(), 2.into());
+    }
 }
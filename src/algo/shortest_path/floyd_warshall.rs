@@ -132,7 +132,7 @@ impl FloydWarshall {
 
             for (v_real_id, edge) in graph.edges_from(u_real_id).unwrap() {
                 let v_virt_id = id_map.virt_id_of(v_real_id);
-                dist[u_virt_id][v_virt_id] = edge.get_weight().clone();
+                dist[u_virt_id][v_virt_id] = *edge.get_weight();
             }
         }
 
@@ -160,12 +160,12 @@ impl FloydWarshall {
         }
 
         let mut distance_map = HashMap::new();
-        for i in 0..vertex_count {
+        for (i, row) in dist.iter().enumerate() {
             let i_real_id = id_map.real_id_of(i);
-            for j in 0..vertex_count {
+            for (j, distance) in row.iter().enumerate() {
                 let j_real_id = id_map.real_id_of(j);
 
-                distance_map.insert((i_real_id, j_real_id), dist[i][j].clone());
+                distance_map.insert((i_real_id, j_real_id), *distance);
             }
         }
 
@@ -0,0 +1,272 @@
+use anyhow::Result;
+use magnitude::Magnitude;
+use num_traits::Zero;
+use std::any::Any;
+use std::collections::{BinaryHeap, HashMap};
+use std::ops::Sub;
+
+use crate::algo::shortest_path::all_pairs_dijkstra::{dijkstra_from, finite_adjacency};
+use crate::algo::BellmanFord;
+use crate::graph::{DefaultEdge, Edge, EdgeDir, ListGraph};
+use crate::provide::{Edges, Graph, Vertices};
+use crate::storage::DiList;
+
+/// Finds shortest path from all vertices to all the other ones using johnson algorithm.
+///
+/// A virtual source with zero weight edges to all the vertices is added, and [`BellmanFord`](crate::algo::BellmanFord) from it gives a potential h(v) to each vertex.
+/// Reweighting each edge (u, v) to w(u, v) + h(u) - h(v) makes all weights non-negative while keeping the shortest paths the same,
+/// so dijkstra algorithm is run from each vertex on the reweighted edges.
+/// Reweighted edges still have a signed weight type, which [`Dijkstra`](crate::algo::Dijkstra) does not accept, so the search is done internally.
+///
+/// Compared to [`FloydWarshall`](crate::algo::FloydWarshall), it is faster on sparse graphs.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::DiMat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::Johnson;
+///
+/// // Given: Graph
+/// //
+/// //      a --4--> b --(-2)--> c
+/// //      |                    ^
+/// //      '----------3---------'
+/// //
+/// let mut graph = MatGraph::init(DiMat::<isize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 4.into());
+/// graph.add_edge(b, c, (-2).into());
+/// graph.add_edge(a, c, 3.into());
+///
+/// // When: Performing johnson algorithm.
+/// let distance_map = Johnson::init().execute(&graph).unwrap();
+///
+/// // Then:
+/// assert_eq!(distance_map[&(a, c)], 2.into());
+/// assert_eq!(distance_map[&(b, c)], (-2).into());
+/// assert!(distance_map[&(c, a)].is_pos_infinite());
+/// ```
+pub struct Johnson {}
+
+impl Johnson {
+    /// Initializes the structure.
+    pub fn init() -> Self {
+        Johnson {}
+    }
+
+    /// Finds shortest path from all vertices to all the other ones.
+    ///
+    /// # Arguments
+    /// `graph`: Graph to search for the shortest paths in. Edges with infinite weight are ignored.
+    ///
+    /// # Returns
+    /// * `Ok`: Containing shortest path information in the form of: (src_id, dst_id) -> distance,
+    ///   which is `PosInfinite` if `dst_id` is not reachable from `src_id`.
+    /// * `Err`: If graph contains negative cycle.
+    ///
+    /// # Complexity
+    /// O(|V||E|log(|V|))
+    pub fn execute<G, W, E, Ty>(self, graph: &G) -> Result<HashMap<(usize, usize), Magnitude<W>>>
+    where
+        W: Copy + Zero + Any + Ord + Sub<Output = W>,
+        E: Edge<W>,
+        Ty: EdgeDir,
+        G: Edges<W, E> + Vertices + Graph<W, E, Ty>,
+    {
+        let vertex_count = graph.vertex_count();
+        let id_map = graph.continuos_id_map();
+
+        let adjacency = finite_adjacency(graph, &id_map);
+
+        let potential_of = Johnson::potentials(&adjacency)?;
+
+        let mut distance_map = HashMap::with_capacity(vertex_count * vertex_count);
+
+        let mut dist: Vec<Option<W>> = vec![None; vertex_count];
+        let mut heap = BinaryHeap::with_capacity(vertex_count);
+        for src_virt_id in 0..vertex_count {
+            dijkstra_from(
+                &adjacency,
+                src_virt_id,
+                &mut dist,
+                &mut heap,
+                |virt_id, n_virt_id, weight| {
+                    weight + potential_of[virt_id] - potential_of[n_virt_id]
+                },
+            );
+
+            let src_id = id_map.real_id_of(src_virt_id);
+            for (dst_virt_id, distance) in dist.iter().enumerate() {
+                // Undo the reweighting: potentials of the vertices in between cancel out.
+                let distance = distance.map_or(Magnitude::PosInfinite, |d| {
+                    (d + potential_of[dst_virt_id] - potential_of[src_virt_id]).into()
+                });
+
+                distance_map.insert((src_id, id_map.real_id_of(dst_virt_id)), distance);
+            }
+        }
+
+        Ok(distance_map)
+    }
+
+    // Computes potential of each vertex(by virtual id) as its distance from a virtual source connected to all the vertices,
+    // by running bellman-ford on a copy of the graph that has the virtual source as its last vertex.
+    //
+    // # Returns
+    // * `Ok`: Containing the potentials.
+    // * `Err`: If graph contains negative cycle.
+    fn potentials<W>(adjacency: &[Vec<(usize, W)>]) -> Result<Vec<W>>
+    where
+        W: Copy + Zero + Any + Ord,
+    {
+        let vertex_count = adjacency.len();
+
+        let mut extended = ListGraph::init(DiList::<W>::init());
+        for _ in 0..=vertex_count {
+            extended.add_vertex();
+        }
+        for (virt_id, neighbors) in adjacency.iter().enumerate() {
+            for (n_virt_id, weight) in neighbors {
+                extended.add_edge(virt_id, *n_virt_id, DefaultEdge::from(*weight))?;
+            }
+            extended.add_edge(vertex_count, virt_id, DefaultEdge::from(W::zero()))?;
+        }
+
+        let sp_subgraph = BellmanFord::init(&extended).execute(&extended, vertex_count)?;
+
+        Ok((0..vertex_count)
+            .map(|virt_id| sp_subgraph.distance_to(virt_id).unwrap().unwrap())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::{Error, ErrorKind, FloydWarshall};
+    use crate::graph::{MatGraph, UndirectedEdge};
+    use crate::storage::{DiMat, Mat};
+
+    // Infinite magnitudes can not be compared, so they are checked separately.
+    fn assert_same_distances(
+        distance_map: &HashMap<(usize, usize), Magnitude<isize>>,
+        expected: &HashMap<(usize, usize), Magnitude<isize>>,
+    ) {
+        assert_eq!(distance_map.len(), expected.len());
+        for (pair, distance) in expected {
+            match distance {
+                Magnitude::Finite(_) => assert_eq!(distance_map[pair], *distance),
+                _ => assert!(distance_map[pair].is_pos_infinite()),
+            }
+        }
+    }
+
+    fn is_negative_cycle(result: Result<HashMap<(usize, usize), Magnitude<isize>>>) -> bool {
+        matches!(
+            result.unwrap_err().downcast_ref::<Error>().unwrap().kind(),
+            ErrorKind::NegativeCycleDetected
+        )
+    }
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(DiMat::<isize>::init());
+
+        assert!(Johnson::init().execute(&graph).unwrap().is_empty());
+    }
+
+    #[test]
+    fn same_as_floyd_warshall_with_negative_edge() {
+        // Given: Graph
+        //
+        //      a --3--> b --4--> c
+        //      |        ^        |
+        //      8      (-5)       1
+        //      |        |        |
+        //      v        |        v
+        //      d --2--> e <--7-- f      g
+        //
+        let mut graph = MatGraph::init(DiMat::<isize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        graph.add_vertex();
+        graph.add_edge(a, b, 3.into()).unwrap();
+        graph.add_edge(b, c, 4.into()).unwrap();
+        graph.add_edge(a, d, 8.into()).unwrap();
+        graph.add_edge(e, b, (-5).into()).unwrap();
+        graph.add_edge(c, f, 1.into()).unwrap();
+        graph.add_edge(d, e, 2.into()).unwrap();
+        graph.add_edge(f, e, 7.into()).unwrap();
+
+        // When: Performing johnson and floyd-warshall algorithms.
+        let distance_map = Johnson::init().execute(&graph).unwrap();
+        let expected = FloydWarshall::init().execute(&graph).unwrap();
+
+        // Then:
+        assert_same_distances(&distance_map, &expected);
+        assert_eq!(distance_map[&(d, b)], (-3).into());
+        assert_eq!(distance_map[&(f, c)], 6.into());
+    }
+
+    #[test]
+    fn negative_cycle() {
+        // Given: Graph
+        //
+        //      a --1--> b --(-3)--> c
+        //      ^                    |
+        //      '---------1----------'
+        //
+        let mut graph = MatGraph::init(DiMat::<isize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, (-3).into()).unwrap();
+        graph.add_edge(c, a, 1.into()).unwrap();
+
+        // Then:
+        assert!(is_negative_cycle(Johnson::init().execute(&graph)));
+    }
+
+    #[test]
+    fn undirected_negative_edge_is_negative_cycle() {
+        let mut graph: MatGraph<isize, UndirectedEdge> = MatGraph::init(Mat::<isize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        graph.add_edge(a, b, (-1).into()).unwrap();
+
+        assert!(is_negative_cycle(Johnson::init().execute(&graph)));
+    }
+
+    #[test]
+    fn undirected_graph() {
+        // Given: Graph
+        //
+        //      a --1-- b --2-- c
+        //       \             /
+        //        '-----5-----'
+        //
+        let mut graph = MatGraph::init(Mat::<isize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 2.into()).unwrap();
+        graph.add_edge(a, c, 5.into()).unwrap();
+
+        // When: Performing johnson and floyd-warshall algorithms.
+        let distance_map = Johnson::init().execute(&graph).unwrap();
+        let expected = FloydWarshall::init().execute(&graph).unwrap();
+
+        // Then:
+        assert_same_distances(&distance_map, &expected);
+        assert_eq!(distance_map[&(c, a)], 3.into());
+    }
+}
@@ -1,7 +1,26 @@
+mod all_pairs_dijkstra;
+mod alt;
+mod astar;
 mod bellman_ford;
+mod constrained;
+mod contraction_hierarchies;
 mod dijkstra;
 mod floyd_warshall;
+mod johnson;
+mod predecessors;
+mod shortest_path_count;
+mod time_window;
+mod turn_penalty;
 
+pub use all_pairs_dijkstra::all_pairs_dijkstra;
+pub use alt::AltHeuristic;
+pub use astar::AStar;
 pub use bellman_ford::BellmanFord;
+pub use constrained::constrained_shortest_path;
+pub use contraction_hierarchies::ContractionHierarchies;
 pub use dijkstra::Dijkstra;
 pub use floyd_warshall::FloydWarshall;
+pub use johnson::Johnson;
+pub use shortest_path_count::shortest_path_count;
+pub use time_window::time_window_shortest_path;
+pub use turn_penalty::turn_penalty_shortest_path;
@@ -0,0 +1,27 @@
+use magnitude::Magnitude;
+
+use crate::provide::IdMap;
+
+// Walks the predecessors back from `dst_virt_id` to `src_virt_id`, which must be reachable from each other.
+//
+// # Arguments
+// * `prev`: Virtual id of the vertex before each vertex(by virtual id) on its shortest path.
+//
+// # Returns
+// Real id of the vertices on the path in order, starting with the source and ending with the destination.
+pub(crate) fn path_to(
+    id_map: &IdMap,
+    prev: &[Magnitude<usize>],
+    src_virt_id: usize,
+    dst_virt_id: usize,
+) -> Vec<usize> {
+    let mut virt_id = dst_virt_id;
+    let mut path = vec![id_map.real_id_of(virt_id)];
+    while virt_id != src_virt_id {
+        virt_id = *prev[virt_id].as_ref().unwrap();
+        path.push(id_map.real_id_of(virt_id));
+    }
+    path.reverse();
+
+    path
+}
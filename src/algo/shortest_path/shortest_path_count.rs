@@ -0,0 +1,199 @@
+use std::collections::VecDeque;
+
+use crate::provide::{Neighbors, Vertices};
+
+/// Counts the distinct shortest paths between two vertices, in which length of a path is its number of edges.
+///
+/// Uses bfs from the source. Number of shortest paths to each vertex is the sum of the numbers of its neighbors
+/// that are one level closer to the source, and each vertex is finalized once its level is done.
+/// Weights of the edges are ignored.
+///
+/// # Arguments
+/// * `graph`: Graph to count the paths in.
+/// * `src_id`: Id of the vertex that paths start from.
+/// * `dst_id`: Id of the vertex that paths end at.
+///
+/// # Returns
+/// (distance, count) in which distance is the number of edges of a shortest path from `src_id` to `dst_id`,
+/// and count is the number of such paths, saturated at `u64::MAX`.
+/// If `dst_id` is not reachable from `src_id`, distance is `usize::MAX` and count is 0.
+///
+/// # Complexity
+/// O(|V| + |E|)
+///
+/// # Panics
+/// If `src_id` or `dst_id` is not in the graph.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::shortest_path_count;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b --- d
+/// //      |           |
+/// //      c --------- e
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// let e = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, d, 1.into());
+/// graph.add_edge(a, c, 1.into());
+/// graph.add_edge(c, e, 1.into());
+/// graph.add_edge(d, e, 1.into());
+///
+/// // When: Counting the shortest paths from a to d.
+/// let (distance, count) = shortest_path_count(&graph, a, d);
+///
+/// // Then: Only a -> b -> d has two edges.
+/// assert_eq!(distance, 2);
+/// assert_eq!(count, 1);
+/// ```
+pub fn shortest_path_count<G>(graph: &G, src_id: usize, dst_id: usize) -> (usize, u64)
+where
+    G: Vertices + Neighbors,
+{
+    let id_map = graph.continuos_id_map();
+    let vertex_count = graph.vertex_count();
+
+    let src_virt_id = id_map.virt_id_of(src_id);
+    let dst_virt_id = id_map.virt_id_of(dst_id);
+
+    let mut distance_of: Vec<Option<usize>> = vec![None; vertex_count];
+    let mut count_of = vec![0u64; vertex_count];
+    let mut queue = VecDeque::new();
+
+    distance_of[src_virt_id] = Some(0);
+    count_of[src_virt_id] = 1;
+    queue.push_back(src_virt_id);
+
+    while let Some(virt_id) = queue.pop_front() {
+        // Vertices farther than the destination can not be on a shortest path to it.
+        if virt_id == dst_virt_id {
+            break;
+        }
+        let distance = distance_of[virt_id].unwrap();
+
+        for n_id in graph.neighbors(id_map.real_id_of(virt_id)).unwrap() {
+            let n_virt_id = id_map.virt_id_of(n_id);
+
+            match distance_of[n_virt_id] {
+                None => {
+                    distance_of[n_virt_id] = Some(distance + 1);
+                    count_of[n_virt_id] = count_of[virt_id];
+                    queue.push_back(n_virt_id);
+                }
+                Some(n_distance) if n_distance == distance + 1 => {
+                    count_of[n_virt_id] = count_of[n_virt_id].saturating_add(count_of[virt_id]);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    match distance_of[dst_virt_id] {
+        Some(distance) => (distance, count_of[dst_virt_id]),
+        None => (usize::MAX, 0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen::GridGraphGenerator;
+    use crate::graph::MatGraph;
+    use crate::provide::Graph;
+    use crate::storage::{DiMat, Mat};
+
+    #[test]
+    fn same_source_and_destination() {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+
+        assert_eq!(shortest_path_count(&graph, a, a), (0, 1));
+    }
+
+    #[test]
+    fn unreachable_destination() {
+        // Given: Graph
+        //
+        //      a --> b     c
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+
+        // Then: Edges can not be traversed backward.
+        assert_eq!(shortest_path_count(&graph, a, b), (1, 1));
+        assert_eq!(shortest_path_count(&graph, b, a), (usize::MAX, 0));
+        assert_eq!(shortest_path_count(&graph, a, c), (usize::MAX, 0));
+    }
+
+    #[test]
+    fn grid_paths_are_binomial() {
+        // Given: 4 by 6 grid.
+        let generator = GridGraphGenerator::init(4, 6);
+        let graph = generator.generate();
+
+        // When: Counting the shortest paths between opposite corners.
+        let src_id = generator.vertex_id_of(0, 0).unwrap();
+        let dst_id = generator.vertex_id_of(3, 5).unwrap();
+        let (distance, count) = shortest_path_count(&graph, src_id, dst_id);
+
+        // Then: Each shortest path is 3 steps down and 5 steps right in some order, so there are C(8, 3) of them.
+        assert_eq!(distance, 8);
+        assert_eq!(count, 56);
+
+        // And: Between a corner and a cell in the middle, there are C(3, 1).
+        let mid_id = generator.vertex_id_of(1, 2).unwrap();
+        assert_eq!(shortest_path_count(&graph, src_id, mid_id), (3, 3));
+    }
+
+    #[test]
+    fn diamond_chain() {
+        // Given: Graph
+        //
+        //        b       e
+        //      /   \   /   \
+        //     a     d       g
+        //      \   / \     /
+        //        c     f --
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        let g = graph.add_vertex();
+        for (src_id, dst_id) in [
+            (a, b),
+            (a, c),
+            (b, d),
+            (c, d),
+            (d, e),
+            (d, f),
+            (e, g),
+            (f, g),
+        ] {
+            graph.add_edge(src_id, dst_id, 1.into()).unwrap();
+        }
+
+        // When: Counting the shortest paths from a to g.
+        let (distance, count) = shortest_path_count(&graph, a, g);
+
+        // Then: Two choices in each diamond.
+        assert_eq!(distance, 4);
+        assert_eq!(count, 4);
+    }
+}
@@ -0,0 +1,237 @@
+use magnitude::Magnitude;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::graph::{Edge, EdgeDir};
+use crate::provide::{Edges, Graph, Vertices};
+
+/// Finds the earliest arrival at a destination when each vertex may only be visited within its time window.
+///
+/// Path leaves the source at time 0 and weight of each edge is the time it takes to cross it.
+/// Arriving at a vertex before its window opens requires waiting there until it opens, and arriving after it closes is infeasible.
+/// Since arriving earlier never makes a vertex less reachable(you can always wait), search is done using dijkstra algorithm over arrival times.
+///
+/// # Arguments
+/// * `graph`: Graph to search for the path in. Edges with infinite weight are ignored.
+/// * `src_id`: Id of the source vertex.
+/// * `dst_id`: Id of the destination vertex.
+/// * `windows`: Maps id of a vertex to (opening time, closing time) of its window, both inclusive. Vertices without a window can be visited anytime.
+///
+/// # Returns
+/// * `Some`: Containing the earliest feasible arrival time at `dst_id`(after waiting for its window to open) alongside id of vertices on the path, starting from `src_id` and ending with `dst_id`.
+/// * `None`: If there is no feasible path from `src_id` to `dst_id`.
+///
+/// # Complexity
+/// O((|V| + |E|)log(|V|))
+///
+/// # Panics
+/// If `src_id` or `dst_id` is not in the graph.
+///
+/// # Examples
+/// ```
+/// use std::collections::HashMap;
+/// use prepona::prelude::*;
+/// use prepona::storage::DiMat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::time_window_shortest_path;
+///
+/// // Given: Graph
+/// //
+/// //      s --1--> a --1--> t
+/// //
+/// let mut graph = MatGraph::init(DiMat::<usize>::init());
+/// let s = graph.add_vertex();
+/// let a = graph.add_vertex();
+/// let t = graph.add_vertex();
+/// graph.add_edge(s, a, 1.into());
+/// graph.add_edge(a, t, 1.into());
+///
+/// // When: a opens at time 5.
+/// let windows: HashMap<usize, (usize, usize)> = [(a, (5, 8))].iter().copied().collect();
+/// let (arrival, path) = time_window_shortest_path(&graph, s, t, windows).unwrap();
+///
+/// // Then: Path waits at a until it opens.
+/// assert_eq!(arrival, 6);
+/// assert_eq!(path, vec![s, a, t]);
+/// ```
+pub fn time_window_shortest_path<E, Ty, G>(
+    graph: &G,
+    src_id: usize,
+    dst_id: usize,
+    windows: HashMap<usize, (usize, usize)>,
+) -> Option<(usize, Vec<usize>)>
+where
+    E: Edge<usize>,
+    Ty: EdgeDir,
+    G: Edges<usize, E> + Vertices + Graph<usize, E, Ty>,
+{
+    let id_map = graph.continuos_id_map();
+    let vertex_count = graph.vertex_count();
+    let src_virt_id = id_map.virt_id_of(src_id);
+    let dst_virt_id = id_map.virt_id_of(dst_id);
+
+    // Earliest time that path can leave the vertex after arriving at `time`, if the window is not closed yet.
+    let departure_of = |virt_id: usize, time: usize| {
+        let window = windows.get(&id_map.real_id_of(virt_id));
+        match window {
+            Some((open, close)) if time <= *close => Some(time.max(*open)),
+            Some(_) => None,
+            None => Some(time),
+        }
+    };
+
+    let mut departure: Vec<Option<usize>> = vec![None; vertex_count];
+    let mut prev: Vec<Option<usize>> = vec![None; vertex_count];
+    let mut heap = BinaryHeap::new();
+
+    departure[src_virt_id] = Some(departure_of(src_virt_id, 0)?);
+    heap.push(Reverse((departure[src_virt_id].unwrap(), src_virt_id)));
+
+    while let Some(Reverse((time, virt_id))) = heap.pop() {
+        // Skip stale entries of vertices that got an earlier departure after being pushed.
+        if departure[virt_id].map_or(false, |t| t < time) {
+            continue;
+        }
+        if virt_id == dst_virt_id {
+            break;
+        }
+
+        for (n_id, edge) in graph.edges_from(id_map.real_id_of(virt_id)).unwrap() {
+            let weight = match edge.get_weight() {
+                Magnitude::Finite(weight) => *weight,
+                _ => continue,
+            };
+
+            let n_virt_id = id_map.virt_id_of(n_id);
+            if let Some(n_time) = departure_of(n_virt_id, time + weight) {
+                if departure[n_virt_id].map_or(true, |t| n_time < t) {
+                    departure[n_virt_id] = Some(n_time);
+                    prev[n_virt_id] = Some(virt_id);
+                    heap.push(Reverse((n_time, n_virt_id)));
+                }
+            }
+        }
+    }
+
+    let arrival = departure[dst_virt_id]?;
+
+    let mut path = vec![dst_id];
+    let mut virt_id = dst_virt_id;
+    while let Some(prev_virt_id) = prev[virt_id] {
+        path.push(id_map.real_id_of(prev_virt_id));
+        virt_id = prev_virt_id;
+    }
+    path.reverse();
+
+    Some((arrival, path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{DirectedEdge, MatGraph};
+    use crate::storage::{DiMat, Mat};
+
+    // Given: Graph
+    //
+    //      s --1--> a --1--> t
+    //      |                 ^
+    //      2                 3
+    //      |                 |
+    //      '------> b -------'
+    //
+    fn two_routes() -> (MatGraph<usize, DirectedEdge>, [usize; 4]) {
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let s = graph.add_vertex();
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let t = graph.add_vertex();
+        graph.add_edge(s, a, 1.into()).unwrap();
+        graph.add_edge(a, t, 1.into()).unwrap();
+        graph.add_edge(s, b, 2.into()).unwrap();
+        graph.add_edge(b, t, 3.into()).unwrap();
+
+        (graph, [s, a, b, t])
+    }
+
+    #[test]
+    fn without_windows() {
+        let (graph, [s, a, _, t]) = two_routes();
+
+        let (arrival, path) = time_window_shortest_path(&graph, s, t, HashMap::new()).unwrap();
+
+        assert_eq!(arrival, 2);
+        assert_eq!(path, vec![s, a, t]);
+        assert_eq!(
+            time_window_shortest_path(&graph, s, s, HashMap::new()),
+            Some((0, vec![s]))
+        );
+    }
+
+    #[test]
+    fn closed_window_forces_longer_route() {
+        let (graph, [s, a, b, t]) = two_routes();
+
+        // When: a closes before the path can reach it.
+        let windows = [(a, (0, 0))].iter().copied().collect();
+        let (arrival, path) = time_window_shortest_path(&graph, s, t, windows).unwrap();
+
+        // Then: Direct route through a is infeasible.
+        assert_eq!(arrival, 5);
+        assert_eq!(path, vec![s, b, t]);
+    }
+
+    #[test]
+    fn waiting_is_compared_with_other_routes() {
+        let (graph, [s, a, b, t]) = two_routes();
+
+        // When: a opens at time 3 or at time 5.
+        let early = [(a, (3, 10))].iter().copied().collect();
+        let late = [(a, (5, 10))].iter().copied().collect();
+
+        // Then: Waiting for a is worth it only when it opens early enough.
+        assert_eq!(
+            time_window_shortest_path(&graph, s, t, early),
+            Some((4, vec![s, a, t]))
+        );
+        assert_eq!(
+            time_window_shortest_path(&graph, s, t, late),
+            Some((5, vec![s, b, t]))
+        );
+    }
+
+    #[test]
+    fn infeasible_destination() {
+        let (graph, [s, _, _, t]) = two_routes();
+
+        // Then: t closes before any route reaches it.
+        let windows = [(t, (0, 1))].iter().copied().collect();
+        assert!(time_window_shortest_path(&graph, s, t, windows).is_none());
+
+        // And: Waiting at the destination for its window to open is part of the arrival time.
+        let windows = [(t, (7, 9))].iter().copied().collect();
+        assert_eq!(
+            time_window_shortest_path(&graph, s, t, windows).unwrap().0,
+            7
+        );
+    }
+
+    #[test]
+    fn source_window() {
+        // Given: Graph
+        //
+        //      a --2-- b
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        graph.add_edge(a, b, 2.into()).unwrap();
+
+        // Then: Path leaves the source once its window opens.
+        let windows = [(a, (4, 6))].iter().copied().collect();
+        assert_eq!(
+            time_window_shortest_path(&graph, a, b, windows),
+            Some((6, vec![a, b]))
+        );
+    }
+}
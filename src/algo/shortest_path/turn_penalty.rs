@@ -0,0 +1,223 @@
+use magnitude::Magnitude;
+use num_traits::{Unsigned, Zero};
+use std::any::Any;
+use std::collections::HashMap;
+
+use crate::graph::{Edge, EdgeDir};
+use crate::provide::{Edges, Graph, Neighbors, Vertices};
+
+/// Finds shortest path between two vertices when passing through a vertex costs a penalty that depends on the edges used to enter and leave it.
+///
+/// Search is done using dijkstra algorithm over edges instead of vertices:
+/// Each state is an edge crossed in a specific direction, so the edge that path has entered a vertex by is known when leaving it.
+///
+/// # Arguments
+/// * `graph`: Graph to search for the shortest path in.
+/// * `src_id`: Id of the source vertex.
+/// * `dst_id`: Id of the destination vertex.
+/// * `penalty_fn`: Receives the edge that path enters a vertex by and the edge that it leaves the vertex by, and returns cost of the turn.
+///   Returning `PosInfinite` forbids the turn.
+///
+/// # Returns
+/// * `Some`: Containing weight of the shortest path(including penalties) alongside id of vertices on the path, starting from `src_id` and ending with `dst_id`.
+/// * `None`: If there is no path from `src_id` to `dst_id`.
+///
+/// # Complexity
+/// O(|E|<sup>2</sup>)
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::turn_penalty_shortest_path;
+///
+/// // Given: Graph
+/// //
+/// //      a --1-- b --1-- c
+/// //       \             /
+/// //        '-----3-----'
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(a, c, 3.into());
+///
+/// // When: Every turn costs 2.
+/// let (weight, path) = turn_penalty_shortest_path(&graph, a, c, |_, _| 2.into()).unwrap();
+///
+/// // Then:
+/// assert_eq!(weight, 3.into());
+/// assert_eq!(path, vec![a, c]);
+/// ```
+pub fn turn_penalty_shortest_path<W, E, Ty, G, F>(
+    graph: &G,
+    src_id: usize,
+    dst_id: usize,
+    penalty_fn: F,
+) -> Option<(Magnitude<W>, Vec<usize>)>
+where
+    W: Copy + Ord + Zero + Any + Unsigned,
+    E: Edge<W>,
+    Ty: EdgeDir,
+    G: Edges<W, E> + Neighbors + Vertices + Graph<W, E, Ty>,
+    F: Fn(&E, &E) -> Magnitude<W>,
+{
+    if !graph.contains_vertex(src_id) || !graph.contains_vertex(dst_id) {
+        return None;
+    }
+
+    if src_id == dst_id {
+        return Some((W::zero().into(), vec![src_id]));
+    }
+
+    // Every edge in each direction it can be crossed, in the format of (src_id, dst_id, edge).
+    let mut arcs = vec![];
+    // Maps each vertex to index of the arcs going out of it.
+    let mut arcs_from: HashMap<usize, Vec<usize>> = HashMap::new();
+    for vertex_id in graph.vertices() {
+        for (n_id, edge) in graph.edges_from(vertex_id).unwrap() {
+            if edge.get_weight().is_finite() {
+                arcs_from.entry(vertex_id).or_default().push(arcs.len());
+                arcs.push((vertex_id, n_id, edge));
+            }
+        }
+    }
+
+    let mut dist = vec![Magnitude::PosInfinite; arcs.len()];
+    let mut prev: Vec<Option<usize>> = vec![None; arcs.len()];
+    let mut visited = vec![false; arcs.len()];
+
+    for index in arcs_from.get(&src_id).into_iter().flatten() {
+        dist[*index] = *arcs[*index].2.get_weight();
+    }
+
+    let mut last_arc = None;
+    while let Some(index) = dist
+        .iter()
+        .enumerate()
+        .filter(|(index, dist)| dist.is_finite() && !visited[*index])
+        .min_by(|(_, dist1), (_, dist2)| dist1.cmp(dist2))
+        .map(|(index, _)| index)
+    {
+        let (_, v_id, in_edge) = arcs[index];
+        if v_id == dst_id {
+            last_arc = Some(index);
+            break;
+        }
+
+        visited[index] = true;
+
+        for next_index in arcs_from.get(&v_id).into_iter().flatten().copied() {
+            let out_edge = arcs[next_index].2;
+            let penalty = penalty_fn(in_edge, out_edge);
+            if visited[next_index] || !penalty.is_finite() {
+                continue;
+            }
+
+            let alt = dist[index] + penalty + *out_edge.get_weight();
+            if dist[next_index].is_pos_infinite() || alt < dist[next_index] {
+                dist[next_index] = alt;
+                prev[next_index] = Some(index);
+            }
+        }
+    }
+
+    let last_arc = last_arc?;
+
+    let mut path = vec![];
+    let mut index = last_arc;
+    loop {
+        path.push(arcs[index].1);
+
+        match prev[index] {
+            Some(prev_index) => index = prev_index,
+            None => break,
+        }
+    }
+    path.push(src_id);
+    path.reverse();
+
+    Some((dist[last_arc], path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::storage::{DiMat, Mat};
+
+    #[test]
+    fn same_source_and_destination() {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+
+        let (weight, path) = turn_penalty_shortest_path(&graph, a, a, |_, _| 1.into()).unwrap();
+
+        assert_eq!(weight, 0.into());
+        assert_eq!(path, vec![a]);
+    }
+
+    #[test]
+    fn unreachable_destination() {
+        // Given: Graph
+        //
+        //      a --> b     c
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+
+        assert!(turn_penalty_shortest_path(&graph, a, c, |_, _| 0.into()).is_none());
+        assert!(turn_penalty_shortest_path(&graph, b, a, |_, _| 0.into()).is_none());
+    }
+
+    #[test]
+    fn penalty_makes_longer_path_optimal() {
+        // Given: Graph
+        //
+        //      s --1-- a
+        //      |       |
+        //      2       1
+        //      |       |
+        //      b       t
+        //      |       |
+        //      1       2
+        //      |       |
+        //      c ------'
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let s = graph.add_vertex();
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let t = graph.add_vertex();
+        let sa = graph.add_edge(s, a, 1.into()).unwrap();
+        let at = graph.add_edge(a, t, 1.into()).unwrap();
+        graph.add_edge(s, b, 2.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, t, 2.into()).unwrap();
+
+        // When: Turning from sa into at is expensive.
+        let no_penalty = turn_penalty_shortest_path(&graph, s, t, |_, _| 0.into()).unwrap();
+        let with_penalty = turn_penalty_shortest_path(&graph, s, t, |in_edge, out_edge| {
+            if in_edge.get_id() == sa && out_edge.get_id() == at {
+                10.into()
+            } else {
+                0.into()
+            }
+        })
+        .unwrap();
+
+        // Then:
+        assert_eq!(no_penalty.0, 2.into());
+        assert_eq!(no_penalty.1, vec![s, a, t]);
+        assert_eq!(with_penalty.0, 5.into());
+        assert_eq!(with_penalty.1, vec![s, b, c, t]);
+    }
+}
@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+
+use crate::algo::Kruskal;
+use crate::graph::{Edge, UndirectedEdge};
+use crate::provide::{self, Edges};
+
+/// Clusters vertices of the graph using single-linkage hierarchical clustering.
+///
+/// For a connected graph, single-linkage clustering with `k` clusters is the same as removing the `k` - 1 heaviest edges of the MST:
+/// Each remaining tree of the forest is a cluster.
+///
+/// # Arguments
+/// * `graph`: Graph to cluster its vertices. Weight of each edge is the distance between its end points.
+/// * `k`: Number of clusters.
+///
+/// # Returns
+/// Clusters, each one as a sorted list of vertex ids. Clusters are sorted by their smallest vertex id. \
+/// If graph has more than `k` connected components, each component is a cluster on its own.
+/// And if graph has fewer than `k` vertices, each vertex is a cluster on its own.
+///
+/// # Complexity
+/// O(|E|log(|E|) + |V|)
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::single_linkage_clusters;
+///
+/// // Given: Graph
+/// //
+/// //      a --1-- b --5-- c --1-- d
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 5.into());
+/// graph.add_edge(c, d, 1.into());
+///
+/// // When: Splitting the graph into two clusters.
+/// let clusters = single_linkage_clusters(&graph, 2);
+///
+/// // Then:
+/// assert_eq!(clusters, vec![vec![a, b], vec![c, d]]);
+/// ```
+pub fn single_linkage_clusters<G, W: Ord, E: Edge<W>>(graph: &G, k: usize) -> Vec<Vec<usize>>
+where
+    G: provide::Edges<W, E>
+        + provide::Neighbors
+        + provide::Vertices
+        + provide::Graph<W, E, UndirectedEdge>,
+{
+    let id_map = graph.continuos_id_map();
+    let vertex_count = graph.vertex_count();
+
+    // Edges of the MST are sorted by their weight, so the heaviest ones are at the end.
+    // Each kept edge merges two clusters, so keeping |V| - k of them leaves k clusters.
+    let mst = Kruskal::init(graph).execute(graph);
+    let tree_edges = mst.edges();
+    let kept_count = vertex_count.saturating_sub(k).min(tree_edges.len());
+
+    // Disjoint set of vertices by virtual id.
+    let mut parent_of = (0..vertex_count).collect::<Vec<usize>>();
+    fn root_of(parent_of: &mut [usize], mut virt_id: usize) -> usize {
+        while parent_of[virt_id] != virt_id {
+            parent_of[virt_id] = parent_of[parent_of[virt_id]];
+            virt_id = parent_of[virt_id];
+        }
+
+        virt_id
+    }
+
+    for (src_id, dst_id, _) in tree_edges.into_iter().take(kept_count) {
+        let src_root = root_of(&mut parent_of, id_map.virt_id_of(src_id));
+        let dst_root = root_of(&mut parent_of, id_map.virt_id_of(dst_id));
+        parent_of[src_root] = dst_root;
+    }
+
+    let mut cluster_of_root = HashMap::new();
+    for virt_id in 0..vertex_count {
+        let root = root_of(&mut parent_of, virt_id);
+        cluster_of_root
+            .entry(root)
+            .or_insert_with(Vec::new)
+            .push(id_map.real_id_of(virt_id));
+    }
+
+    let mut clusters = cluster_of_root
+        .into_values()
+        .map(|mut cluster| {
+            cluster.sort_unstable();
+            cluster
+        })
+        .collect::<Vec<Vec<usize>>>();
+    clusters.sort_unstable();
+
+    clusters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::provide::*;
+    use crate::storage::Mat;
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        assert!(single_linkage_clusters(&graph, 2).is_empty());
+    }
+
+    #[test]
+    fn two_dense_regions() {
+        // Given: Graph
+        //
+        //      a --1-- b           e --2-- f
+        //      | \     |           |     / |
+        //      2   1   1 ---9---   1   2   1
+        //      |     \ |           | /     |
+        //      c --2-- d --8------ g --1-- h
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        let g = graph.add_vertex();
+        let h = graph.add_vertex();
+        let edges = [
+            (a, b, 1),
+            (a, c, 2),
+            (a, d, 1),
+            (b, d, 1),
+            (c, d, 2),
+            (e, f, 2),
+            (e, g, 1),
+            (f, g, 2),
+            (f, h, 1),
+            (g, h, 1),
+            (b, e, 9),
+            (d, g, 8),
+        ];
+        for (src_id, dst_id, weight) in edges.iter() {
+            graph.add_edge(*src_id, *dst_id, (*weight).into()).unwrap();
+        }
+
+        // When: Splitting the graph into clusters.
+        let one_cluster = single_linkage_clusters(&graph, 1);
+        let two_clusters = single_linkage_clusters(&graph, 2);
+        let too_many_clusters = single_linkage_clusters(&graph, 10);
+
+        // Then:
+        assert_eq!(one_cluster, vec![vec![a, b, c, d, e, f, g, h]]);
+        assert_eq!(two_clusters, vec![vec![a, b, c, d], vec![e, f, g, h]]);
+        assert_eq!(too_many_clusters.len(), graph.vertex_count());
+    }
+
+    #[test]
+    fn disconnected_graph() {
+        // Given: Graph
+        //
+        //      a --1-- b       c
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+
+        // When: Splitting the graph into clusters.
+        let one_cluster = single_linkage_clusters(&graph, 1);
+        let two_clusters = single_linkage_clusters(&graph, 2);
+        let three_clusters = single_linkage_clusters(&graph, 3);
+
+        // Then: Components are never merged.
+        assert_eq!(one_cluster, vec![vec![a, b], vec![c]]);
+        assert_eq!(two_clusters, vec![vec![a, b], vec![c]]);
+        assert_eq!(three_clusters, vec![vec![a], vec![b], vec![c]]);
+    }
+}
@@ -0,0 +1,215 @@
+use num_traits::Zero;
+use std::any::Any;
+use std::collections::HashMap;
+
+use crate::graph::{with_vertices_of, DirectedEdge, Edge, FlowEdge, SimpleGraph};
+use crate::provide::{Edges, Graph, Vertices};
+use crate::storage::GraphStorage;
+
+/// Transforms a network with capacities on its vertices into a network with capacities only on its edges.
+///
+/// Each vertex with a capacity is split into an in vertex and an out vertex, connected by an edge from in to out that has the capacity of the vertex and weight of zero.
+/// Edges entering the vertex enter its in vertex, and edges leaving the vertex leave its out vertex.
+/// So any flow passing through the vertex must pass through the new edge, and algorithms like [`MaxFlow`](crate::algo::MaxFlow) respect the capacity of the vertex.
+/// Vertices without a capacity are not split.
+///
+/// # Arguments
+/// * `graph`: Directed flow network.
+/// * `storage`: Empty directed flow storage that the new network uses.
+/// * `capacities`: Maps id of each vertex that must be split to its capacity.
+///
+/// # Returns
+/// * The new network. Its edges keep weight and capacity of the original edges, but have no flow.
+/// * Maps id of each vertex of `graph` to id of its (in, out) vertices in the new network. Both are the same for the vertices that are not split.
+///
+/// In vertex of each vertex keeps the id of the original vertex.
+///
+/// # Complexity
+/// O(|V| + |E|) calls to `add_vertex`, `remove_vertex` and `add_edge` of the new network.
+///
+/// # Panics
+/// * If `storage` is not empty.
+/// * If a vertex in `capacities` does not exist in `graph`.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::{DiFlowList, DiFlowMat};
+/// use prepona::graph::{FlowEdge, SimpleGraph};
+/// use prepona::algo::{split_vertices, MaxFlow};
+/// use std::collections::HashMap;
+///
+/// // Given: Network with capacities 5 on the edges, in which a can only pass 2 units of flow.
+/// //
+/// //      s --5--> a(2) --5--> t
+/// //
+/// let mut graph = SimpleGraph::init(DiFlowMat::<usize>::init());
+/// let s = graph.add_vertex();
+/// let a = graph.add_vertex();
+/// let t = graph.add_vertex();
+/// graph.add_edge(s, a, FlowEdge::init_with(1.into(), 5, 0));
+/// graph.add_edge(a, t, FlowEdge::init_with(1.into(), 5, 0));
+///
+/// // When: Splitting a and finding the max flow.
+/// let capacities: HashMap<usize, usize> = [(a, 2)].iter().copied().collect();
+/// let (mut split, ids_of) = split_vertices(&graph, DiFlowList::init(), &capacities);
+/// let flow = MaxFlow::init(&split).execute(&mut split, ids_of[&s].1, ids_of[&t].0);
+///
+/// // Then:
+/// assert_eq!(flow, 2);
+/// assert_eq!(split.vertex_count(), 4);
+/// assert_eq!(ids_of[&a].0, a);
+/// assert_ne!(ids_of[&a].1, a);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn split_vertices<W, G, S2>(
+    graph: &G,
+    storage: S2,
+    capacities: &HashMap<usize, usize>,
+) -> (
+    SimpleGraph<W, FlowEdge<W>, DirectedEdge, S2>,
+    HashMap<usize, (usize, usize)>,
+)
+where
+    W: Clone + Zero + Any,
+    G: Edges<W, FlowEdge<W>> + Vertices + Graph<W, FlowEdge<W>, DirectedEdge>,
+    S2: GraphStorage<W, FlowEdge<W>, DirectedEdge>,
+{
+    let mut split = with_vertices_of(graph, storage);
+
+    let mut ids_of: HashMap<usize, (usize, usize)> = graph
+        .vertices()
+        .into_iter()
+        .map(|vertex_id| (vertex_id, (vertex_id, vertex_id)))
+        .collect();
+
+    for (vertex_id, capacity) in capacities {
+        assert!(
+            graph.contains_vertex(*vertex_id),
+            "Vertex with id: {} does not exist",
+            vertex_id
+        );
+
+        let out_id = split.add_vertex();
+        split
+            .add_edge(
+                *vertex_id,
+                out_id,
+                FlowEdge::init_with(W::zero().into(), *capacity, 0),
+            )
+            .unwrap();
+
+        ids_of.insert(*vertex_id, (*vertex_id, out_id));
+    }
+
+    for (src_id, dst_id, edge) in graph.edges() {
+        let edge = FlowEdge::init_with(edge.get_weight().clone(), edge.get_capacity(), 0);
+
+        split
+            .add_edge(ids_of[&src_id].1, ids_of[&dst_id].0, edge)
+            .unwrap();
+    }
+
+    (split, ids_of)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::MaxFlow;
+    use crate::storage::{DiFlowList, DiFlowMat};
+
+    #[test]
+    fn binding_vertex_capacity() {
+        // Given: Network with capacities on the edges, in which a and b have capacities 3 and 4.
+        //
+        //      .--5--> a(3) --5--.
+        //      |        |        v
+        //      s        2        t
+        //      |        v        ^
+        //      '--5--> b(4) --5--'
+        //
+        let mut graph = SimpleGraph::init(DiFlowMat::<usize>::init());
+        let s = graph.add_vertex();
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let t = graph.add_vertex();
+        graph
+            .add_edge(s, a, FlowEdge::init_with(1.into(), 5, 0))
+            .unwrap();
+        graph
+            .add_edge(s, b, FlowEdge::init_with(1.into(), 5, 0))
+            .unwrap();
+        graph
+            .add_edge(a, b, FlowEdge::init_with(1.into(), 2, 0))
+            .unwrap();
+        graph
+            .add_edge(a, t, FlowEdge::init_with(1.into(), 5, 0))
+            .unwrap();
+        graph
+            .add_edge(b, t, FlowEdge::init_with(1.into(), 5, 0))
+            .unwrap();
+
+        // When: Finding the max flow without vertex capacities.
+        let mut unsplit = graph.clone();
+        let flow = MaxFlow::init(&unsplit).execute(&mut unsplit, s, t);
+
+        // Then:
+        assert_eq!(flow, 10);
+
+        // When: Splitting a and b and finding the max flow.
+        let capacities: HashMap<usize, usize> = [(a, 3), (b, 4)].iter().copied().collect();
+        let (mut split, ids_of) = split_vertices(&graph, DiFlowList::init(), &capacities);
+        let flow = MaxFlow::init(&split).execute(&mut split, ids_of[&s].1, ids_of[&t].0);
+
+        // Then: Flow is bounded by capacities of a and b.
+        assert_eq!(flow, 7);
+        assert_eq!(split.vertex_count(), 6);
+        assert_eq!(split.edges_count(), 7);
+        assert_eq!(ids_of[&s], (s, s));
+        assert_eq!(ids_of[&t], (t, t));
+        for (vertex_id, capacity) in [(a, 3), (b, 4)] {
+            let (in_id, out_id) = ids_of[&vertex_id];
+            let edge = split.edges_between(in_id, out_id).unwrap()[0];
+
+            assert_eq!(in_id, vertex_id);
+            assert_eq!(edge.get_capacity(), capacity);
+            assert!(edge.is_saturated());
+        }
+    }
+
+    #[test]
+    fn keeps_ids_of_vertices() {
+        // Given: Network that has lost a vertex.
+        let mut graph = SimpleGraph::init(DiFlowList::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph
+            .add_edge(a, c, FlowEdge::init_with(2.into(), 3, 1))
+            .unwrap();
+        graph.remove_vertex(b).unwrap();
+
+        // When: Splitting c.
+        let capacities: HashMap<usize, usize> = [(c, 1)].iter().copied().collect();
+        let (split, ids_of) = split_vertices(&graph, DiFlowList::init(), &capacities);
+
+        // Then: Out vertex of c can reuse id of b, and copied edge has no flow.
+        assert_eq!(ids_of.len(), 2);
+        assert_eq!(ids_of[&a], (a, a));
+        assert_eq!(ids_of[&c].0, c);
+        let edge = split.edges_between(a, c).unwrap()[0];
+        assert_eq!(edge.get_weight(), &2.into());
+        assert_eq!(edge.get_capacity(), 3);
+        assert_eq!(edge.get_flow(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn missing_vertex() {
+        let graph = SimpleGraph::init(DiFlowMat::<usize>::init());
+        let capacities: HashMap<usize, usize> = [(0, 1)].iter().copied().collect();
+
+        split_vertices(&graph, DiFlowMat::init(), &capacities);
+    }
+}
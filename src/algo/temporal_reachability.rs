@@ -0,0 +1,202 @@
+use magnitude::Magnitude;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+
+use crate::graph::Edge;
+use crate::provide::{Edges, Vertices};
+
+/// Finds vertices that are reachable from `src_id` through time-respecting paths.
+///
+/// Weight of each edge is treated as the time at which the edge can be traversed, and traversing an edge takes no time.
+/// A path is time-respecting if times of its edges are non-decreasing and its first edge is at time `start_time` or later.
+/// So a vertex can be connected to the source while still being unreachable, if all paths to it use edges in the wrong order.
+///
+/// Edges with infinite weight are never traversed.
+///
+/// # Arguments
+/// * `graph`: Graph to search in.
+/// * `src_id`: Id of the vertex to start from.
+/// * `start_time`: Time at which the search starts.
+///
+/// # Returns
+/// Id of the reachable vertices, including `src_id`.
+///
+/// # Complexity
+/// O((|V| + |E|)log(|V|))
+///
+/// # Panics
+/// If vertex with id: `src_id` does not exist in the graph.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::DiMat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::temporal_reachability;
+///
+/// // Given: Graph in which weights are the times edges are available.
+/// //
+/// //      a --5--> b --3--> c
+/// //
+/// let mut graph = MatGraph::init(DiMat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 5.into());
+/// graph.add_edge(b, c, 3.into());
+///
+/// // When: Starting from a at time 0.
+/// let reachable = temporal_reachability(&graph, a, 0);
+///
+/// // Then: Edge to c is gone by the time b is reached.
+/// assert_eq!(reachable, [a, b].iter().copied().collect());
+/// ```
+pub fn temporal_reachability<W, E, G>(graph: &G, src_id: usize, start_time: W) -> HashSet<usize>
+where
+    W: Copy + Ord,
+    E: Edge<W>,
+    G: Edges<W, E> + Vertices,
+{
+    let id_map = graph.continuos_id_map();
+    let mut arrival_of: Vec<Option<W>> = vec![None; graph.vertex_count()];
+    let mut is_settled = vec![false; graph.vertex_count()];
+
+    // Vertices are settled in the order of their earliest arrival time.
+    // Arriving earlier never hurts, because one can always wait for a later edge.
+    arrival_of[id_map.virt_id_of(src_id)] = Some(start_time);
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((start_time, src_id)));
+
+    while let Some(Reverse((arrival, vertex_id))) = heap.pop() {
+        let virt_id = id_map.virt_id_of(vertex_id);
+        if is_settled[virt_id] {
+            continue;
+        }
+        is_settled[virt_id] = true;
+
+        for (dst_id, edge) in graph.edges_from(vertex_id).unwrap() {
+            let time = match edge.get_weight() {
+                Magnitude::Finite(time) if *time >= arrival => *time,
+                _ => continue,
+            };
+
+            let dst_virt_id = id_map.virt_id_of(dst_id);
+            if arrival_of[dst_virt_id].map_or(true, |dst_arrival| time < dst_arrival) {
+                arrival_of[dst_virt_id] = Some(time);
+                heap.push(Reverse((time, dst_id)));
+            }
+        }
+    }
+
+    arrival_of
+        .iter()
+        .enumerate()
+        .filter(|(_, arrival)| arrival.is_some())
+        .map(|(virt_id, _)| id_map.real_id_of(virt_id))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{DefaultEdge, MatGraph};
+    use crate::provide::Graph;
+    use crate::storage::{DiMat, Mat};
+
+    #[test]
+    fn single_vertex() {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+
+        assert_eq!(
+            temporal_reachability(&graph, a, 0),
+            [a].iter().copied().collect()
+        );
+    }
+
+    #[test]
+    fn connected_but_temporally_unreachable() {
+        // Given: Graph
+        //
+        //      a --1-- b --4-- c --2-- d
+        //               \             /
+        //                '-----6-----'
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 4.into()).unwrap();
+        graph.add_edge(c, d, 2.into()).unwrap();
+        graph.add_edge(b, d, 6.into()).unwrap();
+
+        // When: Starting from a at time 0.
+        let reachable = temporal_reachability(&graph, a, 0);
+
+        // Then: d is reached through b at time 6, but c can only be reached from b at time 4.
+        assert_eq!(reachable, [a, b, c, d].iter().copied().collect());
+
+        // When: Starting from c at time 0.
+        let reachable = temporal_reachability(&graph, c, 0);
+
+        // Then: From c, d is reached at time 2 and b at time 4 or time 6, but edge to a is at time 1.
+        assert_eq!(reachable, [b, c, d].iter().copied().collect());
+    }
+
+    #[test]
+    fn edges_in_wrong_order() {
+        // Given: Graph
+        //
+        //      a --3--> b --2--> c --5--> d
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 3.into()).unwrap();
+        graph.add_edge(b, c, 2.into()).unwrap();
+        graph.add_edge(c, d, 5.into()).unwrap();
+
+        // Then: c and d are connected to a, but edge to c happens before b is reached.
+        assert_eq!(
+            temporal_reachability(&graph, a, 0),
+            [a, b].iter().copied().collect()
+        );
+        assert_eq!(
+            temporal_reachability(&graph, b, 0),
+            [b, c, d].iter().copied().collect()
+        );
+
+        // Starting late misses the first edge.
+        assert_eq!(
+            temporal_reachability(&graph, a, 4),
+            [a].iter().copied().collect()
+        );
+    }
+
+    #[test]
+    fn equal_times_are_allowed() {
+        // Given: Graph
+        //
+        //      a --2--> b --2--> c --inf--> d
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 2.into()).unwrap();
+        graph.add_edge(b, c, 2.into()).unwrap();
+        graph
+            .add_edge(c, d, DefaultEdge::init(Magnitude::PosInfinite))
+            .unwrap();
+
+        assert_eq!(
+            temporal_reachability(&graph, a, 2),
+            [a, b, c].iter().copied().collect()
+        );
+    }
+}
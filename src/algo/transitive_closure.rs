@@ -0,0 +1,197 @@
+use std::collections::HashSet;
+
+use crate::graph::{DirectedEdge, Edge};
+use crate::provide::{Graph, Neighbors, Vertices};
+
+/// Computes transitive closure of a directed graph: every ordered pair of vertices (u, v) such that v is reachable from u.
+///
+/// Runs a depth-first search from each vertex. Weights of the edges are ignored.
+/// For reachability limited to a number of hops checkout [`k_hop_closure`](crate::algo::k_hop_closure).
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::DiMat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::TransitiveClosure;
+///
+/// // Given: Graph
+/// //
+/// //      a --> b --> c
+/// //            ^     |
+/// //            '-----'
+/// //
+/// let mut graph = MatGraph::init(DiMat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(c, b, 1.into());
+///
+/// // When: Computing the closure.
+/// let closure = TransitiveClosure::init(false).execute(&graph);
+///
+/// // Then: b and c reach themselves through the cycle, but a does not.
+/// assert_eq!(closure.len(), 6);
+/// assert!(closure.contains(&(a, c)));
+/// assert!(closure.contains(&(b, b)));
+/// assert!(!closure.contains(&(a, a)));
+/// assert!(!closure.contains(&(b, a)));
+/// ```
+pub struct TransitiveClosure {
+    reflexive: bool,
+}
+
+impl TransitiveClosure {
+    /// # Arguments
+    /// `reflexive`: If true, each vertex is considered reachable from itself.
+    /// Otherwise a vertex reaches itself only if it is on a cycle.
+    pub fn init(reflexive: bool) -> Self {
+        TransitiveClosure { reflexive }
+    }
+
+    /// Computes the transitive closure.
+    ///
+    /// # Arguments
+    /// `graph`: Directed graph to compute the closure of.
+    ///
+    /// # Returns
+    /// Set of (src_id, dst_id) pairs such that `dst_id` is reachable from `src_id`.
+    ///
+    /// # Complexity
+    /// O(|V|(|V| + |E|))
+    pub fn execute<W, E, G>(&self, graph: &G) -> HashSet<(usize, usize)>
+    where
+        E: Edge<W>,
+        G: Vertices + Neighbors + Graph<W, E, DirectedEdge>,
+    {
+        let id_map = graph.continuos_id_map();
+        let vertex_count = graph.vertex_count();
+
+        let adjacency: Vec<Vec<usize>> = (0..vertex_count)
+            .map(|virt_id| {
+                graph
+                    .neighbors(id_map.real_id_of(virt_id))
+                    .unwrap()
+                    .into_iter()
+                    .map(|n_id| id_map.virt_id_of(n_id))
+                    .collect()
+            })
+            .collect();
+
+        let mut closure = HashSet::new();
+        let mut is_reached = vec![false; vertex_count];
+        for src_virt_id in 0..vertex_count {
+            is_reached.iter_mut().for_each(|reached| *reached = false);
+
+            // Source is not marked beforehand, so it gets reached only by coming back to it through a cycle.
+            let mut stack = adjacency[src_virt_id].clone();
+            while let Some(virt_id) = stack.pop() {
+                if is_reached[virt_id] {
+                    continue;
+                }
+                is_reached[virt_id] = true;
+
+                stack.extend(
+                    adjacency[virt_id]
+                        .iter()
+                        .copied()
+                        .filter(|n_virt_id| !is_reached[*n_virt_id]),
+                );
+            }
+
+            if self.reflexive {
+                is_reached[src_virt_id] = true;
+            }
+
+            let src_id = id_map.real_id_of(src_virt_id);
+            closure.extend(
+                is_reached
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, reached)| **reached)
+                    .map(|(dst_virt_id, _)| (src_id, id_map.real_id_of(dst_virt_id))),
+            );
+        }
+
+        closure
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::storage::DiMat;
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(DiMat::<usize>::init());
+
+        assert!(TransitiveClosure::init(true).execute(&graph).is_empty());
+    }
+
+    #[test]
+    fn dag_chain() {
+        // Given: Graph
+        //
+        //      a --> b --> c --> d
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let vertices: Vec<usize> = (0..4).map(|_| graph.add_vertex()).collect();
+        for window in vertices.windows(2) {
+            graph.add_edge(window[0], window[1], 1.into()).unwrap();
+        }
+
+        // When: Computing the closure with and without reflexivity.
+        let closure = TransitiveClosure::init(false).execute(&graph);
+        let reflexive_closure = TransitiveClosure::init(true).execute(&graph);
+
+        // Then: Each vertex reaches exactly the vertices after it, and itself only if reflexive.
+        let expected: HashSet<(usize, usize)> = (0..4)
+            .flat_map(|i| (i + 1..4).map(move |j| (i, j)))
+            .map(|(i, j)| (vertices[i], vertices[j]))
+            .collect();
+        assert_eq!(closure, expected);
+
+        let expected: HashSet<(usize, usize)> = expected
+            .into_iter()
+            .chain(vertices.iter().map(|vertex_id| (*vertex_id, *vertex_id)))
+            .collect();
+        assert_eq!(reflexive_closure, expected);
+    }
+
+    #[test]
+    fn three_vertex_cycle() {
+        // Given: Graph
+        //
+        //      a --> b --> c      d
+        //      ^           |
+        //      '-----------'
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, a, 1.into()).unwrap();
+
+        // When: Computing the closure without reflexivity.
+        let closure = TransitiveClosure::init(false).execute(&graph);
+
+        // Then: Every vertex of the cycle reaches all of them, including itself, but the isolated vertex reaches nothing.
+        let cycle = [a, b, c];
+        let expected: HashSet<(usize, usize)> = cycle
+            .iter()
+            .flat_map(|src_id| cycle.iter().map(move |dst_id| (*src_id, *dst_id)))
+            .collect();
+        assert_eq!(closure, expected);
+        assert!(!closure.contains(&(d, d)));
+        assert!(TransitiveClosure::init(true)
+            .execute(&graph)
+            .contains(&(d, d)));
+    }
+}
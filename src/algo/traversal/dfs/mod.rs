@@ -71,7 +71,7 @@ impl<'a, L: DfsListener> Dfs<'a, L> {
             self.start_ids
                 .iter()
                 .find(|virt_id| self.colors[**virt_id] == Color::White)
-                .and_then(|virt_id| Some(*virt_id))
+                .copied()
         }
     }
 
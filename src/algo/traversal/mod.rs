@@ -1,8 +1,10 @@
 mod bfs;
 mod dfs;
+mod search;
 
 pub use bfs::{Bfs, BfsListener};
 pub use dfs::{Dfs, DfsListener};
+pub use search::{bfs_until, dfs_until};
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum Color {
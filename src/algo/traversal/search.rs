@@ -0,0 +1,273 @@
+use std::collections::VecDeque;
+
+use magnitude::Magnitude;
+
+use crate::provide::{IdMap, Neighbors, Vertices};
+
+/// Visits vertices in a breath-first manner starting from `src_id` until `predicate` returns true for a vertex.
+///
+/// # Arguments
+/// * `graph`: Graph to search in.
+/// * `src_id`: Id of the vertex to start the search from.
+/// * `predicate`: Gets called with the id of each visited vertex(including `src_id`), in the order they are visited.
+///
+/// # Returns
+/// * `Some`: Containing id of the first vertex that `predicate` returned true for, alongside the path from `src_id` to it.
+///   The path is a list of vertex ids starting with `src_id` and ending with the found vertex. Found path is a path with minimum number of edges.
+/// * `None`: If `predicate` did not return true for any vertex reachable from `src_id`.
+///
+/// # Panics
+/// If vertex with id: `src_id` does not exist in the graph.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::bfs_until;
+///
+/// // Given: Graph
+/// //
+/// //      a --- b --- c --- d
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let d = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 1.into());
+/// graph.add_edge(c, d, 1.into());
+///
+/// // When: Searching for vertex c.
+/// let (found_id, path) = bfs_until(&graph, a, |v_id| v_id == c).unwrap();
+///
+/// // Then:
+/// assert_eq!(found_id, c);
+/// assert_eq!(path, vec![a, b, c]);
+/// ```
+pub fn bfs_until<G, F>(graph: &G, src_id: usize, mut predicate: F) -> Option<(usize, Vec<usize>)>
+where
+    G: Vertices + Neighbors,
+    F: FnMut(usize) -> bool,
+{
+    let id_map = graph.continuos_id_map();
+    let mut parent_of = vec![Magnitude::PosInfinite; graph.vertex_count()];
+    let mut is_discovered = vec![false; graph.vertex_count()];
+
+    let src_virt_id = id_map.virt_id_of(src_id);
+    is_discovered[src_virt_id] = true;
+
+    let mut queue = VecDeque::new();
+    queue.push_back(src_virt_id);
+
+    while let Some(virt_id) = queue.pop_front() {
+        let real_id = id_map.real_id_of(virt_id);
+
+        if predicate(real_id) {
+            return Some((real_id, path_to(&id_map, &parent_of, virt_id)));
+        }
+
+        for n_real_id in graph.neighbors(real_id).unwrap() {
+            let n_virt_id = id_map.virt_id_of(n_real_id);
+
+            if !is_discovered[n_virt_id] {
+                is_discovered[n_virt_id] = true;
+                parent_of[n_virt_id] = virt_id.into();
+                queue.push_back(n_virt_id);
+            }
+        }
+    }
+
+    None
+}
+
+/// Visits vertices in a depth-first manner starting from `src_id` until `predicate` returns true for a vertex.
+///
+/// # Arguments
+/// * `graph`: Graph to search in.
+/// * `src_id`: Id of the vertex to start the search from.
+/// * `predicate`: Gets called with the id of each visited vertex(including `src_id`), in the order they are visited.
+///
+/// # Returns
+/// * `Some`: Containing id of the first vertex that `predicate` returned true for, alongside the path from `src_id` to it.
+///   The path is a list of vertex ids starting with `src_id` and ending with the found vertex. It's the path that dfs took to reach the vertex.
+/// * `None`: If `predicate` did not return true for any vertex reachable from `src_id`.
+///
+/// # Panics
+/// If vertex with id: `src_id` does not exist in the graph.
+pub fn dfs_until<G, F>(graph: &G, src_id: usize, mut predicate: F) -> Option<(usize, Vec<usize>)>
+where
+    G: Vertices + Neighbors,
+    F: FnMut(usize) -> bool,
+{
+    let id_map = graph.continuos_id_map();
+    let mut parent_of = vec![Magnitude::PosInfinite; graph.vertex_count()];
+    let mut is_visited = vec![false; graph.vertex_count()];
+
+    // Each item is (virtual id of the vertex, virtual id of the vertex that pushed it).
+    let mut stack = vec![(id_map.virt_id_of(src_id), Magnitude::PosInfinite)];
+
+    while let Some((virt_id, parent_virt_id)) = stack.pop() {
+        if is_visited[virt_id] {
+            continue;
+        }
+        is_visited[virt_id] = true;
+        parent_of[virt_id] = parent_virt_id;
+
+        let real_id = id_map.real_id_of(virt_id);
+
+        if predicate(real_id) {
+            return Some((real_id, path_to(&id_map, &parent_of, virt_id)));
+        }
+
+        // Neighbors are pushed in reverse so they get visited in the order graph returns them.
+        for n_real_id in graph.neighbors(real_id).unwrap().into_iter().rev() {
+            let n_virt_id = id_map.virt_id_of(n_real_id);
+
+            if !is_visited[n_virt_id] {
+                stack.push((n_virt_id, virt_id.into()));
+            }
+        }
+    }
+
+    None
+}
+
+// Follows parents from `virt_id` back to the source and returns the path in the form of real ids.
+fn path_to(id_map: &IdMap, parent_of: &[Magnitude<usize>], mut virt_id: usize) -> Vec<usize> {
+    let mut path = vec![id_map.real_id_of(virt_id)];
+
+    while parent_of[virt_id].is_finite() {
+        virt_id = parent_of[virt_id].unwrap();
+        path.push(id_map.real_id_of(virt_id));
+    }
+
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::provide::*;
+    use crate::storage::{DiMat, Mat};
+
+    #[test]
+    fn source_matches_predicate() {
+        // Given: Graph
+        //
+        //      a --- b
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+
+        // When: Searching for a vertex that source satisfies.
+        let bfs_result = bfs_until(&graph, a, |_| true);
+        let dfs_result = dfs_until(&graph, a, |_| true);
+
+        // Then:
+        assert_eq!(bfs_result, Some((a, vec![a])));
+        assert_eq!(dfs_result, Some((a, vec![a])));
+    }
+
+    #[test]
+    fn no_vertex_matches_predicate() {
+        // Given: Graph
+        //
+        //      a --> b     c
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+
+        // When: Searching for c which is not reachable from a.
+        let bfs_result = bfs_until(&graph, a, |v_id| v_id == c);
+        let dfs_result = dfs_until(&graph, a, |v_id| v_id == c);
+
+        // Then:
+        assert!(bfs_result.is_none());
+        assert!(dfs_result.is_none());
+    }
+
+    #[test]
+    fn bfs_stops_at_first_matching_vertex() {
+        // Given: Graph
+        //
+        //      a --- b --- d --- f --- g
+        //      |
+        //      c --- e
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        let g = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(a, c, 1.into()).unwrap();
+        graph.add_edge(b, d, 1.into()).unwrap();
+        graph.add_edge(c, e, 1.into()).unwrap();
+        graph.add_edge(d, f, 1.into()).unwrap();
+        graph.add_edge(f, g, 1.into()).unwrap();
+
+        // When: Searching for the first vertex with id greater than 2.
+        let mut visited = vec![];
+        let (found_id, path) = bfs_until(&graph, a, |v_id| {
+            visited.push(v_id);
+            v_id > 2
+        })
+        .unwrap();
+
+        // Then: Vertices farther than d are not explored.
+        assert_eq!(found_id, d);
+        assert_eq!(path, vec![a, b, d]);
+        assert_eq!(visited, vec![a, b, c, d]);
+        assert!(!visited.contains(&f));
+        assert!(!visited.contains(&g));
+    }
+
+    #[test]
+    fn dfs_stops_at_first_matching_vertex() {
+        // Given: Graph
+        //
+        //      a --> b --> d --> f
+        //      |
+        //      v
+        //      c --> e
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(a, c, 1.into()).unwrap();
+        graph.add_edge(b, d, 1.into()).unwrap();
+        graph.add_edge(c, e, 1.into()).unwrap();
+        graph.add_edge(d, f, 1.into()).unwrap();
+
+        // When: Searching for d.
+        let mut visited_count = 0;
+        let (found_id, path) = dfs_until(&graph, a, |v_id| {
+            visited_count += 1;
+            v_id == d
+        })
+        .unwrap();
+
+        // Then: Branch of c is never explored.
+        assert_eq!(found_id, d);
+        assert_eq!(path, vec![a, b, d]);
+        assert_eq!(visited_count, 3);
+        assert!(graph.vertex_count() > visited_count);
+    }
+}
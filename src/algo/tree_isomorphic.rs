@@ -0,0 +1,321 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::graph::{Edge, UndirectedEdge};
+use crate::provide::{Edges, Graph, Vertices};
+
+/// Checks whether two trees are isomorphic, using canonical labeling of Aho, Hopcroft and Ullman(AHU).
+///
+/// Each tree is rooted at its center(s), and each vertex gets a label that identifies the shape of its subtree:
+/// Two subtrees get the same label iff the sorted labels of their children are the same.
+/// So the trees are isomorphic iff their roots get the same label.
+/// Trees with two centers are rooted at each of them, because both trees must be compared with the same kind of root.
+///
+/// # Arguments
+/// * `tree1`: First tree.
+/// * `tree2`: Second tree.
+///
+/// # Returns
+/// `true` if there is a bijection between vertices of the trees that preserves adjacency, `false` otherwise.
+///
+/// # Complexity
+/// O(|V|log(|V|))
+///
+/// # Panics
+/// If `tree1` or `tree2` is not a tree(connected and acyclic). A graph without vertices is considered an empty tree.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::tree_isomorphic;
+///
+/// // Given: Trees
+/// //
+/// //      a --- b --- c           d --- f --- e
+/// //
+/// let mut tree1 = MatGraph::init(Mat::<usize>::init());
+/// let a = tree1.add_vertex();
+/// let b = tree1.add_vertex();
+/// let c = tree1.add_vertex();
+/// tree1.add_edge(a, b, 1.into());
+/// tree1.add_edge(b, c, 1.into());
+///
+/// let mut tree2 = MatGraph::init(Mat::<usize>::init());
+/// let d = tree2.add_vertex();
+/// let e = tree2.add_vertex();
+/// let f = tree2.add_vertex();
+/// tree2.add_edge(d, f, 1.into());
+/// tree2.add_edge(f, e, 1.into());
+///
+/// // When: Checking isomorphism.
+/// let is_isomorphic = tree_isomorphic(&tree1, &tree2);
+///
+/// // Then:
+/// assert!(is_isomorphic);
+/// ```
+pub fn tree_isomorphic<W, E, G1, G2>(tree1: &G1, tree2: &G2) -> bool
+where
+    E: Edge<W>,
+    G1: Vertices + Edges<W, E> + Graph<W, E, UndirectedEdge>,
+    G2: Vertices + Edges<W, E> + Graph<W, E, UndirectedEdge>,
+{
+    let adjacency1 = tree_adjacency(tree1);
+    let adjacency2 = tree_adjacency(tree2);
+
+    if adjacency1.len() != adjacency2.len() {
+        return false;
+    } else if adjacency1.is_empty() {
+        return true;
+    }
+
+    // Labels are shared between the trees, so equal labels mean equal shapes across them.
+    let mut label_of = HashMap::new();
+
+    let root_label = canonical_label(&adjacency1, centers(&adjacency1)[0], &mut label_of);
+
+    centers(&adjacency2)
+        .into_iter()
+        .any(|center| canonical_label(&adjacency2, center, &mut label_of) == root_label)
+}
+
+// Maps each vertex(by virtual id) to virtual id of its neighbors.
+//
+// # Panics
+// If `tree` is not a tree.
+fn tree_adjacency<W, E, G>(tree: &G) -> Vec<Vec<usize>>
+where
+    E: Edge<W>,
+    G: Vertices + Edges<W, E> + Graph<W, E, UndirectedEdge>,
+{
+    let vertex_count = tree.vertex_count();
+    let id_map = tree.continuos_id_map();
+
+    let mut adjacency = vec![vec![]; vertex_count];
+    for (src_id, dst_id, _) in tree.edges() {
+        let src_virt_id = id_map.virt_id_of(src_id);
+        let dst_virt_id = id_map.virt_id_of(dst_id);
+
+        adjacency[src_virt_id].push(dst_virt_id);
+        adjacency[dst_virt_id].push(src_virt_id);
+    }
+
+    // A graph with |V| - 1 edges is a tree iff it is connected.
+    let edge_count: usize = adjacency.iter().map(|neighbors| neighbors.len()).sum();
+    let is_tree = vertex_count == 0
+        || (edge_count == 2 * (vertex_count - 1) && bfs_order(&adjacency, 0).len() == vertex_count);
+    assert!(is_tree, "Graph must be a tree");
+
+    adjacency
+}
+
+// Finds the one or two centers of the tree, by removing the leaves layer by layer.
+fn centers(adjacency: &[Vec<usize>]) -> Vec<usize> {
+    let mut degree_of: Vec<usize> = adjacency.iter().map(|neighbors| neighbors.len()).collect();
+    let mut leaves: Vec<usize> = (0..adjacency.len())
+        .filter(|virt_id| degree_of[*virt_id] <= 1)
+        .collect();
+
+    let mut remaining_count = adjacency.len();
+    while remaining_count > 2 {
+        remaining_count -= leaves.len();
+
+        let mut next_leaves = vec![];
+        for leaf in leaves {
+            for n_virt_id in &adjacency[leaf] {
+                degree_of[*n_virt_id] -= 1;
+                if degree_of[*n_virt_id] == 1 {
+                    next_leaves.push(*n_virt_id);
+                }
+            }
+        }
+        leaves = next_leaves;
+    }
+
+    leaves
+}
+
+// Label of the subtree of `root`, when the tree is rooted at `root`.
+// Children are labeled before their parents by processing the vertices in reverse bfs order.
+fn canonical_label(
+    adjacency: &[Vec<usize>],
+    root: usize,
+    label_of: &mut HashMap<Vec<usize>, usize>,
+) -> usize {
+    let order = bfs_order(adjacency, root);
+
+    let mut parent_of = vec![None; adjacency.len()];
+    for virt_id in &order {
+        for n_virt_id in &adjacency[*virt_id] {
+            if *n_virt_id != root && parent_of[*n_virt_id].is_none() {
+                parent_of[*n_virt_id] = Some(*virt_id);
+            }
+        }
+    }
+
+    let mut children_labels_of: Vec<Vec<usize>> = vec![vec![]; adjacency.len()];
+    let mut vertex_label_of = vec![0; adjacency.len()];
+    for virt_id in order.into_iter().rev() {
+        let mut children_labels = std::mem::take(&mut children_labels_of[virt_id]);
+        children_labels.sort_unstable();
+
+        let next_label = label_of.len();
+        vertex_label_of[virt_id] = *label_of.entry(children_labels).or_insert(next_label);
+
+        if let Some(parent_id) = parent_of[virt_id] {
+            children_labels_of[parent_id].push(vertex_label_of[virt_id]);
+        }
+    }
+
+    vertex_label_of[root]
+}
+
+// Virtual id of the vertices reachable from `root`, in bfs order.
+fn bfs_order(adjacency: &[Vec<usize>], root: usize) -> Vec<usize> {
+    let mut is_visited = vec![false; adjacency.len()];
+    let mut order = vec![];
+    let mut queue = VecDeque::new();
+
+    is_visited[root] = true;
+    queue.push_back(root);
+    while let Some(virt_id) = queue.pop_front() {
+        order.push(virt_id);
+
+        for n_virt_id in &adjacency[virt_id] {
+            if !is_visited[*n_virt_id] {
+                is_visited[*n_virt_id] = true;
+                queue.push_back(*n_virt_id);
+            }
+        }
+    }
+
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{ListGraph, MatGraph};
+    use crate::storage::{List, Mat};
+
+    fn tree_of(vertex_count: usize, edges: &[(usize, usize)]) -> MatGraph<usize, UndirectedEdge> {
+        let mut tree = MatGraph::init(Mat::<usize>::init());
+        for _ in 0..vertex_count {
+            tree.add_vertex();
+        }
+        for (src_id, dst_id) in edges {
+            tree.add_edge(*src_id, *dst_id, 1.into()).unwrap();
+        }
+
+        tree
+    }
+
+    #[test]
+    fn empty_and_single_vertex_trees() {
+        let empty = tree_of(0, &[]);
+        let single = tree_of(1, &[]);
+
+        assert!(tree_isomorphic(&empty, &empty));
+        assert!(tree_isomorphic(&single, &single));
+        assert!(!tree_isomorphic(&empty, &single));
+    }
+
+    #[test]
+    fn relabelings_of_same_tree() {
+        // Given: Tree
+        //
+        //              0
+        //            / | \
+        //           1  2  3
+        //          / \     \
+        //         4   5     6
+        //                    \
+        //                     7
+        //
+        let edges = [(0, 1), (0, 2), (0, 3), (1, 4), (1, 5), (3, 6), (6, 7)];
+        let tree = tree_of(8, &edges);
+
+        // When: Relabeling the vertices with two permutations, and adding edges in a different order.
+        let permutations = [[5, 3, 7, 0, 1, 6, 2, 4], [7, 6, 5, 4, 3, 2, 1, 0]];
+        for permutation in permutations {
+            let relabeled_edges: Vec<(usize, usize)> = edges
+                .iter()
+                .rev()
+                .map(|(src_id, dst_id)| (permutation[*dst_id], permutation[*src_id]))
+                .collect();
+            let relabeled = tree_of(8, &relabeled_edges);
+
+            // Then:
+            assert!(tree_isomorphic(&tree, &relabeled));
+        }
+    }
+
+    #[test]
+    fn path_and_star() {
+        // Given: Path and star with 5 vertices.
+        //
+        //      0 --- 1 --- 2 --- 3 --- 4           1
+        //                                          |
+        //                                     2 -- 0 -- 3
+        //                                          |
+        //                                          4
+        //
+        let path = tree_of(5, &[(0, 1), (1, 2), (2, 3), (3, 4)]);
+        let star = tree_of(5, &[(0, 1), (0, 2), (0, 3), (0, 4)]);
+
+        // Then:
+        assert!(!tree_isomorphic(&path, &star));
+        assert!(tree_isomorphic(&path, &path));
+        assert!(tree_isomorphic(&star, &star));
+    }
+
+    #[test]
+    fn same_degrees_different_shape() {
+        // Given: Two trees with the same degree sequence and two centers each.
+        //
+        //      0 --- 1 --- 2 --- 3 --- 4 --- 5     0 --- 1 --- 2 --- 3 --- 4 --- 5
+        //            |                                         |
+        //            6                                         6
+        //
+        let tree1 = tree_of(7, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (1, 6)]);
+        let tree2 = tree_of(7, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (2, 6)]);
+        let tree3 = tree_of(7, &[(5, 4), (4, 3), (3, 2), (2, 1), (1, 0), (4, 6)]);
+
+        // Then: Mirror image of the first tree is isomorphic to it.
+        assert!(!tree_isomorphic(&tree1, &tree2));
+        assert!(tree_isomorphic(&tree1, &tree3));
+    }
+
+    #[test]
+    fn different_storages() {
+        // Given: Same tree in a matrix and a list storage.
+        let tree1 = tree_of(4, &[(0, 1), (1, 2), (1, 3)]);
+        let mut tree2 = ListGraph::init(List::<usize>::init());
+        let a = tree2.add_vertex();
+        let b = tree2.add_vertex();
+        let c = tree2.add_vertex();
+        let d = tree2.add_vertex();
+        tree2.add_edge(d, a, 1.into()).unwrap();
+        tree2.add_edge(d, b, 1.into()).unwrap();
+        tree2.add_edge(d, c, 1.into()).unwrap();
+
+        // Then:
+        assert!(tree_isomorphic(&tree1, &tree2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn graph_with_cycle() {
+        let cycle = tree_of(3, &[(0, 1), (1, 2), (2, 0)]);
+
+        tree_isomorphic(&cycle, &cycle);
+    }
+
+    #[test]
+    #[should_panic]
+    fn disconnected_graph() {
+        let graph = tree_of(5, &[(0, 1), (1, 2), (2, 0), (3, 4)]);
+
+        tree_isomorphic(&graph, &graph);
+    }
+}
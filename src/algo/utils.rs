@@ -0,0 +1,23 @@
+use std::collections::HashMap;
+
+use crate::graph::Edge;
+use crate::provide::{Edges, Vertices};
+
+// Maps id of each vertex to its degree: the number of edges incident to it.
+// In directed graphs, both incoming and outgoing edges are counted.
+pub(crate) fn degrees_of<W, E: Edge<W>, G: Edges<W, E> + Vertices>(
+    graph: &G,
+) -> HashMap<usize, usize> {
+    let mut degree_of: HashMap<usize, usize> = graph
+        .vertices()
+        .into_iter()
+        .map(|vertex_id| (vertex_id, 0))
+        .collect();
+
+    for (src_id, dst_id, _) in graph.edges() {
+        *degree_of.get_mut(&src_id).unwrap() += 1;
+        *degree_of.get_mut(&dst_id).unwrap() += 1;
+    }
+
+    degree_of
+}
@@ -87,7 +87,7 @@ impl<'a, W, E: Edge<W>> VertexEdgeCut<'a, W, E> {
     /// Cut vertices(first) and cut edges(second). \
     /// Cut vertices will be a vector of vertex ids. \
     /// Cut edges will be vector of (src_id, dst_id, edge reference).
-    pub fn execute<G>(mut self, graph: &'a G) -> (Vec<usize>, Vec<(usize, usize, &E)>)
+    pub fn execute<G>(mut self, graph: &'a G) -> (Vec<usize>, Vec<(usize, usize, &'a E)>)
     where
         G: Vertices + Edges<W, E> + Graph<W, E, UndirectedEdge>,
     {
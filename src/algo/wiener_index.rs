@@ -0,0 +1,139 @@
+use magnitude::Magnitude;
+use num_traits::{Unsigned, Zero};
+use std::any::Any;
+
+use crate::algo::Dijkstra;
+use crate::graph::{Edge, EdgeDir};
+use crate::provide::{Edges, Graph, Neighbors, Vertices};
+
+/// Computes the Wiener index of the graph: sum of shortest distances between all pairs of vertices.
+///
+/// In undirected graphs each unordered pair is counted once.
+/// In directed graphs distance from `u` to `v` and from `v` to `u` are both counted.
+///
+/// # Arguments
+/// `graph`: Graph to compute its Wiener index.
+///
+/// # Returns
+/// Sum of the shortest distances.
+/// It is `PosInfinite` if there is a pair of vertices that one of them can not reach the other.
+///
+/// # Complexity
+/// Runs `Dijkstra` once from every vertex.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::algo::wiener_index;
+///
+/// // Given: Graph
+/// //
+/// //      a --1-- b --2-- c
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 2.into());
+///
+/// // Then: d(a, b) + d(a, c) + d(b, c) = 1 + 3 + 2
+/// assert_eq!(wiener_index(&graph), 6.into());
+/// ```
+pub fn wiener_index<W, E, Ty, G>(graph: &G) -> Magnitude<W>
+where
+    W: Copy + Ord + Zero + Any + Unsigned,
+    E: Edge<W>,
+    Ty: EdgeDir,
+    G: Edges<W, E> + Neighbors + Vertices + Graph<W, E, Ty>,
+{
+    let vertices = graph.vertices();
+
+    let mut total: Magnitude<W> = W::zero().into();
+    for (index, src_id) in vertices.iter().copied().enumerate() {
+        let sp_subgraph = Dijkstra::init(graph).execute(graph, src_id);
+
+        // In undirected graphs, distance to vertices that come before `src_id` is already counted.
+        let skip_count = if Ty::is_directed() { 0 } else { index + 1 };
+        for dst_id in vertices.iter().copied().skip(skip_count) {
+            let distance = sp_subgraph.distance_to(dst_id).unwrap();
+            if !distance.is_finite() {
+                return Magnitude::PosInfinite;
+            }
+
+            total += distance;
+        }
+    }
+
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::storage::{DiMat, Mat};
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        assert_eq!(wiener_index(&graph), 0.into());
+    }
+
+    #[test]
+    fn path_graph() {
+        for vertex_count in 1..8 {
+            // Given: Path graph with unit weights.
+            //
+            //      v0 --1-- v1 --1-- ... --1-- vn-1
+            //
+            let mut graph = MatGraph::init(Mat::<usize>::init());
+            let vertices: Vec<usize> = (0..vertex_count).map(|_| graph.add_vertex()).collect();
+            for pair in vertices.windows(2) {
+                graph.add_edge(pair[0], pair[1], 1.into()).unwrap();
+            }
+
+            // Then: Wiener index of path graph is (n - 1)n(n + 1) / 6.
+            let expected = (vertex_count - 1) * vertex_count * (vertex_count + 1) / 6;
+            assert_eq!(wiener_index(&graph), expected.into());
+        }
+    }
+
+    #[test]
+    fn disconnected_graph() {
+        // Given: Graph
+        //
+        //      a --1-- b       c
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+
+        assert!(wiener_index(&graph).is_pos_infinite());
+    }
+
+    #[test]
+    fn directed_cycle() {
+        // Given: Graph
+        //
+        //      a --1--> b --2--> c
+        //      ^                 |
+        //      '--------3--------'
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 2.into()).unwrap();
+        graph.add_edge(c, a, 3.into()).unwrap();
+
+        // Then: (1 + 3) + (2 + 5) + (3 + 4)
+        assert_eq!(wiener_index(&graph), 18.into());
+    }
+}
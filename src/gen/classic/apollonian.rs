@@ -0,0 +1,180 @@
+use num_traits::One;
+use rand::Rng;
+use std::any::Any;
+
+use crate::graph::{DefaultEdge, Edge, MatGraph, UndirectedEdge};
+use crate::provide::Graph;
+use crate::storage::Mat;
+
+/// Generates Apollonian networks(also known as stacked triangulations), which are maximal planar graphs.
+///
+/// Generation starts from a triangle. Then each new vertex is placed inside a face chosen uniformly at random,
+/// and gets connected to the three corners of the face, which splits the face into three.
+/// So every face stays a triangle, and a graph with n vertices has 3n - 6 edges, which is the most a planar graph can have.
+/// All edges have unit weight.
+///
+/// # Examples
+/// ```
+/// use rand::{rngs::StdRng, SeedableRng};
+/// use prepona::prelude::*;
+/// use prepona::gen::ApollonianNetworkGenerator;
+///
+/// // Given: Generator of Apollonian networks with 10 vertices.
+/// let generator = ApollonianNetworkGenerator::init(10);
+///
+/// // When: Generating a graph using a seeded random number generator.
+/// let graph = generator.generate(&mut StdRng::seed_from_u64(7));
+///
+/// // Then:
+/// assert_eq!(graph.vertex_count(), 10);
+/// assert_eq!(graph.edges_count(), 3 * 10 - 6);
+/// ```
+pub struct ApollonianNetworkGenerator {
+    vertex_count: usize,
+}
+
+impl ApollonianNetworkGenerator {
+    /// # Arguments
+    /// `vertex_count`: Number of vertices, including the three vertices of the initial triangle.
+    ///
+    /// # Returns
+    /// Initialized generator.
+    ///
+    /// # Panics
+    /// If `vertex_count` is less than 3.
+    pub fn init(vertex_count: usize) -> Self {
+        assert!(vertex_count >= 3, "Vertex count must be at least 3");
+
+        ApollonianNetworkGenerator { vertex_count }
+    }
+
+    /// Generates the graph into an existing graph.
+    ///
+    /// # Arguments
+    /// * `graph`: Graph to add the vertices and edges to.
+    /// * `rng`: Random number generator to choose the faces with. Pass a seeded one to get reproducible graphs.
+    ///
+    /// # Returns
+    /// * Id of the added vertices, in the order they are added. The first three of them form the initial triangle.
+    /// * Inner faces of the triangulation, each as id of its three corners. The outer face is the initial triangle.
+    ///
+    /// # Complexity
+    /// O(`vertex_count`) calls to `add_vertex` and `add_edge`.
+    ///
+    /// # Panics
+    /// If `graph` refuses to add an edge between two of the newly added vertices.
+    pub fn generate_into<W, E, G, R>(
+        &self,
+        graph: &mut G,
+        rng: &mut R,
+    ) -> (Vec<usize>, Vec<(usize, usize, usize)>)
+    where
+        W: One + Any,
+        E: Edge<W>,
+        G: Graph<W, E, UndirectedEdge>,
+        R: Rng,
+    {
+        let vertices: Vec<usize> = (0..self.vertex_count).map(|_| graph.add_vertex()).collect();
+
+        let (a, b, c) = (vertices[0], vertices[1], vertices[2]);
+        for (src_id, dst_id) in [(a, b), (b, c), (c, a)] {
+            graph
+                .add_edge(src_id, dst_id, E::init(W::one().into()))
+                .unwrap();
+        }
+
+        let mut faces = vec![(a, b, c)];
+        for vertex_id in vertices.iter().copied().skip(3) {
+            let index = rng.gen_range(0..faces.len());
+            let (a, b, c) = faces.swap_remove(index);
+
+            for corner_id in [a, b, c] {
+                graph
+                    .add_edge(vertex_id, corner_id, E::init(W::one().into()))
+                    .unwrap();
+            }
+
+            faces.push((a, b, vertex_id));
+            faces.push((b, c, vertex_id));
+            faces.push((c, a, vertex_id));
+        }
+
+        (vertices, faces)
+    }
+
+    /// Generates a new graph.
+    ///
+    /// # Arguments
+    /// `rng`: Random number generator to choose the faces with. Pass a seeded one to get reproducible graphs.
+    ///
+    /// # Returns
+    /// Generated graph, in which vertices have ids from 0 to `vertex_count` - 1 in the order they are added.
+    pub fn generate<R: Rng>(&self, rng: &mut R) -> MatGraph<usize, UndirectedEdge> {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+
+        self.generate_into::<usize, DefaultEdge<usize>, _, _>(&mut graph, rng);
+
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::ConnectedComponents;
+    use crate::provide::{Edges, Vertices};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn triangle() {
+        let graph = ApollonianNetworkGenerator::init(3).generate(&mut StdRng::seed_from_u64(0));
+
+        assert_eq!(graph.vertex_count(), 3);
+        assert_eq!(graph.edges_count(), 3);
+    }
+
+    #[test]
+    fn edges_and_faces_count() {
+        for vertex_count in [4, 5, 10, 40] {
+            // When: Generating the graph.
+            let mut graph = MatGraph::init(Mat::<usize>::init());
+            let (vertices, faces) = ApollonianNetworkGenerator::init(vertex_count)
+                .generate_into(&mut graph, &mut StdRng::seed_from_u64(3));
+
+            // Then: Euler's formula holds with the outer face, and every face is a triangle of the graph.
+            assert_eq!(vertices.len(), vertex_count);
+            assert_eq!(graph.edges_count(), 3 * vertex_count - 6);
+            assert_eq!(vertex_count + faces.len() + 1, graph.edges_count() + 2);
+            for (a, b, c) in faces {
+                assert!(graph.has_any_edge(a, b).unwrap());
+                assert!(graph.has_any_edge(b, c).unwrap());
+                assert!(graph.has_any_edge(c, a).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn three_connected() {
+        // Given: Apollonian network with 12 vertices.
+        let graph = ApollonianNetworkGenerator::init(12).generate(&mut StdRng::seed_from_u64(11));
+
+        // When: Removing any two vertices.
+        for v1 in 0..12 {
+            for v2 in v1 + 1..12 {
+                let mut reduced = graph.clone();
+                reduced.remove_vertex(v1).unwrap();
+                reduced.remove_vertex(v2).unwrap();
+
+                // Then: Graph stays connected.
+                let components = ConnectedComponents::init(&reduced).execute(&reduced);
+                assert_eq!(components.len(), 1);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_few_vertices() {
+        ApollonianNetworkGenerator::init(2);
+    }
+}
@@ -0,0 +1,149 @@
+use num_traits::One;
+use std::any::Any;
+use std::collections::HashSet;
+
+use crate::graph::{DefaultEdge, Edge, MatGraph, UndirectedEdge};
+use crate::provide::Graph;
+use crate::storage::Mat;
+
+/// Generates complete bipartite graphs K(n, m).
+///
+/// Vertices are split into two partitions of size `n` and `m`.
+/// Every vertex of the first partition is connected to every vertex of the second one, and no edge connects two vertices of the same partition.
+/// All edges have unit weight.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::gen::CompleteBipartiteGraph;
+///
+/// // Given: Generator of K(2, 3), in which each of 0 and 1 gets connected to each of 2, 3 and 4.
+/// let generator = CompleteBipartiteGraph::init(2, 3);
+///
+/// // When: Generating the graph.
+/// let graph = generator.generate();
+///
+/// // Then:
+/// assert_eq!(graph.vertex_count(), 5);
+/// assert_eq!(graph.edges_count(), 6);
+/// assert!(!graph.has_any_edge(0, 1).unwrap());
+/// ```
+pub struct CompleteBipartiteGraph {
+    n: usize,
+    m: usize,
+}
+
+impl CompleteBipartiteGraph {
+    /// # Arguments
+    /// * `n`: Number of vertices in the first partition.
+    /// * `m`: Number of vertices in the second partition.
+    ///
+    /// # Returns
+    /// Initialized generator.
+    pub fn init(n: usize, m: usize) -> Self {
+        CompleteBipartiteGraph { n, m }
+    }
+
+    /// # Returns
+    /// Id of the vertices of the first and second partition of the graph returned by [`generate`](crate::gen::CompleteBipartiteGraph::generate).
+    /// Which are ids in range [0, `n`) and [`n`, `n` + `m`) respectively.
+    pub fn partitions(&self) -> (HashSet<usize>, HashSet<usize>) {
+        ((0..self.n).collect(), (self.n..self.n + self.m).collect())
+    }
+
+    /// Generates the graph into an existing graph.
+    ///
+    /// # Arguments
+    /// `graph`: Graph to add the vertices and edges to.
+    ///
+    /// # Returns
+    /// Id of the added vertices of the first and second partition, in the order they are added.
+    ///
+    /// # Complexity
+    /// O(`n` * `m`) calls to `add_edge`.
+    ///
+    /// # Panics
+    /// If `graph` refuses to add an edge between two of the newly added vertices.
+    pub fn generate_into<W, E, G>(&self, graph: &mut G) -> (Vec<usize>, Vec<usize>)
+    where
+        W: One + Any,
+        E: Edge<W>,
+        G: Graph<W, E, UndirectedEdge>,
+    {
+        let first: Vec<usize> = (0..self.n).map(|_| graph.add_vertex()).collect();
+        let second: Vec<usize> = (0..self.m).map(|_| graph.add_vertex()).collect();
+
+        for src_id in &first {
+            for dst_id in &second {
+                graph
+                    .add_edge(*src_id, *dst_id, E::init(W::one().into()))
+                    .unwrap();
+            }
+        }
+
+        (first, second)
+    }
+
+    /// # Returns
+    /// Generated graph as a new graph, in which vertices are partitioned as returned by [`partitions`](crate::gen::CompleteBipartiteGraph::partitions).
+    pub fn generate(&self) -> MatGraph<usize, UndirectedEdge> {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+
+        self.generate_into::<usize, DefaultEdge<usize>, _>(&mut graph);
+
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provide::{Edges, Vertices};
+
+    #[test]
+    fn empty_partition() {
+        let graph = CompleteBipartiteGraph::init(0, 4).generate();
+
+        assert_eq!(graph.vertex_count(), 4);
+        assert_eq!(graph.edges_count(), 0);
+    }
+
+    #[test]
+    fn k_3_4() {
+        // Given: Generator of K(3, 4).
+        let generator = CompleteBipartiteGraph::init(3, 4);
+
+        // When: Generating the graph.
+        let graph = generator.generate();
+        let (first, second) = generator.partitions();
+
+        // Then: Every edge goes across the partitions.
+        assert_eq!(graph.vertex_count(), 7);
+        assert_eq!(graph.edges_count(), 3 * 4);
+        assert_eq!(first.len(), 3);
+        assert_eq!(second.len(), 4);
+        assert!(first.is_disjoint(&second));
+        for (src_id, dst_id, edge) in graph.edges() {
+            assert_ne!(first.contains(&src_id), first.contains(&dst_id));
+            assert_ne!(second.contains(&src_id), second.contains(&dst_id));
+            assert_eq!(*edge.get_weight(), 1.into());
+        }
+    }
+
+    #[test]
+    fn generate_into_existing_graph() {
+        // Given: Graph with a vertex already in it.
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+
+        // When: Generating K(2, 2) into it.
+        let (first, second) = CompleteBipartiteGraph::init(2, 2).generate_into(&mut graph);
+
+        // Then: Existing vertex stays isolated.
+        assert_eq!(graph.vertex_count(), 5);
+        assert_eq!(graph.edges_count(), 4);
+        assert!(!first.contains(&a) && !second.contains(&a));
+        assert!(graph.has_any_edge(first[0], second[1]).unwrap());
+        assert!(!graph.has_any_edge(first[0], first[1]).unwrap());
+    }
+}
@@ -0,0 +1,385 @@
+use num_traits::One;
+use std::any::Any;
+use std::collections::HashSet;
+
+use crate::graph::{DefaultEdge, Edge, MatGraph, UndirectedEdge};
+use crate::provide::Graph;
+use crate::storage::Mat;
+
+/// Generates 2D grid graphs, in which each cell is connected to the cells above, below, left and right of it.
+///
+/// Some cells can be blocked. Blocked cells are not added as vertices, so they act like walls.
+/// Cells can also be connected to their diagonal neighbors, using [`set_diagonals`](crate::gen::GridGraphGenerator::set_diagonals).
+/// All edges have unit weight.
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use prepona::prelude::*;
+/// use prepona::gen::GridGraphGenerator;
+///
+/// // Given: 2 by 3 grid with the cell at (0, 1) blocked.
+/// //
+/// //      (0, 0)   ####   (0, 2)
+/// //        |               |
+/// //      (1, 0) - (1, 1) - (1, 2)
+/// //
+/// let blocked: HashSet<(usize, usize)> = [(0, 1)].iter().copied().collect();
+/// let generator = GridGraphGenerator::init_with(2, 3, blocked);
+///
+/// // When: Generating the graph.
+/// let graph = generator.generate();
+///
+/// // Then:
+/// assert_eq!(graph.vertex_count(), 5);
+/// assert_eq!(graph.edges_count(), 4);
+/// ```
+pub struct GridGraphGenerator {
+    rows: usize,
+    cols: usize,
+    blocked: HashSet<(usize, usize)>,
+    diagonals: bool,
+}
+
+impl GridGraphGenerator {
+    /// # Arguments
+    /// * `rows`: Number of rows of the grid.
+    /// * `cols`: Number of columns of the grid.
+    ///
+    /// # Returns
+    /// Generator of a grid without blocked cells.
+    pub fn init(rows: usize, cols: usize) -> Self {
+        GridGraphGenerator::init_with(rows, cols, HashSet::new())
+    }
+
+    /// # Arguments
+    /// * `rows`: Number of rows of the grid.
+    /// * `cols`: Number of columns of the grid.
+    /// * `blocked`: Cells to exclude from the grid in the format of (row, col). Cells outside of the grid are ignored.
+    ///
+    /// # Returns
+    /// Generator of a grid with the specified blocked cells.
+    pub fn init_with(rows: usize, cols: usize, blocked: HashSet<(usize, usize)>) -> Self {
+        GridGraphGenerator {
+            rows,
+            cols,
+            blocked,
+            diagonals: false,
+        }
+    }
+
+    /// # Arguments
+    /// `diagonals`: If `true`, each cell is also connected to the four cells diagonal to it(8-connectivity).
+    /// Otherwise only to the cells above, below, left and right of it(4-connectivity), which is the default.
+    pub fn set_diagonals(&mut self, diagonals: bool) {
+        self.diagonals = diagonals;
+    }
+
+    /// # Arguments
+    /// * `row`: Row of the cell.
+    /// * `col`: Column of the cell.
+    ///
+    /// # Returns
+    /// `true` if the cell is blocked, `false` otherwise.
+    pub fn is_blocked(&self, row: usize, col: usize) -> bool {
+        self.blocked.contains(&(row, col))
+    }
+
+    /// # Arguments
+    /// * `row`: Row of the cell.
+    /// * `col`: Column of the cell.
+    ///
+    /// # Returns
+    /// * `Some`: Containing id of the vertex of the cell in the graph returned by [`generate`](crate::gen::GridGraphGenerator::generate).
+    /// * `None`: If the cell is blocked or outside of the grid.
+    ///
+    /// # Complexity
+    /// O(|B|log(|B|)) where |B| is the number of blocked cells.
+    pub fn vertex_id_of(&self, row: usize, col: usize) -> Option<usize> {
+        if row >= self.rows || col >= self.cols || self.is_blocked(row, col) {
+            return None;
+        }
+
+        // Ids are given in row-major order, skipping the blocked cells.
+        let blocked_before = self
+            .blocked_indices()
+            .into_iter()
+            .filter(|index| *index < row * self.cols + col)
+            .count();
+
+        Some(row * self.cols + col - blocked_before)
+    }
+
+    /// # Arguments
+    /// `vertex_id`: Id of a vertex in the graph returned by [`generate`](crate::gen::GridGraphGenerator::generate).
+    ///
+    /// # Returns
+    /// * `Some`: Containing the cell of the vertex in the format of (row, col).
+    /// * `None`: If there is no vertex with id: `vertex_id` in the generated graph.
+    ///
+    /// # Complexity
+    /// O(|B|log(|B|)) where |B| is the number of blocked cells.
+    pub fn cell_of(&self, vertex_id: usize) -> Option<(usize, usize)> {
+        let mut index = vertex_id;
+        for blocked_index in self.blocked_indices() {
+            if blocked_index <= index {
+                index += 1;
+            }
+        }
+
+        if index < self.rows * self.cols {
+            Some((index / self.cols, index % self.cols))
+        } else {
+            None
+        }
+    }
+
+    // Row-major index of the blocked cells inside of the grid, in ascending order.
+    fn blocked_indices(&self) -> Vec<usize> {
+        let mut indices: Vec<usize> = self
+            .blocked
+            .iter()
+            .filter(|(row, col)| *row < self.rows && *col < self.cols)
+            .map(|(row, col)| row * self.cols + col)
+            .collect();
+        indices.sort_unstable();
+        indices
+    }
+
+    /// Generates the grid into an existing graph.
+    ///
+    /// # Arguments
+    /// `graph`: Graph to add the vertices and edges to.
+    ///
+    /// # Returns
+    /// Id of the vertex of each cell, indexed by row and then column. Entries of blocked cells are `None`.
+    ///
+    /// # Complexity
+    /// O(`rows` * `cols`) calls to `add_vertex` and `add_edge`.
+    ///
+    /// # Panics
+    /// If `graph` refuses to add an edge between two of the newly added vertices.
+    pub fn generate_into<W, E, G>(&self, graph: &mut G) -> Vec<Vec<Option<usize>>>
+    where
+        W: One + Any,
+        E: Edge<W>,
+        G: Graph<W, E, UndirectedEdge>,
+    {
+        let cells: Vec<Vec<Option<usize>>> = (0..self.rows)
+            .map(|row| {
+                (0..self.cols)
+                    .map(|col| {
+                        if self.is_blocked(row, col) {
+                            None
+                        } else {
+                            Some(graph.add_vertex())
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                let src_id = match cells[row][col] {
+                    Some(src_id) => src_id,
+                    None => continue,
+                };
+
+                // Connect each cell to the cells below and right of it, and to the cells diagonally below it.
+                let below = cells.get(row + 1).and_then(|cells| cells[col]);
+                let right = cells[row].get(col + 1).copied().flatten();
+                let mut diagonals = vec![];
+                if self.diagonals {
+                    if let Some(cells_below) = cells.get(row + 1) {
+                        diagonals.extend(cells_below.get(col + 1).copied().flatten());
+                        if col > 0 {
+                            diagonals.extend(cells_below[col - 1]);
+                        }
+                    }
+                }
+                for dst_id in below.into_iter().chain(right).chain(diagonals) {
+                    graph
+                        .add_edge(src_id, dst_id, E::init(W::one().into()))
+                        .unwrap();
+                }
+            }
+        }
+
+        cells
+    }
+
+    /// # Returns
+    /// Generated grid as a new graph.
+    /// Ids are given to cells that are not blocked in row-major order, starting from 0.
+    pub fn generate(&self) -> MatGraph<usize, UndirectedEdge> {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+
+        self.generate_into::<usize, DefaultEdge<usize>, _>(&mut graph);
+
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::Dijkstra;
+    use crate::provide::{Edges, Neighbors, Vertices};
+
+    #[test]
+    fn empty_grid() {
+        let graph = GridGraphGenerator::init(0, 4).generate();
+
+        assert_eq!(graph.vertex_count(), 0);
+    }
+
+    #[test]
+    fn grid_without_blocked_cells() {
+        // Given: 3 by 4 grid.
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+
+        // When: Generating the grid.
+        let cells = GridGraphGenerator::init(3, 4).generate_into(&mut graph);
+
+        // Then:
+        assert_eq!(graph.vertex_count(), 12);
+        assert_eq!(graph.edges_count(), 3 * 3 + 2 * 4);
+        let corner = cells[0][0].unwrap();
+        let mut neighbors = graph.neighbors(corner).unwrap();
+        neighbors.sort_unstable();
+        assert_eq!(neighbors, vec![cells[0][1].unwrap(), cells[1][0].unwrap()]);
+        assert_eq!(graph.neighbors(cells[1][1].unwrap()).unwrap().len(), 4);
+    }
+
+    #[test]
+    fn blocked_cells_are_excluded() {
+        // Given: 3 by 3 grid with blocked center.
+        let blocked = [(1, 1), (5, 5)].iter().copied().collect();
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+
+        // When: Generating the grid.
+        let cells = GridGraphGenerator::init_with(3, 3, blocked).generate_into(&mut graph);
+
+        // Then: Center and its four edges are missing.
+        assert_eq!(graph.vertex_count(), 8);
+        assert_eq!(graph.edges_count(), 12 - 4);
+        assert!(cells[1][1].is_none());
+        assert_eq!(cells.iter().flatten().flatten().count(), 8);
+    }
+
+    #[test]
+    fn path_routes_around_wall() {
+        // Given: 5 by 5 grid with a wall in the middle column that only leaves the bottom cell open.
+        //
+        //      . . # . .
+        //      . . # . .
+        //      s . # . t
+        //      . . # . .
+        //      . . . . .
+        //
+        let blocked = (0..4).map(|row| (row, 2)).collect();
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let cells = GridGraphGenerator::init_with(5, 5, blocked).generate_into(&mut graph);
+        let (s, t) = (cells[2][0].unwrap(), cells[2][4].unwrap());
+
+        // When: Finding the shortest path from s to t.
+        let sp_subgraph = Dijkstra::init(&graph).execute(&graph, s);
+
+        // Then: Path goes down to the last row and back up, instead of going straight.
+        assert_eq!(sp_subgraph.distance_to(t).unwrap(), 8.into());
+    }
+
+    #[test]
+    fn full_wall_disconnects_grid() {
+        // Given: 3 by 3 grid with its middle column blocked.
+        let blocked = (0..3).map(|row| (row, 1)).collect();
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let cells = GridGraphGenerator::init_with(3, 3, blocked).generate_into(&mut graph);
+
+        // When: Finding the shortest path from left side to the right side.
+        let sp_subgraph = Dijkstra::init(&graph).execute(&graph, cells[0][0].unwrap());
+
+        // Then:
+        assert!(sp_subgraph
+            .distance_to(cells[0][2].unwrap())
+            .unwrap()
+            .is_pos_infinite());
+    }
+
+    fn sorted_neighbors(graph: &MatGraph<usize, UndirectedEdge>, vertex_id: usize) -> Vec<usize> {
+        let mut neighbors = graph.neighbors(vertex_id).unwrap();
+        neighbors.sort_unstable();
+        neighbors
+    }
+
+    #[test]
+    fn three_by_three_grid() {
+        // Given: 3 by 3 grid.
+        //
+        //      0 - 1 - 2
+        //      |   |   |
+        //      3 - 4 - 5
+        //      |   |   |
+        //      6 - 7 - 8
+        //
+        let generator = GridGraphGenerator::init(3, 3);
+
+        // When: Generating the grid.
+        let graph = generator.generate();
+
+        // Then: Corners have 2 neighbors and the center has 4.
+        assert_eq!(graph.edges_count(), 12);
+        let corner = generator.vertex_id_of(0, 0).unwrap();
+        let center = generator.vertex_id_of(1, 1).unwrap();
+        assert_eq!(sorted_neighbors(&graph, corner), vec![1, 3]);
+        assert_eq!(sorted_neighbors(&graph, center), vec![1, 3, 5, 7]);
+        assert_eq!(sorted_neighbors(&graph, 8), vec![5, 7]);
+    }
+
+    #[test]
+    fn three_by_three_grid_with_diagonals() {
+        // Given: 3 by 3 grid with 8-connectivity.
+        let mut generator = GridGraphGenerator::init(3, 3);
+        generator.set_diagonals(true);
+
+        // When: Generating the grid.
+        let graph = generator.generate();
+
+        // Then: Corners have 3 neighbors and the center is connected to every other cell.
+        assert_eq!(graph.edges_count(), 12 + 2 * 4);
+        assert_eq!(sorted_neighbors(&graph, 0), vec![1, 3, 4]);
+        assert_eq!(sorted_neighbors(&graph, 2), vec![1, 4, 5]);
+        assert_eq!(sorted_neighbors(&graph, 4), vec![0, 1, 2, 3, 5, 6, 7, 8]);
+        assert_eq!(sorted_neighbors(&graph, 7), vec![3, 4, 5, 6, 8]);
+    }
+
+    #[test]
+    fn coordinates_and_vertex_ids() {
+        // Given: 3 by 4 grid with two blocked cells, and a cell outside of the grid blocked.
+        //
+        //      0 #  1  2
+        //      3 4  #  5
+        //      6 7  8  9
+        //
+        let blocked = [(0, 1), (1, 2), (7, 7)].iter().copied().collect();
+        let generator = GridGraphGenerator::init_with(3, 4, blocked);
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+
+        // When: Generating the grid.
+        let cells = generator.generate_into(&mut graph);
+
+        // Then: Conversions match the ids given to the cells, in both directions.
+        for (row, row_cells) in cells.iter().enumerate() {
+            for (col, cell) in row_cells.iter().enumerate() {
+                assert_eq!(generator.vertex_id_of(row, col), *cell);
+                if let Some(vertex_id) = cell {
+                    assert_eq!(generator.cell_of(*vertex_id), Some((row, col)));
+                }
+            }
+        }
+        assert_eq!(generator.vertex_id_of(1, 3), Some(5));
+        assert_eq!(generator.vertex_id_of(3, 0), None);
+        assert_eq!(generator.cell_of(10), None);
+    }
+}
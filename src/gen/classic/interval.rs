@@ -0,0 +1,272 @@
+use num_traits::One;
+use std::any::Any;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::graph::{DefaultEdge, Edge, MatGraph, UndirectedEdge};
+use crate::provide::Graph;
+use crate::storage::Mat;
+
+/// Generates interval graphs, in which each vertex is an interval and two vertices are adjacent iff their intervals overlap.
+///
+/// Intervals are half-open, so an interval that ends at time `t` does not overlap an interval that starts at `t`.
+/// This matches scheduling, in which a task can start as soon as the previous one ends.
+/// All edges have unit weight.
+///
+/// Interval graphs are perfect, so they can be colored optimally in polynomial time using [`coloring`](crate::gen::IntervalGraph::coloring).
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::gen::IntervalGraph;
+///
+/// // Given: Intervals
+/// //
+/// //      a: [0, 3)   ---
+/// //      b: [2, 5)     ---
+/// //      c: [3, 6)      ---
+/// //
+/// let generator = IntervalGraph::init(vec![(0, 3), (2, 5), (3, 6)]);
+///
+/// // When: Generating the graph.
+/// let graph = generator.generate();
+///
+/// // Then: a and c do not overlap, so they can share a color.
+/// assert_eq!(graph.edges_count(), 2);
+/// assert!(!graph.has_any_edge(0, 2).unwrap());
+/// assert_eq!(generator.coloring(), vec![0, 1, 0]);
+/// ```
+pub struct IntervalGraph<T> {
+    intervals: Vec<(T, T)>,
+}
+
+impl<T: Ord + Copy> IntervalGraph<T> {
+    /// # Arguments
+    /// `intervals`: Intervals in the format of (start, end).
+    ///
+    /// # Returns
+    /// Initialized generator.
+    ///
+    /// # Panics
+    /// If an interval starts after it ends.
+    pub fn init(intervals: Vec<(T, T)>) -> Self {
+        assert!(
+            intervals.iter().all(|(start, end)| start <= end),
+            "Interval must not start after it ends"
+        );
+
+        IntervalGraph { intervals }
+    }
+
+    /// # Arguments
+    /// * `i`: Index of the first interval.
+    /// * `j`: Index of the second interval.
+    ///
+    /// # Returns
+    /// `true` if the intervals at index `i` and `j` overlap, `false` otherwise.
+    pub fn overlaps(&self, i: usize, j: usize) -> bool {
+        let (start1, end1) = self.intervals[i];
+        let (start2, end2) = self.intervals[j];
+
+        // Empty intervals contain no point, so they overlap nothing.
+        start1 < end1 && start2 < end2 && start1 < end2 && start2 < end1
+    }
+
+    /// Generates the graph into an existing graph.
+    ///
+    /// # Arguments
+    /// `graph`: Graph to add the vertices and edges to.
+    ///
+    /// # Returns
+    /// Id of the vertex of each interval, in the same order as the intervals.
+    ///
+    /// # Complexity
+    /// O(|I|<sup>2</sup>) where |I| is the number of intervals, plus calls to `add_vertex` and `add_edge`.
+    ///
+    /// # Panics
+    /// If `graph` refuses to add an edge between two of the newly added vertices.
+    pub fn generate_into<W, E, G>(&self, graph: &mut G) -> Vec<usize>
+    where
+        W: One + Any,
+        E: Edge<W>,
+        G: Graph<W, E, UndirectedEdge>,
+    {
+        let vertices: Vec<usize> = self.intervals.iter().map(|_| graph.add_vertex()).collect();
+
+        for i in 0..vertices.len() {
+            for j in i + 1..vertices.len() {
+                if self.overlaps(i, j) {
+                    graph
+                        .add_edge(vertices[i], vertices[j], E::init(W::one().into()))
+                        .unwrap();
+                }
+            }
+        }
+
+        vertices
+    }
+
+    /// # Returns
+    /// Generated graph as a new graph, in which the vertex of the interval at index `i` has id `i`.
+    pub fn generate(&self) -> MatGraph<usize, UndirectedEdge> {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+
+        self.generate_into::<usize, DefaultEdge<usize>, _>(&mut graph);
+
+        graph
+    }
+
+    /// Colors the intervals so that overlapping intervals get different colors, using as few colors as possible.
+    ///
+    /// Intervals are processed in order of their start, and each one gets the smallest color not used by the intervals that are still running.
+    /// The number of colors used equals [`max_overlap`](crate::gen::IntervalGraph::max_overlap), which is optimal.
+    ///
+    /// # Returns
+    /// Color of each interval, in the same order as the intervals. Colors are in range [0, `max_overlap`).
+    ///
+    /// # Complexity
+    /// O(|I|log(|I|)) where |I| is the number of intervals.
+    pub fn coloring(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.intervals.len()).collect();
+        order.sort_by_key(|index| self.intervals[*index]);
+
+        let mut color_of = vec![0; self.intervals.len()];
+
+        // Running intervals as (end, color), and colors that are free to reuse.
+        let mut running = BinaryHeap::new();
+        let mut free_colors = BinaryHeap::new();
+        let mut colors_count = 0;
+        for index in order {
+            let (start, end) = self.intervals[index];
+
+            while let Some(Reverse((running_end, color))) = running.peek().copied() {
+                if running_end > start {
+                    break;
+                }
+                running.pop();
+                free_colors.push(Reverse(color));
+            }
+
+            // Empty intervals overlap nothing, so they can keep color 0.
+            if start == end {
+                continue;
+            }
+
+            let color = match free_colors.pop() {
+                Some(Reverse(color)) => color,
+                None => {
+                    colors_count += 1;
+                    colors_count - 1
+                }
+            };
+            color_of[index] = color;
+            running.push(Reverse((end, color)));
+        }
+
+        color_of
+    }
+
+    /// # Returns
+    /// Maximum number of intervals that overlap at a single point, which is the size of the largest clique of the graph.
+    ///
+    /// # Complexity
+    /// O(|I|log(|I|)) where |I| is the number of intervals.
+    pub fn max_overlap(&self) -> usize {
+        // Ends come before starts at the same point, because intervals are half-open.
+        let mut events: Vec<(T, bool)> = self
+            .intervals
+            .iter()
+            .filter(|(start, end)| start < end)
+            .flat_map(|(start, end)| vec![(*start, true), (*end, false)])
+            .collect();
+        events.sort_unstable();
+
+        let mut overlap = 0;
+        let mut max_overlap = 0;
+        for (_, is_start) in events {
+            if is_start {
+                overlap += 1;
+                max_overlap = max_overlap.max(overlap);
+            } else {
+                overlap -= 1;
+            }
+        }
+
+        max_overlap
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::k_colorable;
+    use crate::provide::{Edges, Vertices};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn no_intervals() {
+        let generator = IntervalGraph::<usize>::init(vec![]);
+
+        assert_eq!(generator.generate().vertex_count(), 0);
+        assert!(generator.coloring().is_empty());
+        assert_eq!(generator.max_overlap(), 0);
+    }
+
+    #[test]
+    fn touching_and_empty_intervals() {
+        // Given: Intervals
+        //
+        //      a: [0, 2)   --
+        //      b: [2, 4)     --
+        //      c: [3, 3)      .
+        //      d: [1, 5)    ----
+        //
+        let generator = IntervalGraph::init(vec![(0, 2), (2, 4), (3, 3), (1, 5)]);
+
+        // When: Generating the graph.
+        let graph = generator.generate();
+
+        // Then: Only d overlaps the others.
+        assert_eq!(graph.edges_count(), 2);
+        assert!(graph.has_any_edge(0, 3).unwrap());
+        assert!(graph.has_any_edge(1, 3).unwrap());
+        assert_eq!(generator.max_overlap(), 2);
+        let coloring = generator.coloring();
+        assert_eq!(coloring[0], coloring[1]);
+        assert_ne!(coloring[0], coloring[3]);
+    }
+
+    #[test]
+    fn chromatic_number_is_max_overlap() {
+        let mut rng = StdRng::seed_from_u64(11);
+
+        for _ in 0..20 {
+            // Given: Random intervals.
+            let intervals: Vec<(usize, usize)> = (0..10)
+                .map(|_| {
+                    let start = rng.gen_range(0..20);
+                    (start, start + rng.gen_range(1..8))
+                })
+                .collect();
+            let generator = IntervalGraph::init(intervals);
+
+            // When: Generating the graph and coloring it.
+            let graph = generator.generate();
+            let coloring = generator.coloring();
+            let max_overlap = generator.max_overlap();
+
+            // Then: Coloring is proper and uses max overlap colors, and the graph can not be colored with fewer.
+            for (src_id, dst_id, _) in graph.edges() {
+                assert_ne!(coloring[src_id], coloring[dst_id]);
+            }
+            assert_eq!(coloring.iter().max().unwrap() + 1, max_overlap);
+            assert!(k_colorable(&graph, max_overlap - 1).is_none());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn reversed_interval() {
+        IntervalGraph::init(vec![(3, 1)]);
+    }
+}
@@ -0,0 +1,9 @@
+mod apollonian;
+mod complete_bipartite;
+mod grid;
+mod interval;
+
+pub use apollonian::ApollonianNetworkGenerator;
+pub use complete_bipartite::CompleteBipartiteGraph;
+pub use grid::GridGraphGenerator;
+pub use interval::IntervalGraph;
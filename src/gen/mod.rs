@@ -0,0 +1,9 @@
+mod classic;
+mod prufer;
+mod random;
+
+pub use classic::{
+    ApollonianNetworkGenerator, CompleteBipartiteGraph, GridGraphGenerator, IntervalGraph,
+};
+pub use prufer::{prufer_to_tree, tree_to_prufer};
+pub use random::{BarabasiAlbert, RandomDagGenerator, RandomGeometricGenerator, RandomGnp};
@@ -0,0 +1,268 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::algo::ConnectedComponents;
+use crate::graph::{Edge, MatGraph, UndirectedEdge};
+use crate::provide::{Edges, Graph, Neighbors, Vertices};
+use crate::storage::Mat;
+
+/// Encodes a labeled tree as its Prüfer code.
+///
+/// Vertices are labeled by their ids. Leaf with the smallest id is removed repeatedly, and id of its neighbor is appended to the code,
+/// until only two vertices are left. So a tree with n vertices is encoded as a sequence of n - 2 ids,
+/// and each sequence of n - 2 ids in range [0, n) encodes exactly one tree.
+///
+/// # Arguments
+/// `graph`: Tree to encode. Ids of its vertices must be 0 to |V| - 1.
+///
+/// # Returns
+/// Prüfer code of the tree, which can be decoded using [`prufer_to_tree`](crate::gen::prufer_to_tree).
+///
+/// # Complexity
+/// O(|V|log(|V|))
+///
+/// # Panics
+/// * If `graph` is not a tree(connected and acyclic).
+/// * If `graph` has less than two vertices.
+/// * If ids of the vertices are not 0 to |V| - 1.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::gen::{prufer_to_tree, tree_to_prufer};
+///
+/// // Given: Tree
+/// //
+/// //      0 --- 3 --- 1
+/// //            |
+/// //            2
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let v0 = graph.add_vertex();
+/// let v1 = graph.add_vertex();
+/// let v2 = graph.add_vertex();
+/// let v3 = graph.add_vertex();
+/// graph.add_edge(v0, v3, 1.into());
+/// graph.add_edge(v1, v3, 1.into());
+/// graph.add_edge(v2, v3, 1.into());
+///
+/// // When: Encoding the tree.
+/// let code = tree_to_prufer(&graph);
+///
+/// // Then: Each leaf is removed after its neighbor 3 is appended.
+/// assert_eq!(code, vec![3, 3]);
+/// assert_eq!(prufer_to_tree(&code).edges_count(), 3);
+/// ```
+pub fn tree_to_prufer<W, E, G>(graph: &G) -> Vec<usize>
+where
+    E: Edge<W>,
+    G: Vertices + Neighbors + Edges<W, E> + Graph<W, E, UndirectedEdge>,
+{
+    let vertex_count = graph.vertex_count();
+    assert!(vertex_count >= 2, "Tree must have at least two vertices");
+    assert!(
+        (0..vertex_count).all(|vertex_id| graph.contains_vertex(vertex_id)),
+        "Ids of the vertices must be 0 to |V| - 1"
+    );
+    assert!(
+        graph.edges_count() == vertex_count - 1
+            && ConnectedComponents::init(graph).execute(graph).len() == 1,
+        "Graph must be a tree"
+    );
+
+    let neighbors_of: Vec<Vec<usize>> = (0..vertex_count)
+        .map(|vertex_id| graph.neighbors(vertex_id).unwrap())
+        .collect();
+    let mut degree_of: Vec<usize> = neighbors_of
+        .iter()
+        .map(|neighbors| neighbors.len())
+        .collect();
+    let mut is_removed = vec![false; vertex_count];
+
+    let mut leaves: BinaryHeap<Reverse<usize>> = (0..vertex_count)
+        .filter(|vertex_id| degree_of[*vertex_id] == 1)
+        .map(Reverse)
+        .collect();
+
+    let mut code = Vec::with_capacity(vertex_count - 2);
+    while code.len() < vertex_count - 2 {
+        let Reverse(leaf_id) = leaves.pop().unwrap();
+        is_removed[leaf_id] = true;
+
+        // Leaf has exactly one neighbor that is not removed yet.
+        let n_id = *neighbors_of[leaf_id]
+            .iter()
+            .find(|n_id| !is_removed[**n_id])
+            .unwrap();
+        code.push(n_id);
+
+        degree_of[n_id] -= 1;
+        if degree_of[n_id] == 1 {
+            leaves.push(Reverse(n_id));
+        }
+    }
+
+    code
+}
+
+/// Decodes a Prüfer code into the labeled tree it encodes.
+///
+/// # Arguments
+/// `code`: Prüfer code of a tree with `code.len()` + 2 vertices, as returned by [`tree_to_prufer`](crate::gen::tree_to_prufer).
+///
+/// # Returns
+/// The tree, in which vertices have ids from 0 to `code.len()` + 1 and all edges have unit weight.
+///
+/// # Complexity
+/// O(|V|log(|V|))
+///
+/// # Panics
+/// If an id in `code` is not in range [0, `code.len()` + 2).
+pub fn prufer_to_tree(code: &[usize]) -> MatGraph<usize, UndirectedEdge> {
+    let vertex_count = code.len() + 2;
+    assert!(
+        code.iter().all(|vertex_id| *vertex_id < vertex_count),
+        "Ids in the code must be in range [0, {})",
+        vertex_count
+    );
+
+    let mut graph = MatGraph::init(Mat::<usize>::init());
+    for _ in 0..vertex_count {
+        graph.add_vertex();
+    }
+
+    // Each vertex appears in the code one time less than its degree.
+    let mut degree_of = vec![1; vertex_count];
+    for vertex_id in code {
+        degree_of[*vertex_id] += 1;
+    }
+
+    let mut leaves: BinaryHeap<Reverse<usize>> = (0..vertex_count)
+        .filter(|vertex_id| degree_of[*vertex_id] == 1)
+        .map(Reverse)
+        .collect();
+
+    for n_id in code {
+        let Reverse(leaf_id) = leaves.pop().unwrap();
+        graph.add_edge(leaf_id, *n_id, 1.into()).unwrap();
+
+        degree_of[*n_id] -= 1;
+        if degree_of[*n_id] == 1 {
+            leaves.push(Reverse(*n_id));
+        }
+    }
+
+    let Reverse(last_id1) = leaves.pop().unwrap();
+    let Reverse(last_id2) = leaves.pop().unwrap();
+    graph.add_edge(last_id1, last_id2, 1.into()).unwrap();
+
+    graph
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn sorted_edges(graph: &MatGraph<usize, UndirectedEdge>) -> Vec<(usize, usize)> {
+        let mut edges: Vec<(usize, usize)> = graph
+            .edges()
+            .into_iter()
+            .map(|(src_id, dst_id, _)| (src_id.min(dst_id), src_id.max(dst_id)))
+            .collect();
+        edges.sort_unstable();
+        edges
+    }
+
+    #[test]
+    fn two_vertex_tree() {
+        let graph = prufer_to_tree(&[]);
+
+        assert_eq!(graph.vertex_count(), 2);
+        assert_eq!(sorted_edges(&graph), vec![(0, 1)]);
+        assert!(tree_to_prufer(&graph).is_empty());
+    }
+
+    #[test]
+    fn path_tree() {
+        // Given: Tree
+        //
+        //      0 --- 2 --- 4 --- 1 --- 3
+        //
+        let code = vec![2, 4, 1];
+
+        // When: Decoding the code.
+        let graph = prufer_to_tree(&code);
+
+        // Then:
+        assert_eq!(sorted_edges(&graph), vec![(0, 2), (1, 3), (1, 4), (2, 4)]);
+        assert_eq!(tree_to_prufer(&graph), code);
+    }
+
+    #[test]
+    fn round_trip() {
+        // Given: Tree
+        //
+        //      5 --- 0 --- 1 --- 6
+        //            |     |
+        //      7 --- 2     3 --- 4
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        for _ in 0..8 {
+            graph.add_vertex();
+        }
+        for (src_id, dst_id) in [(5, 0), (0, 1), (1, 6), (0, 2), (7, 2), (1, 3), (3, 4)] {
+            graph.add_edge(src_id, dst_id, 1.into()).unwrap();
+        }
+
+        // When: Encoding the tree and decoding it back.
+        let code = tree_to_prufer(&graph);
+        let decoded = prufer_to_tree(&code);
+
+        // Then: Decoded tree has exactly the same edges.
+        assert_eq!(code.len(), 6);
+        assert_eq!(sorted_edges(&decoded), sorted_edges(&graph));
+    }
+
+    #[test]
+    fn random_codes_round_trip() {
+        let mut rng = StdRng::seed_from_u64(17);
+
+        for vertex_count in 2..12 {
+            // Given: Random code.
+            let code: Vec<usize> = (0..vertex_count - 2)
+                .map(|_| rng.gen_range(0..vertex_count))
+                .collect();
+
+            // When: Decoding the code and encoding the tree back.
+            let graph = prufer_to_tree(&code);
+
+            // Then:
+            assert_eq!(graph.vertex_count(), vertex_count);
+            assert_eq!(graph.edges_count(), vertex_count - 1);
+            assert_eq!(tree_to_prufer(&graph), code);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn graph_with_cycle() {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        for _ in 0..4 {
+            graph.add_vertex();
+        }
+        graph.add_edge(0, 1, 1.into()).unwrap();
+        graph.add_edge(1, 2, 1.into()).unwrap();
+        graph.add_edge(2, 0, 1.into()).unwrap();
+
+        tree_to_prufer(&graph);
+    }
+
+    #[test]
+    #[should_panic]
+    fn id_out_of_range() {
+        prufer_to_tree(&[0, 4]);
+    }
+}
@@ -0,0 +1,198 @@
+use num_traits::One;
+use rand::Rng;
+use std::any::Any;
+use std::collections::HashSet;
+
+use crate::graph::{DefaultEdge, Edge, MatGraph, UndirectedEdge};
+use crate::provide::Graph;
+use crate::storage::Mat;
+
+/// Generates scale-free random graphs using Barabási–Albert preferential attachment.
+///
+/// Generation starts from a clique of `m` vertices. Then each new vertex gets connected to `m` distinct existing vertices,
+/// each chosen with probability proportional to its current degree. So vertices with high degree tend to get even more edges.
+/// All edges have unit weight.
+///
+/// # Examples
+/// ```
+/// use rand::{rngs::StdRng, SeedableRng};
+/// use prepona::prelude::*;
+/// use prepona::gen::BarabasiAlbert;
+///
+/// // Given: Generator of graphs with 20 vertices, in which each new vertex gets 2 edges.
+/// let generator = BarabasiAlbert::init(20, 2);
+///
+/// // When: Generating a graph using a seeded random number generator.
+/// let graph = generator.generate(&mut StdRng::seed_from_u64(7));
+///
+/// // Then: Seed clique has 1 edge, and each of the other 18 vertices adds 2 edges.
+/// assert_eq!(graph.vertex_count(), 20);
+/// assert_eq!(graph.edges_count(), 1 + 18 * 2);
+/// ```
+pub struct BarabasiAlbert {
+    vertex_count: usize,
+    m: usize,
+}
+
+impl BarabasiAlbert {
+    /// # Arguments
+    /// * `vertex_count`: Number of vertices(n).
+    /// * `m`: Number of vertices in the seed clique, and number of edges each new vertex adds.
+    ///
+    /// # Returns
+    /// Initialized generator.
+    ///
+    /// # Panics
+    /// If `m` is 0 or greater than `vertex_count`.
+    pub fn init(vertex_count: usize, m: usize) -> Self {
+        assert!(
+            m > 0 && m <= vertex_count,
+            "m must be in range [1, vertex_count]"
+        );
+
+        BarabasiAlbert { vertex_count, m }
+    }
+
+    /// Generates the graph into an existing graph.
+    ///
+    /// # Arguments
+    /// * `graph`: Graph to add the vertices and edges to.
+    /// * `rng`: Random number generator to choose the attachments with. Pass a seeded one to get reproducible graphs.
+    ///
+    /// # Returns
+    /// Id of the added vertices, in the order they are added. The first `m` of them form the seed clique.
+    ///
+    /// # Complexity
+    /// O(`m`<sup>2</sup> + `vertex_count` * `m`) calls to `add_edge`, and expected O(`vertex_count` * `m`) random numbers.
+    ///
+    /// # Panics
+    /// If `graph` refuses to add an edge between two of the newly added vertices.
+    pub fn generate_into<W, E, G, R>(&self, graph: &mut G, rng: &mut R) -> Vec<usize>
+    where
+        W: One + Any,
+        E: Edge<W>,
+        G: Graph<W, E, UndirectedEdge>,
+        R: Rng,
+    {
+        let vertices: Vec<usize> = (0..self.vertex_count).map(|_| graph.add_vertex()).collect();
+
+        // Each vertex appears once per each of its edges, so a uniform pick from it is proportional to degree.
+        let mut endpoints = vec![];
+
+        for (index, src_id) in vertices.iter().take(self.m).enumerate() {
+            for dst_id in vertices.iter().take(index) {
+                graph
+                    .add_edge(*src_id, *dst_id, E::init(W::one().into()))
+                    .unwrap();
+                endpoints.push(*src_id);
+                endpoints.push(*dst_id);
+            }
+        }
+
+        for (index, src_id) in vertices.iter().enumerate().skip(self.m) {
+            let mut targets = HashSet::with_capacity(self.m);
+            while targets.len() < self.m {
+                // Seed of a single vertex has no edges, so it is picked uniformly.
+                let dst_id = if endpoints.is_empty() {
+                    vertices[rng.gen_range(0..index)]
+                } else {
+                    endpoints[rng.gen_range(0..endpoints.len())]
+                };
+
+                targets.insert(dst_id);
+            }
+
+            let mut targets: Vec<usize> = targets.into_iter().collect();
+            targets.sort_unstable();
+            for dst_id in targets {
+                graph
+                    .add_edge(*src_id, dst_id, E::init(W::one().into()))
+                    .unwrap();
+                endpoints.push(*src_id);
+                endpoints.push(dst_id);
+            }
+        }
+
+        vertices
+    }
+
+    /// Generates a new graph.
+    ///
+    /// # Arguments
+    /// `rng`: Random number generator to choose the attachments with. Pass a seeded one to get reproducible graphs.
+    ///
+    /// # Returns
+    /// Generated graph, in which vertices have ids from 0 to `vertex_count` - 1 in the order they are added.
+    pub fn generate<R: Rng>(&self, rng: &mut R) -> MatGraph<usize, UndirectedEdge> {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+
+        self.generate_into::<usize, DefaultEdge<usize>, _, _>(&mut graph, rng);
+
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provide::{Edges, Neighbors, Vertices};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn sorted_edges(graph: &MatGraph<usize, UndirectedEdge>) -> Vec<(usize, usize)> {
+        let mut edges: Vec<(usize, usize)> = graph
+            .edges()
+            .into_iter()
+            .map(|(src_id, dst_id, _)| (src_id.min(dst_id), src_id.max(dst_id)))
+            .collect();
+        edges.sort_unstable();
+        edges
+    }
+
+    #[test]
+    fn edges_count() {
+        for (n, m) in [(1, 1), (10, 1), (10, 3), (50, 4), (6, 6)] {
+            // When: Generating the graph.
+            let graph = BarabasiAlbert::init(n, m).generate(&mut StdRng::seed_from_u64(1));
+
+            // Then:
+            assert_eq!(graph.vertex_count(), n);
+            assert_eq!(graph.edges_count(), m * (n - m) + m * (m - 1) / 2);
+        }
+    }
+
+    #[test]
+    fn new_vertices_attach_to_older_ones() {
+        // Given: Generator of graphs with 30 vertices, in which each new vertex gets 3 edges.
+        let graph = BarabasiAlbert::init(30, 3).generate(&mut StdRng::seed_from_u64(9));
+
+        // Then: Each vertex after the seed clique has exactly 3 neighbors that were added before it.
+        for vertex_id in 3..30 {
+            let older_count = graph
+                .neighbors(vertex_id)
+                .unwrap()
+                .into_iter()
+                .filter(|n_id| *n_id < vertex_id)
+                .count();
+
+            assert_eq!(older_count, 3);
+        }
+    }
+
+    #[test]
+    fn same_seed_same_graph() {
+        let generator = BarabasiAlbert::init(40, 2);
+
+        let graph1 = generator.generate(&mut StdRng::seed_from_u64(5));
+        let graph2 = generator.generate(&mut StdRng::seed_from_u64(5));
+        let graph3 = generator.generate(&mut StdRng::seed_from_u64(6));
+
+        assert_eq!(sorted_edges(&graph1), sorted_edges(&graph2));
+        assert_ne!(sorted_edges(&graph1), sorted_edges(&graph3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn m_larger_than_vertex_count() {
+        BarabasiAlbert::init(3, 4);
+    }
+}
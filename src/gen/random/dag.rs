@@ -0,0 +1,192 @@
+use num_traits::One;
+use rand::{seq::SliceRandom, Rng};
+use std::any::Any;
+
+use crate::graph::{DefaultEdge, DirectedEdge, Edge, MatGraph};
+use crate::provide::Graph;
+use crate::storage::DiMat;
+
+/// Generates random directed acyclic graphs.
+///
+/// A random permutation of the vertices is chosen as their topological order,
+/// and each of the n(n - 1) / 2 possible edges from an earlier vertex to a later one is added independently with probability `p`.
+/// Since every edge goes forward in the order, generated graphs never have a cycle.
+/// All edges have unit weight.
+///
+/// # Examples
+/// ```
+/// use rand::{rngs::StdRng, SeedableRng};
+/// use prepona::prelude::*;
+/// use prepona::gen::RandomDagGenerator;
+///
+/// // Given: Generator of DAGs with 10 vertices, in which every possible edge is added.
+/// let generator = RandomDagGenerator::init(10, 1.0);
+///
+/// // When: Generating a graph using a seeded random number generator.
+/// let (graph, order) = generator.generate(&mut StdRng::seed_from_u64(7));
+///
+/// // Then: Every edge goes forward in the order.
+/// assert_eq!(graph.edges_count(), 45);
+/// for (src_id, dst_id, _) in graph.edges() {
+///     let src_index = order.iter().position(|v_id| *v_id == src_id).unwrap();
+///     let dst_index = order.iter().position(|v_id| *v_id == dst_id).unwrap();
+///     assert!(src_index < dst_index);
+/// }
+/// ```
+pub struct RandomDagGenerator {
+    vertex_count: usize,
+    probability: f64,
+}
+
+impl RandomDagGenerator {
+    /// # Arguments
+    /// * `vertex_count`: Number of vertices(n).
+    /// * `probability`: Probability of adding each forward edge(p).
+    ///
+    /// # Returns
+    /// Initialized generator.
+    ///
+    /// # Panics
+    /// If `probability` is not in range [0, 1].
+    pub fn init(vertex_count: usize, probability: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&probability),
+            "Probability must be in range [0, 1]"
+        );
+
+        RandomDagGenerator {
+            vertex_count,
+            probability,
+        }
+    }
+
+    /// Generates the graph into an existing graph.
+    ///
+    /// # Arguments
+    /// * `graph`: Graph to add the vertices and edges to.
+    /// * `rng`: Random number generator to decide the order and the edges with. Pass a seeded one to get reproducible graphs.
+    ///
+    /// # Returns
+    /// Id of the added vertices in their topological order: every added edge goes from a vertex to one that comes after it.
+    ///
+    /// # Complexity
+    /// O(`vertex_count`<sup>2</sup>) random numbers, and calls to `add_edge` for the added edges.
+    ///
+    /// # Panics
+    /// If `graph` refuses to add an edge between two of the newly added vertices.
+    pub fn generate_into<W, E, G, R>(&self, graph: &mut G, rng: &mut R) -> Vec<usize>
+    where
+        W: One + Any,
+        E: Edge<W>,
+        G: Graph<W, E, DirectedEdge>,
+        R: Rng,
+    {
+        let mut order: Vec<usize> = (0..self.vertex_count).map(|_| graph.add_vertex()).collect();
+        order.shuffle(rng);
+
+        for (index, src_id) in order.iter().enumerate() {
+            for dst_id in order.iter().skip(index + 1) {
+                if rng.gen_bool(self.probability) {
+                    graph
+                        .add_edge(*src_id, *dst_id, E::init(W::one().into()))
+                        .unwrap();
+                }
+            }
+        }
+
+        order
+    }
+
+    /// Generates a new graph.
+    ///
+    /// # Arguments
+    /// `rng`: Random number generator to decide the order and the edges with. Pass a seeded one to get reproducible graphs.
+    ///
+    /// # Returns
+    /// Generated graph, in which vertices have ids from 0 to `vertex_count` - 1, alongside id of the vertices in their topological order.
+    pub fn generate<R: Rng>(&self, rng: &mut R) -> (MatGraph<usize, DirectedEdge>, Vec<usize>) {
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+
+        let order = self.generate_into::<usize, DefaultEdge<usize>, _, _>(&mut graph, rng);
+
+        (graph, order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::{HasCycle, TopologicalSort};
+    use crate::provide::{Edges, Vertices};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn position_of(order: &[usize], vertex_id: usize) -> usize {
+        order.iter().position(|v_id| *v_id == vertex_id).unwrap()
+    }
+
+    #[test]
+    fn no_vertices() {
+        let (graph, order) =
+            RandomDagGenerator::init(0, 0.5).generate(&mut StdRng::seed_from_u64(0));
+
+        assert_eq!(graph.vertex_count(), 0);
+        assert!(order.is_empty());
+    }
+
+    #[test]
+    fn extreme_probabilities() {
+        let (empty, _) = RandomDagGenerator::init(12, 0.0).generate(&mut StdRng::seed_from_u64(1));
+        let (complete, _) =
+            RandomDagGenerator::init(12, 1.0).generate(&mut StdRng::seed_from_u64(1));
+
+        assert_eq!(empty.vertex_count(), 12);
+        assert_eq!(empty.edges_count(), 0);
+        assert_eq!(complete.edges_count(), 12 * 11 / 2);
+        assert!(HasCycle::init(&complete).execute(&complete).is_none());
+    }
+
+    #[test]
+    fn generated_graphs_are_acyclic() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for probability in [0.1, 0.3, 0.6] {
+            // When: Generating graphs with 40 vertices.
+            let (graph, order) = RandomDagGenerator::init(40, probability).generate(&mut rng);
+
+            // Then: There is no cycle and both the returned order and the topological sort are valid.
+            assert!(HasCycle::init(&graph).execute(&graph).is_none());
+
+            let sorted_vertices = TopologicalSort::init().execute(&graph);
+            assert_eq!(sorted_vertices.len(), 40);
+            for (src_id, dst_id, _) in graph.edges() {
+                assert!(position_of(&order, src_id) < position_of(&order, dst_id));
+                assert!(
+                    position_of(&sorted_vertices, src_id) < position_of(&sorted_vertices, dst_id)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn same_seed_same_graph() {
+        let generator = RandomDagGenerator::init(30, 0.3);
+
+        let (graph1, order1) = generator.generate(&mut StdRng::seed_from_u64(5));
+        let (graph2, order2) = generator.generate(&mut StdRng::seed_from_u64(5));
+
+        let mut edges1: Vec<(usize, usize)> =
+            graph1.edges().into_iter().map(|(s, d, _)| (s, d)).collect();
+        let mut edges2: Vec<(usize, usize)> =
+            graph2.edges().into_iter().map(|(s, d, _)| (s, d)).collect();
+        edges1.sort_unstable();
+        edges2.sort_unstable();
+        assert_eq!(edges1, edges2);
+        assert_eq!(order1, order2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_probability() {
+        RandomDagGenerator::init(5, -0.1);
+    }
+}
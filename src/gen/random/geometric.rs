@@ -0,0 +1,176 @@
+use rand::Rng;
+
+use crate::graph::{Edge, MatGraph, UndirectedEdge};
+use crate::provide::Graph;
+use crate::storage::Mat;
+
+/// Generates random geometric graphs.
+///
+/// Vertices are points placed uniformly at random in the unit square and each pair of points that are at most `radius` apart get connected.
+/// Weight of each edge is the euclidean distance between its end points.
+///
+/// # Examples
+/// ```
+/// use rand::{rngs::StdRng, SeedableRng};
+/// use prepona::prelude::*;
+/// use prepona::gen::RandomGeometricGenerator;
+///
+/// // Given: Generator of 20 points that connects points closer than 0.3.
+/// let generator = RandomGeometricGenerator::init(20, 0.3);
+///
+/// // When: Generating a graph using a seeded random number generator.
+/// let (graph, points) = generator.generate(&mut StdRng::seed_from_u64(7));
+///
+/// // Then:
+/// assert_eq!(graph.vertex_count(), 20);
+/// for (src_id, dst_id, _) in graph.edges() {
+///     let (x1, y1) = points[src_id];
+///     let (x2, y2) = points[dst_id];
+///     assert!(((x1 - x2).powi(2) + (y1 - y2).powi(2)).sqrt() <= 0.3);
+/// }
+/// ```
+pub struct RandomGeometricGenerator {
+    vertex_count: usize,
+    radius: f64,
+}
+
+impl RandomGeometricGenerator {
+    /// # Arguments
+    /// * `vertex_count`: Number of points to place.
+    /// * `radius`: Maximum distance between two points that get connected.
+    ///
+    /// # Returns
+    /// Initialized generator.
+    pub fn init(vertex_count: usize, radius: f64) -> Self {
+        RandomGeometricGenerator {
+            vertex_count,
+            radius,
+        }
+    }
+
+    /// Generates the graph into an existing graph.
+    ///
+    /// # Arguments
+    /// * `graph`: Graph to add the vertices and edges to.
+    /// * `rng`: Random number generator to place the points with. Pass a seeded one to get reproducible graphs.
+    ///
+    /// # Returns
+    /// Id of the added vertices alongside coordinates of their points, in the format of (vertex_id, (x, y)).
+    ///
+    /// # Complexity
+    /// O(`vertex_count`<sup>2</sup>) calls to `add_edge` in the worst case.
+    ///
+    /// # Panics
+    /// If `graph` refuses to add an edge between two of the newly added vertices.
+    pub fn generate_into<E, G, R>(&self, graph: &mut G, rng: &mut R) -> Vec<(usize, (f64, f64))>
+    where
+        E: Edge<f64>,
+        G: Graph<f64, E, UndirectedEdge>,
+        R: Rng,
+    {
+        let points: Vec<(usize, (f64, f64))> = (0..self.vertex_count)
+            .map(|_| (graph.add_vertex(), (rng.gen::<f64>(), rng.gen::<f64>())))
+            .collect();
+
+        for (index, (src_id, (x1, y1))) in points.iter().enumerate() {
+            for (dst_id, (x2, y2)) in points.iter().skip(index + 1) {
+                let distance = ((x1 - x2).powi(2) + (y1 - y2).powi(2)).sqrt();
+
+                if distance <= self.radius {
+                    graph
+                        .add_edge(*src_id, *dst_id, E::init(distance.into()))
+                        .unwrap();
+                }
+            }
+        }
+
+        points
+    }
+
+    /// Generates a new graph.
+    ///
+    /// # Arguments
+    /// `rng`: Random number generator to place the points with. Pass a seeded one to get reproducible graphs.
+    ///
+    /// # Returns
+    /// * Generated graph.
+    /// * Coordinates of the points: Entry at index `i` is the coordinates of the vertex with id `i`, in the format of (x, y).
+    pub fn generate<R: Rng>(
+        &self,
+        rng: &mut R,
+    ) -> (MatGraph<f64, UndirectedEdge>, Vec<(f64, f64)>) {
+        let mut graph = MatGraph::init(Mat::<f64>::init());
+
+        let points = self
+            .generate_into(&mut graph, rng)
+            .into_iter()
+            .map(|(_, point)| point)
+            .collect();
+
+        (graph, points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provide::{Edges, Vertices};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn no_vertices() {
+        let (graph, points) =
+            RandomGeometricGenerator::init(0, 0.5).generate(&mut StdRng::seed_from_u64(0));
+
+        assert_eq!(graph.vertex_count(), 0);
+        assert!(points.is_empty());
+    }
+
+    #[test]
+    fn edges_are_within_radius() {
+        // Given: Generator of 50 points.
+        let generator = RandomGeometricGenerator::init(50, 0.2);
+
+        // When: Generating the graph.
+        let (graph, points) = generator.generate(&mut StdRng::seed_from_u64(42));
+
+        // Then: Every pair of points within radius is connected by an edge weighted by their distance.
+        assert_eq!(points.len(), 50);
+        assert!(points
+            .iter()
+            .all(|(x, y)| (0.0..1.0).contains(x) && (0.0..1.0).contains(y)));
+
+        for (src_id, (x1, y1)) in points.iter().enumerate() {
+            for (dst_id, (x2, y2)) in points.iter().enumerate().skip(src_id + 1) {
+                let distance = ((x1 - x2).powi(2) + (y1 - y2).powi(2)).sqrt();
+                let edges = graph.edges_between(src_id, dst_id).unwrap();
+
+                if distance <= 0.2 {
+                    assert_eq!(edges.len(), 1);
+                    assert_eq!(edges[0].get_weight().unwrap(), distance);
+                } else {
+                    assert!(edges.is_empty());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn larger_radius_adds_edges() {
+        // Given: Generators with increasing radius.
+        let edges_counts: Vec<usize> = [0.1, 0.2, 0.4, 0.8, 1.5]
+            .iter()
+            .map(|radius| {
+                // When: Generating the graphs using the same seed, so points are the same.
+                let (graph, _) = RandomGeometricGenerator::init(40, *radius)
+                    .generate(&mut StdRng::seed_from_u64(3));
+
+                graph.edges_count()
+            })
+            .collect();
+
+        // Then:
+        assert!(edges_counts.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(edges_counts[4], 40 * 39 / 2);
+    }
+}
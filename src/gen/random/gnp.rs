@@ -0,0 +1,173 @@
+use num_traits::One;
+use rand::Rng;
+use std::any::Any;
+
+use crate::graph::{DefaultEdge, Edge, MatGraph, UndirectedEdge};
+use crate::provide::Graph;
+use crate::storage::Mat;
+
+/// Generates Erdős–Rényi random graphs in the G(n, p) model.
+///
+/// Each of the n(n - 1) / 2 possible edges between `n` vertices is added independently with probability `p`.
+/// All edges have unit weight.
+///
+/// # Examples
+/// ```
+/// use rand::{rngs::StdRng, SeedableRng};
+/// use prepona::prelude::*;
+/// use prepona::gen::RandomGnp;
+///
+/// // Given: Generator of graphs with 10 vertices, in which every possible edge is added.
+/// let generator = RandomGnp::init(10, 1.0);
+///
+/// // When: Generating a graph using a seeded random number generator.
+/// let graph = generator.generate(&mut StdRng::seed_from_u64(7));
+///
+/// // Then:
+/// assert_eq!(graph.vertex_count(), 10);
+/// assert_eq!(graph.edges_count(), 45);
+/// ```
+pub struct RandomGnp {
+    vertex_count: usize,
+    probability: f64,
+}
+
+impl RandomGnp {
+    /// # Arguments
+    /// * `vertex_count`: Number of vertices(n).
+    /// * `probability`: Probability of adding each edge(p).
+    ///
+    /// # Returns
+    /// Initialized generator.
+    ///
+    /// # Panics
+    /// If `probability` is not in range [0, 1].
+    pub fn init(vertex_count: usize, probability: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&probability),
+            "Probability must be in range [0, 1]"
+        );
+
+        RandomGnp {
+            vertex_count,
+            probability,
+        }
+    }
+
+    /// Generates the graph into an existing graph.
+    ///
+    /// # Arguments
+    /// * `graph`: Graph to add the vertices and edges to.
+    /// * `rng`: Random number generator to decide the edges with. Pass a seeded one to get reproducible graphs.
+    ///
+    /// # Returns
+    /// Id of the added vertices.
+    ///
+    /// # Complexity
+    /// O(`vertex_count`<sup>2</sup>) random numbers, and calls to `add_edge` for the added edges.
+    ///
+    /// # Panics
+    /// If `graph` refuses to add an edge between two of the newly added vertices.
+    pub fn generate_into<W, E, G, R>(&self, graph: &mut G, rng: &mut R) -> Vec<usize>
+    where
+        W: One + Any,
+        E: Edge<W>,
+        G: Graph<W, E, UndirectedEdge>,
+        R: Rng,
+    {
+        let vertices: Vec<usize> = (0..self.vertex_count).map(|_| graph.add_vertex()).collect();
+
+        for (index, src_id) in vertices.iter().enumerate() {
+            for dst_id in vertices.iter().skip(index + 1) {
+                if rng.gen_bool(self.probability) {
+                    graph
+                        .add_edge(*src_id, *dst_id, E::init(W::one().into()))
+                        .unwrap();
+                }
+            }
+        }
+
+        vertices
+    }
+
+    /// Generates a new graph.
+    ///
+    /// # Arguments
+    /// `rng`: Random number generator to decide the edges with. Pass a seeded one to get reproducible graphs.
+    ///
+    /// # Returns
+    /// Generated graph, in which vertices have ids from 0 to `vertex_count` - 1.
+    pub fn generate<R: Rng>(&self, rng: &mut R) -> MatGraph<usize, UndirectedEdge> {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+
+        self.generate_into::<usize, DefaultEdge<usize>, _, _>(&mut graph, rng);
+
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provide::{Edges, Vertices};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn no_vertices() {
+        let graph = RandomGnp::init(0, 0.5).generate(&mut StdRng::seed_from_u64(0));
+
+        assert_eq!(graph.vertex_count(), 0);
+    }
+
+    #[test]
+    fn extreme_probabilities() {
+        let empty = RandomGnp::init(12, 0.0).generate(&mut StdRng::seed_from_u64(1));
+        let complete = RandomGnp::init(12, 1.0).generate(&mut StdRng::seed_from_u64(1));
+
+        assert_eq!(empty.vertex_count(), 12);
+        assert_eq!(empty.edges_count(), 0);
+        assert_eq!(complete.edges_count(), 12 * 11 / 2);
+    }
+
+    #[test]
+    fn edges_count_is_near_expected() {
+        // Given: Generator of graphs with 200 vertices and edge probability of 0.1.
+        let (n, p) = (200, 0.1);
+        let generator = RandomGnp::init(n, p);
+
+        // When: Generating the graph.
+        let graph = generator.generate(&mut StdRng::seed_from_u64(42));
+
+        // Then: Edges count is binomial, so it is within 4 standard deviations of its mean.
+        let pairs_count = (n * (n - 1) / 2) as f64;
+        let mean = p * pairs_count;
+        let deviation = (pairs_count * p * (1.0 - p)).sqrt();
+        assert!((graph.edges_count() as f64 - mean).abs() <= 4.0 * deviation);
+        assert!(graph
+            .edges()
+            .into_iter()
+            .all(|(_, _, edge)| *edge.get_weight() == 1.into()));
+    }
+
+    #[test]
+    fn same_seed_same_graph() {
+        let generator = RandomGnp::init(30, 0.3);
+
+        let graph1 = generator.generate(&mut StdRng::seed_from_u64(5));
+        let graph2 = generator.generate(&mut StdRng::seed_from_u64(5));
+
+        let mut edges1: Vec<(usize, usize)> =
+            graph1.edges().into_iter().map(|(s, d, _)| (s, d)).collect();
+        let mut edges2: Vec<(usize, usize)> =
+            graph2.edges().into_iter().map(|(s, d, _)| (s, d)).collect();
+        edges1.sort_unstable();
+        edges2.sort_unstable();
+        assert_eq!(edges1, edges2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_probability() {
+        RandomGnp::init(5, 1.5);
+    }
+}
@@ -0,0 +1,9 @@
+mod barabasi_albert;
+mod dag;
+mod geometric;
+mod gnp;
+
+pub use barabasi_albert::BarabasiAlbert;
+pub use dag::RandomDagGenerator;
+pub use geometric::RandomGeometricGenerator;
+pub use gnp::RandomGnp;
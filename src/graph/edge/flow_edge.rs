@@ -75,6 +75,38 @@ impl<W> FlowEdge<W> {
         }
         self.capacity = capacity
     }
+
+    /// # Returns
+    /// Amount of flow that can still be pushed through the edge(capacity - flow).
+    pub fn residual_capacity(&self) -> isize {
+        self.get_capacity() as isize - self.get_flow()
+    }
+
+    /// Adds `delta` to the current flow of the edge. `delta` can be negative to cancel some of the flow.
+    ///
+    /// # Arguments
+    /// `delta`: Amount of flow to push through the edge.
+    ///
+    /// # Panics
+    /// If *current flow* + `delta` > *current capacity*.
+    pub fn add_flow(&mut self, delta: isize) {
+        if delta > self.residual_capacity() {
+            panic!(
+                "Can not push more flow than the residual capacity of the edge: {} > {}",
+                delta,
+                self.residual_capacity()
+            );
+        }
+
+        self.flow += delta;
+    }
+
+    /// # Returns
+    /// * `true`: If flow of the edge has reached its capacity.
+    /// * `false`: Otherwise.
+    pub fn is_saturated(&self) -> bool {
+        self.residual_capacity() == 0
+    }
 }
 
 /// For documentation about each function checkout [`Edge`](crate::graph::Edge) trait.
@@ -193,6 +225,38 @@ mod tests {
         assert_eq!(edge.get_flow(), 4);
     }
 
+    #[test]
+    fn push_flow_up_to_saturation() {
+        let mut edge = FlowEdge::init_with(2.into(), 5, 0);
+        assert_eq!(edge.residual_capacity(), 5);
+        assert!(!edge.is_saturated());
+
+        edge.add_flow(3);
+        assert_eq!(edge.get_flow(), 3);
+        assert_eq!(edge.residual_capacity(), 2);
+        assert!(!edge.is_saturated());
+
+        edge.add_flow(2);
+        assert_eq!(edge.get_flow(), 5);
+        assert_eq!(edge.residual_capacity(), 0);
+        assert!(edge.is_saturated());
+
+        edge.add_flow(-4);
+        assert_eq!(edge.get_flow(), 1);
+        assert_eq!(edge.residual_capacity(), 4);
+        assert!(!edge.is_saturated());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Can not push more flow than the residual capacity of the edge: 3 > 2"
+    )]
+    fn over_push_flow() {
+        let mut edge = FlowEdge::init_with(2.into(), 5, 3);
+
+        edge.add_flow(3);
+    }
+
     #[test]
     fn from_triplet() {
         let edge: FlowEdge<usize> = 2.into();
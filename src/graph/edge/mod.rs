@@ -1,10 +1,12 @@
 mod default_edge;
 mod flow_edge;
+mod undirected_flow_edge;
 
 use magnitude::Magnitude;
 
 pub use default_edge::DefaultEdge;
 pub use flow_edge::FlowEdge;
+pub use undirected_flow_edge::UndirectedFlowEdge;
 
 /// Defines functionalities to determine wether edges in the graph are directed or not.
 pub trait EdgeDir {
@@ -0,0 +1,234 @@
+use magnitude::Magnitude;
+use quickcheck::Arbitrary;
+
+use crate::graph::edge::Edge;
+
+/// Represent an undirected flow edge with weight, flow and capacity.
+///
+/// Flow can go through the edge in either direction, up to the capacity of the edge.
+/// So an undirected edge with capacity of c behaves as two opposing directed edges that share the capacity.
+///
+/// Because the same edge is returned when querying edges from either of its end points,
+/// flow is stored relative to the direction from the end point with smaller id to the end point with larger id.
+/// Therefore functions that deal with flow take the direction that the flow is being queried for as (`src_id`, `dst_id`).
+#[derive(Debug, Copy, Clone)]
+pub struct UndirectedFlowEdge<W> {
+    id: usize,
+    weight: Magnitude<W>,
+    capacity: usize,
+    flow: isize,
+}
+
+impl<W> UndirectedFlowEdge<W> {
+    /// # Arguments
+    /// * `weight`: Weight of the edge.
+    /// * `capacity`: Capacity of the edge.
+    ///
+    /// # Returns
+    /// Initialized edge with specified `weight` and `capacity` and flow of 0.
+    pub fn init_with(weight: Magnitude<W>, capacity: usize) -> Self {
+        UndirectedFlowEdge {
+            id: 0,
+            weight,
+            capacity,
+            flow: 0,
+        }
+    }
+
+    /// # Arguments
+    /// * `src_id`: Id of the vertex that flow is going out of.
+    /// * `dst_id`: Id of the vertex that flow is going into.
+    ///
+    /// # Returns
+    /// Flow of the edge from `src_id` to `dst_id`. Negative flow means flow is going from `dst_id` to `src_id`.
+    pub fn get_flow(&self, src_id: usize, dst_id: usize) -> isize {
+        if src_id <= dst_id {
+            self.flow
+        } else {
+            -self.flow
+        }
+    }
+
+    /// # Returns
+    /// Capacity of the edge.
+    pub fn get_capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// # Arguments
+    /// * `src_id`: Id of the vertex that flow is going out of.
+    /// * `dst_id`: Id of the vertex that flow is going into.
+    /// * `flow`: New flow of the edge from `src_id` to `dst_id`.
+    ///
+    /// # Panics
+    /// If |`flow`| > *current capacity*.
+    pub fn set_flow(&mut self, src_id: usize, dst_id: usize, flow: isize) {
+        if flow.abs() > self.get_capacity() as isize {
+            panic!("Flow of the edge can not be greater than the current capacity of the edge in either direction: |{}| > {}", flow, self.get_capacity());
+        }
+
+        self.flow = if src_id <= dst_id { flow } else { -flow };
+    }
+
+    /// # Arguments
+    /// `capacity`: New capacity of the edge.
+    ///
+    /// # Panics
+    /// If `capacity` < |*current flow*|
+    pub fn set_capacity(&mut self, capacity: usize) {
+        if (capacity as isize) < self.flow.abs() {
+            panic!("Capacity of the edge can not be smaller than the current flow of the edge: {} < |{}|", capacity, self.flow);
+        }
+        self.capacity = capacity
+    }
+
+    /// # Arguments
+    /// * `src_id`: Id of the vertex that flow is going out of.
+    /// * `dst_id`: Id of the vertex that flow is going into.
+    ///
+    /// # Returns
+    /// Amount of flow that can still be pushed from `src_id` to `dst_id`.
+    /// This includes cancelling the flow that is currently going from `dst_id` to `src_id`.
+    pub fn residual_capacity(&self, src_id: usize, dst_id: usize) -> isize {
+        self.get_capacity() as isize - self.get_flow(src_id, dst_id)
+    }
+
+    /// Pushes `delta` units of flow from `src_id` to `dst_id`.
+    ///
+    /// # Arguments
+    /// * `src_id`: Id of the vertex that flow is going out of.
+    /// * `dst_id`: Id of the vertex that flow is going into.
+    /// * `delta`: Amount of flow to push through the edge.
+    ///
+    /// # Panics
+    /// If flow from `src_id` to `dst_id` after pushing is bigger than capacity of the edge in either direction.
+    pub fn add_flow(&mut self, src_id: usize, dst_id: usize, delta: isize) {
+        self.set_flow(src_id, dst_id, self.get_flow(src_id, dst_id) + delta);
+    }
+
+    /// # Returns
+    /// * `true`: If flow in either direction has reached the capacity of the edge.
+    /// * `false`: Otherwise.
+    pub fn is_saturated(&self) -> bool {
+        self.flow.abs() == self.get_capacity() as isize
+    }
+}
+
+/// For documentation about each function checkout [`Edge`](crate::graph::Edge) trait.
+impl<W> Edge<W> for UndirectedFlowEdge<W> {
+    fn init(weight: Magnitude<W>) -> Self {
+        UndirectedFlowEdge::init_with(weight, 0)
+    }
+
+    fn get_weight(&self) -> &Magnitude<W> {
+        &self.weight
+    }
+
+    fn set_weight(&mut self, weight: Magnitude<W>) {
+        self.weight = weight
+    }
+
+    fn set_id(&mut self, id: usize) {
+        self.id = id
+    }
+
+    fn get_id(&self) -> usize {
+        self.id
+    }
+}
+
+use std::any::Any;
+use std::convert::From;
+impl<W: Any> From<W> for UndirectedFlowEdge<W> {
+    /// Constructs an `UndirectedFlowEdge` with specified `weight` and flow and capacity of 0.
+    fn from(weight: W) -> Self {
+        UndirectedFlowEdge::init(weight.into())
+    }
+}
+
+impl<W: Any> From<(W, usize)> for UndirectedFlowEdge<W> {
+    /// Constructs an `UndirectedFlowEdge` with specified `weight` and `capacity` and flow of 0.
+    fn from((weight, capacity): (W, usize)) -> Self {
+        UndirectedFlowEdge::init_with(weight.into(), capacity)
+    }
+}
+
+impl<W: PartialEq> PartialEq for UndirectedFlowEdge<W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.weight == other.weight
+            && self.id == other.id
+            && self.flow == other.flow
+            && self.capacity == other.capacity
+    }
+}
+
+impl<W: Arbitrary> Arbitrary for UndirectedFlowEdge<W> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        UndirectedFlowEdge::init(W::arbitrary(g).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn init() {
+        let edge = UndirectedFlowEdge::init(2.into());
+
+        assert_eq!(edge.get_weight(), &2.into());
+        assert_eq!(edge.get_capacity(), 0);
+        assert_eq!(edge.get_flow(0, 1), 0);
+        assert_eq!(edge.get_flow(1, 0), 0);
+    }
+
+    #[test]
+    fn from_pair() {
+        let edge: UndirectedFlowEdge<usize> = (2, 4).into();
+
+        assert_eq!(edge.get_weight(), &2.into());
+        assert_eq!(edge.get_capacity(), 4);
+        assert_eq!(edge.get_flow(0, 1), 0);
+    }
+
+    #[test]
+    fn flow_in_both_directions() {
+        let mut edge = UndirectedFlowEdge::init_with(2.into(), 3);
+
+        // Push flow from 1 to 0.
+        edge.add_flow(1, 0, 2);
+        assert_eq!(edge.get_flow(1, 0), 2);
+        assert_eq!(edge.get_flow(0, 1), -2);
+        assert_eq!(edge.residual_capacity(1, 0), 1);
+        assert_eq!(edge.residual_capacity(0, 1), 5);
+
+        // Cancel the flow and push it in the opposite direction.
+        edge.add_flow(0, 1, 5);
+        assert_eq!(edge.get_flow(0, 1), 3);
+        assert_eq!(edge.residual_capacity(0, 1), 0);
+        assert_eq!(edge.residual_capacity(1, 0), 6);
+        assert!(edge.is_saturated());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Flow of the edge can not be greater than the current capacity of the edge in either direction: |4| > 3"
+    )]
+    fn over_push_flow() {
+        let mut edge = UndirectedFlowEdge::init_with(2.into(), 3);
+
+        edge.add_flow(0, 1, 3);
+        edge.add_flow(1, 0, 7);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Capacity of the edge can not be smaller than the current flow of the edge: 1 < |-2|"
+    )]
+    fn set_capacity_smaller_than_flow() {
+        let mut edge = UndirectedFlowEdge::init_with(2.into(), 3);
+        edge.set_flow(1, 0, 2);
+
+        edge.set_capacity(1);
+    }
+}
@@ -1,6 +1,7 @@
 mod edge;
 mod error;
 mod structs;
+mod transform;
 
 /// Subgraphs are views of graphs.
 ///
@@ -17,6 +18,10 @@ mod structs;
 /// So it just forwards every call of `AsSubgraph` functions to the inner `Subgraph`.
 pub mod subgraph;
 
-pub use edge::{DefaultEdge, DirectedEdge, Edge, EdgeDir, FlowEdge, UndirectedEdge};
+pub use edge::{
+    DefaultEdge, DirectedEdge, Edge, EdgeDir, FlowEdge, UndirectedEdge, UndirectedFlowEdge,
+};
 pub use error::{Error, ErrorKind};
 pub use structs::{FlowListGraph, FlowMatGraph, ListGraph, MatGraph, SimpleGraph};
+pub use transform::{filter_edges, filter_vertices, map_weights};
+pub(crate) use transform::with_vertices_of;
@@ -1,3 +1,4 @@
+use std::collections::{HashMap, HashSet};
 use std::marker::PhantomData;
 use std::{any::Any, fmt::Debug};
 
@@ -6,9 +7,9 @@ use provide::{Edges, Graph, Neighbors, Vertices};
 use quickcheck::Arbitrary;
 
 use crate::provide;
-use crate::storage::{FlowList, FlowMat, GraphStorage, List, Mat};
+use crate::storage::{FlowList, FlowMat, GraphStorage, List, Mat, VertexIdPolicy};
 use crate::{
-    graph::{error::Error, DefaultEdge, Edge, EdgeDir, FlowEdge},
+    graph::{error::Error, DefaultEdge, DirectedEdge, Edge, EdgeDir, FlowEdge},
     storage::AdjMatrix,
 };
 
@@ -30,6 +31,8 @@ pub type FlowListGraph<W, Dir> = SimpleGraph<W, DefaultEdge<W>, Dir, FlowList<W,
 /// `SimpleGraph` forwards most of its function calls to its underlying storage. So the complexities of its functions are dependent to what storage you use to initialize the graph.
 /// Therefore `SimpleGraph` only documents complexity of functions that it adds some additional logic to. For `SimpleGraph`, only `add_edge` function adds additional logic.
 ///
+/// `SimpleGraph` also keeps a set of adjacent vertex pairs alongside the storage, so `is_adjacent` is answered in O(1) regardless of the storage.
+///
 /// ## Generic Parameters
 /// * `W`: **W**eight type associated with edges.
 /// * `E`: **E**dge type that graph uses.
@@ -38,6 +41,10 @@ pub type FlowListGraph<W, Dir> = SimpleGraph<W, DefaultEdge<W>, Dir, FlowList<W,
 pub struct SimpleGraph<W, E: Edge<W>, Dir: EdgeDir, S: GraphStorage<W, E, Dir>> {
     storage: S,
 
+    // Pairs of (src_id, dst_id) that have an edge between them.
+    // For undirected graphs each pair is stored once in the format of (smaller_id, larger_id).
+    adjacency: HashSet<(usize, usize)>,
+
     phantom_w: PhantomData<W>,
     phantom_e: PhantomData<E>,
     phantom_dir: PhantomData<Dir>,
@@ -70,14 +77,261 @@ impl<W: Any, E: Edge<W>, Dir: EdgeDir, S: GraphStorage<W, E, Dir>> SimpleGraph<W
     /// let list_graph = ListGraph::init(DiList::<u32>::init());
     /// ```
     pub fn init(storage: S) -> Self {
+        let adjacency = storage
+            .edges()
+            .into_iter()
+            .map(|(src_id, dst_id, _)| adjacency_key::<Dir>(src_id, dst_id))
+            .collect();
+
         SimpleGraph {
             storage,
+            adjacency,
 
             phantom_e: PhantomData,
             phantom_w: PhantomData,
             phantom_dir: PhantomData,
         }
     }
+
+    /// # Returns
+    /// Policy that graph uses for ids of the removed vertices.
+    pub fn vertex_id_policy(&self) -> VertexIdPolicy {
+        self.storage.vertex_id_policy()
+    }
+
+    /// Changes the policy that graph uses for ids of the removed vertices.
+    ///
+    /// # Arguments
+    /// `policy`: New policy:
+    /// * [`Reuse`](crate::storage::VertexIdPolicy::Reuse): Ids of the removed vertices get reused by next calls to `add_vertex`(default).
+    /// * [`Tombstone`](crate::storage::VertexIdPolicy::Tombstone): Ids of the removed vertices are never reused.
+    ///
+    /// Storages that do not support choosing a policy ignore the change, in which case `vertex_id_policy` keeps returning `Reuse`.
+    ///
+    /// # Examples
+    /// ```
+    /// use prepona::prelude::*;
+    /// use prepona::storage::{Mat, VertexIdPolicy};
+    /// use prepona::graph::MatGraph;
+    ///
+    /// let mut graph = MatGraph::init(Mat::<usize>::init());
+    /// graph.set_vertex_id_policy(VertexIdPolicy::Tombstone);
+    /// let a = graph.add_vertex();
+    /// graph.remove_vertex(a).unwrap();
+    ///
+    /// // Id of a is not reused.
+    /// let b = graph.add_vertex();
+    /// assert_ne!(a, b);
+    /// ```
+    pub fn set_vertex_id_policy(&mut self, policy: VertexIdPolicy) {
+        self.storage.set_vertex_id_policy(policy)
+    }
+
+    /// Requires the `fixedbitset` feature.
+    ///
+    /// # Arguments
+    /// `vertex_id`: Id of the vertex.
+    ///
+    /// # Returns
+    /// Set of neighbors of the vertex, in which bit at index i is set if vertex with id: i is a neighbor. \
+    /// Length of the set is one more than the largest vertex id so sets of different vertices can be intersected directly.
+    /// If vertex does not exist, no bit is set.
+    ///
+    /// # Examples
+    /// ```
+    /// use prepona::prelude::*;
+    /// use prepona::storage::Mat;
+    /// use prepona::graph::MatGraph;
+    ///
+    /// let mut graph = MatGraph::init(Mat::<usize>::init());
+    /// let a = graph.add_vertex();
+    /// let b = graph.add_vertex();
+    /// let c = graph.add_vertex();
+    /// graph.add_edge(a, b, 1.into());
+    ///
+    /// let bitset = graph.neighbor_bitset(a);
+    /// assert!(bitset.contains(b));
+    /// assert!(!bitset.contains(c));
+    /// ```
+    #[cfg(feature = "fixedbitset")]
+    pub fn neighbor_bitset(&self, vertex_id: usize) -> fixedbitset::FixedBitSet {
+        let len = self
+            .vertices()
+            .into_iter()
+            .max()
+            .map_or(0, |max_id| max_id + 1);
+
+        let mut bitset = fixedbitset::FixedBitSet::with_capacity(len);
+        if let Ok(neighbors) = self.neighbors(vertex_id) {
+            bitset.extend(neighbors);
+        }
+
+        bitset
+    }
+}
+
+impl<W: Any + Clone, E: Edge<W>, Dir: EdgeDir, S: GraphStorage<W, E, Dir>>
+    SimpleGraph<W, E, Dir, S>
+{
+    /// Adds `count` vertices to the graph.
+    /// Storage gets the chance to allocate memory for all of the vertices at once.
+    ///
+    /// # Arguments
+    /// `count`: Number of vertices to add.
+    ///
+    /// # Returns
+    /// Ids of the newly added vertices.
+    ///
+    /// # Examples
+    /// ```
+    /// use prepona::prelude::*;
+    /// use prepona::storage::Mat;
+    /// use prepona::graph::MatGraph;
+    ///
+    /// let mut graph = MatGraph::init(Mat::<usize>::init());
+    /// let vertices = graph.add_vertices(3);
+    ///
+    /// assert_eq!(vertices.len(), 3);
+    /// assert_eq!(graph.vertex_count(), 3);
+    /// ```
+    pub fn add_vertices(&mut self, count: usize) -> Vec<usize> {
+        self.storage.add_vertices(count)
+    }
+
+    /// Adds multiple edges to the graph.
+    ///
+    /// # Arguments
+    /// `edges`: Edges to add in the format of (`src_id`, `dst_id`, `weight`).
+    ///
+    /// # Returns
+    /// * `Err`: Error of the first edge that could not be added. Edges before it remain in the graph.
+    ///   Checkout `add_edge` for possible errors.
+    /// * `Ok`: Ids of the newly added edges, in the same order as `edges`.
+    ///
+    /// # Examples
+    /// ```
+    /// use prepona::prelude::*;
+    /// use prepona::storage::Mat;
+    /// use prepona::graph::MatGraph;
+    ///
+    /// let mut graph = MatGraph::init(Mat::<usize>::init());
+    /// let vertices = graph.add_vertices(3);
+    /// let edges = graph
+    ///     .add_edges(&[(vertices[0], vertices[1], 1), (vertices[1], vertices[2], 2)])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(edges.len(), 2);
+    /// assert_eq!(graph.edges_count(), 2);
+    /// ```
+    pub fn add_edges(&mut self, edges: &[(usize, usize, W)]) -> Result<Vec<usize>> {
+        edges
+            .iter()
+            .map(|(src_id, dst_id, weight)| {
+                self.add_edge(*src_id, *dst_id, E::init(weight.clone().into()))
+            })
+            .collect()
+    }
+
+    /// Adds multiple edges to the graph, merging the parallel ones into a single edge.
+    /// Useful for building a graph from noisy data that contains duplicate edges, which `add_edges` rejects.
+    ///
+    /// # Arguments
+    /// * `edges`: Edges to add in the format of (`src_id`, `dst_id`, `weight`). Edges with the same source and destination are parallel.
+    ///   For undirected graphs (`src_id`, `dst_id`) and (`dst_id`, `src_id`) are also parallel.
+    /// * `reducer`: Receives the merged weight so far and weight of the next parallel edge, and returns the new merged weight.
+    ///   For example `|merged, weight| merged + weight` sums the weights and `|merged, _| merged` keeps the first one.
+    ///
+    /// # Returns
+    /// * `Err`: Error of the first merged edge that could not be added. Edges before it remain in the graph.
+    ///   Checkout `add_edge` for possible errors.
+    /// * `Ok`: Ids of the newly added edges, in the order that their first parallel edge appears in `edges`.
+    ///
+    /// # Complexity
+    /// O(|`edges`|) plus the cost of calling `add_edge` for each merged edge.
+    ///
+    /// # Examples
+    /// ```
+    /// use prepona::prelude::*;
+    /// use prepona::storage::DiMat;
+    /// use prepona::graph::MatGraph;
+    ///
+    /// let mut graph = MatGraph::init(DiMat::<usize>::init());
+    /// let vertices = graph.add_vertices(2);
+    /// let (a, b) = (vertices[0], vertices[1]);
+    ///
+    /// // Keep the cheapest of the parallel edges from a to b.
+    /// let edges = graph
+    ///     .add_edges_merged(&[(a, b, 3), (b, a, 4), (a, b, 1)], |merged, weight| merged.min(weight))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(edges.len(), 2);
+    /// assert_eq!(graph.edges_between(a, b).unwrap()[0].get_weight(), &1.into());
+    /// assert_eq!(graph.edges_between(b, a).unwrap()[0].get_weight(), &4.into());
+    /// ```
+    pub fn add_edges_merged<F>(
+        &mut self,
+        edges: &[(usize, usize, W)],
+        reducer: F,
+    ) -> Result<Vec<usize>>
+    where
+        F: Fn(W, W) -> W,
+    {
+        // Merged weight of each edge is wrapped in an option, so it can be taken out and passed to the reducer.
+        let mut index_of: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut merged_edges: Vec<(usize, usize, Option<W>)> = vec![];
+        for (src_id, dst_id, weight) in edges {
+            let key = adjacency_key::<Dir>(*src_id, *dst_id);
+            if let Some(index) = index_of.get(&key) {
+                let merged_weight = &mut merged_edges[*index].2;
+                *merged_weight = Some(reducer(merged_weight.take().unwrap(), weight.clone()));
+            } else {
+                index_of.insert(key, merged_edges.len());
+                merged_edges.push((*src_id, *dst_id, Some(weight.clone())));
+            }
+        }
+
+        merged_edges
+            .into_iter()
+            .map(|(src_id, dst_id, weight)| {
+                self.add_edge(src_id, dst_id, E::init(weight.unwrap().into()))
+            })
+            .collect()
+    }
+}
+
+impl<W: Any, E: Edge<W>, S: GraphStorage<W, E, DirectedEdge>> SimpleGraph<W, E, DirectedEdge, S> {
+    /// Reverses direction of all edges in place, without allocating a transposed copy of the graph.
+    /// Each edge keeps its id, weight and the other data it carries(like flow of a [`FlowEdge`](crate::graph::FlowEdge)).
+    ///
+    /// # Complexity
+    /// Depends on `reverse_edges` of the storage, plus O(|E|) for updating adjacency of vertices.
+    ///
+    /// # Examples
+    /// ```
+    /// use prepona::prelude::*;
+    /// use prepona::storage::DiMat;
+    /// use prepona::graph::MatGraph;
+    ///
+    /// let mut graph = MatGraph::init(DiMat::<usize>::init());
+    /// let a = graph.add_vertex();
+    /// let b = graph.add_vertex();
+    /// let ab = graph.add_edge(a, b, 1.into()).unwrap();
+    ///
+    /// graph.reverse_in_place();
+    ///
+    /// assert!(graph.is_adjacent(b, a));
+    /// assert!(!graph.is_adjacent(a, b));
+    /// assert_eq!(graph.edge_between(b, a, ab).unwrap().get_weight(), &1.into());
+    /// ```
+    pub fn reverse_in_place(&mut self) {
+        self.storage.reverse_edges();
+
+        self.adjacency = self
+            .adjacency
+            .drain()
+            .map(|(src_id, dst_id)| (dst_id, src_id))
+            .collect();
+    }
 }
 
 /// For documentation about each function checkout [`Neighbors`](crate::provide::Neighbors) trait and the storage you use.
@@ -87,6 +341,13 @@ impl<W, E: Edge<W>, Dir: EdgeDir, S: GraphStorage<W, E, Dir>> Neighbors
     fn neighbors(&self, src_id: usize) -> Result<Vec<usize>> {
         self.storage.neighbors(src_id)
     }
+
+    /// # Complexity
+    /// O(1)
+    fn is_adjacent(&self, src_id: usize, dst_id: usize) -> bool {
+        self.adjacency
+            .contains(&adjacency_key::<Dir>(src_id, dst_id))
+    }
 }
 
 /// For documentation about each function checkout [`Vertices`](crate::provide::Vertices) trait and the storage you use.
@@ -156,7 +417,12 @@ impl<W: Any, E: Edge<W>, Dir: EdgeDir, S: GraphStorage<W, E, Dir>> Graph<W, E, D
     }
 
     fn remove_vertex(&mut self, vertex_id: usize) -> Result<()> {
-        self.storage.remove_vertex(vertex_id)
+        self.storage.remove_vertex(vertex_id)?;
+
+        self.adjacency
+            .retain(|(src_id, dst_id)| *src_id != vertex_id && *dst_id != vertex_id);
+
+        Ok(())
     }
 
     /// Adds an edge to the graph.
@@ -178,7 +444,11 @@ impl<W: Any, E: Edge<W>, Dir: EdgeDir, S: GraphStorage<W, E, Dir>> Graph<W, E, D
         } else if src_id == dst_id {
             Err(Error::new_l(src_id))?
         } else {
-            self.storage.add_edge(src_id, dst_id, edge)
+            let edge_id = self.storage.add_edge(src_id, dst_id, edge)?;
+
+            self.adjacency.insert(adjacency_key::<Dir>(src_id, dst_id));
+
+            Ok(edge_id)
         }
     }
 
@@ -187,7 +457,12 @@ impl<W: Any, E: Edge<W>, Dir: EdgeDir, S: GraphStorage<W, E, Dir>> Graph<W, E, D
     }
 
     fn remove_edge(&mut self, src_id: usize, dst_id: usize, edge_id: usize) -> Result<E> {
-        self.storage.remove_edge(src_id, dst_id, edge_id)
+        let edge = self.storage.remove_edge(src_id, dst_id, edge_id)?;
+
+        // Graph does not allow multiple edges, so there is no other edge between the two vertices.
+        self.adjacency.remove(&adjacency_key::<Dir>(src_id, dst_id));
+
+        Ok(edge)
     }
 
     fn filter(
@@ -201,6 +476,15 @@ impl<W: Any, E: Edge<W>, Dir: EdgeDir, S: GraphStorage<W, E, Dir>> Graph<W, E, D
     }
 }
 
+// Returns the key of the pair of vertices in the adjacency set of the graph.
+fn adjacency_key<Dir: EdgeDir>(src_id: usize, dst_id: usize) -> (usize, usize) {
+    if Dir::is_directed() || src_id <= dst_id {
+        (src_id, dst_id)
+    } else {
+        (dst_id, src_id)
+    }
+}
+
 impl<W: Clone + Any, E: Edge<W> + Clone, Dir: EdgeDir, S: GraphStorage<W, E, Dir> + Clone> Clone
     for SimpleGraph<W, E, Dir, S>
 {
@@ -281,7 +565,10 @@ impl<
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::graph::{DirectedEdge, UndirectedEdge};
     use crate::provide::*;
+    use crate::storage::{DiFlowMat, DiList, DiMap, DiMat, Map};
+    use std::time::Instant;
 
     #[test]
     fn add_loop() {
@@ -292,6 +579,427 @@ mod tests {
         assert!(graph.add_edge(0, 0, 1.into()).is_err());
     }
 
+    fn build_one_by_one<S: GraphStorage<usize, DefaultEdge<usize>, Dir>, Dir: EdgeDir>(
+        graph: &mut SimpleGraph<usize, DefaultEdge<usize>, Dir, S>,
+        vertex_count: usize,
+        edges: &[(usize, usize, usize)],
+    ) {
+        for _ in 0..vertex_count {
+            graph.add_vertex();
+        }
+        for (src_id, dst_id, weight) in edges {
+            graph.add_edge(*src_id, *dst_id, (*weight).into()).unwrap();
+        }
+    }
+
+    fn assert_same_graph<S: GraphStorage<usize, DefaultEdge<usize>, Dir>, Dir: EdgeDir>(
+        graph1: &SimpleGraph<usize, DefaultEdge<usize>, Dir, S>,
+        graph2: &SimpleGraph<usize, DefaultEdge<usize>, Dir, S>,
+    ) {
+        let extract_edges = |graph: &SimpleGraph<usize, DefaultEdge<usize>, Dir, S>| {
+            let mut edges = graph
+                .edges()
+                .into_iter()
+                .map(|(src_id, dst_id, edge)| {
+                    (src_id, dst_id, edge.get_id(), edge.get_weight().unwrap())
+                })
+                .collect::<Vec<(usize, usize, usize, usize)>>();
+            edges.sort_unstable();
+            edges
+        };
+
+        let mut vertices1 = graph1.vertices();
+        let mut vertices2 = graph2.vertices();
+        vertices1.sort_unstable();
+        vertices2.sort_unstable();
+
+        assert_eq!(vertices1, vertices2);
+        assert_eq!(extract_edges(graph1), extract_edges(graph2));
+    }
+
+    #[test]
+    fn batch_equals_one_by_one() {
+        // Given: Edges of graph
+        //
+        //      a --1-- b --2-- c
+        //      |     /         |
+        //      3   4           5
+        //      | /             |
+        //      d -------6----- e
+        //
+        let edges = [
+            (0, 1, 1),
+            (1, 2, 2),
+            (0, 3, 3),
+            (1, 3, 4),
+            (2, 4, 5),
+            (3, 4, 6),
+        ];
+
+        // When: Building the graph with and without batch functions.
+        let mut mat_graph = MatGraph::init(Mat::<usize>::init());
+        build_one_by_one(&mut mat_graph, 5, &edges);
+        let mut batch_mat_graph = MatGraph::init(Mat::<usize>::init());
+        assert_eq!(batch_mat_graph.add_vertices(5), vec![0, 1, 2, 3, 4]);
+        assert_eq!(batch_mat_graph.add_edges(&edges).unwrap().len(), 6);
+
+        let mut di_mat_graph = MatGraph::init(DiMat::<usize>::init());
+        build_one_by_one(&mut di_mat_graph, 5, &edges);
+        let mut batch_di_mat_graph = MatGraph::init(DiMat::<usize>::init());
+        batch_di_mat_graph.add_vertices(5);
+        batch_di_mat_graph.add_edges(&edges).unwrap();
+
+        let mut list_graph = ListGraph::init(List::<usize>::init());
+        build_one_by_one(&mut list_graph, 5, &edges);
+        let mut batch_list_graph = ListGraph::init(List::<usize>::init());
+        batch_list_graph.add_vertices(5);
+        batch_list_graph.add_edges(&edges).unwrap();
+
+        // Then:
+        assert_same_graph(&mat_graph, &batch_mat_graph);
+        assert_same_graph(&di_mat_graph, &batch_di_mat_graph);
+        assert_same_graph(&list_graph, &batch_list_graph);
+    }
+
+    // Benchmark of building a graph with batch functions.
+    // Run with `cargo test --release -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn benchmark_batch_building() {
+        // Given: Edges of a path going through 2000 vertices.
+        let vertex_count = 2000;
+        let edges: Vec<(usize, usize, usize)> = (1..vertex_count)
+            .map(|dst_id| (dst_id - 1, dst_id, dst_id))
+            .collect();
+
+        // When: Timing both approaches.
+        let start = Instant::now();
+        let mut mat_graph = MatGraph::init(DiMat::<usize>::init());
+        build_one_by_one(&mut mat_graph, vertex_count, &edges);
+        let one_by_one_elapsed = start.elapsed();
+
+        let start = Instant::now();
+        let mut batch_mat_graph = MatGraph::init(DiMat::<usize>::init());
+        batch_mat_graph.add_vertices(vertex_count);
+        batch_mat_graph.add_edges(&edges).unwrap();
+        let batch_elapsed = start.elapsed();
+
+        println!(
+            "batch: {:?}, one by one: {:?}",
+            batch_elapsed, one_by_one_elapsed
+        );
+
+        // Then: Both build the same graph.
+        assert_same_graph(&mat_graph, &batch_mat_graph);
+    }
+
+    #[test]
+    fn batch_add_invalid_edge() {
+        // Given: Graph
+        //
+        //      a   b   c
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let vertices = graph.add_vertices(3);
+
+        // When: Adding a batch containing a loop.
+        let result = graph.add_edges(&[
+            (vertices[0], vertices[1], 1),
+            (vertices[2], vertices[2], 1),
+            (vertices[1], vertices[2], 1),
+        ]);
+
+        // Then: Edges before the loop are added.
+        assert!(result.is_err());
+        assert_eq!(graph.edges_count(), 1);
+    }
+
+    #[test]
+    fn batch_add_merged_parallel_edges() {
+        // Given: Edge list with duplicates
+        //
+        //      a --1--> b, a --2--> b, a --4--> b, b --3--> a, b --5--> c
+        //
+        let mut di_graph = MatGraph::init(DiMat::<usize>::init());
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let vertices = di_graph.add_vertices(3);
+        graph.add_vertices(3);
+        let (a, b, c) = (vertices[0], vertices[1], vertices[2]);
+        let edges = [(a, b, 1), (a, b, 2), (b, a, 3), (a, b, 4), (b, c, 5)];
+
+        // When: Adding the edges while summing weights of the parallel ones.
+        let di_edge_ids = di_graph
+            .add_edges_merged(&edges, |merged, weight| merged + weight)
+            .unwrap();
+        let edge_ids = graph
+            .add_edges_merged(&edges, |merged, weight| merged + weight)
+            .unwrap();
+
+        // Then: Each ordered pair gets a single edge with the summed weight.
+        assert_eq!(di_edge_ids.len(), 3);
+        assert_eq!(di_graph.edges_count(), 3);
+        assert_eq!(di_graph.edges_between(a, b).unwrap().len(), 1);
+        assert_eq!(
+            di_graph.edges_between(a, b).unwrap()[0].get_weight(),
+            &7.into()
+        );
+        assert_eq!(
+            di_graph.edges_between(b, a).unwrap()[0].get_weight(),
+            &3.into()
+        );
+        assert_eq!(
+            di_graph.edges_between(b, c).unwrap()[0].get_weight(),
+            &5.into()
+        );
+
+        // And: In the undirected graph both directions are parallel.
+        assert_eq!(edge_ids.len(), 2);
+        assert_eq!(graph.edges_count(), 2);
+        assert_eq!(
+            graph.edges_between(b, a).unwrap()[0].get_weight(),
+            &10.into()
+        );
+    }
+
+    #[test]
+    fn batch_add_merged_keeps_reducer_order() {
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let vertices = graph.add_vertices(2);
+        let edges = [(vertices[0], vertices[1], 5), (vertices[0], vertices[1], 2)];
+
+        // When: Keeping the first and the last of the parallel edges.
+        let first = graph.add_edges_merged(&edges, |merged, _| merged).unwrap();
+        let mut last_graph = MatGraph::init(DiMat::<usize>::init());
+        last_graph.add_vertices(2);
+        last_graph
+            .add_edges_merged(&edges, |_, weight| weight)
+            .unwrap();
+
+        // Then:
+        assert_eq!(graph.edge(first[0]).unwrap().get_weight(), &5.into());
+        assert_eq!(
+            last_graph.edges_between(vertices[0], vertices[1]).unwrap()[0].get_weight(),
+            &2.into()
+        );
+    }
+
+    fn assert_add_vertex_after_removal<
+        S: GraphStorage<usize, DefaultEdge<usize>, UndirectedEdge>,
+    >(
+        mut graph: SimpleGraph<usize, DefaultEdge<usize>, UndirectedEdge, S>,
+        policy: VertexIdPolicy,
+    ) {
+        // Given: Graph
+        //
+        //      a --- b --- c
+        //
+        graph.set_vertex_id_policy(policy);
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+
+        // When: Removing b and adding a new vertex.
+        graph.remove_vertex(b).unwrap();
+        let d = graph.add_vertex();
+
+        // Then:
+        assert_eq!(graph.vertex_id_policy(), policy);
+        assert_eq!(graph.vertex_count(), 3);
+        assert!(graph.neighbors(d).unwrap().is_empty());
+        assert!(!graph.has_any_edge(a, d).unwrap());
+        match policy {
+            VertexIdPolicy::Reuse => assert_eq!(d, b),
+            VertexIdPolicy::Tombstone => {
+                assert_eq!(d, 3);
+                assert!(!graph.contains_vertex(b));
+                assert!(graph.remove_vertex(b).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn reuse_vertex_id_policy() {
+        assert_add_vertex_after_removal(MatGraph::init(Mat::init()), VertexIdPolicy::Reuse);
+        assert_add_vertex_after_removal(ListGraph::init(List::init()), VertexIdPolicy::Reuse);
+        assert_add_vertex_after_removal(SimpleGraph::init(Map::init()), VertexIdPolicy::Reuse);
+    }
+
+    #[test]
+    fn tombstone_vertex_id_policy() {
+        assert_add_vertex_after_removal(MatGraph::init(Mat::init()), VertexIdPolicy::Tombstone);
+        assert_add_vertex_after_removal(ListGraph::init(List::init()), VertexIdPolicy::Tombstone);
+        assert_add_vertex_after_removal(SimpleGraph::init(Map::init()), VertexIdPolicy::Tombstone);
+    }
+
+    #[test]
+    fn tombstones_become_reusable() {
+        // Given: Graph with a vertex removed under tombstone policy.
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        graph.set_vertex_id_policy(VertexIdPolicy::Tombstone);
+        let a = graph.add_vertex();
+        graph.add_vertex();
+        graph.remove_vertex(a).unwrap();
+
+        // When: Switching to reuse policy.
+        graph.set_vertex_id_policy(VertexIdPolicy::Reuse);
+
+        // Then: Id of a gets reused.
+        assert_eq!(graph.add_vertex(), a);
+    }
+
+    fn assert_is_adjacent_agrees<S: GraphStorage<usize, DefaultEdge<usize>, Dir>, Dir: EdgeDir>(
+        mut graph: SimpleGraph<usize, DefaultEdge<usize>, Dir, S>,
+    ) {
+        // Given: Graph with random edges between 10 vertices.
+        let vertices = graph.add_vertices(10);
+        for src_id in &vertices {
+            for dst_id in &vertices {
+                if src_id != dst_id
+                    && !graph.has_any_edge(*src_id, *dst_id).unwrap()
+                    && rand::random::<f64>() < 0.3
+                {
+                    graph.add_edge(*src_id, *dst_id, 1.into()).unwrap();
+                }
+            }
+        }
+
+        // When: Removing an edge and a vertex.
+        if let Some((src_id, dst_id, edge_id)) = graph
+            .edges()
+            .first()
+            .map(|(src_id, dst_id, edge)| (*src_id, *dst_id, edge.get_id()))
+        {
+            graph.remove_edge(src_id, dst_id, edge_id).unwrap();
+        }
+        graph.remove_vertex(vertices[0]).unwrap();
+
+        // Then: Including pairs with invalid vertex ids.
+        for src_id in 0..12 {
+            for dst_id in 0..12 {
+                assert_eq!(
+                    graph.is_adjacent(src_id, dst_id),
+                    graph.has_any_edge(src_id, dst_id).unwrap_or(false)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn is_adjacent_agrees_with_has_any_edge() {
+        for _ in 0..20 {
+            assert_is_adjacent_agrees(MatGraph::init(Mat::<usize>::init()));
+            assert_is_adjacent_agrees(MatGraph::init(DiMat::<usize>::init()));
+            assert_is_adjacent_agrees(ListGraph::init(List::<usize>::init()));
+            assert_is_adjacent_agrees(SimpleGraph::init(Map::<usize, DirectedEdge>::init()));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "fixedbitset")]
+    fn neighbor_bitset_matches_neighbors() {
+        // Given: Graph
+        //
+        //      a --> b --> c
+        //      |     ^
+        //      v     |
+        //      d --> e     f
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        graph
+            .add_edges(&[(a, b, 1), (b, c, 1), (a, d, 1), (d, e, 1), (e, b, 1)])
+            .unwrap();
+        graph.remove_vertex(f).unwrap();
+
+        for vertex_id in graph.vertices() {
+            // When: Building bitset of the vertex.
+            let bitset = graph.neighbor_bitset(vertex_id);
+
+            // Then: Set bits are exactly the neighbors.
+            let mut neighbors = graph.neighbors(vertex_id).unwrap();
+            neighbors.sort_unstable();
+            assert_eq!(bitset.ones().collect::<Vec<usize>>(), neighbors);
+            assert_eq!(bitset.len(), 5);
+        }
+        assert_eq!(graph.neighbor_bitset(10).count_ones(..), 0);
+    }
+
+    fn assert_reverse_in_place<S: GraphStorage<usize, E, DirectedEdge>, E: Edge<usize>>(
+        mut graph: SimpleGraph<usize, E, DirectedEdge, S>,
+    ) {
+        // Given: Random directed graph with a removed vertex.
+        let vertices = graph.add_vertices(8);
+        for src_id in &vertices {
+            for dst_id in &vertices {
+                if src_id != dst_id && rand::random::<f64>() < 0.3 {
+                    graph.add_edge(*src_id, *dst_id, E::init(1.into())).unwrap();
+                }
+            }
+        }
+        graph.remove_vertex(vertices[0]).unwrap();
+
+        let predecessors_of = |graph: &SimpleGraph<usize, E, DirectedEdge, S>, dst_id: usize| {
+            let mut predecessors: Vec<usize> = graph
+                .edges()
+                .into_iter()
+                .filter(|(_, d_id, _)| *d_id == dst_id)
+                .map(|(src_id, _, _)| src_id)
+                .collect();
+            predecessors.sort_unstable();
+            predecessors
+        };
+        let vertices = graph.vertices();
+        let predecessors: Vec<Vec<usize>> = vertices
+            .iter()
+            .map(|vertex_id| predecessors_of(&graph, *vertex_id))
+            .collect();
+        let mut edges: Vec<(usize, usize, usize)> = graph
+            .edges()
+            .into_iter()
+            .map(|(src_id, dst_id, edge)| (dst_id, src_id, edge.get_id()))
+            .collect();
+        edges.sort_unstable();
+
+        // When: Reversing the graph.
+        graph.reverse_in_place();
+
+        // Then: Neighbors are the predecessors before reversal and edges keep their ids.
+        for (vertex_id, predecessors) in vertices.into_iter().zip(predecessors) {
+            let mut neighbors = graph.neighbors(vertex_id).unwrap();
+            neighbors.sort_unstable();
+            assert_eq!(neighbors, predecessors);
+
+            for n_id in neighbors {
+                assert!(graph.is_adjacent(vertex_id, n_id));
+            }
+        }
+
+        let mut reversed_edges: Vec<(usize, usize, usize)> = graph
+            .edges()
+            .into_iter()
+            .map(|(src_id, dst_id, edge)| (src_id, dst_id, edge.get_id()))
+            .collect();
+        reversed_edges.sort_unstable();
+        assert_eq!(reversed_edges, edges);
+    }
+
+    #[test]
+    fn reverse_in_place() {
+        for _ in 0..10 {
+            assert_reverse_in_place(MatGraph::init(DiMat::<usize>::init()));
+            assert_reverse_in_place(ListGraph::init(DiList::<usize>::init()));
+            assert_reverse_in_place(SimpleGraph::init(DiMap::<usize>::init()));
+            assert_reverse_in_place(SimpleGraph::init(DiFlowMat::<usize>::init()));
+        }
+    }
+
     #[test]
     fn add_multiple_edge() {
         // Given: Graph
@@ -159,7 +159,7 @@ where
     ///
     /// # Returns
     /// * `Err`: If either vertices with `src_id` or `dst_id` does not exist.
-    /// Also when there is not edge from source to destination with id: `edge_id`.
+    ///   Also when there is not edge from source to destination with id: `edge_id`.
     /// * `Ok`: Containing reference to edge with id: `edge_id` from `src_id` to `dst_id`.
     fn edge_between(&self, src_id: usize, dst_id: usize, edge_id: usize) -> Result<&E> {
         if !self.contains_vertex(src_id) {
@@ -286,7 +286,7 @@ where
     ///
     /// # Returns
     /// * `Err`: If either vertices with `src_id` or `dst_id` does not exist.
-    /// Also when there is not edge from source to destination with id: `edge_id`.
+    ///   Also when there is not edge from source to destination with id: `edge_id`.
     /// * `Ok`:
     fn remove_edge(&mut self, src_id: usize, dst_id: usize, edge_id: usize) -> Result<()> {
         if !self.contains_vertex(src_id) {
@@ -301,6 +301,11 @@ where
             }) {
                 self.edges.swap_remove(index);
 
+                // Undirected edges may be stored in both directions.
+                if Dir::is_undirected() {
+                    self.edges.retain(|(_, _, e_id)| *e_id != edge_id);
+                }
+
                 Ok(())
             } else {
                 Err(Error::new_iei(src_id, dst_id, edge_id))?
@@ -323,7 +328,7 @@ where
             self.vertex_ids.remove(&vertex_id);
 
             self.edges
-                .retain(|(src_id, dst_id, _)| *src_id != vertex_id || *dst_id != vertex_id);
+                .retain(|(src_id, dst_id, _)| *src_id != vertex_id && *dst_id != vertex_id);
 
             Ok(())
         }
@@ -367,11 +372,11 @@ where
         if self.contains_edge(edge_id) {
             Err(Error::new_eae(edge_id))?
         } else {
-            if let Some(_) = self
+            if self
                 .graph
                 .edges_between(src_id, dst_id)?
                 .into_iter()
-                .find(|edge| edge.get_id() == edge_id)
+                .any(|edge| edge.get_id() == edge_id)
             {
                 self.edges.push((src_id, dst_id, edge_id));
 
@@ -459,3 +464,150 @@ where
         Ok(edge_id)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::subgraph::AsMutSubgraph;
+    use crate::graph::{DefaultEdge, DirectedEdge, MatGraph};
+    use crate::storage::{DiMat, Mat};
+
+    #[test]
+    fn edges_between_removed_vertex() {
+        // Given: Subgraph containing all of the graph
+        //
+        //      a --- b --- c
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let ab = graph.add_edge(a, b, 1.into()).unwrap();
+        let bc = graph.add_edge(b, c, 1.into()).unwrap();
+        let mut subgraph = MutSubgraph::init(
+            &mut graph,
+            vec![(a, b, ab), (b, a, ab), (b, c, bc), (c, b, bc)],
+            [a, b, c].iter().copied().collect(),
+        );
+
+        // When: Removing b and then adding it back without its edges.
+        subgraph.remove_vertex(b).unwrap();
+        let is_removed = subgraph.edges_between(b, a).is_err();
+        subgraph.add_vertex_from_graph(b).unwrap();
+
+        // Then:
+        assert!(is_removed);
+        assert!(subgraph.edges_between(a, b).unwrap().is_empty());
+        assert!(subgraph.edges_between(b, c).unwrap().is_empty());
+        assert!(subgraph.edge_between(a, b, ab).is_err());
+        assert!(!subgraph.has_any_edge(a, b).unwrap());
+    }
+
+    // Given: Subgraph containing all of the graph
+    //
+    //      a --> b --> c
+    //      |     ^
+    //      v     |
+    //      d ----'
+    //
+    fn graph() -> MatGraph<usize, DirectedEdge> {
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph
+            .add_edges(&[(a, b, 1), (b, c, 2), (a, d, 3), (d, b, 4)])
+            .unwrap();
+
+        graph
+    }
+
+    fn subgraph_of(
+        graph: &mut MatGraph<usize, DirectedEdge>,
+    ) -> MutSubgraph<'_, usize, DefaultEdge<usize>, DirectedEdge, MatGraph<usize, DirectedEdge>>
+    {
+        let edges = graph
+            .edges()
+            .into_iter()
+            .map(|(src_id, dst_id, edge)| (src_id, dst_id, edge.get_id()))
+            .collect();
+        let vertices = graph.vertices().into_iter().collect();
+
+        MutSubgraph::init(graph, edges, vertices)
+    }
+
+    #[test]
+    fn remove_while_iterating_snapshots() {
+        let mut graph = graph();
+        let mut subgraph = subgraph_of(&mut graph);
+
+        // When: Removing every edge and vertex while iterating over them.
+        for (src_id, dst_id, edge_id) in subgraph.edges_snapshot() {
+            subgraph.remove_edge(src_id, dst_id, edge_id).unwrap();
+        }
+        for vertex_id in subgraph.vertices_snapshot() {
+            subgraph.remove_vertex(vertex_id).unwrap();
+        }
+
+        // Then: Subgraph is empty but graph is untouched.
+        assert_eq!(subgraph.edges_count(), 0);
+        assert_eq!(subgraph.vertex_count(), 0);
+        assert_eq!(graph.edges_count(), 4);
+        assert_eq!(graph.vertex_count(), 4);
+    }
+
+    #[test]
+    fn drain_edges() {
+        let mut graph = graph();
+        let mut subgraph = subgraph_of(&mut graph);
+
+        // When: Draining edges while removing a vertex midway.
+        let mut drained = vec![];
+        let mut drain = subgraph.drain_edges();
+        drained.push(drain.next().unwrap());
+        drop(drain);
+        let (_, dst_id, _) = drained[0];
+        subgraph.remove_vertex_from_graph(dst_id).unwrap();
+        drained.extend(subgraph.drain_edges());
+
+        // Then: No edge is left, and edges of the removed vertex are not yielded.
+        assert_eq!(subgraph.edges_count(), 0);
+        assert_eq!(graph.edges_count(), 0);
+        assert!(drained
+            .iter()
+            .skip(1)
+            .all(|(src_id, d_id, _)| *src_id != dst_id && *d_id != dst_id));
+    }
+
+    #[test]
+    fn drain_undirected_edges() {
+        // Given: Subgraph containing all of the graph
+        //
+        //      a --- b --- c
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let ab = graph.add_edge(a, b, 1.into()).unwrap();
+        let bc = graph.add_edge(b, c, 2.into()).unwrap();
+        let mut subgraph = MutSubgraph::init(
+            &mut graph,
+            vec![(a, b, ab), (b, a, ab), (b, c, bc), (c, b, bc)],
+            [a, b, c].iter().copied().collect(),
+        );
+
+        // When: Draining all edges.
+        let mut drained: Vec<usize> = subgraph
+            .drain_edges()
+            .map(|(_, _, edge)| edge.get_weight().unwrap())
+            .collect();
+        drained.sort_unstable();
+
+        // Then: Each edge is yielded once.
+        assert_eq!(drained, vec![1, 2]);
+        assert!(!subgraph.contains_edge(ab));
+        assert_eq!(graph.edges_count(), 0);
+    }
+}
@@ -3,7 +3,8 @@ use std::{collections::HashSet, marker::PhantomData};
 use anyhow::Result;
 
 use crate::{
-    graph::{error::Error, EdgeDir},
+    algo::ConnectedComponents,
+    graph::{error::Error, EdgeDir, UndirectedEdge},
     prelude::{Edge, Edges, Graph, Neighbors, Vertices},
 };
 
@@ -66,6 +67,113 @@ where
     }
 }
 
+impl<'a, W, E, G> Subgraph<'a, W, E, UndirectedEdge, G>
+where
+    E: Edge<W>,
+    G: Graph<W, E, UndirectedEdge> + Edges<W, E> + Vertices + Neighbors,
+{
+    /// Checks whether the subgraph is connected using only its own vertices and edges.
+    /// Edges of the parent graph that are not added to the subgraph are not followed.
+    ///
+    /// # Returns
+    /// * `true`: If [`ConnectedComponents`](crate::algo::ConnectedComponents) finds exactly one component in the subgraph.
+    /// * `false`: Otherwise, including when the subgraph has no vertices.
+    ///
+    /// # Complexity
+    /// O(|V| + |E|) where |V| and |E| are number of vertices and edges of the subgraph.
+    pub fn is_connected(&self) -> bool {
+        ConnectedComponents::init(self.graph)
+            .execute_on_subgraph(self)
+            .len()
+            == 1
+    }
+}
+
+impl<'a, W, E, Dir, G> Subgraph<'a, W, E, Dir, G>
+where
+    E: Edge<W>,
+    Dir: EdgeDir,
+    G: Graph<W, E, Dir> + Edges<W, E>,
+{
+    // Returns number of edges of the parent graph in the format of: (internal, external, total).
+    fn parent_edges_counts(&self) -> (usize, usize, usize) {
+        let edges = self.graph.edges();
+
+        let mut internal = 0;
+        let mut external = 0;
+        for (src_id, dst_id, _) in &edges {
+            match (self.contains_vertex(*src_id), self.contains_vertex(*dst_id)) {
+                (true, true) => internal += 1,
+                (true, false) | (false, true) => external += 1,
+                (false, false) => {}
+            }
+        }
+
+        (internal, external, edges.len())
+    }
+
+    /// # Returns
+    /// Number of edges of the parent graph that both of their end points are in the subgraph.
+    /// Note that edges of the parent graph are counted, even if they are not added to the subgraph.
+    ///
+    /// # Complexity
+    /// O(|E|) where |E| is number of edges of the parent graph.
+    pub fn internal_edges_count(&self) -> usize {
+        self.parent_edges_counts().0
+    }
+
+    /// # Returns
+    /// Number of edges of the parent graph that connect a vertex of the subgraph to a vertex outside of it.
+    /// In directed graphs both edges leaving and entering the subgraph are counted.
+    ///
+    /// # Complexity
+    /// O(|E|) where |E| is number of edges of the parent graph.
+    pub fn external_edges_count(&self) -> usize {
+        self.parent_edges_counts().1
+    }
+
+    /// # Returns
+    /// Ratio of internal edges to the maximum number of edges possible between vertices of the subgraph.
+    /// It is 0 if subgraph has less than two vertices.
+    ///
+    /// # Complexity
+    /// O(|E|) where |E| is number of edges of the parent graph.
+    pub fn density(&self) -> f64 {
+        let vertex_count = self.vertex_ids.len();
+        if vertex_count < 2 {
+            return 0.0;
+        }
+
+        let mut max_edges_count = vertex_count * (vertex_count - 1);
+        if Dir::is_undirected() {
+            max_edges_count /= 2;
+        }
+
+        self.internal_edges_count() as f64 / max_edges_count as f64
+    }
+
+    /// Computes conductance of the subgraph, which is number of external edges divided by the smaller of the volume of the subgraph and the volume of the rest of the graph.
+    /// Volume of a set of vertices is sum of their degrees. Lower conductance means the subgraph is more separated from the rest of the graph.
+    ///
+    /// # Returns
+    /// * `Some`: Containing the conductance, between 0 and 1.
+    /// * `None`: If either the subgraph or the rest of the graph has no edges attached, so conductance is not defined.
+    ///
+    /// # Complexity
+    /// O(|E|) where |E| is number of edges of the parent graph.
+    pub fn conductance(&self) -> Option<f64> {
+        let (internal, external, total) = self.parent_edges_counts();
+
+        let volume = 2 * internal + external;
+        let rest_volume = 2 * (total - internal - external) + external;
+
+        match volume.min(rest_volume) {
+            0 => None,
+            min_volume => Some(external as f64 / min_volume as f64),
+        }
+    }
+}
+
 impl<'a, W, E, Dir, G> Neighbors for Subgraph<'a, W, E, Dir, G>
 where
     E: Edge<W>,
@@ -78,15 +186,32 @@ where
     /// # Returns
     /// * `Err`: If vertex with id: `src_id` is not present in the subgraph.
     /// * `Ok`: Containing Id of vertices accessible from source vertex using one edge.
+    ///   In undirected subgraphs, an edge is followed from both of its end points.
     fn neighbors(&self, src_id: usize) -> Result<Vec<usize>> {
         if !self.contains_vertex(src_id) {
             Err(Error::new_vnf(src_id))?
         } else {
-            Ok(self
+            let mut neighbors: Vec<usize> = self
                 .edges
                 .iter()
-                .filter_map(|(s_id, dst_id, _)| if *s_id == src_id { Some(*dst_id) } else { None })
-                .collect())
+                .filter_map(|(s_id, d_id, _)| {
+                    if *s_id == src_id {
+                        Some(*d_id)
+                    } else if Dir::is_undirected() && *d_id == src_id {
+                        Some(*s_id)
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            // Undirected edges may be stored in one or both directions, so duplicates are removed.
+            if Dir::is_undirected() {
+                neighbors.sort_unstable();
+                neighbors.dedup();
+            }
+
+            Ok(neighbors)
         }
     }
 }
@@ -347,7 +472,7 @@ where
             self.vertex_ids.remove(&vertex_id);
 
             self.edges
-                .retain(|(src_id, dst_id, _)| *src_id != vertex_id || *dst_id != vertex_id);
+                .retain(|(src_id, dst_id, _)| *src_id != vertex_id && *dst_id != vertex_id);
 
             Ok(())
         }
@@ -409,3 +534,187 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::ConnectedComponents;
+    use crate::graph::{DefaultEdge, MatGraph, UndirectedEdge};
+    use crate::storage::Mat;
+
+    #[test]
+    fn edges_between_removed_vertex() {
+        // Given: Subgraph containing all of the graph
+        //
+        //      a --- b --- c
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let ab = graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        let mut subgraph = Subgraph::init(
+            &graph,
+            graph.as_directed_edges(),
+            graph.vertices().into_iter().collect(),
+        );
+
+        // When: Removing b and then adding it back without its edges.
+        subgraph.remove_vertex(b).unwrap();
+        let is_removed = subgraph.edges_between(b, a).is_err();
+        subgraph.add_vertex_from_graph(b).unwrap();
+
+        // Then:
+        assert!(is_removed);
+        assert!(subgraph.edges_between(a, b).unwrap().is_empty());
+        assert!(subgraph.edges_between(b, a).unwrap().is_empty());
+        assert!(subgraph.edge_between(a, b, ab).is_err());
+        assert!(!subgraph.has_any_edge(a, b).unwrap());
+        assert!(subgraph.neighbors(a).unwrap().is_empty());
+        assert_eq!(subgraph.edges_count(), 0);
+    }
+
+    // Given: Two cliques of four vertices connected by a single edge.
+    //
+    //      a1 ... a4 --- b1 ... b4
+    //
+    fn two_cliques() -> (MatGraph<usize, UndirectedEdge>, Vec<usize>, Vec<usize>) {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a: Vec<usize> = (0..4).map(|_| graph.add_vertex()).collect();
+        let b: Vec<usize> = (0..4).map(|_| graph.add_vertex()).collect();
+        for clique in [&a, &b].iter() {
+            for (index, src_id) in clique.iter().enumerate() {
+                for dst_id in clique.iter().skip(index + 1) {
+                    graph.add_edge(*src_id, *dst_id, 1.into()).unwrap();
+                }
+            }
+        }
+        graph.add_edge(a[3], b[0], 1.into()).unwrap();
+
+        (graph, a, b)
+    }
+
+    #[test]
+    fn clique_cohesion() {
+        let (graph, a, _) = two_cliques();
+
+        // When: Taking subgraph of the first clique.
+        let subgraph = Subgraph::init(&graph, vec![], a.iter().copied().collect());
+
+        // Then: Its 6 edges are internal and only the connecting edge is external.
+        assert_eq!(subgraph.internal_edges_count(), 6);
+        assert_eq!(subgraph.external_edges_count(), 1);
+        assert_eq!(subgraph.density(), 1.0);
+        assert_eq!(subgraph.conductance(), Some(1.0 / 13.0));
+    }
+
+    #[test]
+    fn mixed_subgraph_cohesion() {
+        let (graph, a, b) = two_cliques();
+
+        // When: Taking subgraph of two vertices from each clique.
+        let subgraph = Subgraph::init(
+            &graph,
+            vec![],
+            [a[0], a[1], b[2], b[3]].iter().copied().collect(),
+        );
+
+        // Then: Conductance is much higher than conductance of a clique.
+        assert_eq!(subgraph.internal_edges_count(), 2);
+        assert_eq!(subgraph.external_edges_count(), 8);
+        assert_eq!(subgraph.density(), 2.0 / 6.0);
+        assert_eq!(subgraph.conductance(), Some(8.0 / 12.0));
+    }
+
+    #[test]
+    fn undefined_conductance() {
+        let (graph, a, b) = two_cliques();
+
+        let whole_graph = Subgraph::init(&graph, vec![], a.iter().chain(&b).copied().collect());
+        let empty = Subgraph::init(&graph, vec![], HashSet::new());
+
+        assert_eq!(whole_graph.internal_edges_count(), 13);
+        assert!(whole_graph.conductance().is_none());
+        assert_eq!(empty.density(), 0.0);
+        assert!(empty.conductance().is_none());
+    }
+
+    #[test]
+    fn connected_and_disconnected_subgraphs() {
+        // Given: Graph
+        //
+        //      a --- b --- c --- d
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+        let edges_of = |vertex_ids: &[usize]| -> Vec<(usize, usize, &DefaultEdge<usize>)> {
+            graph
+                .edges()
+                .into_iter()
+                .filter(|(src_id, dst_id, _)| {
+                    vertex_ids.contains(src_id) && vertex_ids.contains(dst_id)
+                })
+                .collect()
+        };
+
+        // When: Taking subgraph of a, b, c and subgraph of a, b, d, each with the edges between its vertices.
+        let connected = Subgraph::init(
+            &graph,
+            edges_of(&[a, b, c]),
+            [a, b, c].iter().copied().collect(),
+        );
+        let disconnected = Subgraph::init(
+            &graph,
+            edges_of(&[a, b, d]),
+            [a, b, d].iter().copied().collect(),
+        );
+
+        // Then: d is separated from a and b once c is left out.
+        assert!(connected.is_connected());
+        assert!(!disconnected.is_connected());
+
+        let ccs = ConnectedComponents::init(&graph).execute_on_subgraph(&connected);
+        assert_eq!(ccs.len(), 1);
+        assert_eq!(ccs[0].len(), 3);
+
+        let mut ccs = ConnectedComponents::init(&graph).execute_on_subgraph(&disconnected);
+        ccs.iter_mut().for_each(|cc| cc.sort_unstable());
+        ccs.sort_unstable();
+        assert_eq!(ccs, vec![vec![a, b], vec![d]]);
+    }
+
+    #[test]
+    fn subgraph_without_edges_or_vertices() {
+        // Given: Graph
+        //
+        //      a --- b --- c
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+
+        let whole_graph = Subgraph::init(
+            &graph,
+            graph.edges(),
+            graph.vertices().into_iter().collect(),
+        );
+        let without_edges = Subgraph::init(&graph, vec![], graph.vertices().into_iter().collect());
+        let single_vertex = Subgraph::init(&graph, vec![], [b].iter().copied().collect());
+        let empty = Subgraph::init(&graph, vec![], HashSet::new());
+
+        assert!(whole_graph.is_connected());
+        assert!(!without_edges.is_connected());
+        assert!(single_vertex.is_connected());
+        assert!(!empty.is_connected());
+    }
+}
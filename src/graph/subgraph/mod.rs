@@ -7,6 +7,7 @@ use crate::graph::Edge;
 use crate::provide::{Edges, Neighbors, Vertices};
 
 use anyhow::Result;
+use std::marker::PhantomData;
 pub use def_mut_subgraph::MutSubgraph;
 pub use def_subgraph::Subgraph;
 pub use mr_subgraph::MultiRootSubgraph;
@@ -75,6 +76,30 @@ pub trait AsSubgraph<W, E: Edge<W>>: AsFrozenSubgraph<W, E> {
     /// * `Err`
     /// * `Ok`: If addition was successful.
     fn add_edge_from_graph(&mut self, src_id: usize, dst_id: usize, edge_id: usize) -> Result<()>;
+
+    /// Unlike `vertices`, which returns a vector, this function makes it explicit that the returned iterator is a snapshot.
+    /// It owns the ids and does not borrow the subgraph, so it's safe to mutate the subgraph(by `remove_vertex` or `remove_edge` for example) while iterating.
+    /// Vertices that get removed during iteration are still yielded, so check them using `contains_vertex` if needed.
+    ///
+    /// # Returns
+    /// Iterator over id of vertices that were present in the subgraph at the time of calling this function.
+    fn vertices_snapshot(&self) -> std::vec::IntoIter<usize> {
+        self.vertices().into_iter()
+    }
+
+    /// Unlike `edges`, which returns references to the edges, this function returns only ids of the edges.
+    /// So the returned iterator does not borrow the subgraph and it's safe to mutate the subgraph(by `remove_vertex` or `remove_edge` for example) while iterating.
+    /// Edges that get removed during iteration are still yielded, so check them using `contains_edge` if needed.
+    ///
+    /// # Returns
+    /// Iterator over edges that were present in the subgraph at the time of calling this function, in the format of (`src_id`, `dst_id`, `edge_id`).
+    fn edges_snapshot(&self) -> std::vec::IntoIter<(usize, usize, usize)> {
+        self.edges()
+            .into_iter()
+            .map(|(src_id, dst_id, edge)| (src_id, dst_id, edge.get_id()))
+            .collect::<Vec<(usize, usize, usize)>>()
+            .into_iter()
+    }
 }
 
 /// Describes a subgraph that can mutate and also can mutate the graph it's representing.
@@ -132,4 +157,57 @@ pub trait AsMutSubgraph<W, E: Edge<W>>: AsSubgraph<W, E> {
     /// * `Err`:
     /// * `Ok`: Containing the id of the newly added edge.
     fn add_edge(&mut self, src_id: usize, dst_id: usize, edge: E) -> Result<usize>;
+
+    /// Removes edges of the subgraph from the graph one at a time, as the returned iterator gets advanced.
+    /// Edges that are not consumed from the iterator remain in the subgraph and the graph.
+    ///
+    /// # Returns
+    /// Iterator over the removed edges in the format of (`src_id`, `dst_id`, `edge`).
+    ///
+    /// # Panics
+    /// If removing an edge from the graph fails.
+    fn drain_edges(&mut self) -> DrainEdges<'_, W, E, Self>
+    where
+        Self: Sized,
+    {
+        DrainEdges {
+            edges: self.edges_snapshot(),
+            subgraph: self,
+            phantom_w: PhantomData,
+            phantom_e: PhantomData,
+        }
+    }
+}
+
+/// Iterator returned from [`drain_edges`](crate::graph::subgraph::AsMutSubgraph::drain_edges).
+///
+/// ## Generic Parameters
+/// * `W`: **W**eight type associated with edges.
+/// * `E`: **E**dge type that subgraph uses.
+/// * `S`: **S**ubgraph that edges are drained from.
+pub struct DrainEdges<'a, W, E: Edge<W>, S: AsMutSubgraph<W, E>> {
+    subgraph: &'a mut S,
+    edges: std::vec::IntoIter<(usize, usize, usize)>,
+
+    phantom_w: PhantomData<W>,
+    phantom_e: PhantomData<E>,
+}
+
+impl<'a, W, E: Edge<W>, S: AsMutSubgraph<W, E>> Iterator for DrainEdges<'a, W, E, S> {
+    type Item = (usize, usize, E);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Skip edges that are removed since the snapshot was taken.
+        let subgraph = &self.subgraph;
+        let (src_id, dst_id, edge_id) = self
+            .edges
+            .find(|(_, _, edge_id)| subgraph.contains_edge(*edge_id))?;
+
+        let edge = self
+            .subgraph
+            .remove_edge_from_graph(src_id, dst_id, edge_id)
+            .unwrap();
+
+        Some((src_id, dst_id, edge))
+    }
 }
@@ -66,6 +66,41 @@ where
     pub fn distance_to(&self, dst_id: usize) -> Option<Magnitude<W>> {
         self.distance_map.get(&dst_id).copied()
     }
+
+    /// Walks from the destination back to the source along the edges of the subgraph.
+    ///
+    /// # Arguments
+    /// * `dst_id`: Id of the destination vertex.
+    ///
+    /// # Returns
+    /// * `Some`: Containing id of the vertices on the shortest path, starting with the source and ending with `dst_id`.
+    /// * `None`: If `dst_id` is not reachable from the source, or distance map does not contain an entry about it.
+    ///
+    /// # Complexity
+    /// O(|E|) where |E| is the number of edges in the subgraph.
+    pub fn path_to(&self, dst_id: usize) -> Option<Vec<usize>> {
+        if !self.distance_to(dst_id)?.is_finite() {
+            return None;
+        }
+
+        // Edges of the shortest path tree are stored as (parent, child), so each vertex has at most one parent.
+        let parent_of: HashMap<usize, usize> = self
+            .subgraph
+            .edges()
+            .into_iter()
+            .map(|(src_id, dst_id, _)| (dst_id, src_id))
+            .collect();
+
+        let mut path = vec![dst_id];
+        let mut vertex_id = dst_id;
+        while let Some(parent_id) = parent_of.get(&vertex_id) {
+            path.push(*parent_id);
+            vertex_id = *parent_id;
+        }
+        path.reverse();
+
+        Some(path)
+    }
 }
 
 /// `ShortestPathSubgraph` uses `Subgraph` internally so for more info checkout [`Subgraph`](crate::graph::subgraph::Subgraph).
@@ -147,3 +182,85 @@ where
     G: Graph<W, E, Dir> + Edges<W, E> + Neighbors,
 {
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::algo::{BellmanFord, Dijkstra};
+    use crate::graph::{Edge, MatGraph};
+    use crate::provide::*;
+    use crate::storage::{DiMat, Mat};
+
+    #[test]
+    fn path_to_source_and_unreachable() {
+        // Given: Graph
+        //
+        //      a --> b     c
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+
+        // When: Performing Dijkstra algorithm.
+        let sp_subgraph = Dijkstra::init(&graph).execute(&graph, a);
+
+        // Then:
+        assert_eq!(sp_subgraph.path_to(a), Some(vec![a]));
+        assert_eq!(sp_subgraph.path_to(b), Some(vec![a, b]));
+        assert_eq!(sp_subgraph.path_to(c), None);
+        assert_eq!(sp_subgraph.path_to(10), None);
+    }
+
+    #[test]
+    fn path_to_matches_reconstruction() {
+        // Given: Graph
+        //
+        //           5
+        //      a ------- b
+        //      |         |
+        //    1 |         | 1
+        //      |    1    |    1
+        //      c ------- d ------- e
+        //      |                   |
+        //    9 '-------- f --------' 2
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let f = graph.add_vertex();
+        graph.add_edge(a, b, 5.into()).unwrap();
+        graph.add_edge(a, c, 1.into()).unwrap();
+        graph.add_edge(b, d, 1.into()).unwrap();
+        graph.add_edge(c, d, 1.into()).unwrap();
+        graph.add_edge(d, e, 1.into()).unwrap();
+        graph.add_edge(c, f, 9.into()).unwrap();
+        graph.add_edge(e, f, 2.into()).unwrap();
+
+        // When: Performing Dijkstra and BellmanFord algorithms.
+        let sp_subgraph = Dijkstra::init(&graph).execute(&graph, a);
+        let mut bellman_ford = BellmanFord::init(&graph);
+        bellman_ford.search(&graph, a).unwrap();
+
+        // Then: Paths are the same, and their length is the distance.
+        assert_eq!(sp_subgraph.path_to(b), Some(vec![a, c, d, b]));
+        assert_eq!(sp_subgraph.path_to(f), Some(vec![a, c, d, e, f]));
+        for dst_id in [a, b, c, d, e, f] {
+            let path = sp_subgraph.path_to(dst_id).unwrap();
+            assert_eq!(Some(path.clone()), bellman_ford.reconstruct_path(dst_id));
+
+            let length: usize = path
+                .windows(2)
+                .map(|ids| {
+                    graph.edges_between(ids[0], ids[1]).unwrap()[0]
+                        .get_weight()
+                        .unwrap()
+                })
+                .sum();
+            assert_eq!(sp_subgraph.distance_to(dst_id).unwrap(), length.into());
+        }
+    }
+}
@@ -0,0 +1,425 @@
+use magnitude::Magnitude;
+use std::any::Any;
+
+use crate::graph::{Edge, EdgeDir, SimpleGraph};
+use crate::provide::{Edges, Graph, Vertices};
+use crate::storage::GraphStorage;
+
+/// Creates a new graph with the same structure as `graph`, in which weight of each edge is transformed by `f`.
+///
+/// Useful for preparing a graph before running an algorithm on it, for example negating weights to find longest paths.
+///
+/// # Arguments
+/// * `graph`: Graph to transform its weights.
+/// * `storage`: Empty storage that the new graph uses.
+/// * `f`: Receives weight of each edge and returns the weight of the corresponding edge in the new graph.
+///
+/// # Returns
+/// The new graph. Vertices keep their ids, but edges may get new ids.
+///
+/// # Complexity
+/// O(|V| + |E|) calls to `add_vertex`, `remove_vertex` and `add_edge` of the new graph.
+///
+/// # Panics
+/// If `storage` is not empty, or if `graph` has loops or multiple edges that `SimpleGraph` does not accept.
+///
+/// # Examples
+/// ```
+/// use magnitude::Magnitude;
+/// use prepona::prelude::*;
+/// use prepona::storage::DiMat;
+/// use prepona::graph::{MatGraph, map_weights};
+///
+/// // Given: Graph
+/// //
+/// //      a --2--> b --3--> c
+/// //
+/// let mut graph = MatGraph::init(DiMat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 2.into());
+/// graph.add_edge(b, c, 3.into());
+///
+/// // When: Converting weights to f64 and halving them.
+/// let halved = map_weights(&graph, DiMat::<f64>::init(), |weight| match weight {
+///     Magnitude::Finite(weight) => (*weight as f64 / 2.0).into(),
+///     _ => Magnitude::PosInfinite,
+/// });
+///
+/// // Then:
+/// assert_eq!(halved.vertices(), graph.vertices());
+/// assert_eq!(halved.edges_between(b, c).unwrap()[0].get_weight(), &1.5.into());
+/// ```
+pub fn map_weights<W, W2, E, E2, Dir, G, S2, F>(
+    graph: &G,
+    storage: S2,
+    f: F,
+) -> SimpleGraph<W2, E2, Dir, S2>
+where
+    W2: Any,
+    E: Edge<W>,
+    E2: Edge<W2>,
+    Dir: EdgeDir,
+    G: Edges<W, E> + Vertices + Graph<W, E, Dir>,
+    S2: GraphStorage<W2, E2, Dir>,
+    F: Fn(&Magnitude<W>) -> Magnitude<W2>,
+{
+    let mut mapped = with_vertices_of(graph, storage);
+
+    for (src_id, dst_id, edge) in graph.edges() {
+        mapped
+            .add_edge(src_id, dst_id, E2::init(f(edge.get_weight())))
+            .unwrap();
+    }
+
+    mapped
+}
+
+// Creates a graph using `storage` that has the same vertices as `graph` with the same ids, but no edges.
+//
+// # Panics
+// If `storage` is not empty.
+pub(crate) fn with_vertices_of<W, W2, E, E2, Dir, Dir2, G, S2>(
+    graph: &G,
+    storage: S2,
+) -> SimpleGraph<W2, E2, Dir2, S2>
+where
+    W2: Any,
+    E: Edge<W>,
+    E2: Edge<W2>,
+    Dir: EdgeDir,
+    Dir2: EdgeDir,
+    G: Vertices + Graph<W, E, Dir>,
+    S2: GraphStorage<W2, E2, Dir2>,
+{
+    let mut graph_with_vertices = SimpleGraph::init(storage);
+
+    // Add vertices up to the largest id and then remove the extra ones, so vertices keep their ids.
+    let vertex_ids = graph.vertices();
+    let total_count = vertex_ids.iter().max().map_or(0, |max_id| max_id + 1);
+    for vertex_id in 0..total_count {
+        assert_eq!(
+            graph_with_vertices.add_vertex(),
+            vertex_id,
+            "Storage must be empty"
+        );
+    }
+    for vertex_id in 0..total_count {
+        if !graph.contains_vertex(vertex_id) {
+            graph_with_vertices.remove_vertex(vertex_id).unwrap();
+        }
+    }
+
+    graph_with_vertices
+}
+
+/// Creates a copy of `graph` that only contains edges for which `predicate` returns `true`.
+///
+/// All vertices are kept, and vertices and edges keep their ids. Useful for thresholding weights, for example in correlation networks.
+///
+/// # Arguments
+/// * `graph`: Graph to filter its edges.
+/// * `predicate`: Receives (`src_id`, `dst_id`, `edge`) of each edge and returns `true` if edge must be kept.
+///
+/// # Returns
+/// The filtered graph.
+///
+/// # Complexity
+/// Cloning the graph plus O(|E|) calls to `remove_edge`.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::{MatGraph, filter_edges};
+///
+/// // Given: Graph
+/// //
+/// //      a --1-- b --5-- c
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// let bc = graph.add_edge(b, c, 5.into()).unwrap();
+///
+/// // When: Keeping edges with weight of at least 2.
+/// let filtered = filter_edges(&graph, |_, _, edge| *edge.get_weight() >= 2.into());
+///
+/// // Then:
+/// assert_eq!(filtered.vertex_count(), 3);
+/// assert_eq!(filtered.edges_count(), 1);
+/// assert!(filtered.contains_edge(bc));
+/// ```
+pub fn filter_edges<W, E, Dir, G, F>(graph: &G, predicate: F) -> G
+where
+    E: Edge<W>,
+    Dir: EdgeDir,
+    G: Edges<W, E> + Graph<W, E, Dir> + Clone,
+    F: Fn(usize, usize, &E) -> bool,
+{
+    let mut filtered = graph.clone();
+
+    for (src_id, dst_id, edge) in graph.edges() {
+        if !predicate(src_id, dst_id, edge) {
+            filtered.remove_edge(src_id, dst_id, edge.get_id()).unwrap();
+        }
+    }
+
+    filtered
+}
+
+/// Creates the subgraph of `graph` induced by vertices for which `predicate` returns `true`.
+///
+/// Edges that have an end point filtered out are dropped. Vertices and edges keep their ids.
+///
+/// # Arguments
+/// * `graph`: Graph to filter its vertices.
+/// * `predicate`: Receives id of each vertex and returns `true` if vertex must be kept.
+///
+/// # Returns
+/// The filtered graph.
+///
+/// # Complexity
+/// Cloning the graph plus O(|V|) calls to `remove_vertex`.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::{MatGraph, filter_vertices};
+///
+/// // Given: Graph
+/// //
+/// //      a --- b --- c
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// let ab = graph.add_edge(a, b, 1.into()).unwrap();
+/// graph.add_edge(b, c, 1.into());
+///
+/// // When: Removing c.
+/// let filtered = filter_vertices(&graph, |vertex_id| vertex_id != c);
+///
+/// // Then:
+/// assert_eq!(filtered.vertex_count(), 2);
+/// assert_eq!(filtered.edges_count(), 1);
+/// assert!(filtered.contains_edge(ab));
+/// ```
+pub fn filter_vertices<W, E, Dir, G, F>(graph: &G, predicate: F) -> G
+where
+    E: Edge<W>,
+    Dir: EdgeDir,
+    G: Vertices + Graph<W, E, Dir> + Clone,
+    F: Fn(usize) -> bool,
+{
+    let mut filtered = graph.clone();
+
+    for vertex_id in graph.vertices() {
+        if !predicate(vertex_id) {
+            filtered.remove_vertex(vertex_id).unwrap();
+        }
+    }
+
+    filtered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::{BellmanFord, Dijkstra};
+    use crate::graph::MatGraph;
+    use crate::provide::Neighbors;
+    use crate::storage::{DiList, DiMat, Mat};
+
+    #[test]
+    fn filter_edges_below_threshold() {
+        // Given: Graph
+        //
+        //      a --3-- b --1-- c
+        //      |     /        |
+        //      2    4         5
+        //      |  /           |
+        //      d ------0----- e
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        let e = graph.add_vertex();
+        let ab = graph.add_edge(a, b, 3.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(a, d, 2.into()).unwrap();
+        let bd = graph.add_edge(b, d, 4.into()).unwrap();
+        let ce = graph.add_edge(c, e, 5.into()).unwrap();
+        graph.add_edge(d, e, 0.into()).unwrap();
+
+        // When: Filtering out edges with weight below 3.
+        let filtered = filter_edges(&graph, |_, _, edge| *edge.get_weight() >= 3.into());
+
+        // Then: All vertices are kept alongside edges with weight of at least 3.
+        let mut vertices = filtered.vertices();
+        vertices.sort_unstable();
+        assert_eq!(vertices, vec![a, b, c, d, e]);
+        let mut edge_ids: Vec<usize> = filtered
+            .edges()
+            .into_iter()
+            .map(|(_, _, edge)| edge.get_id())
+            .collect();
+        edge_ids.sort_unstable();
+        let mut expected = vec![ab, bd, ce];
+        expected.sort_unstable();
+        assert_eq!(edge_ids, expected);
+        assert!(!filtered.is_adjacent(d, e));
+        assert!(filtered.is_adjacent(d, b));
+
+        // Original graph is untouched.
+        assert_eq!(graph.edges_count(), 6);
+    }
+
+    #[test]
+    fn filter_directed_edges_by_end_points() {
+        // Given: Graph
+        //
+        //      a --> b --> c --> a
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, a, 1.into()).unwrap();
+
+        // When: Keeping edges that go to a larger id.
+        let filtered = filter_edges(&graph, |src_id, dst_id, _| src_id < dst_id);
+
+        // Then:
+        assert_eq!(filtered.edges_count(), 2);
+        assert!(filtered.neighbors(c).unwrap().is_empty());
+    }
+
+    #[test]
+    fn filter_even_vertices() {
+        // Given: Graph
+        //
+        //      v0 --- v1 --- v2 --- v3 --- v4
+        //       \           / \           /
+        //        '---------'   '---------'
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let vertices: Vec<usize> = (0..5).map(|_| graph.add_vertex()).collect();
+        for pair in vertices.windows(2) {
+            graph.add_edge(pair[0], pair[1], 1.into()).unwrap();
+        }
+        let v0_v2 = graph.add_edge(vertices[0], vertices[2], 1.into()).unwrap();
+        graph.add_edge(vertices[2], vertices[4], 1.into()).unwrap();
+        let v2_v4 = graph.edges_between(vertices[2], vertices[4]).unwrap()[0].get_id();
+
+        // When: Keeping only vertices with even id.
+        let filtered = filter_vertices(&graph, |vertex_id| vertex_id % 2 == 0);
+
+        // Then: Only edges between even vertices are kept.
+        let mut vertex_ids = filtered.vertices();
+        vertex_ids.sort_unstable();
+        assert_eq!(vertex_ids, vec![0, 2, 4]);
+        let mut edge_ids: Vec<usize> = filtered
+            .edges()
+            .into_iter()
+            .map(|(_, _, edge)| edge.get_id())
+            .collect();
+        edge_ids.sort_unstable();
+        let mut expected = vec![v0_v2, v2_v4];
+        expected.sort_unstable();
+        assert_eq!(edge_ids, expected);
+        assert!(filtered.is_adjacent(0, 2));
+        assert!(!filtered.is_adjacent(0, 4));
+    }
+
+    #[test]
+    fn keeps_removed_vertex_ids() {
+        // Given: Graph with a removed vertex.
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, c, 4.into()).unwrap();
+        graph.remove_vertex(b).unwrap();
+
+        // When: Doubling the weights.
+        let doubled = map_weights(&graph, Mat::<usize>::init(), |weight| *weight + *weight);
+
+        // Then:
+        let mut vertices = doubled.vertices();
+        vertices.sort_unstable();
+        assert_eq!(vertices, vec![a, c]);
+        assert!(!doubled.contains_vertex(b));
+        assert_eq!(doubled.edges_count(), 1);
+        assert_eq!(
+            doubled.edges_between(c, a).unwrap()[0].get_weight(),
+            &8.into()
+        );
+    }
+
+    #[test]
+    fn negated_weights_give_longest_paths() {
+        // Given: Directed acyclic graph
+        //
+        //      a --1--> b --1--> d
+        //      | \               ^ ^
+        //      |  '------5-------' |
+        //      2                   4
+        //      '-----> c ----------'
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+        graph.add_edge(b, d, 1.into()).unwrap();
+        graph.add_edge(a, d, 5.into()).unwrap();
+        graph.add_edge(a, c, 2.into()).unwrap();
+        graph.add_edge(c, d, 4.into()).unwrap();
+
+        // When: Negating the weights.
+        let negated = map_weights(&graph, DiList::<isize>::init(), |weight| {
+            (-(weight.unwrap() as isize)).into()
+        });
+
+        // Then: Shortest paths in original graph are found by Dijkstra.
+        let shortest = Dijkstra::init(&graph).execute(&graph, a);
+        assert_eq!(shortest.distance_to(d).unwrap(), 2.into());
+
+        // And shortest paths in negated graph are negated longest paths of the original graph.
+        let longest = BellmanFord::init(&negated).execute(&negated, a).unwrap();
+        assert_eq!(longest.distance_to(b).unwrap(), (-1).into());
+        assert_eq!(longest.distance_to(c).unwrap(), (-2).into());
+        assert_eq!(longest.distance_to(d).unwrap(), (-6).into());
+    }
+
+    #[test]
+    fn negated_cycle_is_detected() {
+        // Given: Undirected graph, in which every edge is a cycle once negated.
+        //
+        //      a --1-- b
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        graph.add_edge(a, b, 1.into()).unwrap();
+
+        // When: Negating the weights.
+        let negated = map_weights(&graph, Mat::<isize>::init(), |weight| {
+            (-(weight.unwrap() as isize)).into()
+        });
+
+        // Then: Negated graph is not valid for shortest paths any more.
+        assert!(BellmanFord::init(&negated).execute(&negated, a).is_err());
+    }
+}
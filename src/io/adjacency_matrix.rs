@@ -0,0 +1,328 @@
+use anyhow::Result;
+use magnitude::Magnitude;
+use std::any::Any;
+
+use crate::graph::{Edge, EdgeDir};
+use crate::io::Error;
+use crate::provide::{Edges, Graph, Vertices};
+
+/// Adds vertices and edges described by an adjacency matrix to the graph.
+///
+/// Entry at `matrix[i][j]` is the weight of the edge from the i-th vertex to the j-th one.
+/// Entries can either be weights or magnitudes of weights, so matrices exported by [`to_adjacency_matrix`](crate::io::to_adjacency_matrix) can be loaded back.
+/// Since weighted matrices represent "no edge" in different ways(0, NaN, infinity or another sentinel), `no_edge` decides which entries are not edges.
+/// For undirected graphs only entries on and above the diagonal(`i <= j`) are read.
+///
+/// # Arguments
+/// * `graph`: Graph to add the vertices and edges to.
+/// * `matrix`: Square adjacency matrix.
+/// * `no_edge`: Returns `true` if the entry indicates that there is no edge.
+/// * `diagonal_loops`: If `true`, entries on the diagonal that are edges get added as loops. Otherwise the diagonal is ignored.
+///
+/// # Returns
+/// * `Ok`: Containing id of the added vertices. Vertex at index `i` represents the i-th row of the matrix.
+/// * `Err`:
+///   * If matrix is not square. In this case graph remains untouched.
+///   * Error of calling `add_edge` on the graph(for example adding loops to a simple graph fails).
+///
+/// # Complexity
+/// O(|V|<sup>2</sup>) calls to `no_edge` and O(|E|) calls to `add_edge`.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::DiMat;
+/// use prepona::graph::MatGraph;
+/// use prepona::io::from_adjacency_matrix;
+///
+/// // Given: Matrix that uses 0 for no edge.
+/// let matrix = vec![
+///     vec![0, 2, 0],
+///     vec![0, 0, 5],
+///     vec![1, 0, 0],
+/// ];
+///
+/// // When: Loading the matrix.
+/// let mut graph = MatGraph::init(DiMat::<usize>::init());
+/// let ids = from_adjacency_matrix(&mut graph, &matrix, |weight| *weight == 0, false).unwrap();
+///
+/// // Then:
+/// assert_eq!(graph.vertex_count(), 3);
+/// assert_eq!(graph.edges_count(), 3);
+/// assert_eq!(graph.edges_between(ids[1], ids[2]).unwrap()[0].get_weight(), &5.into());
+/// ```
+pub fn from_adjacency_matrix<W, V, E, Dir, G, F>(
+    graph: &mut G,
+    matrix: &[Vec<V>],
+    no_edge: F,
+    diagonal_loops: bool,
+) -> Result<Vec<usize>>
+where
+    W: Any,
+    V: Clone + Into<Magnitude<W>>,
+    E: Edge<W>,
+    Dir: EdgeDir,
+    G: Graph<W, E, Dir>,
+    F: Fn(&V) -> bool,
+{
+    if let Some((row, entries)) = matrix
+        .iter()
+        .enumerate()
+        .find(|(_, entries)| entries.len() != matrix.len())
+    {
+        Err(Error::new_nsm(row, entries.len(), matrix.len()))?
+    }
+
+    let vertex_ids: Vec<usize> = (0..matrix.len()).map(|_| graph.add_vertex()).collect();
+
+    for (i, entries) in matrix.iter().enumerate() {
+        for (j, weight) in entries.iter().enumerate() {
+            let is_skipped = (i == j && !diagonal_loops) || (Dir::is_undirected() && i > j);
+
+            if !is_skipped && !no_edge(weight) {
+                graph.add_edge(vertex_ids[i], vertex_ids[j], E::init(weight.clone().into()))?;
+            }
+        }
+    }
+
+    Ok(vertex_ids)
+}
+
+/// Exports the graph as an adjacency matrix.
+///
+/// Entry at `[i][j]` of the matrix is the weight of the edge from the i-th vertex to the j-th one, in the order that `vertices` returns them.
+/// Entries on the diagonal are weights of loops. For undirected graphs the matrix is symmetric.
+///
+/// # Arguments
+/// * `graph`: Graph to export.
+/// * `no_edge`: Value of the entries that there is no edge for. Usually `PosInfinite` for distance-like weights, or zero for numerical tools that expect a sparse matrix.
+///
+/// # Returns
+/// * `Ok`: Containing the square matrix with one row for each vertex. Use [`continuos_id_map`](crate::provide::Vertices::continuos_id_map) to find the vertex of each row.
+/// * `Err`: If there are multiple edges between two vertices, since a single entry can not represent them.
+///
+/// # Complexity
+/// O(|V|<sup>2</sup> + |E|)
+///
+/// # Examples
+/// ```
+/// use magnitude::Magnitude;
+/// use prepona::prelude::*;
+/// use prepona::storage::DiMat;
+/// use prepona::graph::MatGraph;
+/// use prepona::io::to_adjacency_matrix;
+///
+/// // Given: Graph
+/// //
+/// //      a --2--> b      c
+/// //
+/// let mut graph = MatGraph::init(DiMat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// graph.add_vertex();
+/// graph.add_edge(a, b, 2.into());
+///
+/// // When: Exporting with infinity for no edge.
+/// let matrix = to_adjacency_matrix(&graph, Magnitude::PosInfinite).unwrap();
+///
+/// // Then:
+/// assert_eq!(matrix.len(), 3);
+/// assert_eq!(matrix[a][b], 2.into());
+/// assert!(matrix[b][a].is_pos_infinite());
+/// ```
+pub fn to_adjacency_matrix<W, E, Dir, G>(
+    graph: &G,
+    no_edge: Magnitude<W>,
+) -> Result<Vec<Vec<Magnitude<W>>>>
+where
+    W: Copy + Any,
+    E: Edge<W>,
+    Dir: EdgeDir,
+    G: Edges<W, E> + Vertices + Graph<W, E, Dir>,
+{
+    let id_map = graph.continuos_id_map();
+    let vertex_count = graph.vertex_count();
+
+    let mut matrix: Vec<Vec<Option<Magnitude<W>>>> = vec![vec![None; vertex_count]; vertex_count];
+    for (src_id, dst_id, edge) in graph.edges() {
+        let (src_virt_id, dst_virt_id) = (id_map.virt_id_of(src_id), id_map.virt_id_of(dst_id));
+        if matrix[src_virt_id][dst_virt_id].is_some() {
+            Err(Error::new_pe(src_id, dst_id))?
+        }
+
+        matrix[src_virt_id][dst_virt_id] = Some(*edge.get_weight());
+        if Dir::is_undirected() {
+            matrix[dst_virt_id][src_virt_id] = Some(*edge.get_weight());
+        }
+    }
+
+    Ok(matrix
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|entry| entry.unwrap_or(no_edge))
+                .collect()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::storage::{DiMat, Mat};
+
+    // Given: Matrix
+    //
+    //      [ 0    2    0   ]
+    //      [ inf  0    NaN ]
+    //      [ 1    inf  7   ]
+    //
+    fn matrix() -> Vec<Vec<f64>> {
+        vec![
+            vec![0.0, 2.0, 0.0],
+            vec![f64::INFINITY, 0.0, f64::NAN],
+            vec![1.0, f64::INFINITY, 7.0],
+        ]
+    }
+
+    fn edge_set<G: Edges<f64, E>, E: Edge<f64>>(graph: &G) -> Vec<(usize, usize)> {
+        let mut edges: Vec<(usize, usize)> = graph
+            .edges()
+            .into_iter()
+            .map(|(src_id, dst_id, _)| (src_id, dst_id))
+            .collect();
+        edges.sort_unstable();
+        edges
+    }
+
+    #[test]
+    fn zero_as_no_edge() {
+        let mut graph = MatGraph::init(DiMat::<f64>::init());
+
+        from_adjacency_matrix(&mut graph, &matrix(), |weight| *weight == 0.0, false).unwrap();
+
+        assert_eq!(
+            edge_set(&graph),
+            vec![(0, 1), (1, 0), (1, 2), (2, 0), (2, 1)]
+        );
+    }
+
+    #[test]
+    fn infinity_and_nan_as_no_edge() {
+        let mut graph = MatGraph::init(DiMat::<f64>::init());
+
+        from_adjacency_matrix(
+            &mut graph,
+            &matrix(),
+            |weight| weight.is_infinite() || weight.is_nan(),
+            false,
+        )
+        .unwrap();
+
+        // Zeros become edges with zero weight.
+        assert_eq!(edge_set(&graph), vec![(0, 1), (0, 2), (2, 0)]);
+        assert_eq!(
+            graph.edges_between(0, 2).unwrap()[0].get_weight().unwrap(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn undirected_graph_reads_upper_triangle() {
+        let mut graph = MatGraph::init(Mat::<f64>::init());
+
+        from_adjacency_matrix(&mut graph, &matrix(), |weight| *weight == 0.0, false).unwrap();
+
+        // Entries below the diagonal are ignored.
+        assert_eq!(edge_set(&graph), vec![(0, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn diagonal_loops() {
+        // Simple graphs do not accept loops, so reading the diagonal fails.
+        let mut graph = MatGraph::init(DiMat::<f64>::init());
+        assert!(
+            from_adjacency_matrix(&mut graph, &matrix(), |weight| *weight == 0.0, true).is_err()
+        );
+
+        // Unless diagonal has no edges.
+        let mut graph = MatGraph::init(DiMat::<f64>::init());
+        from_adjacency_matrix(
+            &mut graph,
+            &matrix(),
+            |weight| *weight == 0.0 || *weight == 7.0,
+            true,
+        )
+        .unwrap();
+        assert_eq!(graph.edges_count(), 5);
+    }
+
+    #[test]
+    fn round_trip_weighted_directed_graph() {
+        // Given: Graph
+        //
+        //      a --2--> b --5--> c
+        //      ^                 |
+        //      '--------1--------'
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 2.into()).unwrap();
+        graph.add_edge(b, c, 5.into()).unwrap();
+        graph.add_edge(c, a, 1.into()).unwrap();
+
+        for no_edge in [Magnitude::PosInfinite, 0.into()] {
+            // When: Exporting the graph and importing the matrix back.
+            let matrix = to_adjacency_matrix(&graph, no_edge).unwrap();
+            let mut imported = MatGraph::init(DiMat::<usize>::init());
+            let ids = from_adjacency_matrix(
+                &mut imported,
+                &matrix,
+                |weight| match no_edge {
+                    Magnitude::Finite(_) => *weight == no_edge,
+                    _ => weight.is_pos_infinite(),
+                },
+                false,
+            )
+            .unwrap();
+
+            // Then:
+            assert_eq!(matrix[a][b], 2.into());
+            assert_eq!(matrix[c][a], 1.into());
+            assert_eq!(imported.edges_count(), 3);
+            for (src_id, dst_id, edge) in graph.edges() {
+                assert_eq!(
+                    imported.edges_between(ids[src_id], ids[dst_id]).unwrap()[0].get_weight(),
+                    edge.get_weight()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn undirected_graph_is_symmetric() {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        graph.add_edge(a, b, 3.into()).unwrap();
+
+        let matrix = to_adjacency_matrix(&graph, 0.into()).unwrap();
+
+        assert_eq!(
+            matrix,
+            vec![vec![0.into(), 3.into()], vec![3.into(), 0.into()]]
+        );
+    }
+
+    #[test]
+    fn non_square_matrix() {
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let matrix = vec![vec![0, 1], vec![1]];
+
+        assert!(from_adjacency_matrix(&mut graph, &matrix, |weight| *weight == 0, false).is_err());
+        assert_eq!(graph.vertex_count(), 0);
+    }
+}
@@ -0,0 +1,180 @@
+use magnitude::Magnitude;
+use std::fmt::Display;
+
+use crate::graph::Edge;
+use crate::provide::{Edges, Vertices};
+
+/// Renders the graph as an adjacency matrix grid of text, useful for debugging small graphs in the terminal.
+///
+/// Rows and columns are labeled with id of the vertices, sorted in ascending order.
+/// Cell at row `u` and column `v` contains:
+/// * Weight of the edge from `u` to `v`, if there is exactly one edge with finite weight.
+/// * `*`, if there is an edge with infinite weight or there are multiple edges.
+/// * `.`, if there is no edge.
+///
+/// All columns have the same width so cells line up.
+///
+/// # Arguments
+/// `graph`: Graph to render.
+///
+/// # Returns
+/// Rendered grid. Each line is terminated by `\n`.
+///
+/// # Complexity
+/// O(|V|<sup>2</sup>) calls to `edges_between`.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::DiMat;
+/// use prepona::graph::MatGraph;
+/// use prepona::io::to_ascii;
+///
+/// // Given: Graph
+/// //
+/// //      a --1--> b --12--> c
+/// //
+/// let mut graph = MatGraph::init(DiMat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// let c = graph.add_vertex();
+/// graph.add_edge(a, b, 1.into());
+/// graph.add_edge(b, c, 12.into());
+///
+/// // Then:
+/// assert_eq!(
+///     to_ascii(&graph),
+///     concat!(
+///         "    0  1  2\n",
+///         " 0  .  1  .\n",
+///         " 1  .  . 12\n",
+///         " 2  .  .  .\n",
+///     )
+/// );
+/// ```
+pub fn to_ascii<W, E, G>(graph: &G) -> String
+where
+    W: Display,
+    E: Edge<W>,
+    G: Edges<W, E> + Vertices,
+{
+    let mut vertices = graph.vertices();
+    vertices.sort_unstable();
+
+    let cells: Vec<Vec<String>> = vertices
+        .iter()
+        .map(|src_id| {
+            vertices
+                .iter()
+                .map(|dst_id| {
+                    let edges = graph.edges_between(*src_id, *dst_id).unwrap();
+
+                    match edges.as_slice() {
+                        [] => ".".to_string(),
+                        [edge] => match edge.get_weight() {
+                            Magnitude::Finite(weight) => weight.to_string(),
+                            _ => "*".to_string(),
+                        },
+                        _ => "*".to_string(),
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    let width = vertices
+        .iter()
+        .map(|vertex_id| vertex_id.to_string().len())
+        .chain(cells.iter().flatten().map(|cell| cell.len()))
+        .max()
+        .unwrap_or(0);
+
+    let mut ascii = String::new();
+
+    // Header row containing labels of the columns.
+    ascii.push_str(&" ".repeat(width));
+    for vertex_id in &vertices {
+        ascii.push_str(&format!(" {:>width$}", vertex_id, width = width));
+    }
+    ascii.push('\n');
+
+    for (vertex_id, row) in vertices.iter().zip(cells) {
+        ascii.push_str(&format!("{:>width$}", vertex_id, width = width));
+        for cell in row {
+            ascii.push_str(&format!(" {:>width$}", cell, width = width));
+        }
+        ascii.push('\n');
+    }
+
+    ascii
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{DefaultEdge, MatGraph};
+    use crate::provide::Graph;
+    use crate::storage::{DiMat, Mat};
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(Mat::<usize>::init());
+
+        assert_eq!(to_ascii(&graph), "\n");
+    }
+
+    #[test]
+    fn undirected_graph() {
+        // Given: Graph
+        //
+        //      a --3-- b --1-- c
+        //      |               |
+        //      '------inf------'
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 3.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph
+            .add_edge(a, c, DefaultEdge::init(Magnitude::PosInfinite))
+            .unwrap();
+
+        // Then: Grid is symmetric.
+        assert_eq!(
+            to_ascii(&graph),
+            concat!("  0 1 2\n", "0 . 3 *\n", "1 3 . 1\n", "2 * 1 .\n",)
+        );
+    }
+
+    #[test]
+    fn wide_labels_and_weights() {
+        // Given: Directed graph with some vertices removed, so labels have different widths.
+        //
+        //      0 --250--> 10 --7--> 3
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let vertices: Vec<usize> = (0..11).map(|_| graph.add_vertex()).collect();
+        for vertex_id in &vertices[4..10] {
+            graph.remove_vertex(*vertex_id).unwrap();
+        }
+        graph.remove_vertex(vertices[1]).unwrap();
+        graph.remove_vertex(vertices[2]).unwrap();
+        graph
+            .add_edge(vertices[0], vertices[10], 250.into())
+            .unwrap();
+        graph.add_edge(vertices[10], vertices[3], 7.into()).unwrap();
+
+        // Then: Columns are as wide as the widest label or weight.
+        assert_eq!(
+            to_ascii(&graph),
+            concat!(
+                "      0   3  10\n",
+                "  0   .   . 250\n",
+                "  3   .   .   .\n",
+                " 10   .   7   .\n",
+            )
+        );
+    }
+}
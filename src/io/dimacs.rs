@@ -0,0 +1,327 @@
+use anyhow::Result;
+use magnitude::Magnitude;
+use num_traits::One;
+use std::any::Any;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::graph::{Edge, EdgeDir};
+use crate::io::Error;
+use crate::provide::{Edges, Graph, Vertices};
+
+/// Adds vertices and edges described in [DIMACS](http://www.diag.uniroma1.it/challenge9/format.shtml) format to the graph.
+///
+/// Each line is either:
+/// * `p format n m`: Problem line, which must come before any edge. Graph has `n` vertices and `m` edges. `format` is not checked, so both `edge` and `sp` are accepted.
+/// * `e u v`: Edge from vertex `u` to vertex `v` with weight of one.
+/// * `a u v w`: Edge from vertex `u` to vertex `v` with weight `w`.
+/// * Empty or starting with `c`(comment): Ignored.
+///
+/// Vertices in DIMACS are numbered from 1 to `n`.
+///
+/// # Arguments
+/// * `graph`: Graph to add the vertices and edges to.
+/// * `text`: Content of the DIMACS file.
+///
+/// # Returns
+/// * `Ok`: Containing id of the added vertices. Vertex at index `i` represents the vertex numbered `i + 1` in DIMACS.
+/// * `Err`:
+///   * If problem line is missing, malformed or repeated, if a line is not in any of the described forms,
+///     if a vertex number is not in range [1, `n`] or if number of edges is not `m`.
+///     Vertices and edges of the lines before the error remain in the graph.
+///   * Error of calling `add_edge` on the graph(for example adding multiple edges to a simple graph fails).
+///
+/// # Complexity
+/// O(|V|) calls to `add_vertex` and O(|E|) calls to `add_edge`.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::DiMat;
+/// use prepona::graph::MatGraph;
+/// use prepona::io::read_dimacs;
+///
+/// // Given: DIMACS text
+/// let text = "c example\np sp 3 2\na 1 2 4\na 2 3 7\n";
+///
+/// // When: Loading the text.
+/// let mut graph = MatGraph::init(DiMat::<usize>::init());
+/// let ids = read_dimacs(&mut graph, text).unwrap();
+///
+/// // Then:
+/// assert_eq!(graph.vertex_count(), 3);
+/// assert_eq!(graph.edges_between(ids[1], ids[2]).unwrap()[0].get_weight(), &7.into());
+/// ```
+pub fn read_dimacs<W, E, Dir, G>(graph: &mut G, text: &str) -> Result<Vec<usize>>
+where
+    W: FromStr + One + Any,
+    E: Edge<W>,
+    Dir: EdgeDir,
+    G: Graph<W, E, Dir>,
+{
+    let mut vertex_ids: Option<Vec<usize>> = None;
+    let mut expected_edges_count = 0;
+    let mut edges_count = 0;
+
+    for (index, line) in text.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim();
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+
+        match tokens.first() {
+            None | Some(&"c") => {}
+
+            Some(&"p") => {
+                if vertex_ids.is_some() {
+                    Err(Error::new_id(line_number, line, "repeated problem line"))?
+                }
+
+                let (vertex_count, count) = match tokens[1..] {
+                    [_, n, m] => n.parse::<usize>().ok().zip(m.parse::<usize>().ok()),
+                    _ => None,
+                }
+                .ok_or_else(|| Error::new_id(line_number, line, "expected `p format n m`"))?;
+
+                vertex_ids = Some((0..vertex_count).map(|_| graph.add_vertex()).collect());
+                expected_edges_count = count;
+            }
+
+            Some(&"e") | Some(&"a") => {
+                let vertex_ids = vertex_ids.as_ref().ok_or_else(|| {
+                    Error::new_id(line_number, line, "edge before the problem line")
+                })?;
+
+                let weight = match tokens[..] {
+                    ["e", _, _] => Some(W::one()),
+                    ["a", _, _, weight] => weight.parse().ok(),
+                    _ => None,
+                }
+                .ok_or_else(|| Error::new_id(line_number, line, "expected `e u v` or `a u v w`"))?;
+
+                let vertex_id_of = |token: &str| {
+                    token
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|number| (1..=vertex_ids.len()).contains(number))
+                        .map(|number| vertex_ids[number - 1])
+                        .ok_or_else(|| Error::new_id(line_number, line, "vertex out of range"))
+                };
+                let src_id = vertex_id_of(tokens[1])?;
+                let dst_id = vertex_id_of(tokens[2])?;
+
+                graph.add_edge(src_id, dst_id, E::init(weight.into()))?;
+                edges_count += 1;
+            }
+
+            _ => Err(Error::new_id(line_number, line, "unknown line"))?,
+        }
+    }
+
+    let vertex_ids = vertex_ids
+        .ok_or_else(|| Error::new_id(text.lines().count(), "", "missing problem line"))?;
+
+    if edges_count != expected_edges_count {
+        Err(Error::new_id(
+            text.lines().count(),
+            "",
+            &format!(
+                "problem line declares {} edges but there are {}",
+                expected_edges_count, edges_count
+            ),
+        ))?
+    }
+
+    Ok(vertex_ids)
+}
+
+/// Renders the graph in [DIMACS](http://www.diag.uniroma1.it/challenge9/format.shtml) format.
+///
+/// Output starts with the `p edge n m` problem line, followed by one line for each edge.
+/// Vertices are numbered from 1 in ascending order of their ids, so vertex with the smallest id is numbered 1.
+/// For undirected graphs each edge is written once.
+///
+/// # Arguments
+/// * `graph`: Graph to render.
+/// * `weighted`: If true, edges are written as `a u v w` lines. Otherwise they are written as `e u v` lines and weights are dropped.
+///   Edges with infinite weight are always written as `e u v` lines, since DIMACS can not represent their weight.
+///
+/// # Returns
+/// Rendered graph, in which edges are sorted by their end points.
+///
+/// # Complexity
+/// O(|V| + |E|log(|E|))
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::DiMat;
+/// use prepona::graph::MatGraph;
+/// use prepona::io::write_dimacs;
+///
+/// // Given: Graph
+/// //
+/// //      a --2--> b
+/// //
+/// let mut graph = MatGraph::init(DiMat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// graph.add_edge(a, b, 2.into());
+///
+/// // Then:
+/// assert_eq!(write_dimacs(&graph, true), "p edge 2 1\na 1 2 2\n");
+/// assert_eq!(write_dimacs(&graph, false), "p edge 2 1\ne 1 2\n");
+/// ```
+pub fn write_dimacs<W, E, Dir, G>(graph: &G, weighted: bool) -> String
+where
+    W: Display,
+    E: Edge<W>,
+    Dir: EdgeDir,
+    G: Graph<W, E, Dir> + Vertices + Edges<W, E>,
+{
+    let mut vertex_ids = graph.vertices();
+    vertex_ids.sort_unstable();
+
+    // Maps id of each vertex to its 1-based number.
+    let number_of = |vertex_id: usize| vertex_ids.binary_search(&vertex_id).unwrap() + 1;
+
+    let mut edges: Vec<(usize, usize, &E)> = graph
+        .edges()
+        .into_iter()
+        .map(|(src_id, dst_id, edge)| (number_of(src_id), number_of(dst_id), edge))
+        .collect();
+    edges.sort_by_key(|(src_number, dst_number, _)| (*src_number, *dst_number));
+
+    let mut text = format!("p edge {} {}\n", vertex_ids.len(), edges.len());
+    for (src_number, dst_number, edge) in edges {
+        match edge.get_weight() {
+            Magnitude::Finite(weight) if weighted => {
+                text.push_str(&format!("a {} {} {}\n", src_number, dst_number, weight))
+            }
+            _ => text.push_str(&format!("e {} {}\n", src_number, dst_number)),
+        }
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::io::ErrorKind;
+    use crate::storage::{DiMat, Mat};
+
+    fn is_invalid_dimacs(result: Result<Vec<usize>>) -> bool {
+        matches!(
+            result.unwrap_err().downcast_ref::<Error>().unwrap().kind(),
+            ErrorKind::InvalidDimacs
+        )
+    }
+
+    #[test]
+    fn round_trip_weighted_directed_graph() {
+        // Given: Graph
+        //
+        //      a --3--> b --1--> c
+        //      ^                 |
+        //      '--------8--------'
+        //
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 3.into()).unwrap();
+        graph.add_edge(b, c, 1.into()).unwrap();
+        graph.add_edge(c, a, 8.into()).unwrap();
+
+        // When: Writing the graph and reading it back.
+        let text = write_dimacs(&graph, true);
+        let mut read = MatGraph::init(DiMat::<usize>::init());
+        let ids = read_dimacs(&mut read, &text).unwrap();
+
+        // Then:
+        assert_eq!(text, "p edge 3 3\na 1 2 3\na 2 3 1\na 3 1 8\n");
+        assert_eq!(read.vertex_count(), 3);
+        assert_eq!(read.edges_count(), 3);
+        for (src_id, dst_id, edge) in graph.edges() {
+            assert_eq!(
+                read.edges_between(ids[src_id], ids[dst_id]).unwrap()[0].get_weight(),
+                edge.get_weight()
+            );
+        }
+        assert_eq!(write_dimacs(&read, true), text);
+    }
+
+    #[test]
+    fn round_trip_unweighted_undirected_graph() {
+        // Given: Graph, in which b is removed so ids are not continuous.
+        //
+        //      a --- c --- d
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        let d = graph.add_vertex();
+        graph.add_edge(a, c, 5.into()).unwrap();
+        graph.add_edge(d, c, 5.into()).unwrap();
+        graph.remove_vertex(b).unwrap();
+
+        // When: Writing the graph without weights and reading it back.
+        let text = write_dimacs(&graph, false);
+        let mut read = MatGraph::init(Mat::<usize>::init());
+        let ids = read_dimacs(&mut read, &text).unwrap();
+
+        // Then: Weights become one.
+        assert_eq!(text, "p edge 3 2\ne 1 2\ne 2 3\n");
+        assert_eq!(read.edges_count(), 2);
+        assert_eq!(
+            read.edges_between(ids[1], ids[2]).unwrap()[0].get_weight(),
+            &1.into()
+        );
+    }
+
+    #[test]
+    fn comments_and_empty_lines() {
+        let text = "c header comes next\n\np edge 2 1\nc edges\ne 2 1\n";
+        let mut graph = MatGraph::init(DiMat::<usize>::init());
+
+        let ids = read_dimacs(&mut graph, text).unwrap();
+
+        assert!(graph.has_any_edge(ids[1], ids[0]).unwrap());
+    }
+
+    #[test]
+    fn malformed_problem_line() {
+        let texts = [
+            "e 1 2\n",
+            "p edge 2\ne 1 2\n",
+            "p edge two 1\n",
+            "p edge 2 0\np edge 2 0\n",
+            "a 1 2 3\n",
+            "",
+        ];
+
+        for text in texts.iter() {
+            let mut graph = MatGraph::init(DiMat::<usize>::init());
+            assert!(is_invalid_dimacs(read_dimacs(&mut graph, text)));
+        }
+    }
+
+    #[test]
+    fn malformed_edges() {
+        let texts = [
+            "p edge 2 1\ne 1 3\n",
+            "p edge 2 1\ne 0 1\n",
+            "p edge 2 1\na 1 2\n",
+            "p edge 2 1\na 1 2 heavy\n",
+            "p edge 2 1\nx 1 2\n",
+            "p edge 2 2\ne 1 2\n",
+        ];
+
+        for text in texts.iter() {
+            let mut graph = MatGraph::init(DiMat::<usize>::init());
+            assert!(is_invalid_dimacs(read_dimacs(&mut graph, text)));
+        }
+    }
+}
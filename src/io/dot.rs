@@ -0,0 +1,246 @@
+use magnitude::Magnitude;
+use std::fmt::Display;
+
+use crate::graph::{Edge, EdgeDir};
+use crate::provide::{Edges, Graph, Vertices};
+
+/// Renders the graph in [DOT](https://graphviz.org/doc/info/lang.html) language, so it can be visualized with Graphviz.
+///
+/// Directed graphs are rendered as `digraph` with `->` edges, and undirected ones as `graph` with `--` edges.
+/// Vertices are named by their ids, and edges with finite weight are labeled with their weight.
+///
+/// # Arguments
+/// `graph`: Graph to render.
+///
+/// # Returns
+/// Rendered graph. Vertices come first in ascending order of their ids, followed by the edges sorted by their end points.
+///
+/// # Complexity
+/// O(|V| + |E|log(|E|))
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::DiMat;
+/// use prepona::graph::MatGraph;
+/// use prepona::io::to_dot;
+///
+/// // Given: Graph
+/// //
+/// //      a --2--> b
+/// //
+/// let mut graph = MatGraph::init(DiMat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// graph.add_edge(a, b, 2.into());
+///
+/// // Then:
+/// assert_eq!(
+///     to_dot(&graph),
+///     concat!("digraph {\n", "    0;\n", "    1;\n", "    0 -> 1 [label=\"2\"];\n", "}\n")
+/// );
+/// ```
+pub fn to_dot<W, E, Dir, G>(graph: &G) -> String
+where
+    W: Display,
+    E: Edge<W>,
+    Dir: EdgeDir,
+    G: Graph<W, E, Dir> + Vertices + Edges<W, E>,
+{
+    render(graph, None::<fn(usize) -> String>)
+}
+
+/// Same as [`to_dot`](crate::io::to_dot), but labels each vertex with the string returned by `vertex_label`.
+///
+/// # Arguments
+/// * `graph`: Graph to render.
+/// * `vertex_label`: Receives id of each vertex and returns its label. Double quotes in the label are escaped.
+///
+/// # Returns
+/// Rendered graph. Vertices come first in ascending order of their ids, followed by the edges sorted by their end points.
+///
+/// # Complexity
+/// O(|V| + |E|log(|E|)) plus |V| calls to `vertex_label`.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::io::to_dot_with;
+///
+/// // Given: Graph
+/// //
+/// //      a --2-- b
+/// //
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let a = graph.add_vertex();
+/// let b = graph.add_vertex();
+/// graph.add_edge(a, b, 2.into());
+///
+/// // When: Labeling vertices with letters.
+/// let dot = to_dot_with(&graph, |vertex_id| ((b'a' + vertex_id as u8) as char).to_string());
+///
+/// // Then:
+/// assert_eq!(
+///     dot,
+///     concat!(
+///         "graph {\n",
+///         "    0 [label=\"a\"];\n",
+///         "    1 [label=\"b\"];\n",
+///         "    0 -- 1 [label=\"2\"];\n",
+///         "}\n"
+///     )
+/// );
+/// ```
+pub fn to_dot_with<W, E, Dir, G, F>(graph: &G, vertex_label: F) -> String
+where
+    W: Display,
+    E: Edge<W>,
+    Dir: EdgeDir,
+    G: Graph<W, E, Dir> + Vertices + Edges<W, E>,
+    F: Fn(usize) -> String,
+{
+    render(graph, Some(vertex_label))
+}
+
+fn render<W, E, Dir, G, F>(graph: &G, vertex_label: Option<F>) -> String
+where
+    W: Display,
+    E: Edge<W>,
+    Dir: EdgeDir,
+    G: Graph<W, E, Dir> + Vertices + Edges<W, E>,
+    F: Fn(usize) -> String,
+{
+    let (keyword, connector) = if Dir::is_directed() {
+        ("digraph", "->")
+    } else {
+        ("graph", "--")
+    };
+
+    let mut dot = format!("{} {{\n", keyword);
+
+    let mut vertices = graph.vertices();
+    vertices.sort_unstable();
+    for vertex_id in vertices {
+        match &vertex_label {
+            Some(vertex_label) => dot.push_str(&format!(
+                "    {} [label=\"{}\"];\n",
+                vertex_id,
+                escape(&vertex_label(vertex_id))
+            )),
+            None => dot.push_str(&format!("    {};\n", vertex_id)),
+        }
+    }
+
+    let mut edges = graph.edges();
+    edges.sort_by_key(|(src_id, dst_id, edge)| (*src_id, *dst_id, edge.get_id()));
+    for (src_id, dst_id, edge) in edges {
+        match edge.get_weight() {
+            Magnitude::Finite(weight) => dot.push_str(&format!(
+                "    {} {} {} [label=\"{}\"];\n",
+                src_id,
+                connector,
+                dst_id,
+                escape(&weight.to_string())
+            )),
+            _ => dot.push_str(&format!("    {} {} {};\n", src_id, connector, dst_id)),
+        }
+    }
+
+    dot.push_str("}\n");
+
+    dot
+}
+
+// Escapes characters that can not appear as is in a quoted string of DOT language.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{DefaultEdge, MatGraph};
+    use crate::storage::{DiMat, Mat};
+
+    #[test]
+    fn empty_graph() {
+        let graph = MatGraph::init(DiMat::<usize>::init());
+
+        assert_eq!(to_dot(&graph), "digraph {\n}\n");
+    }
+
+    #[test]
+    fn undirected_triangle() {
+        // Given: Graph
+        //
+        //      a --1-- b
+        //       \     /
+        //      inf   2
+        //         \ /
+        //          c
+        //
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(b, a, 1.into()).unwrap();
+        graph.add_edge(b, c, 2.into()).unwrap();
+        graph
+            .add_edge(c, a, DefaultEdge::init(Magnitude::PosInfinite))
+            .unwrap();
+
+        // Then: Infinite edge has no label.
+        assert_eq!(
+            to_dot(&graph),
+            concat!(
+                "graph {\n",
+                "    0;\n",
+                "    1;\n",
+                "    2;\n",
+                "    0 -- 1 [label=\"1\"];\n",
+                "    0 -- 2;\n",
+                "    1 -- 2 [label=\"2\"];\n",
+                "}\n"
+            )
+        );
+    }
+
+    #[test]
+    fn directed_triangle_with_labels() {
+        // Given: Graph
+        //
+        //      a --1.5--> b
+        //      ^          |
+        //     3.25        2
+        //      |          v
+        //      '--------- c
+        //
+        let mut graph = MatGraph::init(DiMat::<f64>::init());
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+        let c = graph.add_vertex();
+        graph.add_edge(a, b, 1.5.into()).unwrap();
+        graph.add_edge(b, c, 2.0.into()).unwrap();
+        graph.add_edge(c, a, 3.25.into()).unwrap();
+
+        // When: Labeling vertices with quoted names.
+        let dot = to_dot_with(&graph, |vertex_id| format!("\"v{}\"", vertex_id));
+
+        // Then:
+        assert_eq!(
+            dot,
+            concat!(
+                "digraph {\n",
+                "    0 [label=\"\\\"v0\\\"\"];\n",
+                "    1 [label=\"\\\"v1\\\"\"];\n",
+                "    2 [label=\"\\\"v2\\\"\"];\n",
+                "    0 -> 1 [label=\"1.5\"];\n",
+                "    1 -> 2 [label=\"2\"];\n",
+                "    2 -> 0 [label=\"3.25\"];\n",
+                "}\n"
+            )
+        );
+    }
+}
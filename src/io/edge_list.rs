@@ -0,0 +1,275 @@
+use anyhow::Result;
+use num_traits::One;
+use std::any::Any;
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use crate::graph::{Edge, EdgeDir};
+use crate::io::Error;
+use crate::provide::Graph;
+
+/// Adds vertices and edges described by an edge list to the graph.
+///
+/// Each line of the edge list is either:
+/// * `src dst weight`: Edge from vertex labeled `src` to vertex labeled `dst` with weight `weight`.
+/// * `src dst`: Edge with weight of one.
+/// * Empty or starting with `#`: Ignored.
+///
+/// Labels and weight are separated by whitespace. A vertex is added to the graph the first time its label appears.
+///
+/// # Arguments
+/// * `graph`: Graph to add the vertices and edges to.
+/// * `text`: Edge list.
+///
+/// # Returns
+/// * `Ok`: Containing id of the added vertex for each label.
+/// * `Err`:
+///   * If a line is not in the described form. Vertices and edges of the lines before it remain in the graph.
+///   * Error of calling `add_edge` on the graph(for example adding multiple edges to a simple graph fails).
+///
+/// # Complexity
+/// O(|E|) calls to `add_edge`.
+///
+/// # Examples
+/// ```
+/// use prepona::prelude::*;
+/// use prepona::storage::DiMat;
+/// use prepona::graph::MatGraph;
+/// use prepona::io::from_edge_list;
+///
+/// // Given: Edge list
+/// let text = "# src dst weight\na b 2\nb c 5\nc a\n";
+///
+/// // When: Loading the edge list.
+/// let mut graph = MatGraph::init(DiMat::<usize>::init());
+/// let id_of = from_edge_list(&mut graph, text).unwrap();
+///
+/// // Then:
+/// assert_eq!(graph.vertex_count(), 3);
+/// assert_eq!(graph.edges_count(), 3);
+/// assert_eq!(graph.edges_between(id_of["b"], id_of["c"]).unwrap()[0].get_weight(), &5.into());
+/// assert_eq!(graph.edges_between(id_of["c"], id_of["a"]).unwrap()[0].get_weight(), &1.into());
+/// ```
+pub fn from_edge_list<W, E, Dir, G>(graph: &mut G, text: &str) -> Result<HashMap<String, usize>>
+where
+    W: FromStr + One + Any,
+    E: Edge<W>,
+    Dir: EdgeDir,
+    G: Graph<W, E, Dir>,
+{
+    let mut builder = EdgeListBuilder::init();
+
+    for line in text.lines() {
+        builder.add_line(graph, line)?;
+    }
+
+    Ok(builder.id_of)
+}
+
+/// Same as [`from_edge_list`](crate::io::from_edge_list), but reads the edge list line by line from `reader`.
+///
+/// Only one line is kept in memory at a time, so the edge list text is never materialized as a whole.
+///
+/// # Arguments
+/// * `graph`: Graph to add the vertices and edges to.
+/// * `reader`: Source of the edge list.
+///
+/// # Returns
+/// * `Ok`: Containing id of the added vertex for each label.
+/// * `Err`:
+///   * Error of reading from `reader`.
+///   * Any error that [`from_edge_list`](crate::io::from_edge_list) returns.
+///
+/// # Complexity
+/// O(|E|) calls to `add_edge`.
+///
+/// # Examples
+/// ```
+/// use std::io::Cursor;
+/// use prepona::prelude::*;
+/// use prepona::storage::Mat;
+/// use prepona::graph::MatGraph;
+/// use prepona::io::from_edge_list_reader;
+///
+/// // Given: Source of edge list.
+/// let reader = Cursor::new("a b 2\nb c 5\n");
+///
+/// // When: Loading the edge list.
+/// let mut graph = MatGraph::init(Mat::<usize>::init());
+/// let id_of = from_edge_list_reader(&mut graph, reader).unwrap();
+///
+/// // Then:
+/// assert_eq!(graph.vertex_count(), 3);
+/// assert!(graph.has_any_edge(id_of["c"], id_of["b"]).unwrap());
+/// ```
+pub fn from_edge_list_reader<W, E, Dir, G>(
+    graph: &mut G,
+    mut reader: impl BufRead,
+) -> Result<HashMap<String, usize>>
+where
+    W: FromStr + One + Any,
+    E: Edge<W>,
+    Dir: EdgeDir,
+    G: Graph<W, E, Dir>,
+{
+    let mut builder = EdgeListBuilder::init();
+    let mut line = String::new();
+
+    while reader.read_line(&mut line)? != 0 {
+        builder.add_line(graph, &line)?;
+        line.clear();
+    }
+
+    Ok(builder.id_of)
+}
+
+// State of loading an edge list, shared between loading from text and from a reader.
+struct EdgeListBuilder {
+    id_of: HashMap<String, usize>,
+    line_number: usize,
+}
+
+impl EdgeListBuilder {
+    fn init() -> Self {
+        EdgeListBuilder {
+            id_of: HashMap::new(),
+            line_number: 0,
+        }
+    }
+
+    fn add_line<W, E, Dir, G>(&mut self, graph: &mut G, line: &str) -> Result<()>
+    where
+        W: FromStr + One + Any,
+        E: Edge<W>,
+        Dir: EdgeDir,
+        G: Graph<W, E, Dir>,
+    {
+        self.line_number += 1;
+
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(());
+        }
+
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        let weight = match tokens.len() {
+            2 => W::one(),
+            3 => tokens[2]
+                .parse()
+                .map_err(|_| Error::new_iel(self.line_number, line))?,
+            _ => Err(Error::new_iel(self.line_number, line))?,
+        };
+
+        let src_id = self.vertex_id_of(graph, tokens[0]);
+        let dst_id = self.vertex_id_of(graph, tokens[1]);
+        graph.add_edge(src_id, dst_id, E::init(weight.into()))?;
+
+        Ok(())
+    }
+
+    // Returns id of the vertex with `label`, after adding it to the graph if it is the first time the label appears.
+    fn vertex_id_of<W, E, Dir, G>(&mut self, graph: &mut G, label: &str) -> usize
+    where
+        E: Edge<W>,
+        Dir: EdgeDir,
+        G: Graph<W, E, Dir>,
+    {
+        match self.id_of.get(label) {
+            Some(vertex_id) => *vertex_id,
+            None => {
+                let vertex_id = graph.add_vertex();
+                self.id_of.insert(label.to_string(), vertex_id);
+                vertex_id
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::MatGraph;
+    use crate::provide::{Edges, Vertices};
+    use crate::storage::{DiMat, Mat};
+    use std::io::Cursor;
+
+    fn edge_set<G: Edges<usize, E>, E: Edge<usize>>(
+        graph: &G,
+        id_of: &HashMap<String, usize>,
+    ) -> Vec<(String, String, usize)> {
+        let label_of: HashMap<usize, String> = id_of
+            .iter()
+            .map(|(label, vertex_id)| (*vertex_id, label.clone()))
+            .collect();
+
+        let mut edges: Vec<(String, String, usize)> = graph
+            .edges()
+            .into_iter()
+            .map(|(src_id, dst_id, edge)| {
+                (
+                    label_of[&src_id].clone(),
+                    label_of[&dst_id].clone(),
+                    *edge.get_weight().as_ref().unwrap(),
+                )
+            })
+            .collect();
+        edges.sort_unstable();
+        edges
+    }
+
+    #[test]
+    fn reader_matches_text() {
+        // Given: Edge list with comments, blank lines, unweighted edges and irregular whitespace.
+        let text = "# comment\n\n  1 2 3\n2\t3 4\r\n3 1\n\n4 2 10\n  # another comment";
+
+        // When: Loading it both from text and from a cursor over it.
+        let mut text_graph = MatGraph::init(DiMat::<usize>::init());
+        let text_id_of = from_edge_list(&mut text_graph, text).unwrap();
+        let mut reader_graph = MatGraph::init(DiMat::<usize>::init());
+        let reader_id_of = from_edge_list_reader(&mut reader_graph, Cursor::new(text)).unwrap();
+
+        // Then: Both produce the same graph.
+        assert_eq!(text_graph.vertex_count(), 4);
+        assert_eq!(reader_graph.vertex_count(), 4);
+        assert_eq!(
+            edge_set(&text_graph, &text_id_of),
+            edge_set(&reader_graph, &reader_id_of)
+        );
+        assert_eq!(
+            edge_set(&reader_graph, &reader_id_of),
+            vec![
+                ("1".to_string(), "2".to_string(), 3),
+                ("2".to_string(), "3".to_string(), 4),
+                ("3".to_string(), "1".to_string(), 1),
+                ("4".to_string(), "2".to_string(), 10),
+            ]
+        );
+    }
+
+    #[test]
+    fn undirected_graph() {
+        let mut graph = MatGraph::init(Mat::<usize>::init());
+
+        let id_of =
+            from_edge_list_reader(&mut graph, Cursor::new("a b 1\nb c 2\nc a 3\n")).unwrap();
+
+        assert_eq!(graph.edges_count(), 3);
+        assert!(graph.has_any_edge(id_of["b"], id_of["a"]).unwrap());
+
+        // Edge between a and b already exists.
+        assert!(from_edge_list_reader(&mut graph, Cursor::new("a b 1\nb a 1\n")).is_err());
+    }
+
+    #[test]
+    fn invalid_lines() {
+        for text in ["a b 1\na\n", "a b 1\na b c d\n", "a b 1\na b x\n"].iter() {
+            let mut graph = MatGraph::init(DiMat::<usize>::init());
+
+            let error = from_edge_list_reader(&mut graph, Cursor::new(text)).unwrap_err();
+
+            assert!(error.to_string().starts_with("Line 2 "));
+            assert_eq!(graph.edges_count(), 1);
+        }
+    }
+}
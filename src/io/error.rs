@@ -0,0 +1,125 @@
+/// Types of errors that may happen when importing or exporting graphs.
+pub enum ErrorKind {
+    NonSquareMatrix,
+    InvalidEdgeList,
+    ParallelEdges,
+    InvalidDimacs,
+}
+
+/// Error type returns in [`io`](crate::io) module.
+pub struct Error {
+    kind: ErrorKind,
+    msg: String,
+}
+
+impl Error {
+    /// # Arguments
+    /// * `kind`: Specifies what kind of error is being created.
+    /// * `msg`: Cause of the error.
+    ///
+    /// # Returns
+    /// Constructed `Error`.
+    pub fn new(kind: ErrorKind, msg: String) -> Self {
+        Error { kind, msg }
+    }
+
+    /// Creates a new [`NonSquareMatrix`](crate::io::ErrorKind::NonSquareMatrix) kind of error.
+    ///
+    /// # Arguments
+    /// * `row`: Index of the row that its length is different from number of rows.
+    /// * `row_len`: Length of the row.
+    /// * `rows_count`: Number of rows of the matrix.
+    ///
+    /// # Returns
+    /// `Error` with `NonSquareMatrix` kind and predefined message.
+    pub fn new_nsm(row: usize, row_len: usize, rows_count: usize) -> Self {
+        Error {
+            kind: ErrorKind::NonSquareMatrix,
+            msg: format!(
+                "Matrix must be square, but row {} has {} entries while there are {} rows",
+                row, row_len, rows_count
+            ),
+        }
+    }
+
+    /// Creates a new [`InvalidEdgeList`](crate::io::ErrorKind::InvalidEdgeList) kind of error.
+    ///
+    /// # Arguments
+    /// * `line_number`: Number of the offending line, starting from 1.
+    /// * `line`: Content of the offending line.
+    ///
+    /// # Returns
+    /// `Error` with `InvalidEdgeList` kind and predefined message.
+    pub fn new_iel(line_number: usize, line: &str) -> Self {
+        Error {
+            kind: ErrorKind::InvalidEdgeList,
+            msg: format!(
+                "Line {} must be in the form of `src dst [weight]`, but it is: {}",
+                line_number, line
+            ),
+        }
+    }
+
+    /// Creates a new [`ParallelEdges`](crate::io::ErrorKind::ParallelEdges) kind of error.
+    ///
+    /// # Arguments
+    /// * `src_id`: Id of the source vertex of the parallel edges.
+    /// * `dst_id`: Id of the destination vertex of the parallel edges.
+    ///
+    /// # Returns
+    /// `Error` with `ParallelEdges` kind and predefined message.
+    pub fn new_pe(src_id: usize, dst_id: usize) -> Self {
+        Error {
+            kind: ErrorKind::ParallelEdges,
+            msg: format!(
+                "There are multiple edges from vertex {} to vertex {}, which a single entry can not represent",
+                src_id, dst_id
+            ),
+        }
+    }
+
+    /// Creates a new [`InvalidDimacs`](crate::io::ErrorKind::InvalidDimacs) kind of error.
+    ///
+    /// # Arguments
+    /// * `line_number`: Number of the offending line, starting from 1.
+    /// * `line`: Content of the offending line.
+    /// * `reason`: Why the line is invalid.
+    ///
+    /// # Returns
+    /// `Error` with `InvalidDimacs` kind and predefined message.
+    pub fn new_id(line_number: usize, line: &str, reason: &str) -> Self {
+        Error {
+            kind: ErrorKind::InvalidDimacs,
+            msg: format!(
+                "Line {} is invalid DIMACS({}): {}",
+                line_number, reason, line
+            ),
+        }
+    }
+
+    /// # Returns
+    /// Message inside of the error.
+    pub fn msg(&self) -> &str {
+        &self.msg
+    }
+
+    /// # Returns
+    /// What kind the error is.
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+}
+
+impl std::fmt::Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.msg())
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.msg())
+    }
+}
+
+impl std::error::Error for Error {}
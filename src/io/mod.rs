@@ -0,0 +1,15 @@
+mod adjacency_matrix;
+mod ascii;
+mod dimacs;
+mod dot;
+mod edge_list;
+mod error;
+mod validate;
+
+pub use adjacency_matrix::{from_adjacency_matrix, to_adjacency_matrix};
+pub use ascii::to_ascii;
+pub use dimacs::{read_dimacs, write_dimacs};
+pub use dot::{to_dot, to_dot_with};
+pub use edge_list::{from_edge_list, from_edge_list_reader};
+pub use error::{Error, ErrorKind};
+pub use validate::validate_simple_adjacency;
@@ -0,0 +1,153 @@
+use num_traits::Zero;
+
+use crate::graph::EdgeDir;
+
+/// Checks whether an adjacency matrix describes a valid simple graph.
+///
+/// A matrix is valid if:
+/// * It is square.
+/// * All entries are non-negative.
+/// * All entries on the diagonal are zero, so there is no loop.
+/// * It is symmetric, if `Dir` is undirected.
+///
+/// Entries are checked row by row, and the first offending entry is reported.
+///
+/// # Arguments
+/// `matrix`: Adjacency matrix to validate.
+///
+/// ## Generic Parameters
+/// * `Dir`: **Dir**ection of the graph that matrix is going to be imported into: [`Directed`](crate::graph::DirectedEdge) or [`Undirected`](crate::graph::UndirectedEdge).
+/// * `W`: **W**eight type of the entries.
+///
+/// # Returns
+/// * `Ok`: If matrix is valid.
+/// * `Err`: Containing a message that describes the offending row or entry.
+///
+/// # Complexity
+/// O(|V|<sup>2</sup>)
+///
+/// # Examples
+/// ```
+/// use prepona::graph::{DirectedEdge, UndirectedEdge};
+/// use prepona::io::validate_simple_adjacency;
+///
+/// // Given: Matrix that is not symmetric.
+/// let matrix = vec![
+///     vec![0, 2, 0],
+///     vec![2, 0, 5],
+///     vec![0, 0, 0],
+/// ];
+///
+/// // Then: It is only valid for directed graphs.
+/// assert!(validate_simple_adjacency::<DirectedEdge, _>(&matrix).is_ok());
+/// assert_eq!(
+///     validate_simple_adjacency::<UndirectedEdge, _>(&matrix).unwrap_err(),
+///     "Matrix must be symmetric, but entry at (1, 2) is different from entry at (2, 1)"
+/// );
+/// ```
+pub fn validate_simple_adjacency<Dir, W>(matrix: &[Vec<W>]) -> Result<(), String>
+where
+    Dir: EdgeDir,
+    W: PartialOrd + Zero,
+{
+    for (i, entries) in matrix.iter().enumerate() {
+        if entries.len() != matrix.len() {
+            return Err(format!(
+                "Matrix must be square, but row {} has {} entries while there are {} rows",
+                i,
+                entries.len(),
+                matrix.len()
+            ));
+        }
+    }
+
+    for (i, entries) in matrix.iter().enumerate() {
+        for (j, weight) in entries.iter().enumerate() {
+            if *weight < W::zero() {
+                return Err(format!("Entry at ({}, {}) is negative", i, j));
+            }
+
+            if i == j && !weight.is_zero() {
+                return Err(format!(
+                    "Entry at ({}, {}) is on the diagonal and must be zero, because loops are not allowed",
+                    i, j
+                ));
+            }
+
+            if Dir::is_undirected() && i < j && *weight != matrix[j][i] {
+                return Err(format!(
+                    "Matrix must be symmetric, but entry at ({}, {}) is different from entry at ({}, {})",
+                    i, j, j, i
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{DirectedEdge, UndirectedEdge};
+
+    #[test]
+    fn valid_matrix() {
+        let matrix = vec![
+            vec![0.0, 1.5, 0.0],
+            vec![1.5, 0.0, 2.0],
+            vec![0.0, 2.0, 0.0],
+        ];
+
+        assert!(validate_simple_adjacency::<UndirectedEdge, _>(&matrix).is_ok());
+        assert!(validate_simple_adjacency::<DirectedEdge, _>(&matrix).is_ok());
+    }
+
+    #[test]
+    fn empty_matrix() {
+        let matrix: Vec<Vec<usize>> = vec![];
+
+        assert!(validate_simple_adjacency::<UndirectedEdge, _>(&matrix).is_ok());
+    }
+
+    #[test]
+    fn non_square_matrix() {
+        let matrix = vec![vec![0, 1], vec![1, 0, 0]];
+
+        assert_eq!(
+            validate_simple_adjacency::<DirectedEdge, _>(&matrix).unwrap_err(),
+            "Matrix must be square, but row 1 has 3 entries while there are 2 rows"
+        );
+    }
+
+    #[test]
+    fn negative_entry() {
+        let matrix = vec![vec![0, 1, 0], vec![1, 0, 0], vec![0, -3, 0]];
+
+        assert_eq!(
+            validate_simple_adjacency::<DirectedEdge, _>(&matrix).unwrap_err(),
+            "Entry at (2, 1) is negative"
+        );
+    }
+
+    #[test]
+    fn loop_on_diagonal() {
+        let matrix = vec![vec![0, 1], vec![1, 4]];
+
+        assert_eq!(
+            validate_simple_adjacency::<UndirectedEdge, _>(&matrix).unwrap_err(),
+            "Entry at (1, 1) is on the diagonal and must be zero, because loops are not allowed"
+        );
+    }
+
+    #[test]
+    fn asymmetric_matrix() {
+        let matrix = vec![vec![0, 1, 2], vec![1, 0, 0], vec![0, 0, 0]];
+
+        assert!(validate_simple_adjacency::<DirectedEdge, _>(&matrix).is_ok());
+        assert_eq!(
+            validate_simple_adjacency::<UndirectedEdge, _>(&matrix).unwrap_err(),
+            "Matrix must be symmetric, but entry at (0, 2) is different from entry at (2, 0)"
+        );
+    }
+}
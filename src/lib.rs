@@ -1,3 +1,6 @@
+// Tests assert on `vec!` literals and literal booleans for readability, which clippy would flag.
+#![cfg_attr(test, allow(clippy::useless_vec, clippy::bool_assert_comparison, clippy::get_first))]
+
 /// Containing algorithms that can get executed on graphs and subgraphs.
 ///
 /// Graphs and subgraphs expose some functionalities defined in the [`provide`](crate::provide) module.
@@ -5,6 +8,14 @@
 /// So for one algorithm to be executable on a specific graph or subgraph, it is necessary for the graphs exposed functionalities to match the requirements of the algorithm.
 pub mod algo;
 
+/// Generators that build graphs with a specific structure.
+///
+/// Each generator can either add its vertices and edges to an existing graph using `generate_into`, or build a new graph using `generate`.
+pub mod gen;
+
+/// Importing graphs from and exporting them to other formats.
+pub mod io;
+
 /// Re-exports traits and structs that are necessary to accomplish basic tasks with prepona.
 pub mod prelude;
 
@@ -30,11 +41,11 @@ pub mod provide;
 ///
 /// There are two storage types that are supported:
 /// * Adjacency matrix: Is a matrix used to represent a finite graph.
-///                     The elements of the matrix indicate whether pairs of vertices are adjacent or not in the graph.
-///                     For more info read [`AdjMatrix`](crate::storage::AdjMatrix).
+///   The elements of the matrix indicate whether pairs of vertices are adjacent or not in the graph.
+///   For more info read [`AdjMatrix`](crate::storage::AdjMatrix).
 /// * Adjacency list:   Is a collection of unordered lists used to represent a finite graph.
-///                     Each list describes the set of neighbors of a vertex in the graph.
-///                     For more info read [`AdjList`](crate::storage::AdjList)
+///   Each list describes the set of neighbors of a vertex in the graph.
+///   For more info read [`AdjList`](crate::storage::AdjList)
 ///
 /// Each storage must implement the [`GraphStorage`](crate::storage::GraphStorage) trait.
 /// So You can create your own storage and after implementing the `GraphStorage`, pass it to the graph to use it as backend storage of the graph.
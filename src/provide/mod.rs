@@ -14,6 +14,19 @@ pub trait Neighbors {
     /// * `Err`
     /// * `Ok`: Containing Id of vertices accessible from source vertex using one edge.
     fn neighbors(&self, src_id: usize) -> Result<Vec<usize>>;
+
+    /// # Arguments:
+    /// * `src_id`: Id of the source vertex.
+    /// * `dst_id`: Id of the destination vertex.
+    ///
+    /// # Returns
+    /// * `true`: If vertex with id: `dst_id` is accessible from vertex with id: `src_id` using one edge.
+    /// * `false`: Otherwise, or if either of the vertices does not exist.
+    fn is_adjacent(&self, src_id: usize, dst_id: usize) -> bool {
+        self.neighbors(src_id)
+            .map(|neighbors| neighbors.contains(&dst_id))
+            .unwrap_or(false)
+    }
 }
 
 /// Provides access to vertices of the graph.
@@ -5,7 +5,7 @@ use anyhow::Result;
 use quickcheck::Arbitrary;
 
 use crate::graph::{DefaultEdge, DirectedEdge, Edge, EdgeDir, FlowEdge, UndirectedEdge};
-use crate::storage::{Error, GraphStorage};
+use crate::storage::{Error, GraphStorage, VertexIdPolicy};
 
 /// An adjacency list that uses [`undirected`](crate::graph::UndirectedEdge) [`default edges`](crate::graph::DefaultEdge).
 pub type List<W, Dir = UndirectedEdge> = AdjList<W, DefaultEdge<W>, Dir>;
@@ -24,9 +24,9 @@ pub type DiFlowList<W> = AdjList<W, FlowEdge<W>, DirectedEdge>;
 /// ## Note
 /// From now on
 /// * |V|: Means total number of vertices that are stored in the storage.
-/// Note that this is different from number of vertices that are present in the graph.
-/// Because even if you remove a vertex from storage, the allocated memory for that vertex will not get freed and will be reused again when adding a new vertex.
-/// You can retrieve the amount of |V| using `total_vertex_count` function(as opposed to number of vertices present in the graph which can be retrieved using `vertex_count` function).
+///   Note that this is different from number of vertices that are present in the graph.
+///   Because even if you remove a vertex from storage, the allocated memory for that vertex will not get freed and will be reused again when adding a new vertex.
+///   You can retrieve the amount of |V| using `total_vertex_count` function(as opposed to number of vertices present in the graph which can be retrieved using `vertex_count` function).
 /// * |E|: Means number of edges present in the graph.
 /// * |E<sub>out</sub>|: Means number of edges exiting a vertex(out degree of the vertex).
 ///
@@ -42,6 +42,7 @@ pub type DiFlowList<W> = AdjList<W, FlowEdge<W>, DirectedEdge>;
 pub struct AdjList<W, E: Edge<W>, Dir: EdgeDir = UndirectedEdge> {
     edges_of: Vec<Vec<(usize, E)>>,
     reusable_vertex_ids: HashSet<usize>,
+    vertex_id_policy: VertexIdPolicy,
 
     max_edge_id: usize,
     reusable_edge_ids: HashSet<usize>,
@@ -83,6 +84,7 @@ impl<W: Clone, E: Edge<W> + Clone, Dir: EdgeDir> AdjList<W, E, Dir> {
         AdjList {
             edges_of: vec![],
             reusable_vertex_ids: HashSet::new(),
+            vertex_id_policy: VertexIdPolicy::default(),
 
             max_edge_id: 0,
             reusable_edge_ids: HashSet::new(),
@@ -101,6 +103,10 @@ impl<W: Clone, E: Edge<W> + Clone, Dir: EdgeDir> AdjList<W, E, Dir> {
     // # Complexity
     // O(1)
     fn next_reusable_vertex_id(&mut self) -> Option<usize> {
+        if self.vertex_id_policy == VertexIdPolicy::Tombstone {
+            return None;
+        }
+
         if let Some(id) = self.reusable_vertex_ids.iter().take(1).next().copied() {
             self.reusable_vertex_ids.remove(&id);
 
@@ -242,6 +248,23 @@ impl<W: Clone, E: Edge<W> + Clone, Dir: EdgeDir> GraphStorage<W, E, Dir> for Adj
         }
     }
 
+    /// Adds `count` vertices to the storage.
+    ///
+    /// # Arguments
+    /// `count`: Number of vertices to add.
+    ///
+    /// # Returns
+    /// Unique ids of the newly added vertices.
+    ///
+    /// # Complexity
+    /// O(`count`)
+    fn add_vertices(&mut self, count: usize) -> Vec<usize> {
+        self.edges_of
+            .reserve(count.saturating_sub(self.reusable_vertex_ids.len()));
+
+        (0..count).map(|_| self.add_vertex()).collect()
+    }
+
     /// Removes the vertex with id: `vertex_id` from storage.
     ///
     /// # Arguments
@@ -270,6 +293,20 @@ impl<W: Clone, E: Edge<W> + Clone, Dir: EdgeDir> GraphStorage<W, E, Dir> for Adj
         Ok(())
     }
 
+    /// # Returns
+    /// Policy that storage uses for ids of the removed vertices.
+    fn vertex_id_policy(&self) -> VertexIdPolicy {
+        self.vertex_id_policy
+    }
+
+    /// Changes the policy that storage uses for ids of the removed vertices.
+    ///
+    /// # Arguments
+    /// `policy`: New policy.
+    fn set_vertex_id_policy(&mut self, policy: VertexIdPolicy) {
+        self.vertex_id_policy = policy
+    }
+
     /// Adds `edge` from vertex with id `src_id`: to vertex with id: `dst_id`.
     ///
     /// # Arguments
@@ -401,6 +438,26 @@ impl<W: Clone, E: Edge<W> + Clone, Dir: EdgeDir> GraphStorage<W, E, Dir> for Adj
         }
     }
 
+    /// Reverses direction of all edges in place by moving each edge to the list of its destination.
+    /// Ids of the edges remain unchanged.
+    ///
+    /// # Complexity
+    /// O(|V| + |E|)
+    fn reverse_edges(&mut self) {
+        if Dir::is_undirected() {
+            return;
+        }
+
+        let mut reversed_edges_of = vec![vec![]; self.edges_of.len()];
+        for (src_id, edges) in self.edges_of.iter_mut().enumerate() {
+            for (dst_id, edge) in edges.drain(..) {
+                reversed_edges_of[dst_id].push((src_id, edge));
+            }
+        }
+
+        self.edges_of = reversed_edges_of;
+    }
+
     /// # Returns
     /// Number of vertices in the storage.
     ///
@@ -459,7 +516,7 @@ impl<W: Clone, E: Edge<W> + Clone, Dir: EdgeDir> GraphStorage<W, E, Dir> for Adj
     fn neighbors(&self, src_id: usize) -> Result<Vec<usize>> {
         Ok(self
             .edges_of(src_id)?
-            .into_iter()
+            .iter()
             .map(|(dst_id, _)| *dst_id)
             .collect())
     }
@@ -477,7 +534,7 @@ impl<W: Clone, E: Edge<W> + Clone, Dir: EdgeDir> GraphStorage<W, E, Dir> for Adj
     fn edges_between(&self, src_id: usize, dst_id: usize) -> Result<Vec<&E>> {
         Ok(self
             .edges_of(src_id)?
-            .into_iter()
+            .iter()
             .filter_map(|(d_id, edge)| if *d_id == dst_id { Some(edge) } else { None })
             .collect())
     }
@@ -522,7 +579,7 @@ impl<W: Clone, E: Edge<W> + Clone, Dir: EdgeDir> GraphStorage<W, E, Dir> for Adj
             // So calling `edges_of_unsafe` is reasonable.
             let mut out_going_edges = self
                 .edges_of_unsafe(src_id)
-                .into_iter()
+                .iter()
                 .map(|(dst_id, edge)| (src_id, *dst_id, edge))
                 .collect();
 
@@ -545,7 +602,7 @@ impl<W: Clone, E: Edge<W> + Clone, Dir: EdgeDir> GraphStorage<W, E, Dir> for Adj
     fn has_any_edge(&self, src_id: usize, dst_id: usize) -> Result<bool> {
         Ok(self
             .edges_of(src_id)?
-            .into_iter()
+            .iter()
             .any(|(d_id, _)| *d_id == dst_id))
     }
 
@@ -571,7 +628,7 @@ impl<W: Clone, E: Edge<W> + Clone, Dir: EdgeDir> GraphStorage<W, E, Dir> for Adj
             // So calling `edges_of_unsafe` is reasonable.
             if let Some(edge) = self
                 .edges_of_unsafe(vertex_id)
-                .into_iter()
+                .iter()
                 .find(|(_, edge)| edge.get_id() == edge_id)
                 .map(|(_, edge)| edge)
             {
@@ -668,6 +725,7 @@ impl<W: Clone + 'static, E: Edge<W> + Clone, Dir: EdgeDir> Clone for AdjList<W,
         AdjList {
             edges_of: self.edges_of.clone(),
             reusable_vertex_ids: self.reusable_vertex_ids.clone(),
+            vertex_id_policy: self.vertex_id_policy,
 
             max_edge_id: self.max_edge_id,
             reusable_edge_ids: self.reusable_edge_ids.clone(),
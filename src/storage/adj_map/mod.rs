@@ -13,7 +13,7 @@ use crate::{
 use anyhow::Result;
 use quickcheck::Arbitrary;
 
-use super::{Error, GraphStorage};
+use super::{Error, GraphStorage, VertexIdPolicy};
 
 /// An adjacency map that uses [`undirected`](crate::graph::UndirectedEdge) [`default edges`](crate::graph::DefaultEdge).
 pub type Map<W, Dir = UndirectedEdge> = AdjMap<W, DefaultEdge<W>, Dir>;
@@ -49,6 +49,7 @@ pub struct AdjMap<W: Clone, E: Edge<W> + Clone, Dir: EdgeDir = UndirectedEdge> {
     map: HashMap<usize, HashMap<usize, Vec<E>>>,
 
     reusable_vertex_ids: HashSet<usize>,
+    vertex_id_policy: VertexIdPolicy,
     reusable_edge_ids: HashSet<usize>,
 
     vertex_count: usize,
@@ -91,6 +92,7 @@ impl<W: Clone, E: Edge<W> + Clone, Dir: EdgeDir> AdjMap<W, E, Dir> {
             map: HashMap::new(),
 
             reusable_vertex_ids: HashSet::new(),
+            vertex_id_policy: VertexIdPolicy::default(),
             reusable_edge_ids: HashSet::new(),
 
             vertex_count: 0,
@@ -109,6 +111,10 @@ impl<W: Clone, E: Edge<W> + Clone, Dir: EdgeDir> AdjMap<W, E, Dir> {
     // # Complexity
     // O(1)
     fn next_reusable_vertex_id(&mut self) -> Option<usize> {
+        if self.vertex_id_policy == VertexIdPolicy::Tombstone {
+            return None;
+        }
+
         if let Some(id) = self.reusable_vertex_ids.iter().take(1).next().copied() {
             self.reusable_vertex_ids.remove(&id);
 
@@ -228,7 +234,8 @@ impl<W: Clone, E: Edge<W> + Clone, Dir: EdgeDir> GraphStorage<W, E, Dir> for Adj
         let vertex_id = if let Some(reusable_id) = self.next_reusable_vertex_id() {
             reusable_id
         } else {
-            self.vertex_count
+            // Under `Tombstone` policy, ids of the removed vertices are still taken.
+            self.vertex_count + self.reusable_vertex_ids.len()
         };
 
         self.map.insert(vertex_id, HashMap::new());
@@ -238,6 +245,22 @@ impl<W: Clone, E: Edge<W> + Clone, Dir: EdgeDir> GraphStorage<W, E, Dir> for Adj
         vertex_id
     }
 
+    /// Adds `count` vertices to the storage.
+    ///
+    /// # Arguments
+    /// `count`: Number of vertices to add.
+    ///
+    /// # Returns
+    /// Unique ids of the newly added vertices.
+    ///
+    /// # Complexity
+    /// O(`count`)
+    fn add_vertices(&mut self, count: usize) -> Vec<usize> {
+        self.map.reserve(count);
+
+        (0..count).map(|_| self.add_vertex()).collect()
+    }
+
     /// Removes the vertex with id: `vertex_id` from storage.
     ///
     /// # Arguments
@@ -250,7 +273,7 @@ impl<W: Clone, E: Edge<W> + Clone, Dir: EdgeDir> GraphStorage<W, E, Dir> for Adj
     /// # Complexity
     /// O(|V|)
     fn remove_vertex(&mut self, vertex_id: usize) -> Result<()> {
-        if let Some(_) = self.map.remove(&vertex_id) {
+        if self.map.remove(&vertex_id).is_some() {
             // `v_id` comes from `vertices()`. So it's always valid.
             // So it's reasonable to use `index_mut`.
             for v_id in self.vertices() {
@@ -267,6 +290,20 @@ impl<W: Clone, E: Edge<W> + Clone, Dir: EdgeDir> GraphStorage<W, E, Dir> for Adj
         }
     }
 
+    /// # Returns
+    /// Policy that storage uses for ids of the removed vertices.
+    fn vertex_id_policy(&self) -> VertexIdPolicy {
+        self.vertex_id_policy
+    }
+
+    /// Changes the policy that storage uses for ids of the removed vertices.
+    ///
+    /// # Arguments
+    /// `policy`: New policy.
+    fn set_vertex_id_policy(&mut self, policy: VertexIdPolicy) {
+        self.vertex_id_policy = policy
+    }
+
     /// Adds `edge` from vertex with id `src_id`: to vertex with id: `dst_id`.
     ///
     /// # Arguments
@@ -395,6 +432,31 @@ impl<W: Clone, E: Edge<W> + Clone, Dir: EdgeDir> GraphStorage<W, E, Dir> for Adj
         }
     }
 
+    /// Reverses direction of all edges in place by moving each list of edges from `map[src][dst]` to `map[dst][src]`.
+    /// Ids of the edges remain unchanged.
+    ///
+    /// # Complexity
+    /// O(|V| + |E|)
+    fn reverse_edges(&mut self) {
+        if Dir::is_undirected() {
+            return;
+        }
+
+        let mut reversed_map: HashMap<usize, HashMap<usize, Vec<E>>> = self
+            .map
+            .keys()
+            .map(|vertex_id| (*vertex_id, HashMap::new()))
+            .collect();
+
+        for (src_id, edges_to) in self.map.drain() {
+            for (dst_id, edges) in edges_to {
+                reversed_map.get_mut(&dst_id).unwrap().insert(src_id, edges);
+            }
+        }
+
+        self.map = reversed_map;
+    }
+
     /// # Returns
     /// Number of vertices in the storage.
     ///
@@ -437,7 +499,7 @@ impl<W: Clone, E: Edge<W> + Clone, Dir: EdgeDir> GraphStorage<W, E, Dir> for Adj
             .iter()
             .flat_map(|(dst_id, edges)| {
                 edges
-                    .into_iter()
+                    .iter()
                     .map(|edge| (*dst_id, edge))
                     .collect::<Vec<(usize, &E)>>()
             })
@@ -456,7 +518,7 @@ impl<W: Clone, E: Edge<W> + Clone, Dir: EdgeDir> GraphStorage<W, E, Dir> for Adj
     fn neighbors(&self, src_id: usize) -> Result<Vec<usize>> {
         Ok(self
             .get_map(src_id)?
-            .into_iter()
+            .iter()
             .filter_map(|(dst_id, edges)| {
                 if !edges.is_empty() {
                     Some(*dst_id)
@@ -479,7 +541,7 @@ impl<W: Clone, E: Edge<W> + Clone, Dir: EdgeDir> GraphStorage<W, E, Dir> for Adj
     /// # Complexity
     /// O(|E<sub>src->dst</sub>|)
     fn edges_between(&self, src_id: usize, dst_id: usize) -> Result<Vec<&E>> {
-        Ok(self.get_edges(src_id, dst_id)?.into_iter().collect())
+        Ok(self.get_edges(src_id, dst_id)?.iter().collect())
     }
 
     /// # Arguments
@@ -523,7 +585,7 @@ impl<W: Clone, E: Edge<W> + Clone, Dir: EdgeDir> GraphStorage<W, E, Dir> for Adj
             for (dst_id, src_to_dst_edges) in self[*src_id].iter() {
                 edges.append(
                     &mut src_to_dst_edges
-                        .into_iter()
+                        .iter()
                         .map(|edge| (*src_id, *dst_id, edge))
                         .collect(),
                 )
@@ -544,7 +606,11 @@ impl<W: Clone, E: Edge<W> + Clone, Dir: EdgeDir> GraphStorage<W, E, Dir> for Adj
     /// # Complexity
     /// O(1)
     fn has_any_edge(&self, src_id: usize, dst_id: usize) -> Result<bool> {
-        Ok(self.get_map(src_id)?.contains_key(&dst_id))
+        // Removing the last edge between two vertices leaves an empty list of edges behind.
+        Ok(self
+            .get_map(src_id)?
+            .get(&dst_id)
+            .map_or(false, |edges| !edges.is_empty()))
     }
 
     /// # Note:
@@ -658,6 +724,7 @@ impl<W: Clone + 'static, E: Edge<W> + Clone, Dir: EdgeDir> Clone for AdjMap<W, E
             map: self.map.clone(),
 
             reusable_vertex_ids: self.reusable_vertex_ids.clone(),
+            vertex_id_policy: self.vertex_id_policy,
             reusable_edge_ids: self.reusable_edge_ids.clone(),
 
             vertex_count: self.vertex_count,
@@ -8,7 +8,7 @@ use anyhow::Result;
 use quickcheck::Arbitrary;
 
 use crate::graph::{DefaultEdge, DirectedEdge, Edge, EdgeDir, FlowEdge, UndirectedEdge};
-use crate::storage::{Error, GraphStorage};
+use crate::storage::{Error, GraphStorage, VertexIdPolicy};
 
 /// An adjacency matrix that uses [`undirected`](crate::graph::UndirectedEdge) [`default edges`](crate::graph::DefaultEdge).
 pub type Mat<W, Dir = UndirectedEdge> = AdjMatrix<W, DefaultEdge<W>, Dir>;
@@ -28,9 +28,9 @@ pub type DiFlowMat<W> = AdjMatrix<W, FlowEdge<W>, DirectedEdge>;
 /// ## Note
 /// From now on
 /// * |V|: Means total number of vertices that are stored in the storage.
-/// Note that this is different from number of vertices that are valid.
-/// Because even if you remove a vertex from storage, the allocated memory for that vertex will not get freed and will be reused again when adding a new vertex.
-/// You can retrieve the amount of |V| using `total_vertex_count` function(as opposed to number of vertices which can be retrieved using `vertex_count` function).
+///   Note that this is different from number of vertices that are valid.
+///   Because even if you remove a vertex from storage, the allocated memory for that vertex will not get freed and will be reused again when adding a new vertex.
+///   You can retrieve the amount of |V| using `total_vertex_count` function(as opposed to number of vertices which can be retrieved using `vertex_count` function).
 /// * |E|: Means total number of edges.
 /// * |E<sub>out</sub>|: Means number of edges exiting a vertex(out degree of the vertex).
 /// * |E<sub>src->dst</sub>|: Means number of edges from vertex with id: `src` to vertex with id: `dst`.
@@ -48,6 +48,7 @@ pub struct AdjMatrix<W, E: Edge<W>, Dir: EdgeDir = UndirectedEdge> {
     vec: Vec<Vec<E>>,
 
     reusable_vertex_ids: HashSet<usize>,
+    vertex_id_policy: VertexIdPolicy,
 
     max_edge_id: usize,
     reusable_edge_ids: HashSet<usize>,
@@ -87,6 +88,7 @@ impl<W: Any + Clone, E: Edge<W> + Clone, Dir: EdgeDir> AdjMatrix<W, E, Dir> {
         AdjMatrix {
             vec: vec![],
             reusable_vertex_ids: HashSet::new(),
+            vertex_id_policy: VertexIdPolicy::default(),
 
             max_edge_id: 0,
             reusable_edge_ids: HashSet::new(),
@@ -105,6 +107,10 @@ impl<W: Any + Clone, E: Edge<W> + Clone, Dir: EdgeDir> AdjMatrix<W, E, Dir> {
     // # Complexity
     // O(1)
     fn next_reusable_vertex_id(&mut self) -> Option<usize> {
+        if self.vertex_id_policy == VertexIdPolicy::Tombstone {
+            return None;
+        }
+
         if let Some(id) = self.reusable_vertex_ids.iter().take(1).next().copied() {
             self.reusable_vertex_ids.remove(&id);
 
@@ -290,12 +296,60 @@ impl<W: Any + Clone, E: Edge<W> + Clone, Dir: EdgeDir> GraphStorage<W, E, Dir>
                 self.vec.len() + self.total_vertex_count() + 1
             };
 
-            self.vec.resize_with(new_size, || vec![]);
+            // Under `Tombstone` policy, ids of the removed vertices are still taken.
+            let vertex_id = self.total_vertex_count();
+
+            self.vec.resize_with(new_size, std::vec::Vec::new);
 
             self.vertex_count += 1;
 
-            self.vertex_count - 1
+            vertex_id
+        }
+    }
+
+    /// Adds `count` vertices to the storage.
+    ///
+    /// Reusable ids are used first. Then memory for the rest of vertices is allocated at once.
+    ///
+    /// # Arguments
+    /// `count`: Number of vertices to add.
+    ///
+    /// # Returns
+    /// Unique ids of the newly added vertices.
+    ///
+    /// # Complexity
+    /// O(|V|<sup>2</sup>) after adding the vertices.
+    ///
+    /// # Panics
+    /// If number of vertices exceeds the maximum value that `usize` can represent.
+    fn add_vertices(&mut self, count: usize) -> Vec<usize> {
+        let mut vertex_ids = Vec::with_capacity(count);
+        while vertex_ids.len() < count {
+            if let Some(reusable_id) = self.next_reusable_vertex_id() {
+                self.vertex_count += 1;
+
+                vertex_ids.push(reusable_id);
+            } else {
+                break;
+            }
         }
+
+        let first_new_id = self.total_vertex_count();
+        let new_total_vertex_count = first_new_id + (count - vertex_ids.len());
+
+        // Check `add_vertex` for how the length of the vector grows with each vertex.
+        let new_size = if self.is_directed() {
+            new_total_vertex_count * new_total_vertex_count
+        } else {
+            (new_total_vertex_count * (new_total_vertex_count + 1)) >> 1
+        };
+
+        self.vec.resize_with(new_size, std::vec::Vec::new);
+
+        self.vertex_count += new_total_vertex_count - first_new_id;
+        vertex_ids.extend(first_new_id..new_total_vertex_count);
+
+        vertex_ids
     }
 
     /// Removes the vertex with id: `vertex_id` from storage.
@@ -328,6 +382,20 @@ impl<W: Any + Clone, E: Edge<W> + Clone, Dir: EdgeDir> GraphStorage<W, E, Dir>
         Ok(())
     }
 
+    /// # Returns
+    /// Policy that storage uses for ids of the removed vertices.
+    fn vertex_id_policy(&self) -> VertexIdPolicy {
+        self.vertex_id_policy
+    }
+
+    /// Changes the policy that storage uses for ids of the removed vertices.
+    ///
+    /// # Arguments
+    /// `policy`: New policy.
+    fn set_vertex_id_policy(&mut self, policy: VertexIdPolicy) {
+        self.vertex_id_policy = policy
+    }
+
     /// Adds `edge` from vertex with id `src_id`: to vertex with id: `dst_id`.
     ///
     /// # Arguments
@@ -421,6 +489,27 @@ impl<W: Any + Clone, E: Edge<W> + Clone, Dir: EdgeDir> GraphStorage<W, E, Dir>
         }
     }
 
+    /// Reverses direction of all edges in place by transposing the matrix.
+    /// Ids of the edges remain unchanged.
+    ///
+    /// # Complexity
+    /// O(|V|<sup>2</sup>)
+    fn reverse_edges(&mut self) {
+        if Dir::is_undirected() {
+            return;
+        }
+
+        let total_vertex_count = self.total_vertex_count();
+        for src_id in 0..total_vertex_count {
+            for dst_id in src_id + 1..total_vertex_count {
+                self.vec.swap(
+                    utils::from_ij(src_id, dst_id, true),
+                    utils::from_ij(dst_id, src_id, true),
+                );
+            }
+        }
+    }
+
     /// # Returns
     /// Number of vertices in the storage.
     ///
@@ -446,7 +535,6 @@ impl<W: Any + Clone, E: Edge<W> + Clone, Dir: EdgeDir> GraphStorage<W, E, Dir>
     /// O(|V|)
     fn vertices(&self) -> Vec<usize> {
         (0..self.total_vertex_count())
-            .into_iter()
             .filter(|v_id| !self.reusable_vertex_ids.contains(v_id))
             .collect()
     }
@@ -474,7 +562,7 @@ impl<W: Any + Clone, E: Edge<W> + Clone, Dir: EdgeDir> GraphStorage<W, E, Dir>
                 // `dst_id` comes from  `vertices()` so it's always valid. `src_id` is checked to be valid at the start of this function.
                 // So it's reasonable to use `get_unsafe`.
                 self.get_unsafe(src_id, dst_id)
-                    .into_iter()
+                    .iter()
                     .map(|edge| (dst_id, edge))
                     .collect::<Vec<(usize, &E)>>()
             })
@@ -704,6 +792,7 @@ impl<W: Any + Clone, E: Edge<W> + Clone, Dir: EdgeDir> Clone for AdjMatrix<W, E,
             vec: self.vec.clone(),
 
             reusable_vertex_ids: self.reusable_vertex_ids.clone(),
+            vertex_id_policy: self.vertex_id_policy,
 
             max_edge_id: self.max_edge_id,
             reusable_edge_ids: self.reusable_edge_ids.clone(),
@@ -874,6 +963,37 @@ mod tests {
             .all(|(src_id, dst_id)| !matrix.has_any_edge(src_id, dst_id).unwrap()));
     }
 
+    #[test]
+    fn add_vertices() {
+        // Given: Directed and undirected matrices with one removed vertex each.
+        let mut di_matrix = DiMat::<usize>::init();
+        let a = di_matrix.add_vertex();
+        di_matrix.add_vertex();
+        di_matrix.remove_vertex(a).unwrap();
+
+        let mut matrix = Mat::<usize>::init();
+        let a = matrix.add_vertex();
+        matrix.add_vertex();
+        matrix.remove_vertex(a).unwrap();
+
+        // When: Adding 3 vertices at once.
+        let di_vertices = di_matrix.add_vertices(3);
+        let vertices = matrix.add_vertices(3);
+
+        // Then: Removed vertex gets reused and memory for the other two gets allocated.
+        assert_eq!(di_vertices, vec![0, 2, 3]);
+        assert_eq!(di_matrix.vertex_count(), 4);
+        assert_eq!(di_matrix.total_vertex_count(), 4);
+        assert_eq!(di_matrix.vec.len(), 16);
+        assert_eq!(di_matrix.reusable_vertex_ids.len(), 0);
+
+        assert_eq!(vertices, vec![0, 2, 3]);
+        assert_eq!(matrix.vertex_count(), 4);
+        assert_eq!(matrix.total_vertex_count(), 4);
+        assert_eq!(matrix.vec.len(), 10);
+        assert_eq!(matrix.reusable_vertex_ids.len(), 0);
+    }
+
     #[test]
     fn directed_delete_vertex() {
         // Given: Directed graph
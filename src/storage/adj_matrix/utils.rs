@@ -57,6 +57,6 @@ pub fn from_ij(mut i: usize, mut j: usize, is_directed: bool) -> usize {
             std::mem::swap(&mut i, &mut j);
         }
         // Prevent division: i * (i + 1) is definitely an even number thus i * (i + 1) / 2 == i * (i + 1) >> 1.
-        (i * (i + 1) >> 1) + j
+        ((i * (i + 1)) >> 1) + j
     }
 }
@@ -13,6 +13,18 @@ use crate::graph::{Edge, EdgeDir};
 
 use anyhow::Result;
 
+/// Determines what happens to the id of a removed vertex.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VertexIdPolicy {
+    /// Id of the removed vertex gets reused for one of the next added vertices.
+    #[default]
+    Reuse,
+
+    /// Id of the removed vertex is left as a hole(tombstone) and is never given to a newly added vertex.
+    /// So vertex ids only grow, which keeps ids stable for algorithms that index arrays by vertex id.
+    Tombstone,
+}
+
 /// Defines the api that a storage must provide in order to be usable for storing graph data.
 ///
 /// ## Implementing a storage
@@ -45,6 +57,17 @@ pub trait GraphStorage<W, E: Edge<W>, Dir: EdgeDir> {
     /// Unique id of the newly added vertex.
     fn add_vertex(&mut self) -> usize;
 
+    /// Adds `count` vertices to the storage.
+    ///
+    /// # Arguments
+    /// `count`: Number of vertices to add.
+    ///
+    /// # Returns
+    /// Unique ids of the newly added vertices.
+    fn add_vertices(&mut self, count: usize) -> Vec<usize> {
+        (0..count).map(|_| self.add_vertex()).collect()
+    }
+
     /// Removes the vertex with id: `vertex_id` from storage.
     ///
     /// # Arguments
@@ -55,6 +78,28 @@ pub trait GraphStorage<W, E: Edge<W>, Dir: EdgeDir> {
     /// * `Err`: [`VertexNotFound`](crate::storage::ErrorKind::VertexNotFound) if vertex with specified id does not exist.
     fn remove_vertex(&mut self, vertex_id: usize) -> Result<()>;
 
+    /// Default implementation returns [`Reuse`](crate::storage::VertexIdPolicy::Reuse),
+    /// which is how storages that do not support choosing a policy treat ids of the removed vertices.
+    ///
+    /// # Returns
+    /// Policy that storage uses for ids of the removed vertices.
+    fn vertex_id_policy(&self) -> VertexIdPolicy {
+        VertexIdPolicy::Reuse
+    }
+
+    /// Changes the policy that storage uses for ids of the removed vertices.
+    ///
+    /// Ids of vertices removed under [`Tombstone`](crate::storage::VertexIdPolicy::Tombstone) policy become reusable
+    /// if policy changes to [`Reuse`](crate::storage::VertexIdPolicy::Reuse).
+    ///
+    /// Default implementation does nothing, so storages that do not override it keep reusing ids of the removed vertices
+    /// and [`vertex_id_policy`](crate::storage::GraphStorage::vertex_id_policy) keeps returning `Reuse`.
+    /// Storages defined in this module override it.
+    ///
+    /// # Arguments
+    /// `policy`: New policy.
+    fn set_vertex_id_policy(&mut self, _policy: VertexIdPolicy) {}
+
     /// # Arguments
     /// `vertex_id`: Id of the vertex.
     ///
@@ -102,6 +147,39 @@ pub trait GraphStorage<W, E: Edge<W>, Dir: EdgeDir> {
     /// * `Err`: [`EdgeNotFound`](crate::storage::ErrorKind::EdgeNotFound) if edge with specified id does not exist.
     fn remove_edge(&mut self, src_id: usize, dst_id: usize, edge_id: usize) -> Result<E>;
 
+    /// Reverses direction of all edges in place, so each edge from `src_id` to `dst_id` goes from `dst_id` to `src_id` afterwards.
+    /// Storages of undirected edges remain unchanged.
+    ///
+    /// Default implementation removes each edge and adds it back in the reverse direction.
+    /// So ids of the edges may change. Storages defined in this module override it to keep the ids.
+    fn reverse_edges(&mut self) {
+        if Dir::is_undirected() {
+            return;
+        }
+
+        let edges: Vec<(usize, usize, usize)> = self
+            .edges()
+            .into_iter()
+            .map(|(src_id, dst_id, edge)| (src_id, dst_id, edge.get_id()))
+            .collect();
+
+        // Edges get removed first so reversed edges are not mistaken for the original ones.
+        let removed_edges: Vec<(usize, usize, E)> = edges
+            .into_iter()
+            .map(|(src_id, dst_id, edge_id)| {
+                (
+                    src_id,
+                    dst_id,
+                    self.remove_edge(src_id, dst_id, edge_id).unwrap(),
+                )
+            })
+            .collect();
+
+        for (src_id, dst_id, edge) in removed_edges {
+            self.add_edge(dst_id, src_id, edge).unwrap();
+        }
+    }
+
     /// # Arguments
     /// `edge_id`: Id of the edge.
     ///
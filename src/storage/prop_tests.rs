@@ -1,4 +1,5 @@
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod prop_tests {
     use std::{collections::HashSet, fmt::Debug};
 